@@ -6,7 +6,15 @@ CREATE TABLE axcelium.users (
   application_id UUID,
   username TEXT,
   email TEXT,
+  username_normalized TEXT,
+  email_normalized TEXT,
   hashed_password TEXT,
+  first_name TEXT,
+  last_name TEXT,
+  locale TEXT,
+  phone TEXT,
+  avatar_url TEXT,
+  metadata TEXT,
   created_at TIMESTAMP,
   updated_at TIMESTAMP,
   is_active BOOLEAN,
@@ -37,8 +45,8 @@ FROM axcelium.users
 WHERE organization_id IS NOT NULL
   AND application_id IS NOT NULL
   AND user_id IS NOT NULL
-  AND username IS NOT NULL PRIMARY KEY (
-    (organization_id, application_id, username),
+  AND username_normalized IS NOT NULL PRIMARY KEY (
+    (organization_id, application_id, username_normalized),
     user_id
   );
 CREATE MATERIALIZED VIEW users_by_email_app_org AS
@@ -47,7 +55,38 @@ FROM axcelium.users
 WHERE organization_id IS NOT NULL
   AND application_id IS NOT NULL
   AND user_id IS NOT NULL
-  AND email IS NOT NULL PRIMARY KEY ((organization_id, application_id, email),user_id);
+  AND email_normalized IS NOT NULL PRIMARY KEY ((organization_id, application_id, email_normalized),user_id);
+CREATE TABLE axcelium.username_reservations (
+  organization_id UUID,
+  application_id UUID,
+  username_normalized TEXT,
+  user_id UUID,
+  PRIMARY KEY ((organization_id, application_id, username_normalized))
+);
+CREATE TABLE axcelium.email_reservations (
+  organization_id UUID,
+  application_id UUID,
+  email_normalized TEXT,
+  user_id UUID,
+  PRIMARY KEY ((organization_id, application_id, email_normalized))
+);
+CREATE TABLE axcelium.users_pending_purge (
+  deactivated_day TEXT,
+  organization_id UUID,
+  application_id UUID,
+  user_id UUID,
+  deactivated_at TIMESTAMP,
+  PRIMARY KEY ((deactivated_day), organization_id, application_id, user_id)
+);
+CREATE TABLE axcelium.user_password_history (
+  organization_id UUID,
+  application_id UUID,
+  user_id UUID,
+  changed_at TIMESTAMP,
+  hashed_password TEXT,
+  PRIMARY KEY ((organization_id, application_id, user_id), changed_at)
+) WITH CLUSTERING
+ORDER BY (changed_at DESC);
 CREATE TABLE axcelium.user_count_by_app (
   organization_id UUID,
   application_id UUID,
@@ -102,6 +141,12 @@ CREATE TABLE refresh_tokens (
   issued_at TIMESTAMP,
   expires_at TIMESTAMP,
   revoked BOOLEAN,
+  last_used_at TIMESTAMP,
+  session_started_at TIMESTAMP,
+  device_name TEXT,
+  ip_address TEXT,
+  user_agent TEXT,
+  scope TEXT,
   PRIMARY KEY ((organization_id, application_id, token_id))
 ) WITH default_time_to_live = 2592000;
 CREATE MATERIALIZED VIEW refresh_tokens_with_token_version AS
@@ -136,16 +181,29 @@ CREATE TABLE axcelium.roles_by_app (
   description TEXT,
   permissions
   SET < TEXT >,
+  parent_role_ids
+  SET < UUID >,
     created_at TIMESTAMP,
     updated_at TIMESTAMP,
     PRIMARY KEY ((organization_id, application_id), role_id)
 )WITH cdc = {'enabled':true};
+CREATE TABLE axcelium.permissions_by_app (
+  organization_id UUID,
+  application_id UUID,
+  name TEXT,
+  description TEXT,
+  category TEXT,
+  created_at TIMESTAMP,
+  updated_at TIMESTAMP,
+  PRIMARY KEY ((organization_id, application_id), name)
+);
 CREATE TABLE axcelium.user_roles_by_user (
   organization_id UUID,
   application_id UUID,
   user_id UUID,
   role_id UUID,
   assigned_at TIMESTAMP,
+  expires_at TIMESTAMP,
   PRIMARY KEY (
     (organization_id, application_id, user_id),
     role_id
@@ -157,8 +215,118 @@ CREATE TABLE axcelium.role_users_by_role (
   role_id UUID,
   user_id UUID,
   assigned_at TIMESTAMP,
+  expires_at TIMESTAMP,
   PRIMARY KEY (
     (organization_id, application_id, role_id),
     user_id
   )
 );
+CREATE TABLE axcelium.role_assignments_pending_expiry (
+  expires_day TEXT,
+  organization_id UUID,
+  application_id UUID,
+  user_id UUID,
+  role_id UUID,
+  expires_at TIMESTAMP,
+  PRIMARY KEY ((expires_day), organization_id, application_id, user_id, role_id)
+);
+CREATE TABLE axcelium.groups_by_app (
+  organization_id UUID,
+  application_id UUID,
+  group_id UUID,
+  name TEXT,
+  description TEXT,
+  role_ids
+  SET < UUID >,
+    created_at TIMESTAMP,
+    updated_at TIMESTAMP,
+    PRIMARY KEY ((organization_id, application_id), group_id)
+);
+CREATE TABLE axcelium.group_members_by_group (
+  organization_id UUID,
+  application_id UUID,
+  group_id UUID,
+  user_id UUID,
+  added_at TIMESTAMP,
+  PRIMARY KEY (
+    (organization_id, application_id, group_id),
+    user_id
+  )
+);
+CREATE TABLE axcelium.groups_by_user (
+  organization_id UUID,
+  application_id UUID,
+  user_id UUID,
+  group_id UUID,
+  added_at TIMESTAMP,
+  PRIMARY KEY (
+    (organization_id, application_id, user_id),
+    group_id
+  )
+);
+CREATE TABLE axcelium.application_signing_keys (
+  organization_id UUID,
+  application_id UUID,
+  kid TEXT,
+  public_key TEXT,
+  encrypted_private_key TEXT,
+  private_key_nonce TEXT,
+  created_at TIMESTAMP,
+  retired_at TIMESTAMP,
+  expires_at TIMESTAMP,
+  PRIMARY KEY ((organization_id, application_id), kid)
+);
+CREATE MATERIALIZED VIEW application_signing_keys_by_kid AS
+SELECT *
+FROM axcelium.application_signing_keys
+WHERE kid IS NOT NULL
+  AND organization_id IS NOT NULL
+  AND application_id IS NOT NULL PRIMARY KEY (kid, organization_id, application_id);
+CREATE TABLE axcelium.service_accounts (
+  organization_id UUID,
+  application_id UUID,
+  service_account_id UUID,
+  application_client_id UUID,
+  client_id UUID,
+  name TEXT,
+  description TEXT,
+  encrypted_client_secret TEXT,
+  is_active BOOLEAN,
+  created_at TIMESTAMP,
+  updated_at TIMESTAMP,
+  PRIMARY KEY ((organization_id, application_id), service_account_id)
+);
+CREATE MATERIALIZED VIEW service_accounts_by_client_id AS
+SELECT *
+FROM axcelium.service_accounts
+WHERE client_id IS NOT NULL
+  AND organization_id IS NOT NULL
+  AND application_id IS NOT NULL
+  AND service_account_id IS NOT NULL PRIMARY KEY (
+    client_id,
+    organization_id,
+    application_id,
+    service_account_id
+  );
+CREATE TABLE axcelium.user_identities (
+  organization_id UUID,
+  application_id UUID,
+  provider_id TEXT,
+  subject TEXT,
+  user_id UUID,
+  email TEXT,
+  created_at TIMESTAMP,
+  PRIMARY KEY ((organization_id, application_id), provider_id, subject)
+);
+CREATE MATERIALIZED VIEW user_identities_by_user AS
+SELECT *
+FROM axcelium.user_identities
+WHERE user_id IS NOT NULL
+  AND organization_id IS NOT NULL
+  AND application_id IS NOT NULL
+  AND provider_id IS NOT NULL
+  AND subject IS NOT NULL PRIMARY KEY (
+    (organization_id, application_id, user_id),
+    provider_id,
+    subject
+  );
@@ -0,0 +1,82 @@
+use crate::application::dto::payload::application::{
+    ApplicationIdQuery, UpdateIdentityProvidersPayload, UpdateRedirectUrisPayload,
+};
+use crate::application::dto::response::application::{
+    ApplicationConfigResponse, IdentityProvidersResponse, RedirectUrisResponse,
+};
+use crate::application::services::applications::{
+    config::ApplicationConfigService,
+    update_identity_providers::UpdateIdentityProvidersService,
+    update_redirect_uris::UpdateRedirectUrisService,
+};
+use crate::domain::{
+    entities::apporg_client_id::CleanAppOrgByClientId, errors::repositories_errors::ApiError,
+};
+use actix_web::HttpMessage;
+use actix_web::{Result, web};
+use serde_json::Value;
+
+pub async fn update_redirect_uris_handle(
+    req: actix_web::HttpRequest,
+    post_data: web::Json<UpdateRedirectUrisPayload>,
+    application_service: web::Data<dyn UpdateRedirectUrisService>,
+) -> Result<web::Json<RedirectUrisResponse>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = application_service
+        .execute(apporg, post_data.into_inner().redirect_uris)
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn update_identity_providers_handle(
+    req: actix_web::HttpRequest,
+    post_data: web::Json<UpdateIdentityProvidersPayload>,
+    application_service: web::Data<dyn UpdateIdentityProvidersService>,
+) -> Result<web::Json<IdentityProvidersResponse>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = application_service
+        .execute(apporg, post_data.into_inner().identity_providers)
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_application_config_handle(
+    req: actix_web::HttpRequest,
+    path: web::Path<ApplicationIdQuery>,
+    application_service: web::Data<dyn ApplicationConfigService>,
+) -> Result<web::Json<ApplicationConfigResponse>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = application_service
+        .get(apporg, path.application_id)
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn update_application_config_handle(
+    req: actix_web::HttpRequest,
+    path: web::Path<ApplicationIdQuery>,
+    post_data: web::Json<Value>,
+    application_service: web::Data<dyn ApplicationConfigService>,
+) -> Result<web::Json<ApplicationConfigResponse>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = application_service
+        .update(apporg, path.application_id, post_data.into_inner())
+        .await?;
+    Ok(web::Json(res))
+}
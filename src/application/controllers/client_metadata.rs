@@ -0,0 +1,19 @@
+use crate::domain::value_objects::client_metadata::ClientMetadata;
+use actix_web::{HttpRequest, http::header};
+
+/// Device details of the HTTP caller. The address honours `Forwarded` and
+/// `X-Forwarded-For`, so it is only fit for display.
+pub fn request_client_metadata(req: &HttpRequest) -> ClientMetadata {
+    ClientMetadata {
+        device_name: None,
+        ip_address: req
+            .connection_info()
+            .realip_remote_addr()
+            .map(|addr| addr.to_string()),
+        user_agent: req
+            .headers()
+            .get(header::USER_AGENT)
+            .and_then(|hv| hv.to_str().ok())
+            .map(|s| s.to_string()),
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    application::{
+        dto::{
+            payload::{
+                group::{
+                    AddGroupMemberPayload, AssignGroupRolePayload, CreateGroupPayload,
+                    GroupIdQuery, GroupMemberQuery, GroupRoleQuery, UpdateGroupPayload,
+                },
+                user::PaginationQuery,
+            },
+            response::{
+                group::{
+                    CreateGroupResponse, GetGroupMembersResponse, GetGroupsResponse, GroupResponse,
+                },
+                refresh_token::SimpleResponse,
+            },
+        },
+        services::groups::{
+            manage::ManageGroupsService, members::GroupMembersService, roles::GroupRolesService,
+        },
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId, errors::repositories_errors::ApiError,
+    },
+};
+use actix_web::HttpMessage;
+use actix_web::{Result, web};
+
+pub async fn create_group_handler(
+    req: actix_web::HttpRequest,
+    group_service: web::Data<dyn ManageGroupsService>,
+    post_data: web::Json<CreateGroupPayload>,
+) -> Result<web::Json<CreateGroupResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = group_service.create(apporg, post_data.into_inner()).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_groups_handler(
+    req: actix_web::HttpRequest,
+    group_service: web::Data<dyn ManageGroupsService>,
+) -> Result<web::Json<GetGroupsResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = group_service.list(apporg).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_group_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupIdQuery>,
+    group_service: web::Data<dyn ManageGroupsService>,
+) -> Result<web::Json<GroupResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = group_service.get(apporg, path.group_id).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn update_group_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupIdQuery>,
+    post_data: web::Json<UpdateGroupPayload>,
+    group_service: web::Data<dyn ManageGroupsService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = group_service
+        .update(apporg, path.group_id, post_data.into_inner())
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn delete_group_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupIdQuery>,
+    group_service: web::Data<dyn ManageGroupsService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = group_service.delete(apporg, path.group_id).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_group_members_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupIdQuery>,
+    query: web::Query<PaginationQuery>,
+    members_service: web::Data<dyn GroupMembersService>,
+) -> Result<web::Json<GetGroupMembersResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let query = query.into_inner();
+    let res = members_service
+        .list(
+            apporg,
+            path.group_id,
+            query.page_size.unwrap_or(20),
+            query.paging_state,
+        )
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn add_group_member_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupIdQuery>,
+    post_data: web::Json<AddGroupMemberPayload>,
+    members_service: web::Data<dyn GroupMembersService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = members_service
+        .add(apporg, path.group_id, post_data.user_id)
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn remove_group_member_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupMemberQuery>,
+    members_service: web::Data<dyn GroupMembersService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = members_service
+        .remove(apporg, path.group_id, path.user_id)
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn assign_group_role_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupIdQuery>,
+    post_data: web::Json<AssignGroupRolePayload>,
+    roles_service: web::Data<dyn GroupRolesService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = roles_service
+        .assign(apporg, path.group_id, post_data.role_id)
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn remove_group_role_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GroupRoleQuery>,
+    roles_service: web::Data<dyn GroupRolesService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = roles_service
+        .remove(apporg, path.group_id, path.role_id)
+        .await?;
+    Ok(web::Json(res))
+}
@@ -1,6 +1,13 @@
+pub mod application_handle;
 pub mod cdc;
+pub mod client_metadata;
+pub mod group_handle;
 pub mod hello_handle;
+pub mod oauth_handle;
+pub mod permission_handle;
 pub mod queue;
 pub mod refresh_token_handle;
 pub mod role_handle;
+pub mod scim_handle;
+pub mod service_account_handle;
 pub mod user_handle;
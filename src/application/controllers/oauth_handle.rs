@@ -0,0 +1,377 @@
+use crate::application::controllers::client_metadata::request_client_metadata;
+use crate::application::dto::payload::oauth::{
+    AuthorizePayload, AuthorizeQuery, DeviceAuthorizationPayload, DeviceVerificationPayload,
+    DeviceVerificationQuery, DiscoveryQuery, FederationCallbackQuery, KeySetQuery,
+    TokenLookupPayload, TokenPayload,
+};
+use crate::application::dto::response::oauth::{
+    DeviceAuthorizationResponse, IntrospectionResponse, JwksResponse, OpenIdConfigurationResponse,
+    PaserkKeysResponse, TokenResponse, UserInfoResponse,
+};
+use crate::application::services::oauth::{
+    authorize::AuthorizeService, device::DeviceAuthorizationService, discovery::DiscoveryService,
+    federation::FederationService, introspect::IntrospectService, revoke::RevokeTokenService,
+    token::TokenService, userinfo::UserInfoService,
+};
+use crate::domain::{
+    entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+    errors::repositories_errors::ApiError,
+};
+use actix_web::http::header;
+use actix_web::{HttpMessage, HttpResponse, Result, web};
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn hidden_input(name: &str, value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!(
+            r#"<input type="hidden" name="{name}" value="{}">"#,
+            escape_html(value)
+        ),
+        None => String::new(),
+    }
+}
+
+/// The pages take credentials, so they must never render inside another
+/// site's frame.
+fn html_page(body: String) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .insert_header((header::CACHE_CONTROL, "no-store"))
+        .insert_header((header::X_FRAME_OPTIONS, "DENY"))
+        .insert_header((header::CONTENT_SECURITY_POLICY, "frame-ancestors 'none'"))
+        .body(body)
+}
+
+/// Minimal sign-in form; it posts the authorization request back to
+/// `/oauth/authorize` together with the user's credentials, or hands it to
+/// one of the application's identity providers.
+fn login_page(
+    request: &AuthorizeQuery,
+    apporg: &CleanAppOrgByClientId,
+    csrf_token: &str,
+    error: Option<&str>,
+) -> HttpResponse {
+    let client_id = request.client_id.to_string();
+    let fields = [
+        hidden_input("response_type", Some(&request.response_type)),
+        hidden_input("client_id", Some(&client_id)),
+        hidden_input("redirect_uri", Some(&request.redirect_uri)),
+        hidden_input("scope", request.scope.as_deref()),
+        hidden_input("state", request.state.as_deref()),
+        hidden_input("code_challenge", Some(&request.code_challenge)),
+        hidden_input(
+            "code_challenge_method",
+            request.code_challenge_method.as_deref(),
+        ),
+        hidden_input("nonce", request.nonce.as_deref()),
+    ]
+    .concat();
+    let providers = apporg
+        .get_config()
+        .map(|c| c.identity_providers)
+        .unwrap_or_default()
+        .iter()
+        .map(|p| {
+            format!(
+                r#"<form method="get" action="/oauth/federation/{}">
+{fields}
+<button type="submit">Sign in with {}</button>
+</form>"#,
+                escape_html(&p.id),
+                escape_html(&p.name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let error = error
+        .map(|e| format!("<p>{}</p>", escape_html(e)))
+        .unwrap_or_default();
+    let body = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Sign in to {name}</title></head>
+<body>
+<h1>Sign in to {name}</h1>
+{error}
+<form method="post" action="/oauth/authorize">
+{fields}
+{csrf}
+<input name="username" placeholder="Username or email" autocomplete="username" required>
+<input name="password" type="password" placeholder="Password" autocomplete="current-password" required>
+<button type="submit">Sign in</button>
+</form>
+{providers}
+</body>
+</html>"#,
+        name = escape_html(&apporg.application_name),
+        csrf = hidden_input("csrf_token", Some(csrf_token)),
+    );
+    html_page(body)
+}
+
+/// Form where the user enters the code shown on their device and approves
+/// or denies it with their credentials.
+fn device_page(
+    user_code: &str,
+    application_name: Option<&str>,
+    error: Option<&str>,
+) -> HttpResponse {
+    let title = match application_name {
+        Some(name) => format!("Connect a device to {}", escape_html(name)),
+        None => "Connect a device".to_string(),
+    };
+    let error = error
+        .map(|e| format!("<p>{}</p>", escape_html(e)))
+        .unwrap_or_default();
+    let body = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>{title}</title></head>
+<body>
+<h1>{title}</h1>
+{error}
+<form method="post" action="/oauth/device">
+<input name="user_code" placeholder="Code shown on your device" value="{user_code}" autocomplete="off" required>
+<input name="username" placeholder="Username or email" autocomplete="username" required>
+<input name="password" type="password" placeholder="Password" autocomplete="current-password" required>
+<button type="submit" name="action" value="approve">Allow</button>
+<button type="submit" name="action" value="deny">Deny</button>
+</form>
+</body>
+</html>"#,
+        user_code = escape_html(user_code),
+    );
+    html_page(body)
+}
+
+fn device_done_page(message: &str) -> HttpResponse {
+    html_page(format!(
+        r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Connect a device</title></head>
+<body>
+<h1>{}</h1>
+<p>You can return to your device.</p>
+</body>
+</html>"#,
+        escape_html(message)
+    ))
+}
+
+pub async fn authorize_handle(
+    query: web::Query<AuthorizeQuery>,
+    authorize_service: web::Data<dyn AuthorizeService>,
+) -> Result<HttpResponse, ApiError> {
+    let apporg = authorize_service.validate(&query).await?;
+    let csrf_token = authorize_service.issue_csrf_token(&apporg).await?;
+    Ok(login_page(&query, &apporg, &csrf_token, None))
+}
+
+pub async fn authorize_submit_handle(
+    form: web::Form<AuthorizePayload>,
+    authorize_service: web::Data<dyn AuthorizeService>,
+) -> Result<HttpResponse, ApiError> {
+    let form = form.into_inner();
+    let request = AuthorizeQuery::from(&form);
+    match authorize_service
+        .execute(
+            request.clone(),
+            form.csrf_token,
+            form.username,
+            form.password,
+        )
+        .await
+    {
+        Ok(location) => Ok(HttpResponse::Found()
+            .insert_header((header::LOCATION, location))
+            .finish()),
+        Err(e) if e.code == 401 || e.code == 403 => {
+            let apporg = authorize_service.validate(&request).await?;
+            let csrf_token = authorize_service.issue_csrf_token(&apporg).await?;
+            Ok(login_page(&request, &apporg, &csrf_token, Some(&e.message)))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn token_handle(
+    req: actix_web::HttpRequest,
+    form: web::Form<TokenPayload>,
+    token_service: web::Data<dyn TokenService>,
+) -> Result<HttpResponse, ApiError> {
+    let res: TokenResponse = token_service
+        .execute(form.into_inner(), request_client_metadata(&req))
+        .await?;
+    Ok(HttpResponse::Ok()
+        .insert_header((header::CACHE_CONTROL, "no-store"))
+        .json(res))
+}
+
+pub async fn federation_start_handle(
+    path: web::Path<String>,
+    query: web::Query<AuthorizeQuery>,
+    federation_service: web::Data<dyn FederationService>,
+) -> Result<HttpResponse, ApiError> {
+    let location = federation_service
+        .start(query.into_inner(), path.into_inner())
+        .await?;
+    Ok(HttpResponse::Found()
+        .insert_header((header::LOCATION, location))
+        .finish())
+}
+
+pub async fn federation_callback_handle(
+    query: web::Query<FederationCallbackQuery>,
+    federation_service: web::Data<dyn FederationService>,
+) -> Result<HttpResponse, ApiError> {
+    let query = query.into_inner();
+    let location = federation_service
+        .callback(query.state, query.code, query.error)
+        .await?;
+    Ok(HttpResponse::Found()
+        .insert_header((header::LOCATION, location))
+        .finish())
+}
+
+pub async fn device_authorization_handle(
+    form: web::Form<DeviceAuthorizationPayload>,
+    device_authorization_service: web::Data<dyn DeviceAuthorizationService>,
+) -> Result<HttpResponse, ApiError> {
+    let form = form.into_inner();
+    let res: DeviceAuthorizationResponse = device_authorization_service
+        .authorize(form.client_id, form.scope)
+        .await?;
+    Ok(HttpResponse::Ok()
+        .insert_header((header::CACHE_CONTROL, "no-store"))
+        .json(res))
+}
+
+pub async fn device_verification_handle(
+    query: web::Query<DeviceVerificationQuery>,
+    device_authorization_service: web::Data<dyn DeviceAuthorizationService>,
+) -> Result<HttpResponse, ApiError> {
+    let Some(user_code) = query.into_inner().user_code else {
+        return Ok(device_page("", None, None));
+    };
+    match device_authorization_service
+        .find_client_by_user_code(&user_code)
+        .await
+    {
+        Ok(apporg) => Ok(device_page(
+            &user_code,
+            Some(&apporg.application_name),
+            None,
+        )),
+        Err(e) if e.code == 404 => Ok(device_page(&user_code, None, Some(&e.message))),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn device_verification_submit_handle(
+    form: web::Form<DeviceVerificationPayload>,
+    device_authorization_service: web::Data<dyn DeviceAuthorizationService>,
+) -> Result<HttpResponse, ApiError> {
+    let form = form.into_inner();
+    let result = match form.action.as_str() {
+        "approve" => device_authorization_service
+            .approve(
+                &form.user_code,
+                form.username.as_deref().unwrap_or_default(),
+                form.password.as_deref().unwrap_or_default(),
+            )
+            .await
+            .map(|_| "Device connected"),
+        "deny" => device_authorization_service
+            .deny(
+                &form.user_code,
+                form.username.as_deref().unwrap_or_default(),
+                form.password.as_deref().unwrap_or_default(),
+            )
+            .await
+            .map(|_| "Device request denied"),
+        _ => return Err(ApiError::new("unknown action".to_string(), 400)),
+    };
+    match result {
+        Ok(message) => Ok(device_done_page(message)),
+        Err(e) if e.code == 401 || e.code == 404 => {
+            Ok(device_page(&form.user_code, None, Some(&e.message)))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn userinfo_handle(
+    req: actix_web::HttpRequest,
+    userinfo_service: web::Data<dyn UserInfoService>,
+) -> Result<web::Json<UserInfoResponse>, ApiError> {
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|hv| hv.to_str().ok())
+        .map(|s| s.to_string());
+    let res = userinfo_service.execute(token).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn introspect_handle(
+    req: actix_web::HttpRequest,
+    form: web::Form<TokenLookupPayload>,
+    introspect_service: web::Data<dyn IntrospectService>,
+) -> Result<web::Json<IntrospectionResponse>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = introspect_service
+        .execute(apporg, form.into_inner())
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn revoke_handle(
+    req: actix_web::HttpRequest,
+    form: web::Form<TokenLookupPayload>,
+    revoke_token_service: web::Data<dyn RevokeTokenService>,
+) -> Result<HttpResponse, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    revoke_token_service
+        .execute(apporg, form.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+pub async fn openid_configuration_handle(
+    query: web::Query<DiscoveryQuery>,
+    discovery_service: web::Data<dyn DiscoveryService>,
+) -> Result<web::Json<OpenIdConfigurationResponse>, ApiError> {
+    Ok(web::Json(
+        discovery_service.openid_configuration(query.client_id),
+    ))
+}
+
+pub async fn jwks_handle(
+    query: web::Query<KeySetQuery>,
+    discovery_service: web::Data<dyn DiscoveryService>,
+) -> Result<web::Json<JwksResponse>, ApiError> {
+    Ok(web::Json(discovery_service.jwks(query.client_id).await?))
+}
+
+pub async fn paserk_handle(
+    query: web::Query<KeySetQuery>,
+    discovery_service: web::Data<dyn DiscoveryService>,
+) -> Result<web::Json<PaserkKeysResponse>, ApiError> {
+    Ok(web::Json(discovery_service.paserks(query.client_id).await?))
+}
@@ -0,0 +1,118 @@
+use crate::{
+    application::{
+        dto::{
+            payload::permission::{
+                CreatePermissionPayload, DeletePermissionQuery, PermissionNameQuery,
+                UpdatePermissionPayload,
+            },
+            response::{
+                permission::{
+                    GetPermissionsResponse, GetUnresolvedRolesResponse, PermissionResponse,
+                },
+                refresh_token::SimpleResponse,
+            },
+        },
+        services::permissions::catalog::PermissionCatalogService,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId, errors::repositories_errors::ApiError,
+    },
+};
+use actix_web::HttpMessage;
+use actix_web::{Result, web};
+
+pub async fn create_permission_handler(
+    req: actix_web::HttpRequest,
+    permission_service: web::Data<dyn PermissionCatalogService>,
+    post_data: web::Json<CreatePermissionPayload>,
+) -> Result<web::Json<PermissionResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = permission_service
+        .create(apporg, post_data.into_inner())
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_permissions_handler(
+    req: actix_web::HttpRequest,
+    permission_service: web::Data<dyn PermissionCatalogService>,
+) -> Result<web::Json<GetPermissionsResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = permission_service.list(apporg).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_permission_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<PermissionNameQuery>,
+    permission_service: web::Data<dyn PermissionCatalogService>,
+) -> Result<web::Json<PermissionResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = permission_service
+        .get(apporg, path.into_inner().name)
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn update_permission_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<PermissionNameQuery>,
+    post_data: web::Json<UpdatePermissionPayload>,
+    permission_service: web::Data<dyn PermissionCatalogService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = permission_service
+        .update(apporg, path.into_inner().name, post_data.into_inner())
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn delete_permission_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<PermissionNameQuery>,
+    query: web::Query<DeletePermissionQuery>,
+    permission_service: web::Data<dyn PermissionCatalogService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = permission_service
+        .delete(
+            apporg,
+            path.into_inner().name,
+            query.strict.unwrap_or(false),
+        )
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_unresolved_roles_handler(
+    req: actix_web::HttpRequest,
+    permission_service: web::Data<dyn PermissionCatalogService>,
+) -> Result<web::Json<GetUnresolvedRolesResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = permission_service.unresolved_roles(apporg).await?;
+    Ok(web::Json(res))
+}
@@ -1,11 +1,17 @@
 use crate::application::dto::payload::refresh_token::{
     CreateTokenPayload, GetTokenQuery, GetUserQuery, PaginationRefreshTokensByUserQuery, RotateTokenPayload
 };
-use crate::application::dto::response::refresh_token::{CreateTokenResponse, GetRefreshTokensResponse, SimpleResponse};
+use crate::application::dto::response::refresh_token::{
+    CreateTokenResponse, GetRefreshTokensResponse, GetSessionsResponse,
+    RevokeAllSessionsResponse, SimpleResponse,
+};
+use crate::application::controllers::client_metadata::request_client_metadata;
 use crate::application::services::refresh_token::create::CreateRefreshTokenService;
 use crate::application::services::refresh_token::get::GetRefreshTokenService;
 use crate::application::services::refresh_token::revoke::RevokeRefreshTokenService;
+use crate::application::services::refresh_token::revoke_all::RevokeAllSessionsService;
 use crate::application::services::refresh_token::rotate::RotateRefreshTokenService;
+use crate::application::services::refresh_token::sessions::GetSessionsService;
 use crate::domain::{
     entities::apporg_client_id::CleanAppOrgByClientId, errors::repositories_errors::ApiError,
 };
@@ -21,12 +27,10 @@ pub async fn create_refresh_token_handle(
         .get::<CleanAppOrgByClientId>()
         .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
         .cloned()?;
+    let post_data = post_data.into_inner();
+    let client = post_data.client.sanitized().or(request_client_metadata(&req));
     let token = token_service
-        .execute(
-            apporg,
-            post_data.user_id.clone(),
-            post_data.private_key.clone(),
-        )
+        .execute(apporg, post_data.user_id, client, None)
         .await?;
     Ok(web::Json(token))
 }
@@ -40,13 +44,10 @@ pub async fn rotate_refresh_token_handle(
         .get::<CleanAppOrgByClientId>()
         .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
         .cloned()?;
+    let post_data = post_data.into_inner();
+    let client = post_data.client.sanitized().or(request_client_metadata(&req));
     let res = token_service
-        .execute(
-            apporg,
-            post_data.refresh_token.clone(),
-            post_data.public_key.clone(),
-            post_data.private_key.clone(),
-        )
+        .execute(apporg, post_data.refresh_token, client)
         .await?;
     Ok(web::Json(res))
 }
@@ -84,3 +85,40 @@ pub async fn get_refresh_token_handle(
     let res =token_service.execute(apporg, user_id, page_size, paging_state).await?;
     Ok(web::Json(res))
 }
+
+pub async fn get_sessions_handle(
+    req: actix_web::HttpRequest,
+    path: web::Path<GetUserQuery>,
+    query: web::Query<PaginationRefreshTokensByUserQuery>,
+    sessions_service: web::Data<dyn GetSessionsService>,
+) -> Result<web::Json<GetSessionsResponse>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let query = query.into_inner();
+    let res = sessions_service
+        .execute(
+            apporg,
+            path.user_id,
+            query.page_size.unwrap_or(20),
+            query.paging_state,
+        )
+        .await?;
+    Ok(web::Json(res))
+}
+
+pub async fn revoke_all_sessions_handle(
+    req: actix_web::HttpRequest,
+    path: web::Path<GetUserQuery>,
+    revoke_service: web::Data<dyn RevokeAllSessionsService>,
+) -> Result<web::Json<RevokeAllSessionsResponse>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = revoke_service.execute(apporg, path.user_id).await?;
+    Ok(web::Json(res))
+}
@@ -1,21 +1,24 @@
 use crate::{
     application::{
         dto::{
-            payload::role::{
-                CreateRolePayload, GetRoleIdQuery, GetRolesByUserPayload, UpdateRolePayload,
+            payload::{
+                role::{AssignRolePayload, CreateRolePayload, GetRoleIdQuery, UpdateRolePayload},
+                user::GetUserQuery,
             },
             response::{
                 refresh_token::SimpleResponse,
                 role::{
                     GetRoleResponse, GetRolesByAppResponse, GetRolesByUserResponse,
-                    GetUsersByRoleResponse,
+                    GetUserPermissionsResponse, GetUsersByRoleResponse,
                 },
             },
         },
         services::roles::{
             assign::AssignService, create_roles::CreateRoleService, delete_role::DeleteRoleService,
             get_role_by_app::GetRoleByAppService, get_roles_by_app::GetRolesByAppService,
-            get_roles_by_user::GetRolesByUserService, get_users_by_role::GetUsersByRoleService,
+            get_roles_by_user::GetRolesByUserService,
+            get_user_permissions::GetUserPermissionsService,
+            get_users_by_role::GetUsersByRoleService,
             update_role::UpdateRoleService,
         },
     },
@@ -56,7 +59,7 @@ pub async fn delete_role_handler(
 pub async fn assign_handler(
     req: actix_web::HttpRequest,
     path: web::Path<GetRoleIdQuery>,
-    post_data: web::Json<GetRolesByUserPayload>,
+    post_data: web::Json<AssignRolePayload>,
     role_service: web::Data<dyn AssignService>,
 ) -> Result<web::Json<SimpleResponse>> {
     let apporg = req
@@ -65,7 +68,7 @@ pub async fn assign_handler(
         .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
         .cloned()?;
     let res = role_service
-        .execute(apporg, path.role_id, post_data.user_id)
+        .execute(apporg, path.role_id, post_data.user_id, post_data.expires_at)
         .await?;
     Ok(web::Json(res))
 }
@@ -113,7 +116,7 @@ pub async fn get_roles_by_app_handler(
 }
 pub async fn get_roles_by_user_handler(
     req: actix_web::HttpRequest,
-    post_data: web::Json<GetRolesByUserPayload>,
+    path: web::Path<GetUserQuery>,
     role_service: web::Data<dyn GetRolesByUserService>,
 ) -> Result<web::Json<GetRolesByUserResponse>> {
     let apporg = req
@@ -121,7 +124,21 @@ pub async fn get_roles_by_user_handler(
         .get::<CleanAppOrgByClientId>()
         .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
         .cloned()?;
-    let res = role_service.execute(apporg, post_data.user_id).await?;
+    let res = role_service.execute(apporg, path.user_id).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_user_permissions_handler(
+    req: actix_web::HttpRequest,
+    path: web::Path<GetUserQuery>,
+    role_service: web::Data<dyn GetUserPermissionsService>,
+) -> Result<web::Json<GetUserPermissionsResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = role_service.execute(apporg, path.user_id).await?;
     Ok(web::Json(res))
 }
 
@@ -0,0 +1,208 @@
+use crate::{
+    application::{
+        dto::{
+            payload::scim::{
+                ScimGroupPayload, ScimListQuery, ScimPatchPayload, ScimResourceQuery,
+                ScimUserPayload,
+            },
+            response::scim::{
+                ScimListResponse, group_schema, resource_types, service_provider_config,
+                user_schema,
+            },
+        },
+        services::scim::{groups::ScimGroupsService, users::ScimUsersService},
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::scim_errors::ScimError,
+        value_objects::scim::{GROUP_SCHEMA, SCIM_CONTENT_TYPE, USER_SCHEMA},
+    },
+};
+use actix_web::{HttpMessage, HttpRequest, HttpResponse, HttpResponseBuilder, http::header, web};
+use serde::Serialize;
+
+fn apporg(req: &HttpRequest) -> Result<CleanAppOrgByClientId, ScimError> {
+    req.extensions()
+        .get::<CleanAppOrgByClientId>()
+        .cloned()
+        .ok_or_else(|| ScimError::new(500, None, "Missing AppOrg data".to_string()))
+}
+
+fn scim_json(mut builder: HttpResponseBuilder, body: impl Serialize) -> HttpResponse {
+    builder.content_type(SCIM_CONTENT_TYPE).json(body)
+}
+
+fn created(location: String, body: impl Serialize) -> HttpResponse {
+    let mut builder = HttpResponse::Created();
+    builder.insert_header((header::LOCATION, location));
+    scim_json(builder, body)
+}
+
+fn not_found(detail: &str) -> ScimError {
+    ScimError::new(404, None, detail.to_string())
+}
+
+pub async fn service_provider_config_handle() -> HttpResponse {
+    scim_json(HttpResponse::Ok(), service_provider_config())
+}
+
+pub async fn schemas_handle() -> HttpResponse {
+    let schemas = vec![user_schema(), group_schema()];
+    scim_json(HttpResponse::Ok(), ScimListResponse::new(2, 1, schemas))
+}
+
+pub async fn schema_handle(path: web::Path<String>) -> Result<HttpResponse, ScimError> {
+    let schema = match path.as_str() {
+        USER_SCHEMA => user_schema(),
+        GROUP_SCHEMA => group_schema(),
+        _ => return Err(not_found("schema not found")),
+    };
+    Ok(scim_json(HttpResponse::Ok(), schema))
+}
+
+pub async fn resource_types_handle() -> HttpResponse {
+    let types = resource_types();
+    scim_json(
+        HttpResponse::Ok(),
+        ScimListResponse::new(types.len(), 1, types),
+    )
+}
+
+pub async fn resource_type_handle(path: web::Path<String>) -> Result<HttpResponse, ScimError> {
+    let resource_type = resource_types()
+        .into_iter()
+        .find(|resource_type| resource_type["id"] == path.as_str())
+        .ok_or_else(|| not_found("resource type not found"))?;
+    Ok(scim_json(HttpResponse::Ok(), resource_type))
+}
+
+pub async fn list_users_handle(
+    req: HttpRequest,
+    query: web::Query<ScimListQuery>,
+    scim_service: web::Data<dyn ScimUsersService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .list(apporg(&req)?, query.into_inner())
+        .await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn get_user_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    scim_service: web::Data<dyn ScimUsersService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service.get(apporg(&req)?, path.id).await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn create_user_handle(
+    req: HttpRequest,
+    post_data: web::Json<ScimUserPayload>,
+    scim_service: web::Data<dyn ScimUsersService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .create(apporg(&req)?, post_data.into_inner())
+        .await?;
+    Ok(created(format!("/scim/v2/Users/{}", res.id), res))
+}
+
+pub async fn replace_user_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    post_data: web::Json<ScimUserPayload>,
+    scim_service: web::Data<dyn ScimUsersService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .replace(apporg(&req)?, path.id, post_data.into_inner())
+        .await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn patch_user_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    post_data: web::Json<ScimPatchPayload>,
+    scim_service: web::Data<dyn ScimUsersService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .patch(apporg(&req)?, path.id, post_data.into_inner())
+        .await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn delete_user_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    scim_service: web::Data<dyn ScimUsersService>,
+) -> Result<HttpResponse, ScimError> {
+    scim_service.delete(apporg(&req)?, path.id).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn list_groups_handle(
+    req: HttpRequest,
+    query: web::Query<ScimListQuery>,
+    scim_service: web::Data<dyn ScimGroupsService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .list(apporg(&req)?, query.into_inner())
+        .await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn get_group_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    query: web::Query<ScimListQuery>,
+    scim_service: web::Data<dyn ScimGroupsService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .get(apporg(&req)?, path.id, !query.excludes("members"))
+        .await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn create_group_handle(
+    req: HttpRequest,
+    post_data: web::Json<ScimGroupPayload>,
+    scim_service: web::Data<dyn ScimGroupsService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .create(apporg(&req)?, post_data.into_inner())
+        .await?;
+    Ok(created(format!("/scim/v2/Groups/{}", res.id), res))
+}
+
+pub async fn replace_group_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    post_data: web::Json<ScimGroupPayload>,
+    scim_service: web::Data<dyn ScimGroupsService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .replace(apporg(&req)?, path.id, post_data.into_inner())
+        .await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn patch_group_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    post_data: web::Json<ScimPatchPayload>,
+    scim_service: web::Data<dyn ScimGroupsService>,
+) -> Result<HttpResponse, ScimError> {
+    let res = scim_service
+        .patch(apporg(&req)?, path.id, post_data.into_inner())
+        .await?;
+    Ok(scim_json(HttpResponse::Ok(), res))
+}
+
+pub async fn delete_group_handle(
+    req: HttpRequest,
+    path: web::Path<ScimResourceQuery>,
+    scim_service: web::Data<dyn ScimGroupsService>,
+) -> Result<HttpResponse, ScimError> {
+    scim_service.delete(apporg(&req)?, path.id).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,61 @@
+use crate::{
+    application::{
+        dto::{
+            payload::service_account::{CreateServiceAccountPayload, ServiceAccountIdPath},
+            response::{
+                refresh_token::SimpleResponse,
+                service_account::{CreateServiceAccountResponse, GetServiceAccountsResponse},
+            },
+        },
+        services::service_accounts::{
+            create::CreateServiceAccountService, delete::DeleteServiceAccountService,
+            get::GetServiceAccountsService,
+        },
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId, errors::repositories_errors::ApiError,
+    },
+};
+use actix_web::HttpMessage;
+use actix_web::{Result, web};
+
+pub async fn create_service_account_handle(
+    req: actix_web::HttpRequest,
+    post_data: web::Json<CreateServiceAccountPayload>,
+    service: web::Data<dyn CreateServiceAccountService>,
+) -> Result<web::Json<CreateServiceAccountResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = service.execute(apporg, post_data.into_inner()).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn get_service_accounts_handle(
+    req: actix_web::HttpRequest,
+    service: web::Data<dyn GetServiceAccountsService>,
+) -> Result<web::Json<GetServiceAccountsResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = service.execute(apporg).await?;
+    Ok(web::Json(res))
+}
+
+pub async fn delete_service_account_handle(
+    req: actix_web::HttpRequest,
+    path: web::Path<ServiceAccountIdPath>,
+    service: web::Data<dyn DeleteServiceAccountService>,
+) -> Result<web::Json<SimpleResponse>> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let res = service.execute(apporg, path.service_account_id).await?;
+    Ok(web::Json(res))
+}
@@ -2,7 +2,10 @@ use super::super::services::users::create::CreateUserService;
 use crate::{
     application::{
         dto::{
-            payload::user::{CreateUserPayload, GetUserQuery, PaginationQuery, UpdateUserPayload},
+            payload::user::{
+                CreateUserPayload, DeleteUserQuery, GetUserQuery, ImportJobQuery,
+                PaginationQuery, TransferQuery, UpdateUserPayload,
+            },
             response::user::{
                 BanUserResponse, CreateUserResponse, DisableMFAUserResponse, GetUserCountResponse,
                 GetUserResponse, GetUsersResponse, UnbanUserResponse, UpdateUsersResponse,
@@ -10,17 +13,22 @@ use crate::{
         },
         services::users::{
             ban_user::BanUserService, delete::DeleteUserService,
-            disable_mfa_user::DisableMFAUserService, get_user::GetUserService,
-            get_user_count::GetUserCountService, get_users::GetUsersService,
+            disable_mfa_user::DisableMFAUserService, export_users::ExportUsersService,
+            get_user::GetUserService, get_user_count::GetUserCountService,
+            get_users::GetUsersService, import_users::ImportUsersService,
             unban_user::UnbanUserService, update_user::UpdateUserService,
         },
     },
     domain::{
         entities::apporg_client_id::CleanAppOrgByClientId, errors::repositories_errors::ApiError,
+        value_objects::user_transfer::TransferFormat,
     },
+    infrastructure::models::user_import::UserImportJobModel,
 };
 use actix_web::HttpMessage;
-use actix_web::{Result, web};
+use actix_web::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use actix_web::{HttpResponse, Result, web};
+use futures::TryStreamExt;
 use validator::Validate;
 pub async fn create_user_handle(
     req: actix_web::HttpRequest,
@@ -108,6 +116,7 @@ pub async fn update_user_handle(
 pub async fn delate_user_handle(
     req: actix_web::HttpRequest,
     path: web::Path<GetUserQuery>,
+    query: web::Query<DeleteUserQuery>,
     user_service: web::Data<dyn DeleteUserService>,
 ) -> Result<web::Json<UpdateUsersResponse>, ApiError> {
     let apporg = req
@@ -116,7 +125,12 @@ pub async fn delate_user_handle(
         .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
         .cloned()?;
     let created_user = user_service
-        .execute(apporg.organization_id, apporg.application_id, path.user_id)
+        .execute(
+            apporg.organization_id,
+            apporg.application_id,
+            path.user_id,
+            query.soft.unwrap_or(false),
+        )
         .await?;
     Ok(web::Json(created_user))
 }
@@ -182,3 +196,69 @@ pub async fn disable_mfa_user_handle(
         .await?;
     Ok(web::Json(created_user))
 }
+
+pub async fn import_users_handle(
+    req: actix_web::HttpRequest,
+    query: web::Query<TransferQuery>,
+    body: web::Bytes,
+    import_service: web::Data<dyn ImportUsersService>,
+) -> Result<HttpResponse, ApiError> {
+    let format = query
+        .format
+        .or_else(|| {
+            req.headers()
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(TransferFormat::from_content_type)
+        })
+        .ok_or_else(|| {
+            ApiError::new(
+                "expected text/csv or application/x-ndjson".to_string(),
+                400,
+            )
+        })?;
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let job = import_service.start(apporg, format, body).await?;
+    Ok(HttpResponse::Accepted().json(job))
+}
+
+pub async fn get_import_job_handle(
+    req: actix_web::HttpRequest,
+    path: web::Path<ImportJobQuery>,
+    import_service: web::Data<dyn ImportUsersService>,
+) -> Result<web::Json<UserImportJobModel>, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let job = import_service.get_job(apporg, path.job_id).await?;
+    Ok(web::Json(job))
+}
+
+pub async fn export_users_handle(
+    req: actix_web::HttpRequest,
+    query: web::Query<TransferQuery>,
+    export_service: web::Data<dyn ExportUsersService>,
+) -> Result<HttpResponse, ApiError> {
+    let apporg = req
+        .extensions()
+        .get::<CleanAppOrgByClientId>()
+        .ok_or_else(|| ApiError::new("Missing AppOrg data".to_string(), 500))
+        .cloned()?;
+    let format = query.format.unwrap_or(TransferFormat::Ndjson);
+    let users = export_service
+        .execute(apporg.organization_id, apporg.application_id, format)
+        .map_err(|e| actix_web::Error::from(ApiError::from(e)));
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header((
+            CONTENT_DISPOSITION,
+            format!("attachment; filename=\"users.{}\"", format.extension()),
+        ))
+        .streaming(users))
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct UpdateRedirectUrisPayload {
+    pub redirect_uris: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct IdentityProviderPayload {
+    pub id: String,
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    /// May be left out when updating a provider to keep its current secret.
+    pub client_secret: Option<String>,
+    pub scopes: Option<Vec<String>>,
+    #[serde(default)]
+    pub link_by_email: bool,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateIdentityProvidersPayload {
+    pub identity_providers: Vec<IdentityProviderPayload>,
+}
+
+#[derive(Deserialize)]
+pub struct ApplicationIdQuery {
+    pub application_id: Uuid,
+}
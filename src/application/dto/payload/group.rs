@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateGroupPayload {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateGroupPayload {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GroupIdQuery {
+    pub group_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GroupMemberQuery {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GroupRoleQuery {
+    pub group_id: Uuid,
+    pub role_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddGroupMemberPayload {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignGroupRolePayload {
+    pub role_id: Uuid,
+}
@@ -0,0 +1,105 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthorizeQuery {
+    pub response_type: String,
+    pub client_id: Uuid,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub code_challenge: String,
+    pub code_challenge_method: Option<String>,
+    pub nonce: Option<String>,
+}
+
+/// Login form posted back to `/oauth/authorize`; it repeats the
+/// authorization request so the flow stays stateless until a code is issued.
+#[derive(Deserialize)]
+pub struct AuthorizePayload {
+    pub response_type: String,
+    pub client_id: Uuid,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub code_challenge: String,
+    pub code_challenge_method: Option<String>,
+    pub nonce: Option<String>,
+    pub csrf_token: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl From<&AuthorizePayload> for AuthorizeQuery {
+    fn from(payload: &AuthorizePayload) -> Self {
+        Self {
+            response_type: payload.response_type.clone(),
+            client_id: payload.client_id,
+            redirect_uri: payload.redirect_uri.clone(),
+            scope: payload.scope.clone(),
+            state: payload.state.clone(),
+            code_challenge: payload.code_challenge.clone(),
+            code_challenge_method: payload.code_challenge_method.clone(),
+            nonce: payload.nonce.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TokenPayload {
+    pub grant_type: String,
+    pub client_id: Uuid,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub code_verifier: Option<String>,
+    pub refresh_token: Option<String>,
+    pub client_secret: Option<String>,
+    pub device_code: Option<String>,
+}
+
+/// Body of `/oauth/device_authorization` (RFC 8628 3.1).
+#[derive(Deserialize)]
+pub struct DeviceAuthorizationPayload {
+    pub client_id: Uuid,
+    pub scope: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DeviceVerificationQuery {
+    pub user_code: Option<String>,
+}
+
+/// Verification form posted to `/oauth/device`; credentials are needed to
+/// approve or deny.
+#[derive(Deserialize)]
+pub struct DeviceVerificationPayload {
+    pub user_code: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub action: String,
+}
+
+/// Where an upstream identity provider sends the user back to.
+#[derive(Deserialize)]
+pub struct FederationCallbackQuery {
+    pub state: String,
+    pub code: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DiscoveryQuery {
+    pub client_id: Option<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct KeySetQuery {
+    pub client_id: Uuid,
+}
+
+/// Body of `/oauth/introspect` (RFC 7662) and `/oauth/revoke` (RFC 7009).
+#[derive(Deserialize)]
+pub struct TokenLookupPayload {
+    pub token: String,
+    pub token_type_hint: Option<String>,
+}
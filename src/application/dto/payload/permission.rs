@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreatePermissionPayload {
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdatePermissionPayload {
+    pub description: Option<String>,
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PermissionNameQuery {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeletePermissionQuery {
+    pub strict: Option<bool>,
+}
@@ -1,16 +1,21 @@
+use crate::domain::value_objects::client_metadata::ClientMetadata;
 use serde::Deserialize;
 use uuid::Uuid;
 
+/// The optional device fields describe the end user's device when the
+/// application backend requests tokens on its behalf; otherwise the
+/// calling request itself is recorded.
 #[derive(Deserialize)]
 pub struct CreateTokenPayload {
     pub user_id: Uuid,
-    pub private_key: String,
+    #[serde(flatten)]
+    pub client: ClientMetadata,
 }
 #[derive(Deserialize)]
 pub struct RotateTokenPayload {
     pub refresh_token: String,
-    pub public_key: String,
-    pub private_key: String,
+    #[serde(flatten)]
+    pub client: ClientMetadata,
 }
 
 #[derive(Deserialize)]
@@ -7,6 +7,9 @@ pub struct CreateRolePayload {
     pub name: String,
     pub description: Option<String>,
     pub permissions: HashSet<String>,
+    /// Roles to inherit permissions from.
+    #[serde(default)]
+    pub parent_role_ids: HashSet<Uuid>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -14,6 +17,7 @@ pub struct UpdateRolePayload {
     pub name: Option<String>,
     pub description: Option<String>,
     pub permissions: Option<HashSet<String>>,
+    pub parent_role_ids: Option<HashSet<Uuid>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,6 +26,8 @@ pub struct GetRoleIdQuery {
 }
 
 #[derive(Debug, Deserialize)]
-pub struct GetRolesByUserPayload {
+pub struct AssignRolePayload {
     pub user_id: Uuid,
+    /// Unix millis after which the assignment lapses; omit for a permanent one.
+    pub expires_at: Option<i64>,
 }
@@ -0,0 +1,102 @@
+use crate::domain::value_objects::scim::MAX_RESULTS;
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    pub filter: Option<String>,
+    /// 1-based, as in RFC 7644 3.4.2.4.
+    pub start_index: Option<usize>,
+    pub count: Option<usize>,
+    pub excluded_attributes: Option<String>,
+}
+
+impl ScimListQuery {
+    pub fn start_index(&self) -> usize {
+        self.start_index.unwrap_or(1).max(1)
+    }
+
+    pub fn count(&self) -> usize {
+        self.count.unwrap_or(MAX_RESULTS).min(MAX_RESULTS)
+    }
+
+    pub fn excludes(&self, attribute: &str) -> bool {
+        self.excluded_attributes.as_deref().is_some_and(|excluded| {
+            excluded
+                .split(',')
+                .any(|a| a.trim().eq_ignore_ascii_case(attribute))
+        })
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimNamePayload {
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScimMultiValuePayload {
+    pub value: String,
+    #[serde(default)]
+    pub primary: bool,
+}
+
+/// The primary entry of a multi-valued attribute, or the first one.
+pub fn primary_value(values: &[ScimMultiValuePayload]) -> Option<String> {
+    values
+        .iter()
+        .find(|v| v.primary)
+        .or_else(|| values.first())
+        .map(|v| v.value.clone())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserPayload {
+    pub user_name: String,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub name: ScimNamePayload,
+    #[serde(default)]
+    pub emails: Vec<ScimMultiValuePayload>,
+    #[serde(default)]
+    pub phone_numbers: Vec<ScimMultiValuePayload>,
+    pub locale: Option<String>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScimMemberPayload {
+    pub value: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupPayload {
+    pub display_name: String,
+    #[serde(default)]
+    pub members: Vec<ScimMemberPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScimPatchOperation {
+    /// `add`, `replace` or `remove`; some clients capitalise it.
+    pub op: String,
+    pub path: Option<String>,
+    pub value: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScimPatchPayload {
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScimResourceQuery {
+    pub id: Uuid,
+}
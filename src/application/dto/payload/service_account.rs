@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateServiceAccountPayload {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServiceAccountIdPath {
+    pub service_account_id: Uuid,
+}
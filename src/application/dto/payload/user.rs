@@ -1,5 +1,7 @@
+use crate::domain::value_objects::{user_profile::UserProfile, user_transfer::TransferFormat};
 use email_address::EmailAddress;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 use uuid::Uuid;
 use validator::{Validate, ValidationError};
 
@@ -13,6 +15,39 @@ pub struct CreateUserPayload {
 
     #[validate(length(min = 8))]
     pub password: String,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub profile: UserProfilePayload,
+
+    #[serde(default)]
+    pub metadata: Map<String, Value>,
+}
+
+#[derive(Debug, Default, Validate, Deserialize)]
+pub struct UserProfilePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub first_name: Option<String>,
+    #[validate(length(min = 1, max = 100))]
+    pub last_name: Option<String>,
+    #[validate(length(min = 2, max = 35))]
+    pub locale: Option<String>,
+    #[validate(length(min = 4, max = 32))]
+    pub phone: Option<String>,
+    #[validate(url)]
+    pub avatar_url: Option<String>,
+}
+
+impl From<UserProfilePayload> for UserProfile {
+    fn from(payload: UserProfilePayload) -> Self {
+        Self {
+            first_name: payload.first_name,
+            last_name: payload.last_name,
+            locale: payload.locale,
+            phone: payload.phone,
+            avatar_url: payload.avatar_url,
+        }
+    }
 }
 
 fn validate_username_or_email(value: &str) -> Result<(), ValidationError> {
@@ -35,6 +70,11 @@ pub struct GetUserQuery {
     pub user_id: Uuid,
 }
 
+#[derive(Deserialize)]
+pub struct DeleteUserQuery {
+    pub soft: Option<bool>,
+}
+
 #[derive(Debug, Validate, Deserialize)]
 pub struct UpdateUserPayload {
     #[validate(custom(function = "validate_username_or_email"))]
@@ -43,4 +83,48 @@ pub struct UpdateUserPayload {
     pub email: Option<String>,
     #[validate(length(min = 8))]
     pub password: Option<String>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub profile: UserProfilePayload,
+
+    /// Merged into the stored metadata; a `null` value removes the key.
+    #[serde(default)]
+    pub metadata: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+pub struct TransferQuery {
+    /// Overrides the format implied by `Content-Type`.
+    pub format: Option<TransferFormat>,
+}
+
+#[derive(Deserialize)]
+pub struct ImportJobQuery {
+    pub job_id: Uuid,
+}
+
+/// One record of a bulk import. CSV headers and NDJSON keys use these names,
+/// the same ones the export writes.
+#[derive(Debug, Deserialize)]
+pub struct ImportUserRow {
+    pub username: String,
+    pub email: Option<String>,
+    pub password: Option<String>,
+    /// An argon2 PHC string, stored without rehashing.
+    pub password_hash: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub locale: Option<String>,
+    pub phone: Option<String>,
+    pub avatar_url: Option<String>,
+    pub metadata: Option<ImportMetadata>,
+}
+
+/// An object in NDJSON, a JSON-encoded cell in CSV.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ImportMetadata {
+    Object(Map<String, Value>),
+    Encoded(String),
 }
@@ -0,0 +1,30 @@
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RedirectUrisResponse {
+    pub redirect_uris: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdentityProviderResponse {
+    pub id: String,
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub scopes: Vec<String>,
+    pub link_by_email: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdentityProvidersResponse {
+    pub identity_providers: Vec<IdentityProviderResponse>,
+}
+
+/// The settings document without client secrets.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApplicationConfigResponse {
+    pub application_id: Uuid,
+    pub config: Value,
+}
@@ -0,0 +1,50 @@
+use scylla::value::CqlTimestamp;
+use serde::Serialize;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::infrastructure::{
+    models::group::{GroupMemberModel, GroupModel},
+    repositories::database::scylla_serialize::serialize_cql_timestamp,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateGroupResponse {
+    pub group_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupResponse {
+    pub group_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub role_ids: HashSet<Uuid>,
+    #[serde(serialize_with = "serialize_cql_timestamp")]
+    pub created_at: CqlTimestamp,
+    #[serde(serialize_with = "serialize_cql_timestamp")]
+    pub updated_at: CqlTimestamp,
+}
+
+impl From<GroupModel> for GroupResponse {
+    fn from(group: GroupModel) -> Self {
+        Self {
+            group_id: group.group_id,
+            name: group.name,
+            description: group.description,
+            role_ids: group.role_ids,
+            created_at: group.created_at,
+            updated_at: group.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetGroupsResponse {
+    pub groups: Vec<GroupResponse>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetGroupMembersResponse {
+    pub members: Vec<GroupMemberModel>,
+    pub paging_state: Option<String>,
+}
@@ -1,4 +1,10 @@
+pub mod application;
 pub mod hello;
+pub mod oauth;
 pub mod user;
 pub mod refresh_token;
-pub mod role;
\ No newline at end of file
+pub mod role;
+pub mod service_account;
+pub mod scim;
+pub mod group;
+pub mod permission;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use scylla::value::CqlTimestamp;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::infrastructure::{
+    models::permission::PermissionModel,
+    repositories::database::scylla_serialize::serialize_cql_timestamp,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PermissionResponse {
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    #[serde(serialize_with = "serialize_cql_timestamp")]
+    pub created_at: CqlTimestamp,
+    #[serde(serialize_with = "serialize_cql_timestamp")]
+    pub updated_at: CqlTimestamp,
+}
+
+impl From<PermissionModel> for PermissionResponse {
+    fn from(permission: PermissionModel) -> Self {
+        Self {
+            name: permission.name,
+            description: permission.description,
+            category: permission.category,
+            created_at: permission.created_at,
+            updated_at: permission.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetPermissionsResponse {
+    pub permissions: Vec<PermissionResponse>,
+}
+
+/// A role still granting permissions that are no longer in the catalog.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnresolvedRoleResponse {
+    pub role_id: Uuid,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetUnresolvedRolesResponse {
+    pub roles: Vec<UnresolvedRoleResponse>,
+}
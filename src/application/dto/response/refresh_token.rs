@@ -1,6 +1,13 @@
+use scylla::value::CqlTimestamp;
 use serde::Serialize;
+use uuid::Uuid;
 
-use crate::infrastructure::models::refresh_token::FoundRefreshTokenModelByUser;
+use crate::infrastructure::{
+    models::refresh_token::FoundRefreshTokenModelByUser,
+    repositories::database::scylla_serialize::{
+        serialize_cql_timestamp, serialize_optional_cql_timestamp,
+    },
+};
 
 
 #[derive(Debug, Clone, Serialize)]
@@ -22,4 +29,43 @@ pub struct SimpleResponse {
 pub struct GetRefreshTokensResponse {
     pub refresh_tokens: Vec<FoundRefreshTokenModelByUser>,
     pub paging_state: Option<String>,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionResponse {
+    pub session_id: Uuid,
+    pub device_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    #[serde(serialize_with = "serialize_cql_timestamp")]
+    pub issued_at: CqlTimestamp,
+    #[serde(serialize_with = "serialize_optional_cql_timestamp")]
+    pub last_used_at: Option<CqlTimestamp>,
+    #[serde(serialize_with = "serialize_cql_timestamp")]
+    pub expires_at: CqlTimestamp,
+}
+
+impl From<FoundRefreshTokenModelByUser> for SessionResponse {
+    fn from(token: FoundRefreshTokenModelByUser) -> Self {
+        Self {
+            session_id: token.token_id,
+            device_name: token.device_name,
+            ip_address: token.ip_address,
+            user_agent: token.user_agent,
+            issued_at: token.issued_at,
+            last_used_at: token.last_used_at,
+            expires_at: token.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetSessionsResponse {
+    pub sessions: Vec<SessionResponse>,
+    pub paging_state: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RevokeAllSessionsResponse {
+    pub revoked: usize,
+}
@@ -2,20 +2,30 @@ use crate::infrastructure::{models::role::{RoleUserModel, SelectedRoleByAppModel
 use scylla::value::CqlTimestamp;
 use serde::Serialize;
 use std::collections::HashSet;
+use uuid::Uuid;
 #[derive(Debug, Clone, Serialize)]
 pub struct GetRoleResponse {
     pub name: String,
     pub description: Option<String>,
     pub permissions: HashSet<String>,
+    pub parent_role_ids: HashSet<Uuid>,
     #[serde(serialize_with = "serialize_cql_timestamp")]
     pub created_at: CqlTimestamp,
     #[serde(serialize_with = "serialize_cql_timestamp")]
     pub updated_at: CqlTimestamp,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupRoleResponse {
+    pub group_id: Uuid,
+    pub role_id: Uuid,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct GetRolesByUserResponse {
     pub roles: Vec<UserRoleModel>,
+    /// Roles the user holds through the groups they belong to.
+    pub group_roles: Vec<GroupRoleResponse>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -25,4 +35,9 @@ pub struct GetRolesByAppResponse {
 #[derive(Debug, Clone, Serialize)]
 pub struct GetUsersByRoleResponse {
     pub users: Vec<RoleUserModel>,
-}
\ No newline at end of file
+}
+#[derive(Debug, Clone, Serialize)]
+pub struct GetUserPermissionsResponse {
+    pub user_id: Uuid,
+    pub permissions: Vec<String>,
+}
@@ -0,0 +1,217 @@
+use crate::domain::value_objects::scim::{
+    GROUP_SCHEMA, LIST_RESPONSE_SCHEMA, MAX_RESULTS, RESOURCE_TYPE_SCHEMA, SCHEMA_SCHEMA,
+    SERVICE_PROVIDER_CONFIG_SCHEMA, USER_SCHEMA,
+};
+use chrono::{DateTime, SecondsFormat};
+use serde::Serialize;
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: String,
+    pub created: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl ScimMeta {
+    pub fn new(resource_type: &str, created_ms: i64, last_modified_ms: i64) -> Self {
+        let format = |ms: i64| {
+            DateTime::from_timestamp_millis(ms)
+                .map(|at| at.to_rfc3339_opts(SecondsFormat::Millis, true))
+        };
+        Self {
+            resource_type: resource_type.to_string(),
+            created: format(created_ms),
+            last_modified: format(last_modified_ms),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScimMultiValue {
+    pub value: String,
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResponse {
+    pub schemas: Vec<String>,
+    pub id: Uuid,
+    pub user_name: String,
+    pub name: ScimName,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub emails: Vec<ScimMultiValue>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub phone_numbers: Vec<ScimMultiValue>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub photos: Vec<ScimMultiValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    pub active: bool,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScimMember {
+    pub value: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupResponse {
+    pub schemas: Vec<String>,
+    pub id: Uuid,
+    pub display_name: String,
+    /// Left out when the client asked for `excludedAttributes=members`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<ScimMember>>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse<T> {
+    pub schemas: Vec<String>,
+    pub total_results: usize,
+    pub start_index: usize,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<T>,
+}
+
+impl<T> ScimListResponse<T> {
+    pub fn new(total_results: usize, start_index: usize, resources: Vec<T>) -> Self {
+        Self {
+            schemas: vec![LIST_RESPONSE_SCHEMA.to_string()],
+            total_results,
+            start_index,
+            items_per_page: resources.len(),
+            resources,
+        }
+    }
+
+    /// Cuts one page out of a fully loaded list.
+    pub fn paginate(items: Vec<T>, start_index: usize, count: usize) -> Self {
+        let total_results = items.len();
+        let resources = items
+            .into_iter()
+            .skip(start_index - 1)
+            .take(count)
+            .collect();
+        Self::new(total_results, start_index, resources)
+    }
+}
+
+pub fn service_provider_config() -> Value {
+    json!({
+        "schemas": [SERVICE_PROVIDER_CONFIG_SCHEMA],
+        "patch": { "supported": true },
+        "bulk": { "supported": false, "maxOperations": 0, "maxPayloadSize": 0 },
+        "filter": { "supported": true, "maxResults": MAX_RESULTS },
+        "changePassword": { "supported": true },
+        "sort": { "supported": false },
+        "etag": { "supported": false },
+        "authenticationSchemes": [{
+            "type": "oauthbearertoken",
+            "name": "OAuth Bearer Token",
+            "description": "An application access token sent in the Authorization header",
+            "primary": true
+        }],
+        "meta": { "resourceType": "ServiceProviderConfig" }
+    })
+}
+
+fn attribute(name: &str, kind: &str, multi_valued: bool, required: bool, mutability: &str) -> Value {
+    json!({
+        "name": name,
+        "type": kind,
+        "multiValued": multi_valued,
+        "required": required,
+        "caseExact": false,
+        "mutability": mutability,
+        "returned": if name == "password" { "never" } else { "default" },
+        "uniqueness": if name == "userName" || name == "displayName" { "server" } else { "none" }
+    })
+}
+
+fn complex(name: &str, multi_valued: bool, sub_attributes: Vec<Value>) -> Value {
+    let mut value = attribute(name, "complex", multi_valued, false, "readWrite");
+    value["subAttributes"] = Value::Array(sub_attributes);
+    value
+}
+
+pub fn user_schema() -> Value {
+    let multi_value = || {
+        vec![
+            attribute("value", "string", false, false, "readWrite"),
+            attribute("primary", "boolean", false, false, "readWrite"),
+        ]
+    };
+    json!({
+        "schemas": [SCHEMA_SCHEMA],
+        "id": USER_SCHEMA,
+        "name": "User",
+        "description": "User Account",
+        "attributes": [
+            attribute("userName", "string", false, true, "readWrite"),
+            complex("name", false, vec![
+                attribute("givenName", "string", false, false, "readWrite"),
+                attribute("familyName", "string", false, false, "readWrite"),
+            ]),
+            attribute("password", "string", false, false, "writeOnly"),
+            complex("emails", true, multi_value()),
+            complex("phoneNumbers", true, multi_value()),
+            complex("photos", true, multi_value()),
+            attribute("locale", "string", false, false, "readWrite"),
+            attribute("active", "boolean", false, false, "readWrite"),
+        ],
+        "meta": { "resourceType": "Schema", "location": format!("/scim/v2/Schemas/{USER_SCHEMA}") }
+    })
+}
+
+pub fn group_schema() -> Value {
+    json!({
+        "schemas": [SCHEMA_SCHEMA],
+        "id": GROUP_SCHEMA,
+        "name": "Group",
+        "description": "Group, backed by an application role",
+        "attributes": [
+            attribute("displayName", "string", false, true, "readWrite"),
+            complex("members", true, vec![
+                attribute("value", "string", false, false, "immutable"),
+            ]),
+        ],
+        "meta": { "resourceType": "Schema", "location": format!("/scim/v2/Schemas/{GROUP_SCHEMA}") }
+    })
+}
+
+pub fn resource_types() -> Vec<Value> {
+    [("User", "/Users", USER_SCHEMA), ("Group", "/Groups", GROUP_SCHEMA)]
+        .into_iter()
+        .map(|(name, endpoint, schema)| {
+            json!({
+                "schemas": [RESOURCE_TYPE_SCHEMA],
+                "id": name,
+                "name": name,
+                "endpoint": endpoint,
+                "schema": schema,
+                "meta": {
+                    "resourceType": "ResourceType",
+                    "location": format!("/scim/v2/ResourceTypes/{name}")
+                }
+            })
+        })
+        .collect()
+}
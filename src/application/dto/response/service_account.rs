@@ -0,0 +1,45 @@
+use scylla::value::CqlTimestamp;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::infrastructure::{
+    models::service_account::ServiceAccountModel,
+    repositories::database::scylla_serialize::serialize_cql_timestamp,
+};
+
+/// Returned once on creation; the secret cannot be read back afterwards.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateServiceAccountResponse {
+    pub service_account_id: Uuid,
+    pub client_id: Uuid,
+    pub client_secret: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceAccountResponse {
+    pub service_account_id: Uuid,
+    pub client_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_active: bool,
+    #[serde(serialize_with = "serialize_cql_timestamp")]
+    pub created_at: CqlTimestamp,
+}
+
+impl From<ServiceAccountModel> for ServiceAccountResponse {
+    fn from(account: ServiceAccountModel) -> Self {
+        Self {
+            service_account_id: account.service_account_id,
+            client_id: account.client_id,
+            name: account.name,
+            description: account.description,
+            is_active: account.is_active,
+            created_at: account.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetServiceAccountsResponse {
+    pub service_accounts: Vec<ServiceAccountResponse>,
+}
@@ -1,9 +1,11 @@
+use crate::domain::value_objects::user_profile::UserProfile;
 use crate::infrastructure::models::user::CleannedUserModel;
 use crate::infrastructure::repositories::database::scylla_serialize::{
     serialize_cql_timestamp, serialize_optional_cql_timestamp,
 };
 use scylla::value::CqlTimestamp;
 use serde::Serialize;
+use serde_json::{Map, Value};
 use uuid::Uuid;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct CreateUserResponse {
@@ -25,6 +27,8 @@ pub struct GetUserResponse {
     pub application_id: Uuid,
     pub username: String,
     pub email: Option<String>,
+    pub profile: UserProfile,
+    pub metadata: Map<String, Value>,
     #[serde(
         serialize_with = "serialize_cql_timestamp",
         deserialize_with = "deserialize_cql_timestamp"
@@ -75,4 +79,112 @@ pub struct UnbanUserResponse {
 #[derive(Debug, Clone, Serialize)]
 pub struct DisableMFAUserResponse {
     pub massage: String,
-}
\ No newline at end of file
+}
+#[derive(Debug, Clone, Serialize)]
+pub struct IdentifierCollision {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub user_id: Uuid,
+    pub field: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NormalizeIdentifiersResponse {
+    pub scanned: u64,
+    pub updated: u64,
+    pub collisions: Vec<IdentifierCollision>,
+}
+
+/// One exported user; the same names are read back by the import.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedUserRow {
+    pub user_id: Uuid,
+    pub username: String,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub locale: Option<String>,
+    pub phone: Option<String>,
+    pub avatar_url: Option<String>,
+    pub metadata: Option<Value>,
+    pub is_active: bool,
+    pub is_verified: bool,
+    pub is_locked: bool,
+    pub mfa_enabled: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub last_login: Option<i64>,
+}
+
+impl From<CleannedUserModel> for ExportedUserRow {
+    fn from(user: CleannedUserModel) -> Self {
+        Self {
+            user_id: user.user_id,
+            username: user.username,
+            email: user.email,
+            first_name: user.first_name,
+            last_name: user.last_name,
+            locale: user.locale,
+            phone: user.phone,
+            avatar_url: user.avatar_url,
+            metadata: user
+                .metadata
+                .map(|raw| serde_json::from_str(&raw).unwrap_or(Value::String(raw))),
+            is_active: user.is_active,
+            is_verified: user.is_verified,
+            is_locked: user.is_locked,
+            mfa_enabled: user.mfa_enabled,
+            created_at: user.created_at.0,
+            updated_at: user.updated_at.0,
+            last_login: user.last_login.map(|at| at.0),
+        }
+    }
+}
+
+impl ExportedUserRow {
+    pub const CSV_HEADER: [&'static str; 16] = [
+        "user_id",
+        "username",
+        "email",
+        "first_name",
+        "last_name",
+        "locale",
+        "phone",
+        "avatar_url",
+        "metadata",
+        "is_active",
+        "is_verified",
+        "is_locked",
+        "mfa_enabled",
+        "created_at",
+        "updated_at",
+        "last_login",
+    ];
+
+    /// Cells in `CSV_HEADER` order; metadata stays JSON-encoded.
+    pub fn csv_record(&self) -> [String; 16] {
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+        [
+            self.user_id.to_string(),
+            self.username.clone(),
+            text(&self.email),
+            text(&self.first_name),
+            text(&self.last_name),
+            text(&self.locale),
+            text(&self.phone),
+            text(&self.avatar_url),
+            self.metadata
+                .as_ref()
+                .map(Value::to_string)
+                .unwrap_or_default(),
+            self.is_active.to_string(),
+            self.is_verified.to_string(),
+            self.is_locked.to_string(),
+            self.mfa_enabled.to_string(),
+            self.created_at.to_string(),
+            self.updated_at.to_string(),
+            self.last_login.map(|at| at.to_string()).unwrap_or_default(),
+        ]
+    }
+}
@@ -1,4 +1,6 @@
 pub mod application;
 pub mod model;
+pub mod permission;
 pub mod user;
-pub mod role;
\ No newline at end of file
+pub mod role;
+pub mod scim;
\ No newline at end of file
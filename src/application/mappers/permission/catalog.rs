@@ -0,0 +1,16 @@
+use crate::{
+    application::mappers::application::ApplicationMapper,
+    domain::value_objects::permission_catalog::PermissionCatalog,
+    infrastructure::models::permission::PermissionModel,
+};
+
+pub struct PermissionCatalogMapper;
+impl ApplicationMapper<Vec<PermissionModel>, PermissionCatalog> for PermissionCatalogMapper {
+    fn to_dto(permissions: Vec<PermissionModel>) -> PermissionCatalog {
+        let mut catalog = PermissionCatalog::new();
+        for permission in permissions {
+            catalog.insert(permission.name);
+        }
+        catalog
+    }
+}
@@ -12,6 +12,7 @@ impl ModelMapper<RoleModel> for RoleByApp {
             name: entity.name,
             description: Some(entity.description),
             permissions: entity.permissions,
+            parent_role_ids: entity.parent_role_ids,
             created_at: entity.created_at,
             updated_at: entity.updated_at,
         }
@@ -25,6 +26,7 @@ impl ModelMapper<RoleModel> for RoleByApp {
             name: self.name.clone(),
             description: self.description.clone().unwrap_or_default(),
             permissions: self.permissions.clone(),
+            parent_role_ids: self.parent_role_ids.clone(),
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -0,0 +1,16 @@
+use crate::{
+    application::mappers::application::ApplicationMapper,
+    domain::value_objects::role_hierarchy::RoleHierarchy,
+    infrastructure::models::role::SelectedRoleByAppModel,
+};
+
+pub struct RoleHierarchyMapper;
+impl ApplicationMapper<Vec<SelectedRoleByAppModel>, RoleHierarchy> for RoleHierarchyMapper {
+    fn to_dto(roles: Vec<SelectedRoleByAppModel>) -> RoleHierarchy {
+        let mut hierarchy = RoleHierarchy::new();
+        for role in roles {
+            hierarchy.insert(role.role_id, role.parent_role_ids, role.permissions);
+        }
+        hierarchy
+    }
+}
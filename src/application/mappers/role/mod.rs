@@ -1 +1,2 @@
-pub mod create;
\ No newline at end of file
+pub mod create;
+pub mod hierarchy;
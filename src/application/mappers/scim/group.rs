@@ -0,0 +1,32 @@
+use crate::{
+    application::{
+        dto::response::scim::{ScimGroupResponse, ScimMember, ScimMeta},
+        mappers::application::ApplicationMapper,
+    },
+    domain::value_objects::scim::GROUP_SCHEMA,
+    infrastructure::models::role::{RoleUserModel, SelectedRoleByAppModel},
+};
+
+pub struct ScimGroupMapper;
+impl ApplicationMapper<(SelectedRoleByAppModel, Option<Vec<RoleUserModel>>), ScimGroupResponse>
+    for ScimGroupMapper
+{
+    fn to_dto(
+        (role, members): (SelectedRoleByAppModel, Option<Vec<RoleUserModel>>),
+    ) -> ScimGroupResponse {
+        ScimGroupResponse {
+            schemas: vec![GROUP_SCHEMA.to_string()],
+            id: role.role_id,
+            display_name: role.name,
+            members: members.map(|members| {
+                members
+                    .into_iter()
+                    .map(|member| ScimMember {
+                        value: member.user_id,
+                    })
+                    .collect()
+            }),
+            meta: ScimMeta::new("Group", role.created_at.0, role.updated_at.0),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    application::{
+        dto::response::scim::{ScimMeta, ScimMultiValue, ScimName, ScimUserResponse},
+        mappers::application::ApplicationMapper,
+    },
+    domain::value_objects::scim::USER_SCHEMA,
+    infrastructure::models::user::CleannedUserModel,
+};
+
+/// SCIM `active`: a locked or soft-deleted user cannot sign in.
+pub fn is_active(model: &CleannedUserModel) -> bool {
+    model.is_active && !model.is_locked && model.deactivated_at.is_none()
+}
+
+pub struct ScimUserMapper;
+impl ApplicationMapper<CleannedUserModel, ScimUserResponse> for ScimUserMapper {
+    fn to_dto(model: CleannedUserModel) -> ScimUserResponse {
+        let single = |value: Option<String>| {
+            value
+                .map(|value| ScimMultiValue {
+                    value,
+                    primary: true,
+                })
+                .into_iter()
+                .collect()
+        };
+        ScimUserResponse {
+            schemas: vec![USER_SCHEMA.to_string()],
+            id: model.user_id,
+            active: is_active(&model),
+            user_name: model.username,
+            name: ScimName {
+                given_name: model.first_name,
+                family_name: model.last_name,
+            },
+            emails: single(model.email),
+            phone_numbers: single(model.phone),
+            photos: single(model.avatar_url),
+            locale: model.locale,
+            meta: ScimMeta::new("User", model.created_at.0, model.updated_at.0),
+        }
+    }
+}
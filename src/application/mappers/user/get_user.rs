@@ -1,6 +1,7 @@
 use crate::{
     application::{dto::response::user::GetUserResponse, mappers::application::ApplicationMapper},
-    infrastructure::models::user::CleannedUserModel,
+    domain::value_objects::user_profile::UserProfile,
+    infrastructure::models::user::{CleannedUserModel, metadata_from_column},
 };
 
 pub struct GetUserMapper;
@@ -12,6 +13,14 @@ impl ApplicationMapper<CleannedUserModel, GetUserResponse> for GetUserMapper {
             application_id: model.application_id,
             username: model.username,
             email: model.email,
+            profile: UserProfile {
+                first_name: model.first_name,
+                last_name: model.last_name,
+                locale: model.locale,
+                phone: model.phone,
+                avatar_url: model.avatar_url,
+            },
+            metadata: metadata_from_column(model.metadata.as_deref()),
             created_at: model.created_at,
             updated_at: model.updated_at,
             is_active: model.is_active,
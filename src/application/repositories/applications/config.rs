@@ -0,0 +1,95 @@
+use crate::{
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::app_config::AppConfig,
+    },
+    infrastructure::repositories::{
+        cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+        database::application_repository::ApplicationDatabaseRepository,
+    },
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use std::sync::Arc;
+
+pub struct ApplicationConfigRepositoryImpl {
+    application_repo: Arc<dyn ApplicationDatabaseRepository>,
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+}
+
+impl ApplicationConfigRepositoryImpl {
+    pub fn new(
+        application_repo: Arc<dyn ApplicationDatabaseRepository>,
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    ) -> Self {
+        Self {
+            application_repo,
+            apporg_cachelayer_repo,
+        }
+    }
+}
+
+/// Writes `config` to the client id table only if it's still the document
+/// `c_apporg` was read from (409 otherwise), then to the applications
+/// table; the cache layer drops the cached copy.
+pub(super) async fn write_application_config(
+    application_repo: &dyn ApplicationDatabaseRepository,
+    apporg_cachelayer_repo: &dyn ApplicationsOrganizationByClientIdCacheLayerRepository,
+    c_apporg: &CleanAppOrgByClientId,
+    config: &AppConfig,
+) -> RepositoryResult<()> {
+    let document = serde_json::to_string(config)?;
+    let updated_at = CqlTimestamp(Utc::now().timestamp_millis());
+    let applied = apporg_cachelayer_repo
+        .update_application_config(
+            c_apporg.client_id,
+            document.clone(),
+            updated_at,
+            c_apporg.updated_at,
+        )
+        .await?;
+    if !applied {
+        return Err(RepositoryError::new(
+            "application config was changed by another request".to_string(),
+            409,
+        ));
+    }
+    application_repo
+        .update_application_config(
+            c_apporg.organization_id,
+            c_apporg.application_id,
+            document,
+            updated_at,
+        )
+        .await
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ApplicationConfigRepository: Send + Sync {
+    /// See `write_application_config`.
+    async fn update_application_config(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        config: &AppConfig,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl ApplicationConfigRepository for ApplicationConfigRepositoryImpl {
+    async fn update_application_config(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        config: &AppConfig,
+    ) -> RepositoryResult<()> {
+        write_application_config(
+            self.application_repo.as_ref(),
+            self.apporg_cachelayer_repo.as_ref(),
+            c_apporg,
+            config,
+        )
+        .await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod config;
+pub mod update_identity_providers;
+pub mod update_redirect_uris;
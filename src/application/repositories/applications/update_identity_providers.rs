@@ -0,0 +1,70 @@
+use crate::{
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+        value_objects::app_config::AppConfig,
+    },
+    infrastructure::repositories::{
+        cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+        cipher::aes_gcm_repository::AesGcmCipherRepository,
+        database::application_repository::ApplicationDatabaseRepository,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::config::write_application_config;
+
+pub struct UpdateIdentityProvidersRepositoryImpl {
+    application_repo: Arc<dyn ApplicationDatabaseRepository>,
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    aes_repo: Arc<dyn AesGcmCipherRepository>,
+}
+
+impl UpdateIdentityProvidersRepositoryImpl {
+    pub fn new(
+        application_repo: Arc<dyn ApplicationDatabaseRepository>,
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+        aes_repo: Arc<dyn AesGcmCipherRepository>,
+    ) -> Self {
+        Self {
+            application_repo,
+            apporg_cachelayer_repo,
+            aes_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UpdateIdentityProvidersRepository: Send + Sync {
+    /// Returns `nonce.ciphertext`.
+    async fn encrypt_client_secret(&self, client_secret: &str) -> RepositoryResult<String>;
+    async fn update_application_config(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        config: &AppConfig,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl UpdateIdentityProvidersRepository for UpdateIdentityProvidersRepositoryImpl {
+    async fn encrypt_client_secret(&self, client_secret: &str) -> RepositoryResult<String> {
+        let (nonce, ciphertext) = self.aes_repo.encrypt(client_secret.as_bytes()).await?;
+        Ok(format!("{nonce}.{ciphertext}"))
+    }
+
+    async fn update_application_config(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        config: &AppConfig,
+    ) -> RepositoryResult<()> {
+        write_application_config(
+            self.application_repo.as_ref(),
+            self.apporg_cachelayer_repo.as_ref(),
+            c_apporg,
+            config,
+        )
+        .await
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+        value_objects::app_config::AppConfig,
+    },
+    infrastructure::repositories::{
+        cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+        database::application_repository::ApplicationDatabaseRepository,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::config::write_application_config;
+
+pub struct UpdateRedirectUrisRepositoryImpl {
+    application_repo: Arc<dyn ApplicationDatabaseRepository>,
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+}
+
+impl UpdateRedirectUrisRepositoryImpl {
+    pub fn new(
+        application_repo: Arc<dyn ApplicationDatabaseRepository>,
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    ) -> Self {
+        Self {
+            application_repo,
+            apporg_cachelayer_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UpdateRedirectUrisRepository: Send + Sync {
+    /// See `config::write_application_config`.
+    async fn update_application_config(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        config: &AppConfig,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl UpdateRedirectUrisRepository for UpdateRedirectUrisRepositoryImpl {
+    async fn update_application_config(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        config: &AppConfig,
+    ) -> RepositoryResult<()> {
+        write_application_config(
+            self.application_repo.as_ref(),
+            self.apporg_cachelayer_repo.as_ref(),
+            c_apporg,
+            config,
+        )
+        .await
+    }
+}
@@ -5,9 +5,13 @@ use crate::{
         },
         errors::replicator::ReplicatorRepoError,
     },
-    domain::entities::user::User,
+    domain::{
+        entities::user::User,
+        value_objects::{identifier::normalize_username, user_profile::UserProfile},
+    },
     infrastructure::{
-        errors::queue::ReplicatorError, models::queue::users::QueueUser,
+        errors::queue::ReplicatorError,
+        models::{queue::users::QueueUser, user::metadata_from_column},
         repositories::queue::producer::ProducerRepository,
     },
 };
@@ -74,13 +78,25 @@ impl ReplicatorRepository for ReplicatorRepositoryImpl {
         self.send(message)
     }
     fn parse_user_from_cdcrow(&self, data: &CDCRow<'_>) -> Result<User, ReplicatorRepoError> {
+        let username = get_string(data, "username")?;
         Ok(User {
             user_id: get_uuid(data, "user_id")?,
             organization_id: get_uuid(data, "organization_id")?,
             application_id: get_uuid(data, "application_id")?,
-            username: get_string(data, "username")?,
+            username_normalized: get_optional_string(data, "username_normalized")?
+                .unwrap_or_else(|| normalize_username(&username)),
+            username,
             email: get_optional_string(data, "email")?,
+            email_normalized: get_optional_string(data, "email_normalized")?,
             hashed_password: get_string(data, "hashed_password")?,
+            profile: UserProfile {
+                first_name: get_optional_string(data, "first_name")?,
+                last_name: get_optional_string(data, "last_name")?,
+                locale: get_optional_string(data, "locale")?,
+                phone: get_optional_string(data, "phone")?,
+                avatar_url: get_optional_string(data, "avatar_url")?,
+            },
+            metadata: metadata_from_column(get_optional_string(data, "metadata")?.as_deref()),
             created_at: get_i64(data, "created_at")?,
             updated_at: get_i64(data, "updated_at")?,
             is_active: get_bool(data, "is_active")?,
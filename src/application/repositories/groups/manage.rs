@@ -0,0 +1,132 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::group::{GroupModel, UpdateGroupModel},
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            database::groups::GroupDatabaseRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
+
+pub struct ManageGroupsRepositoryImpl {
+    database_repo: Arc<dyn GroupDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+}
+
+impl ManageGroupsRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn GroupDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            permissions_cache_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ManageGroupsRepository: Send + Sync {
+    fn new_group(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        name: String,
+        description: Option<String>,
+    ) -> GroupModel;
+    async fn create_group(&self, group: &GroupModel) -> RepositoryResult<()>;
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>>;
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>>;
+    async fn update_group(&self, update: &UpdateGroupModel) -> RepositoryResult<()>;
+    /// Deletes the group with its memberships; members lose the roles the
+    /// group granted.
+    async fn delete_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl ManageGroupsRepository for ManageGroupsRepositoryImpl {
+    fn new_group(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        name: String,
+        description: Option<String>,
+    ) -> GroupModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        GroupModel {
+            organization_id,
+            application_id,
+            group_id: Uuid::new_v4(),
+            name,
+            description,
+            role_ids: HashSet::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    async fn create_group(&self, group: &GroupModel) -> RepositoryResult<()> {
+        self.database_repo.create_group(group).await
+    }
+
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>> {
+        self.database_repo.get_group(org_id, app_id, group_id).await
+    }
+
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>> {
+        self.database_repo.get_groups(org_id, app_id).await
+    }
+
+    async fn update_group(&self, update: &UpdateGroupModel) -> RepositoryResult<()> {
+        self.database_repo.update_group(update).await
+    }
+
+    async fn delete_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let members = self
+            .database_repo
+            .get_members(org_id, app_id, group_id)
+            .await?;
+        for member in &members {
+            self.database_repo
+                .remove_member(org_id, app_id, group_id, member.user_id)
+                .await?;
+        }
+        self.database_repo
+            .remove_members_of_group(org_id, app_id, group_id)
+            .await?;
+        self.database_repo
+            .delete_group(org_id, app_id, group_id)
+            .await?;
+        let user_ids: Vec<Uuid> = members.into_iter().map(|m| m.user_id).collect();
+        self.permissions_cache_repo
+            .invalidate_cache(org_id, app_id, &user_ids)
+            .await
+    }
+}
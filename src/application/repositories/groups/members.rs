@@ -0,0 +1,159 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::group::{GroupMembershipModel, GroupModel, PaginatedGroupMembersModel},
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            cipher::base64_repository::Base64Repository,
+            database::{groups::GroupDatabaseRepository, user_repository::UserDatabaseRepository},
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GroupMembersRepositoryImpl {
+    database_repo: Arc<dyn GroupDatabaseRepository>,
+    user_database_repo: Arc<dyn UserDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    base64_repo: Arc<dyn Base64Repository>,
+}
+
+impl GroupMembersRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn GroupDatabaseRepository>,
+        user_database_repo: Arc<dyn UserDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+        base64_repo: Arc<dyn Base64Repository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            user_database_repo,
+            permissions_cache_repo,
+            base64_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GroupMembersRepository: Send + Sync {
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>>;
+    async fn user_exists(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+    async fn get_members(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedGroupMembersModel>;
+    async fn add_member(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn remove_member(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    fn bytes_to_base64(&self, bytes: Vec<u8>) -> String;
+    fn base64_to_bytes(&self, base64: String) -> RepositoryResult<Vec<u8>>;
+}
+
+#[async_trait]
+impl GroupMembersRepository for GroupMembersRepositoryImpl {
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>> {
+        self.database_repo.get_group(org_id, app_id, group_id).await
+    }
+
+    async fn user_exists(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        let user = self
+            .user_database_repo
+            .find_user(app_id, org_id, user_id)
+            .await?;
+        Ok(user.is_some())
+    }
+
+    async fn get_members(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedGroupMembersModel> {
+        self.database_repo
+            .get_members_paginated(org_id, app_id, group_id, page_size, paging_state_u8)
+            .await
+    }
+
+    async fn add_member(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let membership = GroupMembershipModel {
+            organization_id: org_id,
+            application_id: app_id,
+            group_id,
+            user_id,
+        };
+        self.database_repo.add_member(&membership).await?;
+        self.permissions_cache_repo
+            .invalidate_cache(org_id, app_id, &[user_id])
+            .await
+    }
+
+    async fn remove_member(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .remove_member(org_id, app_id, group_id, user_id)
+            .await?;
+        self.permissions_cache_repo
+            .invalidate_cache(org_id, app_id, &[user_id])
+            .await
+    }
+
+    fn bytes_to_base64(&self, bytes: Vec<u8>) -> String {
+        self.base64_repo.encode(bytes.as_slice())
+    }
+
+    fn base64_to_bytes(&self, base64: String) -> RepositoryResult<Vec<u8>> {
+        let decoded = self.base64_repo.decode(base64.as_str())?;
+        Ok(decoded)
+    }
+}
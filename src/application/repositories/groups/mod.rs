@@ -0,0 +1,3 @@
+pub mod manage;
+pub mod members;
+pub mod roles;
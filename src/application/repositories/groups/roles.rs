@@ -0,0 +1,129 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::{group::GroupModel, role::SelectedRoleByIdModel},
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            database::{groups::GroupDatabaseRepository, roles::RoleDatabaseRepository},
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GroupRolesRepositoryImpl {
+    database_repo: Arc<dyn GroupDatabaseRepository>,
+    role_database_repo: Arc<dyn RoleDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+}
+
+impl GroupRolesRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn GroupDatabaseRepository>,
+        role_database_repo: Arc<dyn RoleDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            role_database_repo,
+            permissions_cache_repo,
+        }
+    }
+
+    async fn invalidate_members(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let members = self
+            .database_repo
+            .get_members(org_id, app_id, group_id)
+            .await?;
+        let user_ids: Vec<Uuid> = members.into_iter().map(|m| m.user_id).collect();
+        self.permissions_cache_repo
+            .invalidate_cache(org_id, app_id, &user_ids)
+            .await
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GroupRolesRepository: Send + Sync {
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>>;
+    async fn get_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Option<SelectedRoleByIdModel>>;
+    async fn assign_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn remove_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl GroupRolesRepository for GroupRolesRepositoryImpl {
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>> {
+        self.database_repo.get_group(org_id, app_id, group_id).await
+    }
+
+    async fn get_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Option<SelectedRoleByIdModel>> {
+        self.role_database_repo
+            .get_role(org_id, app_id, role_id)
+            .await
+    }
+
+    async fn assign_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .add_role_to_group(org_id, app_id, group_id, role_id)
+            .await?;
+        self.invalidate_members(org_id, app_id, group_id).await
+    }
+
+    async fn remove_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .remove_role_from_group(org_id, app_id, group_id, role_id)
+            .await?;
+        self.invalidate_members(org_id, app_id, group_id).await
+    }
+}
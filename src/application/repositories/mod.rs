@@ -1,7 +1,14 @@
+pub mod applications;
 pub mod cdc;
 pub mod errors;
+pub mod groups;
 pub mod initial_core;
+pub mod oauth;
+pub mod permissions;
 pub mod refresh_tokens;
 pub mod roles;
+pub mod scim;
+pub mod service_accounts;
+pub mod signing_keys;
 pub mod users;
 pub mod validate_bearer_auth_repository;
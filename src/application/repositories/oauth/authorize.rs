@@ -0,0 +1,211 @@
+use crate::{
+    application::mappers::model::ModelMapper,
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+    infrastructure::{
+        models::{authorization_code::AuthorizationCodeModel, user::UserModel},
+        repositories::{
+            cache::{
+                authorization_code_repository::AuthorizationCodeCacheRepository,
+                csrf_token_repository::CsrfTokenCacheRepository,
+            },
+            cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+            database::user_repository::UserDatabaseRepository,
+            security::argon2_repository::PasswordHasherRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand_core::{OsRng, TryRngCore};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn random_token() -> RepositoryResult<String> {
+    let mut token = vec![0u8; 32];
+    OsRng.try_fill_bytes(&mut token)?;
+    Ok(URL_SAFE_NO_PAD.encode(&token))
+}
+
+pub struct AuthorizeRepositoryImpl {
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    database_repo: Arc<dyn UserDatabaseRepository>,
+    hasher_repo: Arc<dyn PasswordHasherRepository>,
+    authorization_code_repo: Arc<dyn AuthorizationCodeCacheRepository>,
+    csrf_token_repo: Arc<dyn CsrfTokenCacheRepository>,
+}
+
+impl AuthorizeRepositoryImpl {
+    pub fn new(
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+        database_repo: Arc<dyn UserDatabaseRepository>,
+        hasher_repo: Arc<dyn PasswordHasherRepository>,
+        authorization_code_repo: Arc<dyn AuthorizationCodeCacheRepository>,
+        csrf_token_repo: Arc<dyn CsrfTokenCacheRepository>,
+    ) -> Self {
+        Self {
+            apporg_cachelayer_repo,
+            database_repo,
+            hasher_repo,
+            authorization_code_repo,
+            csrf_token_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait AuthorizeRepository: Send + Sync {
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>>;
+    async fn find_user_id_by_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+    ) -> RepositoryResult<Option<Uuid>>;
+    async fn find_user_id_by_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+    ) -> RepositoryResult<Option<Uuid>>;
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<UserModel>>;
+    fn verify_password(&self, hashed: &str, plain: &str) -> RepositoryResult<bool>;
+    /// Whether a legacy or outdated hash should be upgraded after sign-in.
+    fn needs_rehash(&self, hashed: &str) -> bool;
+    fn hash_password(&self, plain: &str) -> RepositoryResult<String>;
+    async fn update_hashed_password(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        previous: String,
+        hashed_password: String,
+    ) -> RepositoryResult<bool>;
+    fn generate_authorization_code(&self) -> RepositoryResult<String>;
+    async fn store_authorization_code(&self, code: &AuthorizationCodeModel)
+    -> RepositoryResult<()>;
+    fn generate_csrf_token(&self) -> RepositoryResult<String>;
+    async fn store_csrf_token(&self, token: &str, client_id: Uuid) -> RepositoryResult<()>;
+    /// Consumes the token and returns the client whose form it was issued for.
+    async fn take_csrf_token(&self, token: &str) -> RepositoryResult<Option<Uuid>>;
+}
+
+#[async_trait]
+impl AuthorizeRepository for AuthorizeRepositoryImpl {
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>> {
+        let apporg = self
+            .apporg_cachelayer_repo
+            .find_apporg_by_client_id(client_id)
+            .await?;
+        Ok(apporg.map(|a| CleanAppOrgByClientId::from(a.to_entity())))
+    }
+
+    async fn find_user_id_by_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+    ) -> RepositoryResult<Option<Uuid>> {
+        let user = self
+            .database_repo
+            .find_user_by_username(username, application_id, organization_id)
+            .await?;
+        Ok(user.map(|u| u.user_id))
+    }
+
+    async fn find_user_id_by_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+    ) -> RepositoryResult<Option<Uuid>> {
+        let user = self
+            .database_repo
+            .find_user_by_email(email, application_id, organization_id)
+            .await?;
+        Ok(user.map(|u| u.user_id))
+    }
+
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<UserModel>> {
+        self.database_repo
+            .find_raw_user(application_id, organization_id, user_id)
+            .await
+    }
+
+    fn verify_password(&self, hashed: &str, plain: &str) -> RepositoryResult<bool> {
+        self.hasher_repo.verify(hashed, plain)
+    }
+
+    fn needs_rehash(&self, hashed: &str) -> bool {
+        self.hasher_repo.needs_rehash(hashed)
+    }
+
+    fn hash_password(&self, plain: &str) -> RepositoryResult<String> {
+        self.hasher_repo.hash(plain)
+    }
+
+    async fn update_hashed_password(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        previous: String,
+        hashed_password: String,
+    ) -> RepositoryResult<bool> {
+        self.database_repo
+            .update_hashed_password(
+                organization_id,
+                application_id,
+                user_id,
+                previous,
+                hashed_password,
+            )
+            .await
+    }
+
+    fn generate_authorization_code(&self) -> RepositoryResult<String> {
+        random_token()
+    }
+
+    async fn store_authorization_code(
+        &self,
+        code: &AuthorizationCodeModel,
+    ) -> RepositoryResult<()> {
+        self.authorization_code_repo
+            .store_authorization_code(code)
+            .await
+    }
+
+    fn generate_csrf_token(&self) -> RepositoryResult<String> {
+        random_token()
+    }
+
+    async fn store_csrf_token(&self, token: &str, client_id: Uuid) -> RepositoryResult<()> {
+        self.csrf_token_repo
+            .store_csrf_token(token, client_id)
+            .await
+    }
+
+    async fn take_csrf_token(&self, token: &str) -> RepositoryResult<Option<Uuid>> {
+        self.csrf_token_repo.take_csrf_token(token).await
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    application::mappers::{
+        application::ApplicationMapper, model::ModelMapper, role::hierarchy::RoleHierarchyMapper,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+    infrastructure::{
+        models::service_account::ServiceAccountModel,
+        repositories::{
+            cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+            cipher::aes_gcm_repository::AesGcmCipherRepository,
+            database::{
+                roles::RoleDatabaseRepository, service_account::ServiceAccountDatabaseRepository,
+            },
+            keys::signing_key_store::SigningKeyStore,
+            paseto::{access_token::AccessTokenPasetoRepository, signing_key::SigningKeyPair},
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ClientCredentialsRepositoryImpl {
+    service_account_database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+    role_database_repo: Arc<dyn RoleDatabaseRepository>,
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    aes_repo: Arc<dyn AesGcmCipherRepository>,
+    access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
+}
+
+impl ClientCredentialsRepositoryImpl {
+    pub fn new(
+        service_account_database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+        role_database_repo: Arc<dyn RoleDatabaseRepository>,
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+        aes_repo: Arc<dyn AesGcmCipherRepository>,
+        access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
+    ) -> Self {
+        Self {
+            service_account_database_repo,
+            role_database_repo,
+            apporg_cachelayer_repo,
+            aes_repo,
+            access_token_paseto_repo,
+            signing_key_store,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ClientCredentialsRepository: Send + Sync {
+    async fn find_service_account_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>>;
+    async fn decrypt_client_secret(
+        &self,
+        client_key: &str,
+        encrypted_client_secret: &str,
+    ) -> RepositoryResult<String>;
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>>;
+    /// Union of the permissions of every role assigned to the account,
+    /// inherited ones included.
+    async fn find_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Vec<String>>;
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair>;
+    async fn create_access_token(
+        &self,
+        signing_key: &SigningKeyPair,
+        service_account_id: Uuid,
+        client_id: Uuid,
+        permissions: Vec<String>,
+        issued_at: String,
+        expires_at: String,
+    ) -> RepositoryResult<String>;
+}
+
+#[async_trait]
+impl ClientCredentialsRepository for ClientCredentialsRepositoryImpl {
+    async fn find_service_account_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>> {
+        self.service_account_database_repo
+            .find_service_account_by_client_id(client_id)
+            .await
+    }
+
+    async fn decrypt_client_secret(
+        &self,
+        client_key: &str,
+        encrypted_client_secret: &str,
+    ) -> RepositoryResult<String> {
+        self.aes_repo
+            .decrypt(client_key, encrypted_client_secret)
+            .await
+    }
+
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>> {
+        let apporg = self
+            .apporg_cachelayer_repo
+            .find_apporg_by_client_id(client_id)
+            .await?;
+        Ok(apporg.map(|a| CleanAppOrgByClientId::from(a.to_entity())))
+    }
+
+    async fn find_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Vec<String>> {
+        let assignments = self
+            .role_database_repo
+            .get_roles_by_user(organization_id, application_id, service_account_id)
+            .await?;
+        if assignments.is_empty() {
+            return Ok(Vec::new());
+        }
+        let roles = self
+            .role_database_repo
+            .get_roles(organization_id, application_id)
+            .await?;
+        Ok(RoleHierarchyMapper::to_dto(roles)
+            .effective_permissions(assignments.into_iter().map(|a| a.role_id))
+            .into_iter()
+            .collect())
+    }
+
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair> {
+        self.signing_key_store
+            .active_key(organization_id, application_id)
+            .await
+    }
+
+    async fn create_access_token(
+        &self,
+        signing_key: &SigningKeyPair,
+        service_account_id: Uuid,
+        client_id: Uuid,
+        permissions: Vec<String>,
+        issued_at: String,
+        expires_at: String,
+    ) -> RepositoryResult<String> {
+        self.access_token_paseto_repo
+            .encrypt_for_service_account(
+                signing_key,
+                service_account_id,
+                client_id,
+                permissions,
+                issued_at,
+                expires_at,
+            )
+            .await
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    application::mappers::model::ModelMapper,
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+        value_objects::device_code::{USER_CODE_ALPHABET, USER_CODE_LENGTH},
+    },
+    infrastructure::{
+        models::device_authorization::{DeviceAuthorizationDecision, DeviceAuthorizationModel},
+        repositories::{
+            cache::device_authorization_repository::DeviceAuthorizationCacheRepository,
+            cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+            jwt::id_token::IdTokenJwtRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand_core::{OsRng, TryRngCore};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct DeviceAuthorizationRepositoryImpl {
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    device_authorization_repo: Arc<dyn DeviceAuthorizationCacheRepository>,
+    id_token_repo: Arc<dyn IdTokenJwtRepository>,
+}
+
+impl DeviceAuthorizationRepositoryImpl {
+    pub fn new(
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+        device_authorization_repo: Arc<dyn DeviceAuthorizationCacheRepository>,
+        id_token_repo: Arc<dyn IdTokenJwtRepository>,
+    ) -> Self {
+        Self {
+            apporg_cachelayer_repo,
+            device_authorization_repo,
+            id_token_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DeviceAuthorizationRepository: Send + Sync {
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>>;
+    fn generate_device_code(&self) -> RepositoryResult<String>;
+    fn generate_user_code(&self) -> RepositoryResult<String>;
+    async fn store_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+    ) -> RepositoryResult<()>;
+    async fn find_device_authorization_by_user_code(
+        &self,
+        user_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>>;
+    /// Records the user's answer; false when the request was already answered.
+    async fn decide_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        decision: &DeviceAuthorizationDecision,
+    ) -> RepositoryResult<bool>;
+    fn device_code_ttl(&self) -> u64;
+    fn poll_interval(&self) -> u64;
+    fn issuer(&self) -> String;
+}
+
+#[async_trait]
+impl DeviceAuthorizationRepository for DeviceAuthorizationRepositoryImpl {
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>> {
+        let apporg = self
+            .apporg_cachelayer_repo
+            .find_apporg_by_client_id(client_id)
+            .await?;
+        Ok(apporg.map(|a| CleanAppOrgByClientId::from(a.to_entity())))
+    }
+
+    fn generate_device_code(&self) -> RepositoryResult<String> {
+        let mut code = vec![0u8; 32];
+        OsRng.try_fill_bytes(&mut code)?;
+        Ok(URL_SAFE_NO_PAD.encode(&code))
+    }
+
+    fn generate_user_code(&self) -> RepositoryResult<String> {
+        // bytes past the largest multiple of the alphabet size are dropped
+        // so every character is equally likely
+        let limit = u8::MAX - u8::MAX % USER_CODE_ALPHABET.len() as u8;
+        let mut code = String::with_capacity(USER_CODE_LENGTH);
+        let mut buf = [0u8; 16];
+        while code.len() < USER_CODE_LENGTH {
+            OsRng.try_fill_bytes(&mut buf)?;
+            code.extend(
+                buf.iter()
+                    .filter(|b| **b < limit)
+                    .map(|b| USER_CODE_ALPHABET[*b as usize % USER_CODE_ALPHABET.len()] as char)
+                    .take(USER_CODE_LENGTH - code.len()),
+            );
+        }
+        Ok(code)
+    }
+
+    async fn store_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+    ) -> RepositoryResult<()> {
+        self.device_authorization_repo
+            .store_device_authorization(authorization)
+            .await
+    }
+
+    async fn find_device_authorization_by_user_code(
+        &self,
+        user_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>> {
+        self.device_authorization_repo
+            .find_device_authorization_by_user_code(user_code)
+            .await
+    }
+
+    async fn decide_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        decision: &DeviceAuthorizationDecision,
+    ) -> RepositoryResult<bool> {
+        self.device_authorization_repo
+            .decide_device_authorization(authorization, decision)
+            .await
+    }
+
+    fn device_code_ttl(&self) -> u64 {
+        self.device_authorization_repo.ttl()
+    }
+
+    fn poll_interval(&self) -> u64 {
+        self.device_authorization_repo.interval()
+    }
+
+    fn issuer(&self) -> String {
+        self.id_token_repo.issuer()
+    }
+}
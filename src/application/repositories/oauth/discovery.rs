@@ -0,0 +1,88 @@
+use crate::{
+    application::mappers::model::ModelMapper,
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+    infrastructure::{
+        models::jwk::Jwk,
+        repositories::{
+            cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+            jwt::id_token::IdTokenJwtRepository, keys::signing_key_store::SigningKeyStore,
+            paseto::signing_key::VerificationKey,
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct DiscoveryRepositoryImpl {
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    id_token_repo: Arc<dyn IdTokenJwtRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
+}
+
+impl DiscoveryRepositoryImpl {
+    pub fn new(
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+        id_token_repo: Arc<dyn IdTokenJwtRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
+    ) -> Self {
+        Self {
+            apporg_cachelayer_repo,
+            id_token_repo,
+            signing_key_store,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DiscoveryRepository: Send + Sync {
+    fn issuer(&self) -> String;
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>>;
+    /// Keys of the application that tokens may still be verified with,
+    /// including retired keys inside their overlap window.
+    async fn verification_keys(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<VerificationKey>>;
+    fn jwk(&self, key: &VerificationKey) -> Jwk;
+}
+
+#[async_trait]
+impl DiscoveryRepository for DiscoveryRepositoryImpl {
+    fn issuer(&self) -> String {
+        self.id_token_repo.issuer()
+    }
+
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>> {
+        let apporg = self
+            .apporg_cachelayer_repo
+            .find_apporg_by_client_id(client_id)
+            .await?;
+        Ok(apporg.map(|a| CleanAppOrgByClientId::from(a.to_entity())))
+    }
+
+    async fn verification_keys(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<VerificationKey>> {
+        self.signing_key_store
+            .verification_keys(organization_id, application_id)
+            .await
+    }
+
+    fn jwk(&self, key: &VerificationKey) -> Jwk {
+        self.id_token_repo.jwk(key)
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    domain::errors::repositories_errors::{RepositoryError, RepositoryResult},
+    infrastructure::{
+        models::{
+            federation_state::FederationStateModel,
+            upstream_oidc::{ProviderMetadata, UpstreamIdTokenClaims},
+            user::UserModel,
+            user_identity::UserIdentityModel,
+        },
+        repositories::{
+            cache::federation_state_repository::FederationStateCacheRepository,
+            cipher::aes_gcm_repository::AesGcmCipherRepository,
+            database::{
+                user_identity::UserIdentityDatabaseRepository,
+                user_repository::UserDatabaseRepository,
+            },
+            jwt::id_token::IdTokenJwtRepository,
+            oidc::upstream::UpstreamOidcRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand_core::{OsRng, TryRngCore};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct FederationRepositoryImpl {
+    identity_database_repo: Arc<dyn UserIdentityDatabaseRepository>,
+    user_database_repo: Arc<dyn UserDatabaseRepository>,
+    state_repo: Arc<dyn FederationStateCacheRepository>,
+    upstream_repo: Arc<dyn UpstreamOidcRepository>,
+    aes_repo: Arc<dyn AesGcmCipherRepository>,
+    id_token_repo: Arc<dyn IdTokenJwtRepository>,
+}
+
+impl FederationRepositoryImpl {
+    pub fn new(
+        identity_database_repo: Arc<dyn UserIdentityDatabaseRepository>,
+        user_database_repo: Arc<dyn UserDatabaseRepository>,
+        state_repo: Arc<dyn FederationStateCacheRepository>,
+        upstream_repo: Arc<dyn UpstreamOidcRepository>,
+        aes_repo: Arc<dyn AesGcmCipherRepository>,
+        id_token_repo: Arc<dyn IdTokenJwtRepository>,
+    ) -> Self {
+        Self {
+            identity_database_repo,
+            user_database_repo,
+            state_repo,
+            upstream_repo,
+            aes_repo,
+            id_token_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait FederationRepository: Send + Sync {
+    /// 32 random bytes, base64url encoded; used for state, nonce and PKCE.
+    fn generate_random(&self) -> RepositoryResult<String>;
+    fn code_challenge(&self, code_verifier: &str) -> String;
+    /// Where upstream providers send the user back to.
+    fn callback_uri(&self) -> String;
+    async fn store_state(&self, state: &FederationStateModel) -> RepositoryResult<()>;
+    async fn take_state(&self, state: &str) -> RepositoryResult<Option<FederationStateModel>>;
+    async fn discover(&self, issuer: &str) -> RepositoryResult<ProviderMetadata>;
+    async fn decrypt_client_secret(&self, encrypted: &str) -> RepositoryResult<String>;
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        code_verifier: &str,
+    ) -> RepositoryResult<String>;
+    fn decode_id_token(&self, id_token: &str) -> RepositoryResult<UpstreamIdTokenClaims>;
+    async fn find_identity(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        provider_id: &str,
+        subject: &str,
+    ) -> RepositoryResult<Option<Uuid>>;
+    /// Returns false when the subject is already linked to another user.
+    async fn link_identity(&self, identity: &UserIdentityModel) -> RepositoryResult<bool>;
+    async fn find_user_id_by_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+    ) -> RepositoryResult<Option<Uuid>>;
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<UserModel>>;
+}
+
+#[async_trait]
+impl FederationRepository for FederationRepositoryImpl {
+    fn generate_random(&self) -> RepositoryResult<String> {
+        let mut bytes = vec![0u8; 32];
+        OsRng.try_fill_bytes(&mut bytes)?;
+        Ok(URL_SAFE_NO_PAD.encode(&bytes))
+    }
+
+    fn code_challenge(&self, code_verifier: &str) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+    }
+
+    fn callback_uri(&self) -> String {
+        format!(
+            "{}/oauth/federation/callback",
+            self.id_token_repo.issuer().trim_end_matches('/')
+        )
+    }
+
+    async fn store_state(&self, state: &FederationStateModel) -> RepositoryResult<()> {
+        self.state_repo.store_federation_state(state).await
+    }
+
+    async fn take_state(&self, state: &str) -> RepositoryResult<Option<FederationStateModel>> {
+        self.state_repo.take_federation_state(state).await
+    }
+
+    async fn discover(&self, issuer: &str) -> RepositoryResult<ProviderMetadata> {
+        self.upstream_repo.discover(issuer).await
+    }
+
+    async fn decrypt_client_secret(&self, encrypted: &str) -> RepositoryResult<String> {
+        let (nonce, ciphertext) = encrypted.split_once('.').ok_or_else(|| {
+            RepositoryError::new("invalid identity provider secret".to_string(), 500)
+        })?;
+        self.aes_repo.decrypt(nonce, ciphertext).await
+    }
+
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        code_verifier: &str,
+    ) -> RepositoryResult<String> {
+        self.upstream_repo
+            .exchange_code(
+                token_endpoint,
+                client_id,
+                client_secret,
+                code,
+                &self.callback_uri(),
+                code_verifier,
+            )
+            .await
+    }
+
+    fn decode_id_token(&self, id_token: &str) -> RepositoryResult<UpstreamIdTokenClaims> {
+        self.upstream_repo.decode_id_token(id_token)
+    }
+
+    async fn find_identity(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        provider_id: &str,
+        subject: &str,
+    ) -> RepositoryResult<Option<Uuid>> {
+        let identity = self
+            .identity_database_repo
+            .find_user_identity(organization_id, application_id, provider_id, subject)
+            .await?;
+        Ok(identity.map(|i| i.user_id))
+    }
+
+    async fn link_identity(&self, identity: &UserIdentityModel) -> RepositoryResult<bool> {
+        self.identity_database_repo
+            .create_user_identity(identity)
+            .await
+    }
+
+    async fn find_user_id_by_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+    ) -> RepositoryResult<Option<Uuid>> {
+        let user = self
+            .user_database_repo
+            .find_user_by_email(email, application_id, organization_id)
+            .await?;
+        Ok(user.map(|u| u.user_id))
+    }
+
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<UserModel>> {
+        self.user_database_repo
+            .find_raw_user(application_id, organization_id, user_id)
+            .await
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::{
+            refresh_token::FoundRefreshTokenModel,
+            service_account::ServiceAccountModel,
+            token_claim::{AccessTokenClaims, TokenClaims},
+            user::CleannedUserModel,
+        },
+        repositories::{
+            cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository,
+            database::{
+                service_account::ServiceAccountDatabaseRepository,
+                user_repository::UserDatabaseRepository,
+            },
+            keys::signing_key_store::SigningKeyStore,
+            paseto::{
+                access_token::AccessTokenPasetoRepository, refresh_token::PasetoRepository,
+                signing_key::VerificationKey,
+            },
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct IntrospectRepositoryImpl {
+    database_repo: Arc<dyn UserDatabaseRepository>,
+    service_account_database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
+    refresh_token_paseto_repo: Arc<dyn PasetoRepository>,
+    access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
+}
+
+impl IntrospectRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn UserDatabaseRepository>,
+        service_account_database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+        refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
+        refresh_token_paseto_repo: Arc<dyn PasetoRepository>,
+        access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
+    ) -> Self {
+        Self {
+            database_repo,
+            service_account_database_repo,
+            refresh_token_cachelayer_repo,
+            refresh_token_paseto_repo,
+            access_token_paseto_repo,
+            signing_key_store,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait IntrospectRepository: Send + Sync {
+    async fn find_verification_key(&self, token: &str)
+    -> RepositoryResult<Option<VerificationKey>>;
+    async fn decrypt_refresh_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<TokenClaims>;
+    async fn decrypt_access_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims>;
+    async fn find_refresh_token(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>>;
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>>;
+    async fn find_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>>;
+}
+
+#[async_trait]
+impl IntrospectRepository for IntrospectRepositoryImpl {
+    async fn find_verification_key(
+        &self,
+        token: &str,
+    ) -> RepositoryResult<Option<VerificationKey>> {
+        self.signing_key_store.find_verification_key(token).await
+    }
+
+    async fn decrypt_refresh_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<TokenClaims> {
+        self.refresh_token_paseto_repo.decrypt(token, key).await
+    }
+
+    async fn decrypt_access_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims> {
+        self.access_token_paseto_repo.decrypt(token, key).await
+    }
+
+    async fn find_refresh_token(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>> {
+        self.refresh_token_cachelayer_repo
+            .find_refresh_token(organization_id, application_id, token_id, token_version)
+            .await
+    }
+
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>> {
+        self.database_repo
+            .find_user(application_id, organization_id, user_id)
+            .await
+    }
+
+    async fn find_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>> {
+        self.service_account_database_repo
+            .find_service_account(organization_id, application_id, service_account_id)
+            .await
+    }
+}
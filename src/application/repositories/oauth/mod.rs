@@ -0,0 +1,9 @@
+pub mod authorize;
+pub mod client_credentials;
+pub mod device;
+pub mod discovery;
+pub mod federation;
+pub mod introspect;
+pub mod revoke;
+pub mod token;
+pub mod userinfo;
@@ -0,0 +1,100 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::token_claim::{AccessTokenClaims, TokenClaims},
+        repositories::{
+            cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository,
+            keys::signing_key_store::SigningKeyStore,
+            paseto::{
+                access_token::AccessTokenPasetoRepository, refresh_token::PasetoRepository,
+                signing_key::VerificationKey,
+            },
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RevokeTokenRepositoryImpl {
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
+    refresh_token_paseto_repo: Arc<dyn PasetoRepository>,
+    access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
+}
+
+impl RevokeTokenRepositoryImpl {
+    pub fn new(
+        refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
+        refresh_token_paseto_repo: Arc<dyn PasetoRepository>,
+        access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
+    ) -> Self {
+        Self {
+            refresh_token_cachelayer_repo,
+            refresh_token_paseto_repo,
+            access_token_paseto_repo,
+            signing_key_store,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RevokeTokenRepository: Send + Sync {
+    async fn find_verification_key(&self, token: &str)
+    -> RepositoryResult<Option<VerificationKey>>;
+    async fn decrypt_refresh_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<TokenClaims>;
+    async fn decrypt_access_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims>;
+    async fn revoke_refresh_token(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl RevokeTokenRepository for RevokeTokenRepositoryImpl {
+    async fn find_verification_key(
+        &self,
+        token: &str,
+    ) -> RepositoryResult<Option<VerificationKey>> {
+        self.signing_key_store.find_verification_key(token).await
+    }
+
+    async fn decrypt_refresh_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<TokenClaims> {
+        self.refresh_token_paseto_repo.decrypt(token, key).await
+    }
+
+    async fn decrypt_access_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims> {
+        self.access_token_paseto_repo.decrypt(token, key).await
+    }
+
+    async fn revoke_refresh_token(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.refresh_token_cachelayer_repo
+            .revoke_refresh_token(organization_id, application_id, token_id)
+            .await
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    application::mappers::model::ModelMapper,
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+    infrastructure::{
+        models::{
+            authorization_code::AuthorizationCodeModel,
+            device_authorization::DeviceAuthorizationModel, token_claim::IdTokenClaims,
+            user::CleannedUserModel,
+        },
+        repositories::{
+            cache::{
+                authorization_code_repository::AuthorizationCodeCacheRepository,
+                device_authorization_repository::DeviceAuthorizationCacheRepository,
+            },
+            cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+            database::user_repository::UserDatabaseRepository,
+            jwt::id_token::IdTokenJwtRepository,
+            keys::signing_key_store::SigningKeyStore,
+            paseto::{access_token::AccessTokenPasetoRepository, signing_key::SigningKeyPair},
+        },
+    },
+};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct TokenRepositoryImpl {
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    database_repo: Arc<dyn UserDatabaseRepository>,
+    authorization_code_repo: Arc<dyn AuthorizationCodeCacheRepository>,
+    access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+    id_token_repo: Arc<dyn IdTokenJwtRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
+    device_authorization_repo: Arc<dyn DeviceAuthorizationCacheRepository>,
+}
+
+impl TokenRepositoryImpl {
+    pub fn new(
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+        database_repo: Arc<dyn UserDatabaseRepository>,
+        authorization_code_repo: Arc<dyn AuthorizationCodeCacheRepository>,
+        access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+        id_token_repo: Arc<dyn IdTokenJwtRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
+        device_authorization_repo: Arc<dyn DeviceAuthorizationCacheRepository>,
+    ) -> Self {
+        Self {
+            apporg_cachelayer_repo,
+            database_repo,
+            authorization_code_repo,
+            access_token_paseto_repo,
+            id_token_repo,
+            signing_key_store,
+            device_authorization_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TokenRepository: Send + Sync {
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>>;
+    async fn take_authorization_code(
+        &self,
+        code: &str,
+    ) -> RepositoryResult<Option<AuthorizationCodeModel>>;
+    async fn find_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>>;
+    /// Adds `seconds` to the device's polling interval without rewriting
+    /// the request, so a concurrent approval or denial is kept.
+    async fn slow_down_device_polling(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        seconds: u64,
+    ) -> RepositoryResult<()>;
+    async fn take_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>>;
+    /// False when the device polled again before its interval elapsed.
+    async fn record_device_poll(&self, device_code: &str, interval: u64) -> RepositoryResult<bool>;
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>>;
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair>;
+    async fn create_access_token(
+        &self,
+        signing_key: &SigningKeyPair,
+        user_id: Uuid,
+        client_id: Uuid,
+        scope: Option<String>,
+        issued_at: String,
+        expires_at: String,
+    ) -> RepositoryResult<String>;
+    fn create_id_token(
+        &self,
+        signing_key: &SigningKeyPair,
+        client_id: Uuid,
+        user: &CleannedUserModel,
+        nonce: Option<String>,
+        issued_at: i64,
+        expires_at: i64,
+    ) -> RepositoryResult<String>;
+    fn access_token_ttl(&self) -> i64;
+    /// S256 transform from RFC 7636: BASE64URL(SHA256(code_verifier)).
+    fn code_challenge(&self, code_verifier: &str) -> String;
+}
+
+#[async_trait]
+impl TokenRepository for TokenRepositoryImpl {
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>> {
+        let apporg = self
+            .apporg_cachelayer_repo
+            .find_apporg_by_client_id(client_id)
+            .await?;
+        Ok(apporg.map(|a| CleanAppOrgByClientId::from(a.to_entity())))
+    }
+
+    async fn take_authorization_code(
+        &self,
+        code: &str,
+    ) -> RepositoryResult<Option<AuthorizationCodeModel>> {
+        self.authorization_code_repo
+            .take_authorization_code(code)
+            .await
+    }
+
+    async fn find_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>> {
+        self.device_authorization_repo
+            .find_device_authorization(device_code)
+            .await
+    }
+
+    async fn slow_down_device_polling(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        seconds: u64,
+    ) -> RepositoryResult<()> {
+        self.device_authorization_repo
+            .slow_down(authorization, seconds)
+            .await
+    }
+
+    async fn take_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>> {
+        self.device_authorization_repo
+            .take_device_authorization(device_code)
+            .await
+    }
+
+    async fn record_device_poll(&self, device_code: &str, interval: u64) -> RepositoryResult<bool> {
+        self.device_authorization_repo
+            .record_poll(device_code, interval)
+            .await
+    }
+
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>> {
+        self.database_repo
+            .find_user(application_id, organization_id, user_id)
+            .await
+    }
+
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair> {
+        self.signing_key_store
+            .active_key(organization_id, application_id)
+            .await
+    }
+
+    async fn create_access_token(
+        &self,
+        signing_key: &SigningKeyPair,
+        user_id: Uuid,
+        client_id: Uuid,
+        scope: Option<String>,
+        issued_at: String,
+        expires_at: String,
+    ) -> RepositoryResult<String> {
+        self.access_token_paseto_repo
+            .encrypt(
+                signing_key,
+                user_id,
+                client_id,
+                scope,
+                issued_at,
+                expires_at,
+            )
+            .await
+    }
+
+    fn create_id_token(
+        &self,
+        signing_key: &SigningKeyPair,
+        client_id: Uuid,
+        user: &CleannedUserModel,
+        nonce: Option<String>,
+        issued_at: i64,
+        expires_at: i64,
+    ) -> RepositoryResult<String> {
+        let claims = IdTokenClaims {
+            iss: self.id_token_repo.issuer(),
+            sub: user.user_id.to_string(),
+            aud: client_id.to_string(),
+            iat: issued_at,
+            exp: expires_at,
+            nonce,
+            email: user.email.clone(),
+            email_verified: user.is_verified,
+        };
+        self.id_token_repo.sign(signing_key, &claims)
+    }
+
+    fn access_token_ttl(&self) -> i64 {
+        self.access_token_paseto_repo.ttl()
+    }
+
+    fn code_challenge(&self, code_verifier: &str) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    application::mappers::model::ModelMapper,
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+    infrastructure::{
+        models::{token_claim::AccessTokenClaims, user::CleannedUserModel},
+        repositories::{
+            cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerRepository,
+            database::user_repository::UserDatabaseRepository,
+            keys::signing_key_store::SigningKeyStore,
+            paseto::{access_token::AccessTokenPasetoRepository, signing_key::VerificationKey},
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct UserInfoRepositoryImpl {
+    apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+    database_repo: Arc<dyn UserDatabaseRepository>,
+    access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
+}
+
+impl UserInfoRepositoryImpl {
+    pub fn new(
+        apporg_cachelayer_repo: Arc<dyn ApplicationsOrganizationByClientIdCacheLayerRepository>,
+        database_repo: Arc<dyn UserDatabaseRepository>,
+        access_token_paseto_repo: Arc<dyn AccessTokenPasetoRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
+    ) -> Self {
+        Self {
+            apporg_cachelayer_repo,
+            database_repo,
+            access_token_paseto_repo,
+            signing_key_store,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UserInfoRepository: Send + Sync {
+    async fn find_verification_key(&self, token: &str)
+    -> RepositoryResult<Option<VerificationKey>>;
+    async fn decrypt_access_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims>;
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>>;
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>>;
+}
+
+#[async_trait]
+impl UserInfoRepository for UserInfoRepositoryImpl {
+    async fn find_verification_key(
+        &self,
+        token: &str,
+    ) -> RepositoryResult<Option<VerificationKey>> {
+        self.signing_key_store.find_verification_key(token).await
+    }
+
+    async fn decrypt_access_token(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims> {
+        self.access_token_paseto_repo.decrypt(token, key).await
+    }
+
+    async fn find_apporg_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<CleanAppOrgByClientId>> {
+        let apporg = self
+            .apporg_cachelayer_repo
+            .find_apporg_by_client_id(client_id)
+            .await?;
+        Ok(apporg.map(|a| CleanAppOrgByClientId::from(a.to_entity())))
+    }
+
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>> {
+        self.database_repo
+            .find_user(application_id, organization_id, user_id)
+            .await
+    }
+}
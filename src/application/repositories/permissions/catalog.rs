@@ -0,0 +1,141 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::{
+            permission::{PermissionModel, UpdatePermissionModel},
+            role::SelectedRoleByAppModel,
+        },
+        repositories::database::{
+            permissions::PermissionDatabaseRepository, roles::RoleDatabaseRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct PermissionCatalogRepositoryImpl {
+    database_repo: Arc<dyn PermissionDatabaseRepository>,
+    role_database_repo: Arc<dyn RoleDatabaseRepository>,
+}
+
+impl PermissionCatalogRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn PermissionDatabaseRepository>,
+        role_database_repo: Arc<dyn RoleDatabaseRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            role_database_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PermissionCatalogRepository: Send + Sync {
+    fn new_permission(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        name: String,
+        description: Option<String>,
+        category: Option<String>,
+    ) -> PermissionModel;
+    /// Returns false when the name is already declared.
+    async fn create_permission(&self, permission: &PermissionModel) -> RepositoryResult<bool>;
+    async fn get_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<Option<PermissionModel>>;
+    async fn get_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>>;
+    async fn update_permission(&self, update: &UpdatePermissionModel) -> RepositoryResult<()>;
+    async fn delete_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<()>;
+    async fn get_roles(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>>;
+}
+
+#[async_trait]
+impl PermissionCatalogRepository for PermissionCatalogRepositoryImpl {
+    fn new_permission(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        name: String,
+        description: Option<String>,
+        category: Option<String>,
+    ) -> PermissionModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        PermissionModel {
+            organization_id,
+            application_id,
+            name,
+            description,
+            category,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    async fn create_permission(&self, permission: &PermissionModel) -> RepositoryResult<bool> {
+        self.database_repo.create_permission(permission).await
+    }
+
+    async fn get_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<Option<PermissionModel>> {
+        self.database_repo
+            .get_permission(org_id, app_id, name)
+            .await
+    }
+
+    async fn get_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>> {
+        self.database_repo.get_permissions(org_id, app_id).await
+    }
+
+    async fn update_permission(&self, update: &UpdatePermissionModel) -> RepositoryResult<()> {
+        self.database_repo.update_permission(update).await
+    }
+
+    async fn delete_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .delete_permission(org_id, app_id, name)
+            .await
+    }
+
+    async fn get_roles(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>> {
+        self.role_database_repo.get_roles(org_id, app_id).await
+    }
+}
@@ -1,11 +1,16 @@
 use crate::domain::entities::refresh_token::RefreshToken;
 use crate::domain::errors::repositories_errors::RepositoryResult;
-use crate::infrastructure::models::refresh_token::RefreshTokenModel;
+use crate::domain::value_objects::client_metadata::ClientMetadata;
+use crate::infrastructure::models::refresh_token::{
+    PaginatedRefreshTokensByUserModel, RefreshTokenModel,
+};
+use crate::infrastructure::repositories::cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository;
 use crate::infrastructure::repositories::cipher::{
     aes_gcm_repository::AesGcmCipherRepository, base64_repository::Base64Repository,
 };
-use crate::infrastructure::repositories::database::refresh_token::RefreshTokenDatabaseRepository;
+use crate::infrastructure::repositories::keys::signing_key_store::SigningKeyStore;
 use crate::infrastructure::repositories::paseto::refresh_token::PasetoRepository;
+use crate::infrastructure::repositories::paseto::signing_key::SigningKeyPair;
 use async_trait::async_trait;
 use rand_core::{OsRng, TryRngCore};
 use scylla::value::CqlTimestamp;
@@ -15,26 +20,30 @@ use uuid::Uuid;
 
 pub struct CreateRefreshTokenRepositoryImpl {
     paseto_repo: Arc<dyn PasetoRepository>,
-    database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
     base64_repo: Arc<dyn Base64Repository>,
     aes_repo: Arc<dyn AesGcmCipherRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
 }
 impl CreateRefreshTokenRepositoryImpl {
     pub fn new(
         paseto_repo: Arc<dyn PasetoRepository>,
-        database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+        refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
         base64_repo: Arc<dyn Base64Repository>,
         aes_repo: Arc<dyn AesGcmCipherRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
     ) -> Self {
         Self {
             paseto_repo,
-            database_repo,
+            refresh_token_cachelayer_repo,
             base64_repo,
             aes_repo,
+            signing_key_store,
         }
     }
 }
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait CreateRefreshTokenRepository: Send + Sync {
     async fn encode_refresh_token_secret(
@@ -57,7 +66,7 @@ pub trait CreateRefreshTokenRepository: Send + Sync {
 
     async fn create_pesato_token(
         &self,
-        key: &Vec<u8>,
+        key: &SigningKeyPair,
         rt: RefreshToken,
         secret: &str,
         secret_key: &str,
@@ -70,7 +79,26 @@ pub trait CreateRefreshTokenRepository: Send + Sync {
 
     fn decode_base64(&self, plaintext: &str) -> RepositoryResult<Vec<u8>>;
 
-    async fn store_refresh_token(&self, rf: &RefreshToken) -> RepositoryResult<()>;
+    async fn store_refresh_token(&self, rf: &RefreshToken, ttl: i32) -> RepositoryResult<()>;
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair>;
+    async fn find_refresh_tokens_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedRefreshTokensByUserModel>;
+    async fn revoke_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()>;
 }
 
 #[async_trait]
@@ -117,14 +145,18 @@ impl CreateRefreshTokenRepository for CreateRefreshTokenRepositoryImpl {
             encrypted_token_secret,
             token_version,
             parent_version: None,
-            issued_at: CqlTimestamp(issued_at.unix_timestamp()),
-            expires_at: CqlTimestamp(expires_at.unix_timestamp()),
+            issued_at: CqlTimestamp(issued_at.unix_timestamp() * 1000),
+            expires_at: CqlTimestamp(expires_at.unix_timestamp() * 1000),
             revoked: false,
+            last_used_at: CqlTimestamp(issued_at.unix_timestamp() * 1000),
+            session_started_at: CqlTimestamp(issued_at.unix_timestamp() * 1000),
+            client: ClientMetadata::default(),
+            scope: None,
         }
     }
     async fn create_pesato_token(
         &self,
-        key: &Vec<u8>,
+        key: &SigningKeyPair,
         rt: RefreshToken,
         secret: &str,
         secret_key: &str,
@@ -136,8 +168,41 @@ impl CreateRefreshTokenRepository for CreateRefreshTokenRepositoryImpl {
             .encrypt(key, rt, secret, secret_key, issued_at, expire, notbefore)
             .await
     }
-    async fn store_refresh_token(&self, rf: &RefreshToken) -> RepositoryResult<()> {
+    async fn store_refresh_token(&self, rf: &RefreshToken, ttl: i32) -> RepositoryResult<()> {
         let model: RefreshTokenModel = rf.into();
-        self.database_repo.create_refresh_token(&model).await
+        self.refresh_token_cachelayer_repo
+            .create_refresh_token(&model, ttl)
+            .await
+    }
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair> {
+        self.signing_key_store
+            .active_key(organization_id, application_id)
+            .await
+    }
+    async fn find_refresh_tokens_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedRefreshTokensByUserModel> {
+        self.refresh_token_cachelayer_repo
+            .find_refresh_token_by_user(org_id, app_id, user_id, page_size, paging_state_u8)
+            .await
+    }
+    async fn revoke_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.refresh_token_cachelayer_repo
+            .revoke_refresh_token(org_id, app_id, token_id)
+            .await
     }
 }
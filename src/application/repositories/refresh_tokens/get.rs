@@ -1,22 +1,22 @@
 use crate::domain::errors::repositories_errors::RepositoryResult;
 use crate::infrastructure::models::refresh_token::PaginatedRefreshTokensByUserModel;
+use crate::infrastructure::repositories::cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository;
 use crate::infrastructure::repositories::cipher::base64_repository::Base64Repository;
-use crate::infrastructure::repositories::database::refresh_token::RefreshTokenDatabaseRepository;
 use async_trait::async_trait;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct GetRefreshTokenRepositoryImpl {
-    database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
     base64_repo: Arc<dyn Base64Repository>,
 }
 impl GetRefreshTokenRepositoryImpl {
     pub fn new(
-        database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+        refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
         base64_repo: Arc<dyn Base64Repository>,
     ) -> Self {
         Self {
-            database_repo,
+            refresh_token_cachelayer_repo,
             base64_repo,
         }
     }
@@ -46,7 +46,7 @@ impl GetRefreshTokenRepository for GetRefreshTokenRepositoryImpl {
         page_size: i32,
         paging_state_u8: Option<Vec<u8>>,
     ) -> RepositoryResult<PaginatedRefreshTokensByUserModel> {
-        self.database_repo
+        self.refresh_token_cachelayer_repo
             .find_refresh_token_by_user(org_id, app_id, user_id, page_size, paging_state_u8)
             .await
     }
@@ -1,4 +1,5 @@
 pub mod create;
 pub mod rotate;
 pub mod revoke;
-pub mod get;
\ No newline at end of file
+pub mod revoke_all;
+pub mod get;
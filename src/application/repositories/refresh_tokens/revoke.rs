@@ -1,15 +1,17 @@
 use crate::domain::errors::repositories_errors::RepositoryResult;
-use crate::infrastructure::repositories::database::refresh_token::RefreshTokenDatabaseRepository;
+use crate::infrastructure::repositories::cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository;
 use async_trait::async_trait;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct RevokeRefreshTokenRepositoryImpl {
-    database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
 }
 impl RevokeRefreshTokenRepositoryImpl {
-    pub fn new(database_repo: Arc<dyn RefreshTokenDatabaseRepository>) -> Self {
-        Self { database_repo }
+    pub fn new(refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>) -> Self {
+        Self {
+            refresh_token_cachelayer_repo,
+        }
     }
 }
 
@@ -31,7 +33,7 @@ impl RevokeRefreshTokenRepository for RevokeRefreshTokenRepositoryImpl {
         app_id: Uuid,
         token_id: Uuid,
     ) -> RepositoryResult<()> {
-        self.database_repo
+        self.refresh_token_cachelayer_repo
             .revoke_refresh_token(org_id, app_id, token_id)
             .await
     }
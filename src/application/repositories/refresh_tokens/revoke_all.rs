@@ -0,0 +1,63 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::infrastructure::models::refresh_token::PaginatedRefreshTokensByUserModel;
+use crate::infrastructure::repositories::cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RevokeAllRefreshTokensRepositoryImpl {
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
+}
+impl RevokeAllRefreshTokensRepositoryImpl {
+    pub fn new(refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>) -> Self {
+        Self {
+            refresh_token_cachelayer_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RevokeAllRefreshTokensRepository: Send + Sync {
+    async fn find_refresh_tokens_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedRefreshTokensByUserModel>;
+    async fn revoke_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl RevokeAllRefreshTokensRepository for RevokeAllRefreshTokensRepositoryImpl {
+    async fn find_refresh_tokens_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedRefreshTokensByUserModel> {
+        self.refresh_token_cachelayer_repo
+            .find_refresh_token_by_user(org_id, app_id, user_id, page_size, paging_state_u8)
+            .await
+    }
+
+    async fn revoke_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.refresh_token_cachelayer_repo
+            .revoke_refresh_token(org_id, app_id, token_id)
+            .await
+    }
+}
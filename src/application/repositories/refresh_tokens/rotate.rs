@@ -1,12 +1,15 @@
 use crate::domain::entities::refresh_token::RefreshToken;
 use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::domain::value_objects::client_metadata::ClientMetadata;
 use crate::infrastructure::models::refresh_token::{FoundRefreshTokenModel, RefreshTokenModel};
 use crate::infrastructure::models::token_claim::TokenClaims;
+use crate::infrastructure::repositories::cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository;
 use crate::infrastructure::repositories::cipher::{
     aes_gcm_repository::AesGcmCipherRepository, base64_repository::Base64Repository,
 };
-use crate::infrastructure::repositories::database::refresh_token::RefreshTokenDatabaseRepository;
+use crate::infrastructure::repositories::keys::signing_key_store::SigningKeyStore;
 use crate::infrastructure::repositories::paseto::refresh_token::PasetoRepository;
+use crate::infrastructure::repositories::paseto::signing_key::{SigningKeyPair, VerificationKey};
 use async_trait::async_trait;
 use rand_core::{OsRng, TryRngCore};
 use scylla::value::CqlTimestamp;
@@ -16,22 +19,25 @@ use uuid::Uuid;
 
 pub struct RotateRefreshTokenRepositoryImpl {
     paseto_repo: Arc<dyn PasetoRepository>,
-    database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
     base64_repo: Arc<dyn Base64Repository>,
     aes_repo: Arc<dyn AesGcmCipherRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
 }
 impl RotateRefreshTokenRepositoryImpl {
     pub fn new(
         paseto_repo: Arc<dyn PasetoRepository>,
-        database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+        refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
         base64_repo: Arc<dyn Base64Repository>,
         aes_repo: Arc<dyn AesGcmCipherRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
     ) -> Self {
         Self {
             paseto_repo,
-            database_repo,
+            refresh_token_cachelayer_repo,
             base64_repo,
             aes_repo,
+            signing_key_store,
         }
     }
 }
@@ -60,7 +66,7 @@ pub trait RotateRefreshTokenRepository: Send + Sync {
 
     async fn create_pesato_token(
         &self,
-        key: &Vec<u8>,
+        key: &SigningKeyPair,
         rt: RefreshToken,
         secret: &str,
         secret_key: &str,
@@ -69,13 +75,23 @@ pub trait RotateRefreshTokenRepository: Send + Sync {
         notbefore: String,
     ) -> RepositoryResult<String>;
 
-    async fn decrypt_paseto(&self, rt: &str, pk: &Vec<u8>) -> RepositoryResult<TokenClaims>;
+    async fn decrypt_paseto(
+        &self,
+        rt: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<TokenClaims>;
     fn encode_base64(&self, bytes: &Vec<u8>) -> String;
 
     fn decode_base64(&self, plaintext: &str) -> RepositoryResult<Vec<u8>>;
 
-    async fn store_refresh_token(&self, rf: &RefreshToken) -> RepositoryResult<()>;
-    async fn update_refresh_token(&self, rf: &RefreshToken) -> RepositoryResult<()>;
+    async fn store_refresh_token(&self, rf: &RefreshToken, ttl: i32) -> RepositoryResult<()>;
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair>;
+    async fn find_verification_key(&self, rt: &str) -> RepositoryResult<Option<VerificationKey>>;
+    async fn update_refresh_token(&self, rf: &RefreshToken, ttl: i32) -> RepositoryResult<bool>;
     async fn find_refresh_token(
         &self,
         org_id: Uuid,
@@ -144,14 +160,18 @@ impl RotateRefreshTokenRepository for RotateRefreshTokenRepositoryImpl {
             encrypted_token_secret,
             token_version,
             parent_version: Some(parent_version),
-            issued_at: CqlTimestamp(issued_at.unix_timestamp()),
-            expires_at: CqlTimestamp(expires_at.unix_timestamp()),
+            issued_at: CqlTimestamp(issued_at.unix_timestamp() * 1000),
+            expires_at: CqlTimestamp(expires_at.unix_timestamp() * 1000),
             revoked: false,
+            last_used_at: CqlTimestamp(issued_at.unix_timestamp() * 1000),
+            session_started_at: CqlTimestamp(issued_at.unix_timestamp() * 1000),
+            client: ClientMetadata::default(),
+            scope: None,
         }
     }
     async fn create_pesato_token(
         &self,
-        key: &Vec<u8>,
+        key: &SigningKeyPair,
         rt: RefreshToken,
         secret: &str,
         secret_key: &str,
@@ -163,17 +183,37 @@ impl RotateRefreshTokenRepository for RotateRefreshTokenRepositoryImpl {
             .encrypt(key, rt, secret, secret_key, issued_at, expire, notbefore)
             .await
     }
-    async fn decrypt_paseto(&self, rt: &str, pk: &Vec<u8>) -> RepositoryResult<TokenClaims> {
-        self.paseto_repo.decrypt(&rt, &pk).await
+    async fn decrypt_paseto(
+        &self,
+        rt: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<TokenClaims> {
+        self.paseto_repo.decrypt(rt, key).await
     }
-    async fn store_refresh_token(&self, rf: &RefreshToken) -> RepositoryResult<()> {
+    async fn store_refresh_token(&self, rf: &RefreshToken, ttl: i32) -> RepositoryResult<()> {
         let model: RefreshTokenModel = rf.into();
-        self.database_repo.create_refresh_token(&model).await
+        self.refresh_token_cachelayer_repo
+            .create_refresh_token(&model, ttl)
+            .await
+    }
+    async fn signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair> {
+        self.signing_key_store
+            .active_key(organization_id, application_id)
+            .await
+    }
+    async fn find_verification_key(&self, rt: &str) -> RepositoryResult<Option<VerificationKey>> {
+        self.signing_key_store.find_verification_key(rt).await
     }
 
-    async fn update_refresh_token(&self, rf: &RefreshToken) -> RepositoryResult<()> {
+    async fn update_refresh_token(&self, rf: &RefreshToken, ttl: i32) -> RepositoryResult<bool> {
         let model: RefreshTokenModel = rf.into();
-        self.database_repo.update_refresh_token(&model).await
+        self.refresh_token_cachelayer_repo
+            .update_refresh_token(&model, ttl)
+            .await
     }
 
     async fn find_refresh_token(
@@ -183,7 +223,7 @@ impl RotateRefreshTokenRepository for RotateRefreshTokenRepositoryImpl {
         token_id: Uuid,
         token_version: &String,
     ) -> RepositoryResult<Option<FoundRefreshTokenModel>> {
-        self.database_repo
+        self.refresh_token_cachelayer_repo
             .find_refresh_token(org_id, app_id, token_id, token_version)
             .await
     }
@@ -194,7 +234,7 @@ impl RotateRefreshTokenRepository for RotateRefreshTokenRepositoryImpl {
         app_id: Uuid,
         token_id: Uuid,
     ) -> RepositoryResult<()> {
-        self.database_repo
+        self.refresh_token_cachelayer_repo
             .revoke_refresh_token(org_id, app_id, token_id)
             .await
     }
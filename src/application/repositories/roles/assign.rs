@@ -1,28 +1,47 @@
 use crate::{
     domain::errors::repositories_errors::RepositoryResult,
-    infrastructure::{models::role::RoleAssignmentModel, repositories::database::roles::RoleDatabaseRepository},
+    infrastructure::{
+        models::role::{EXPIRY_LOOKBACK_DAYS, RoleAssignmentModel},
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            database::roles::RoleDatabaseRepository,
+        },
+    },
 };
 use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use scylla::value::CqlTimestamp;
 use std::sync::Arc;
 use uuid::Uuid;
 pub struct AssignRepositoryImpl {
     database_repo: Arc<dyn RoleDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
 }
 
 impl AssignRepositoryImpl {
-    pub fn new(database_repo: Arc<dyn RoleDatabaseRepository>) -> Self {
-        Self { database_repo }
+    pub fn new(
+        database_repo: Arc<dyn RoleDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            permissions_cache_repo,
+        }
     }
 }
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait AssignRepository: Send + Sync {
+    /// `expires_at` is in unix millis. Both assignment rows get a TTL of
+    /// `EXPIRY_LOOKBACK_DAYS + 1` days past it, so the expiry job still finds
+    /// them and can tell a lapse from a deliberate removal.
     async fn assign(
         &self,
         org_id: Uuid,
         app_id: Uuid,
         role_id: Uuid,
         user_id: Uuid,
+        expires_at: Option<i64>,
     ) -> RepositoryResult<()>;
 }
 
@@ -34,15 +53,28 @@ impl AssignRepository for AssignRepositoryImpl {
         app_id: Uuid,
         role_id: Uuid,
         user_id: Uuid,
+        expires_at: Option<i64>,
     ) -> RepositoryResult<()> {
+        // reads stop at `expires_at`; the rows stay around for the expiry job
+        // to remove, so it can tell a lapse from a removal
+        let ttl = expires_at.map_or(0, |e| {
+            let millis = (e - Utc::now().timestamp_millis()).max(1)
+                + Duration::days(EXPIRY_LOOKBACK_DAYS + 1).num_milliseconds();
+            i32::try_from((millis + 999) / 1000).unwrap_or(i32::MAX)
+        });
         let moddel = RoleAssignmentModel{
             organization_id:org_id,
             application_id: app_id,
             role_id,
-            user_id
+            user_id,
+            expires_at: expires_at.map(CqlTimestamp),
+            ttl,
         };
         self.database_repo
             .assign_user_to_role(&moddel)
+            .await?;
+        self.permissions_cache_repo
+            .invalidate_cache(org_id, app_id, &[user_id])
             .await
     }
 }
@@ -1,7 +1,13 @@
 use crate::{
     domain::{entities::role_by_app::RoleByApp, errors::repositories_errors::RepositoryResult},
     infrastructure::{
-        models::role::{RoleModel, SelectedRoleByIdModel}, repositories::database::roles::RoleDatabaseRepository,
+        models::{
+            permission::PermissionModel,
+            role::{RoleModel, SelectedRoleByAppModel, SelectedRoleByIdModel},
+        },
+        repositories::database::{
+            permissions::PermissionDatabaseRepository, roles::RoleDatabaseRepository,
+        },
     },
 };
 use async_trait::async_trait;
@@ -11,11 +17,18 @@ use std::{collections::HashSet, sync::Arc};
 use uuid::Uuid;
 pub struct CreateRoleRepositoryImpl {
     database_repo: Arc<dyn RoleDatabaseRepository>,
+    permission_database_repo: Arc<dyn PermissionDatabaseRepository>,
 }
 
 impl CreateRoleRepositoryImpl {
-    pub fn new(database_repo: Arc<dyn RoleDatabaseRepository>) -> Self {
-        Self { database_repo }
+    pub fn new(
+        database_repo: Arc<dyn RoleDatabaseRepository>,
+        permission_database_repo: Arc<dyn PermissionDatabaseRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            permission_database_repo,
+        }
     }
 }
 #[cfg_attr(test, mockall::automock)]
@@ -28,6 +41,7 @@ pub trait CreateRoleRepository: Send + Sync {
         name: String,
         description: Option<String>,
         permissions: HashSet<String>,
+        parent_role_ids: HashSet<Uuid>,
     ) -> RoleByApp;
     async fn create_role(&self, role: &RoleModel) -> RepositoryResult<()>;
     async fn get_role(
@@ -36,6 +50,16 @@ pub trait CreateRoleRepository: Send + Sync {
         application_id: Uuid,
         role_id: Uuid,
     ) -> RepositoryResult<Option<SelectedRoleByIdModel>>;
+    async fn get_roles(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>>;
+    async fn get_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>>;
 }
 
 #[async_trait]
@@ -47,6 +71,7 @@ impl CreateRoleRepository for CreateRoleRepositoryImpl {
         name: String,
         description: Option<String>,
         permissions: HashSet<String>,
+        parent_role_ids: HashSet<Uuid>,
     ) -> RoleByApp {
         let now = Utc::now().timestamp_millis();
         RoleByApp {
@@ -56,6 +81,7 @@ impl CreateRoleRepository for CreateRoleRepositoryImpl {
             name,
             description,
             permissions,
+            parent_role_ids,
             created_at: CqlTimestamp(now),
             updated_at: CqlTimestamp(now),
         }
@@ -73,4 +99,24 @@ impl CreateRoleRepository for CreateRoleRepositoryImpl {
         self.database_repo
             .get_role(organization_id, application_id, role_id).await
     }
+
+    async fn get_roles(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>> {
+        self.database_repo
+            .get_roles(organization_id, application_id)
+            .await
+    }
+
+    async fn get_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>> {
+        self.permission_database_repo
+            .get_permissions(organization_id, application_id)
+            .await
+    }
 }
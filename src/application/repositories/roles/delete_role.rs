@@ -1,17 +1,31 @@
 use crate::{
+    application::mappers::{application::ApplicationMapper, role::hierarchy::RoleHierarchyMapper},
     domain::errors::repositories_errors::RepositoryResult,
-    infrastructure::repositories::database::roles::RoleDatabaseRepository,
+    infrastructure::repositories::{
+        cache::user_permissions_repository::UserPermissionsCacheRepository,
+        database::{groups::GroupDatabaseRepository, roles::RoleDatabaseRepository},
+    },
 };
 use async_trait::async_trait;
 use std::sync::Arc;
 use uuid::Uuid;
 pub struct DeleteRoleRepositoryImpl {
     database_repo: Arc<dyn RoleDatabaseRepository>,
+    group_database_repo: Arc<dyn GroupDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
 }
 
 impl DeleteRoleRepositoryImpl {
-    pub fn new(database_repo: Arc<dyn RoleDatabaseRepository>) -> Self {
-        Self { database_repo }
+    pub fn new(
+        database_repo: Arc<dyn RoleDatabaseRepository>,
+        group_database_repo: Arc<dyn GroupDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            group_database_repo,
+            permissions_cache_repo,
+        }
     }
 }
 #[cfg_attr(test, mockall::automock)]
@@ -23,8 +37,31 @@ pub trait DeleteRoleRepository: Send + Sync {
 #[async_trait]
 impl DeleteRoleRepository for DeleteRoleRepositoryImpl {
     async fn delete_role(&self, org_id: Uuid, app_id: Uuid, role_id: Uuid) -> RepositoryResult<()> {
+        // roles inheriting from this one lose its permissions too
+        let roles = self.database_repo.get_roles(org_id, app_id).await?;
+        let affected = RoleHierarchyMapper::to_dto(roles).descendants(role_id);
+        let mut user_ids: Vec<Uuid> = Vec::new();
+        for affected_role_id in &affected {
+            let users = self
+                .database_repo
+                .get_users_by_role(org_id, app_id, *affected_role_id)
+                .await?;
+            user_ids.extend(users.into_iter().map(|u| u.user_id));
+        }
+        // groups keep the dangling id, which then grants nothing
+        let groups = self.group_database_repo.get_groups(org_id, app_id).await?;
+        for group in groups.iter().filter(|g| !g.role_ids.is_disjoint(&affected)) {
+            let members = self
+                .group_database_repo
+                .get_members(org_id, app_id, group.group_id)
+                .await?;
+            user_ids.extend(members.into_iter().map(|m| m.user_id));
+        }
         self.database_repo
             .delete_role(org_id, app_id, role_id)
+            .await?;
+        self.permissions_cache_repo
+            .invalidate_cache(org_id, app_id, &user_ids)
             .await
     }
 }
@@ -0,0 +1,107 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::role::PendingRoleExpiryModel,
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            database::roles::RoleDatabaseRepository,
+            queue::producer_roles_repository::RoleProducerRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct ExpireRoleAssignmentsRepositoryImpl {
+    database_repo: Arc<dyn RoleDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    role_producer_repo: Arc<dyn RoleProducerRepository>,
+}
+
+impl ExpireRoleAssignmentsRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn RoleDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+        role_producer_repo: Arc<dyn RoleProducerRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            permissions_cache_repo,
+            role_producer_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ExpireRoleAssignmentsRepository: Send + Sync {
+    fn expiry_day(&self, timestamp_millis: i64) -> String;
+    async fn get_pending_expiries(
+        &self,
+        expires_day: String,
+    ) -> RepositoryResult<Vec<PendingRoleExpiryModel>>;
+    /// Removes the assignment if it still carries this expiry; false when it
+    /// was renewed or removed since.
+    async fn remove_assignment(&self, expiry: &PendingRoleExpiryModel) -> RepositoryResult<bool>;
+    /// Publishes an `assignment_expired` event and drops the user's cached
+    /// permissions.
+    async fn lapse(&self, expiry: &PendingRoleExpiryModel) -> RepositoryResult<()>;
+    async fn clear_pending_expiry(
+        &self,
+        expires_day: String,
+        expiry: &PendingRoleExpiryModel,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl ExpireRoleAssignmentsRepository for ExpireRoleAssignmentsRepositoryImpl {
+    fn expiry_day(&self, timestamp_millis: i64) -> String {
+        PendingRoleExpiryModel::day(timestamp_millis)
+    }
+
+    async fn get_pending_expiries(
+        &self,
+        expires_day: String,
+    ) -> RepositoryResult<Vec<PendingRoleExpiryModel>> {
+        self.database_repo.get_pending_expiries(expires_day).await
+    }
+
+    async fn remove_assignment(&self, expiry: &PendingRoleExpiryModel) -> RepositoryResult<bool> {
+        self.database_repo
+            .lapse_assignment(
+                expiry.organization_id,
+                expiry.application_id,
+                expiry.user_id,
+                expiry.role_id,
+                expiry.expires_at,
+            )
+            .await
+    }
+
+    async fn lapse(&self, expiry: &PendingRoleExpiryModel) -> RepositoryResult<()> {
+        self.role_producer_repo.assignment_expired(expiry)?;
+        self.permissions_cache_repo
+            .invalidate_cache(
+                expiry.organization_id,
+                expiry.application_id,
+                &[expiry.user_id],
+            )
+            .await
+    }
+
+    async fn clear_pending_expiry(
+        &self,
+        expires_day: String,
+        expiry: &PendingRoleExpiryModel,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .delete_pending_expiry(
+                expires_day,
+                expiry.organization_id,
+                expiry.application_id,
+                expiry.user_id,
+                expiry.role_id,
+            )
+            .await
+    }
+}
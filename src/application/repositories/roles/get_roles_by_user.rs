@@ -1,7 +1,11 @@
 use crate::{
     domain::errors::repositories_errors::RepositoryResult,
     infrastructure::{
-        models::role::UserRoleModel, repositories::database::roles::RoleDatabaseRepository,
+        models::{
+            group::{GroupModel, UserGroupModel},
+            role::UserRoleModel,
+        },
+        repositories::database::{groups::GroupDatabaseRepository, roles::RoleDatabaseRepository},
     },
 };
 use async_trait::async_trait;
@@ -9,11 +13,18 @@ use std::sync::Arc;
 use uuid::Uuid;
 pub struct GetRolesByUserRepositoryImpl {
     database_repo: Arc<dyn RoleDatabaseRepository>,
+    group_database_repo: Arc<dyn GroupDatabaseRepository>,
 }
 
 impl GetRolesByUserRepositoryImpl {
-    pub fn new(database_repo: Arc<dyn RoleDatabaseRepository>) -> Self {
-        Self { database_repo }
+    pub fn new(
+        database_repo: Arc<dyn RoleDatabaseRepository>,
+        group_database_repo: Arc<dyn GroupDatabaseRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            group_database_repo,
+        }
     }
 }
 #[cfg_attr(test, mockall::automock)]
@@ -25,6 +36,13 @@ pub trait GetRolesByUserRepository: Send + Sync {
         app_id: Uuid,
         user_id: Uuid,
     ) -> RepositoryResult<Vec<UserRoleModel>>;
+    async fn get_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserGroupModel>>;
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>>;
 }
 
 #[async_trait]
@@ -39,4 +57,19 @@ impl GetRolesByUserRepository for GetRolesByUserRepositoryImpl {
             .get_roles_by_user(org_id, app_id, user_id)
             .await
     }
+
+    async fn get_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserGroupModel>> {
+        self.group_database_repo
+            .get_groups_of_user(org_id, app_id, user_id)
+            .await
+    }
+
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>> {
+        self.group_database_repo.get_groups(org_id, app_id).await
+    }
 }
@@ -0,0 +1,132 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::{
+            group::{GroupModel, UserGroupModel},
+            role::{SelectedRoleByAppModel, UserRoleModel},
+        },
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            database::{groups::GroupDatabaseRepository, roles::RoleDatabaseRepository},
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+pub struct GetUserPermissionsRepositoryImpl {
+    database_repo: Arc<dyn RoleDatabaseRepository>,
+    group_database_repo: Arc<dyn GroupDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+}
+
+impl GetUserPermissionsRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn RoleDatabaseRepository>,
+        group_database_repo: Arc<dyn GroupDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            group_database_repo,
+            permissions_cache_repo,
+        }
+    }
+}
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GetUserPermissionsRepository: Send + Sync {
+    async fn get_cached_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<Vec<String>>>;
+    async fn cache_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        permissions: &[String],
+        expires_at: Option<i64>,
+    ) -> RepositoryResult<()>;
+    async fn get_roles_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserRoleModel>>;
+    async fn get_roles(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>>;
+    async fn get_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserGroupModel>>;
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>>;
+}
+
+#[async_trait]
+impl GetUserPermissionsRepository for GetUserPermissionsRepositoryImpl {
+    async fn get_cached_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<Vec<String>>> {
+        self.permissions_cache_repo
+            .get_cached_permissions(org_id, app_id, user_id)
+            .await
+    }
+
+    async fn cache_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        permissions: &[String],
+        expires_at: Option<i64>,
+    ) -> RepositoryResult<()> {
+        self.permissions_cache_repo
+            .cache_permissions(org_id, app_id, user_id, permissions, expires_at)
+            .await
+    }
+
+    async fn get_roles_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserRoleModel>> {
+        self.database_repo
+            .get_roles_by_user(org_id, app_id, user_id)
+            .await
+    }
+
+    async fn get_roles(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>> {
+        self.database_repo.get_roles(org_id, app_id).await
+    }
+
+    async fn get_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserGroupModel>> {
+        self.group_database_repo
+            .get_groups_of_user(org_id, app_id, user_id)
+            .await
+    }
+
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>> {
+        self.group_database_repo.get_groups(org_id, app_id).await
+    }
+}
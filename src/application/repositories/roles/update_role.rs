@@ -1,8 +1,18 @@
 use crate::{
+    application::mappers::{application::ApplicationMapper, role::hierarchy::RoleHierarchyMapper},
     domain::errors::repositories_errors::RepositoryResult,
     infrastructure::{
-        models::role::{SelectedRoleByIdModel, UpdateRoleModel},
-        repositories::database::roles::RoleDatabaseRepository,
+        models::{
+            permission::PermissionModel,
+            role::{SelectedRoleByAppModel, SelectedRoleByIdModel, UpdateRoleModel},
+        },
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            database::{
+                groups::GroupDatabaseRepository, permissions::PermissionDatabaseRepository,
+                roles::RoleDatabaseRepository,
+            },
+        },
     },
 };
 use async_trait::async_trait;
@@ -10,11 +20,24 @@ use std::{collections::HashSet, sync::Arc};
 use uuid::Uuid;
 pub struct UpdateRoleRepositoryImpl {
     database_repo: Arc<dyn RoleDatabaseRepository>,
+    group_database_repo: Arc<dyn GroupDatabaseRepository>,
+    permission_database_repo: Arc<dyn PermissionDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
 }
 
 impl UpdateRoleRepositoryImpl {
-    pub fn new(database_repo: Arc<dyn RoleDatabaseRepository>) -> Self {
-        Self { database_repo }
+    pub fn new(
+        database_repo: Arc<dyn RoleDatabaseRepository>,
+        group_database_repo: Arc<dyn GroupDatabaseRepository>,
+        permission_database_repo: Arc<dyn PermissionDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            group_database_repo,
+            permission_database_repo,
+            permissions_cache_repo,
+        }
     }
 }
 #[cfg_attr(test, mockall::automock)]
@@ -26,6 +49,16 @@ pub trait UpdateRoleRepository: Send + Sync {
         app_id: Uuid,
         role_id: Uuid,
     ) -> RepositoryResult<Option<SelectedRoleByIdModel>>;
+    async fn get_roles(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>>;
+    async fn get_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>>;
     fn new_model(
         &self,
         organization_id: Uuid,
@@ -35,6 +68,8 @@ pub trait UpdateRoleRepository: Send + Sync {
         description: Option<String>,
         permissions: HashSet<String>,
     ) -> UpdateRoleModel;
+    /// Also drops the cached permissions of every user holding the role or
+    /// one inheriting from it.
     async fn update_role(&self, update: &UpdateRoleModel) -> RepositoryResult<()>;
 }
 
@@ -56,6 +91,7 @@ impl UpdateRoleRepository for UpdateRoleRepositoryImpl {
             name,
             description,
             permissions,
+            parent_role_ids: HashSet::new(),
         }
     }
 
@@ -69,7 +105,58 @@ impl UpdateRoleRepository for UpdateRoleRepositoryImpl {
             .get_role(organization_id, application_id, role_id)
             .await
     }
+
+    async fn get_roles(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>> {
+        self.database_repo
+            .get_roles(organization_id, application_id)
+            .await
+    }
+
+    async fn get_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>> {
+        self.permission_database_repo
+            .get_permissions(organization_id, application_id)
+            .await
+    }
     async fn update_role(&self, update: &UpdateRoleModel) -> RepositoryResult<()> {
-        self.database_repo.update_role(update).await
+        self.database_repo.update_role(update).await?;
+        let roles = self
+            .database_repo
+            .get_roles(update.organization_id, update.application_id)
+            .await?;
+        let affected = RoleHierarchyMapper::to_dto(roles).descendants(update.role_id);
+        let mut user_ids: Vec<Uuid> = Vec::new();
+        for role_id in &affected {
+            let users = self
+                .database_repo
+                .get_users_by_role(update.organization_id, update.application_id, *role_id)
+                .await?;
+            user_ids.extend(users.into_iter().map(|u| u.user_id));
+        }
+        let groups = self
+            .group_database_repo
+            .get_groups(update.organization_id, update.application_id)
+            .await?;
+        for group in groups.iter().filter(|g| !g.role_ids.is_disjoint(&affected)) {
+            let members = self
+                .group_database_repo
+                .get_members(
+                    update.organization_id,
+                    update.application_id,
+                    group.group_id,
+                )
+                .await?;
+            user_ids.extend(members.into_iter().map(|m| m.user_id));
+        }
+        self.permissions_cache_repo
+            .invalidate_cache(update.organization_id, update.application_id, &user_ids)
+            .await
     }
 }
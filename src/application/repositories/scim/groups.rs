@@ -0,0 +1,218 @@
+use crate::{
+    application::mappers::model::ModelMapper,
+    domain::{entities::role_by_app::RoleByApp, errors::repositories_errors::RepositoryResult},
+    infrastructure::{
+        models::role::{RoleModel, RoleUserModel, SelectedRoleByAppModel, UpdateRoleModel},
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            database::{roles::RoleDatabaseRepository, user_repository::UserDatabaseRepository},
+        },
+    },
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
+
+pub struct ScimGroupsRepositoryImpl {
+    role_database_repo: Arc<dyn RoleDatabaseRepository>,
+    user_database_repo: Arc<dyn UserDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+}
+
+impl ScimGroupsRepositoryImpl {
+    pub fn new(
+        role_database_repo: Arc<dyn RoleDatabaseRepository>,
+        user_database_repo: Arc<dyn UserDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    ) -> Self {
+        Self {
+            role_database_repo,
+            user_database_repo,
+            permissions_cache_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ScimGroupsRepository: Send + Sync {
+    async fn get_roles(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>>;
+    async fn get_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Option<SelectedRoleByAppModel>>;
+    /// A group is a role without permissions until an admin grants some.
+    async fn create_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        name: String,
+    ) -> RepositoryResult<Uuid>;
+    async fn rename_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role: SelectedRoleByAppModel,
+        name: String,
+    ) -> RepositoryResult<()>;
+    async fn delete_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn get_members(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Vec<RoleUserModel>>;
+    async fn remove_member(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn user_exists(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+}
+
+#[async_trait]
+impl ScimGroupsRepository for ScimGroupsRepositoryImpl {
+    async fn get_roles(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<SelectedRoleByAppModel>> {
+        self.role_database_repo
+            .get_roles(organization_id, application_id)
+            .await
+    }
+
+    async fn get_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Option<SelectedRoleByAppModel>> {
+        Ok(self
+            .role_database_repo
+            .get_role(organization_id, application_id, role_id)
+            .await?
+            .map(|role| SelectedRoleByAppModel {
+                role_id,
+                name: role.name,
+                description: role.description,
+                permissions: role.permissions,
+                parent_role_ids: role.parent_role_ids,
+                created_at: role.created_at,
+                updated_at: role.updated_at,
+            }))
+    }
+
+    async fn create_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        name: String,
+    ) -> RepositoryResult<Uuid> {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        let role = RoleByApp {
+            organization_id,
+            application_id,
+            role_id: Uuid::new_v4(),
+            name,
+            description: None,
+            permissions: HashSet::new(),
+            parent_role_ids: HashSet::new(),
+            created_at: now,
+            updated_at: now,
+        };
+        let model: RoleModel = role.to_entity();
+        self.role_database_repo.create_role(&model).await?;
+        Ok(role.role_id)
+    }
+
+    async fn rename_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role: SelectedRoleByAppModel,
+        name: String,
+    ) -> RepositoryResult<()> {
+        self.role_database_repo
+            .update_role(&UpdateRoleModel {
+                organization_id,
+                application_id,
+                role_id: role.role_id,
+                name,
+                description: role.description,
+                permissions: role.permissions,
+                parent_role_ids: role.parent_role_ids,
+            })
+            .await
+    }
+
+    async fn delete_role(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.role_database_repo
+            .delete_role(organization_id, application_id, role_id)
+            .await
+    }
+
+    async fn get_members(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Vec<RoleUserModel>> {
+        self.role_database_repo
+            .get_users_by_role(organization_id, application_id, role_id)
+            .await
+    }
+
+    async fn remove_member(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        role_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.role_database_repo
+            .remove_user_from_role(organization_id, application_id, role_id, user_id)
+            .await?;
+        self.permissions_cache_repo
+            .invalidate_cache(organization_id, application_id, &[user_id])
+            .await
+    }
+
+    async fn user_exists(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        Ok(self
+            .user_database_repo
+            .find_user(application_id, organization_id, user_id)
+            .await?
+            .is_some())
+    }
+}
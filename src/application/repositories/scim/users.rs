@@ -0,0 +1,165 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::user::{CleannedUserModel, PaginatedUsersModel, UserModel, UserSearchDocument},
+        repositories::{
+            database::user_repository::UserDatabaseRepository,
+            fulltext_search::user_fulltext_search::UserFulltextSearchRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ScimUsersRepositoryImpl {
+    database_repo: Arc<dyn UserDatabaseRepository>,
+    fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+}
+
+impl ScimUsersRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn UserDatabaseRepository>,
+        fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            fulltext_search_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ScimUsersRepository: Send + Sync {
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>>;
+    async fn find_raw_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<UserModel>>;
+    async fn find_user_id_by_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username_normalized: String,
+    ) -> RepositoryResult<Option<Uuid>>;
+    async fn find_user_id_by_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email_normalized: String,
+    ) -> RepositoryResult<Option<Uuid>>;
+    async fn find_users_page(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        page_size: i32,
+        paging_state: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedUsersModel>;
+    async fn count_users(&self, organization_id: Uuid, application_id: Uuid)
+    -> RepositoryResult<i64>;
+    async fn update_user(&self, user: UserModel) -> RepositoryResult<()>;
+    async fn index_user(
+        &self,
+        user: UserModel,
+        searchable_attributes: Vec<String>,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl ScimUsersRepository for ScimUsersRepositoryImpl {
+    async fn find_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>> {
+        self.database_repo
+            .find_user(application_id, organization_id, user_id)
+            .await
+    }
+
+    async fn find_raw_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<UserModel>> {
+        self.database_repo
+            .find_raw_user(application_id, organization_id, user_id)
+            .await
+    }
+
+    async fn find_user_id_by_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username_normalized: String,
+    ) -> RepositoryResult<Option<Uuid>> {
+        Ok(self
+            .database_repo
+            .find_user_by_username(username_normalized, application_id, organization_id)
+            .await?
+            .map(|user| user.user_id))
+    }
+
+    async fn find_user_id_by_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email_normalized: String,
+    ) -> RepositoryResult<Option<Uuid>> {
+        Ok(self
+            .database_repo
+            .find_user_by_email(email_normalized, application_id, organization_id)
+            .await?
+            .map(|user| user.user_id))
+    }
+
+    async fn find_users_page(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        page_size: i32,
+        paging_state: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedUsersModel> {
+        self.database_repo
+            .find_all_users_paginated(organization_id, application_id, page_size, paging_state)
+            .await
+    }
+
+    async fn count_users(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<i64> {
+        self.database_repo
+            .get_user_count(organization_id, application_id)
+            .await
+    }
+
+    async fn update_user(&self, user: UserModel) -> RepositoryResult<()> {
+        self.database_repo.update_user(user).await
+    }
+
+    async fn index_user(
+        &self,
+        user: UserModel,
+        searchable_attributes: Vec<String>,
+    ) -> RepositoryResult<()> {
+        self.fulltext_search_repo
+            .create(UserSearchDocument::from_entity(
+                user.to_entity(),
+                &searchable_attributes,
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    application::mappers::model::ModelMapper,
+    domain::{
+        entities::service_account::ServiceAccount, errors::repositories_errors::RepositoryResult,
+    },
+    infrastructure::{
+        models::service_account::ServiceAccountModel,
+        repositories::{
+            cipher::{
+                aes_gcm_repository::AesGcmCipherRepository, base64_repository::Base64Repository,
+            },
+            database::service_account::ServiceAccountDatabaseRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct CreateServiceAccountRepositoryImpl {
+    database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+    aes_repo: Arc<dyn AesGcmCipherRepository>,
+    base64_repo: Arc<dyn Base64Repository>,
+}
+
+impl CreateServiceAccountRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+        aes_repo: Arc<dyn AesGcmCipherRepository>,
+        base64_repo: Arc<dyn Base64Repository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            aes_repo,
+            base64_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CreateServiceAccountRepository: Send + Sync {
+    fn generate_client_secret(&self) -> RepositoryResult<Vec<u8>>;
+    /// Returns `(client_key, encrypted_client_secret)`, like application secrets.
+    async fn encrypt_client_secret(
+        &self,
+        client_secret: &[u8],
+    ) -> RepositoryResult<(String, String)>;
+    fn encode_base64(&self, bytes: &[u8]) -> String;
+    async fn create_service_account(&self, account: ServiceAccount) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl CreateServiceAccountRepository for CreateServiceAccountRepositoryImpl {
+    fn generate_client_secret(&self) -> RepositoryResult<Vec<u8>> {
+        Ok(ServiceAccount::gen_client_secret()?)
+    }
+
+    async fn encrypt_client_secret(
+        &self,
+        client_secret: &[u8],
+    ) -> RepositoryResult<(String, String)> {
+        self.aes_repo.encrypt(client_secret).await
+    }
+
+    fn encode_base64(&self, bytes: &[u8]) -> String {
+        self.base64_repo.encode(bytes)
+    }
+
+    async fn create_service_account(&self, account: ServiceAccount) -> RepositoryResult<()> {
+        self.database_repo
+            .create_service_account(&ServiceAccountModel::from_entity(account))
+            .await
+    }
+}
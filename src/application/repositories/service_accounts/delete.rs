@@ -0,0 +1,89 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::service_account::ServiceAccountModel,
+        repositories::database::{
+            roles::RoleDatabaseRepository, service_account::ServiceAccountDatabaseRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct DeleteServiceAccountRepositoryImpl {
+    database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+    role_database_repo: Arc<dyn RoleDatabaseRepository>,
+}
+
+impl DeleteServiceAccountRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+        role_database_repo: Arc<dyn RoleDatabaseRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            role_database_repo,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DeleteServiceAccountRepository: Send + Sync {
+    async fn find_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>>;
+    /// Drops the account and every role assigned to it.
+    async fn delete_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl DeleteServiceAccountRepository for DeleteServiceAccountRepositoryImpl {
+    async fn find_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>> {
+        self.database_repo
+            .find_service_account(organization_id, application_id, service_account_id)
+            .await
+    }
+
+    async fn delete_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let roles = self
+            .role_database_repo
+            .get_roles_by_user(organization_id, application_id, service_account_id)
+            .await?;
+        for role in roles {
+            self.role_database_repo
+                .remove_user_from_role(
+                    organization_id,
+                    application_id,
+                    role.role_id,
+                    service_account_id,
+                )
+                .await?;
+        }
+        self.role_database_repo
+            .remove_roles_of_user(organization_id, application_id, service_account_id)
+            .await?;
+        self.database_repo
+            .delete_service_account(organization_id, application_id, service_account_id)
+            .await
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::service_account::ServiceAccountModel,
+        repositories::database::service_account::ServiceAccountDatabaseRepository,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GetServiceAccountsRepositoryImpl {
+    database_repo: Arc<dyn ServiceAccountDatabaseRepository>,
+}
+
+impl GetServiceAccountsRepositoryImpl {
+    pub fn new(database_repo: Arc<dyn ServiceAccountDatabaseRepository>) -> Self {
+        Self { database_repo }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GetServiceAccountsRepository: Send + Sync {
+    async fn find_service_accounts(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<ServiceAccountModel>>;
+}
+
+#[async_trait]
+impl GetServiceAccountsRepository for GetServiceAccountsRepositoryImpl {
+    async fn find_service_accounts(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<ServiceAccountModel>> {
+        self.database_repo
+            .find_service_accounts(organization_id, application_id)
+            .await
+    }
+}
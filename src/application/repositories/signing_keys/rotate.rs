@@ -0,0 +1,113 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::signing_key::{PaginatedSigningKeysModel, SigningKeyModel},
+        repositories::{
+            database::signing_key::SigningKeyDatabaseRepository,
+            keys::signing_key_store::SigningKeyStore,
+        },
+    },
+};
+use async_trait::async_trait;
+use scylla::value::CqlTimestamp;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RotateSigningKeysRepositoryImpl {
+    database_repo: Arc<dyn SigningKeyDatabaseRepository>,
+    signing_key_store: Arc<dyn SigningKeyStore>,
+}
+
+impl RotateSigningKeysRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn SigningKeyDatabaseRepository>,
+        signing_key_store: Arc<dyn SigningKeyStore>,
+    ) -> Self {
+        Self {
+            database_repo,
+            signing_key_store,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RotateSigningKeysRepository: Send + Sync {
+    async fn scan_signing_keys(
+        &self,
+        page_size: i32,
+        paging_state: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedSigningKeysModel>;
+    async fn create_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyModel>;
+    async fn retire_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: String,
+        retired_at: i64,
+        expires_at: i64,
+    ) -> RepositoryResult<()>;
+    async fn delete_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: String,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl RotateSigningKeysRepository for RotateSigningKeysRepositoryImpl {
+    async fn scan_signing_keys(
+        &self,
+        page_size: i32,
+        paging_state: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedSigningKeysModel> {
+        self.database_repo
+            .scan_signing_keys(page_size, paging_state)
+            .await
+    }
+
+    async fn create_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyModel> {
+        self.signing_key_store
+            .create_key(organization_id, application_id)
+            .await
+    }
+
+    async fn retire_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: String,
+        retired_at: i64,
+        expires_at: i64,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .retire_signing_key(
+                organization_id,
+                application_id,
+                &kid,
+                CqlTimestamp(retired_at),
+                CqlTimestamp(expires_at),
+            )
+            .await
+    }
+
+    async fn delete_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: String,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .delete_signing_key(organization_id, application_id, &kid)
+            .await
+    }
+}
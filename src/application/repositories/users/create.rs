@@ -5,16 +5,21 @@ use crate::{
     },
     domain::{
         entities::{
-            apporg_client_id::CleanAppOrgByClientId, user::User,
+            apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+            user::User,
             user_organization::UserOrganization,
         },
         errors::repositories_errors::RepositoryResult,
     },
     infrastructure::{
-        models::user::UserModel,
+        models::user::{UserModel, UserSearchDocument},
         repositories::{
             database::user_repository::UserDatabaseRepository,
-            security::argon2_repository::PasswordHasherRepository,
+            fulltext_search::user_fulltext_search::UserFulltextSearchRepository,
+            security::{
+                argon2_repository::PasswordHasherRepository,
+                breached_password_repository::BreachedPasswordRepository,
+            },
         },
     },
 };
@@ -24,16 +29,22 @@ use uuid::Uuid;
 pub struct CreateUserRepositoryImpl {
     database_repo: Arc<dyn UserDatabaseRepository>,
     hasher_repo: Arc<dyn PasswordHasherRepository>,
+    fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+    breached_password_repo: Arc<dyn BreachedPasswordRepository>,
 }
 
 impl CreateUserRepositoryImpl {
     pub fn new(
         database_repo: Arc<dyn UserDatabaseRepository>,
         hasher_repo: Arc<dyn PasswordHasherRepository>,
+        fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+        breached_password_repo: Arc<dyn BreachedPasswordRepository>,
     ) -> Self {
         Self {
             database_repo,
             hasher_repo,
+            fulltext_search_repo,
+            breached_password_repo,
         }
     }
 }
@@ -52,6 +63,8 @@ pub trait CreateUserRepository: Send + Sync {
         hashed_password: String,
     ) -> User;
     fn hash_password(&self, password: String) -> RepositoryResult<String>;
+    fn is_supported_hash(&self, hashed: &str) -> bool;
+    fn is_breached_password(&self, password: &str) -> bool;
     async fn find_user_by_username(
         &self,
         username: String,
@@ -67,6 +80,46 @@ pub trait CreateUserRepository: Send + Sync {
     ) -> RepositoryResult<Option<CreateUserResponse>>;
 
     async fn create_user(&self, user: User) -> RepositoryResult<()>;
+    async fn index_user(
+        &self,
+        user: User,
+        searchable_attributes: Vec<String>,
+    ) -> RepositoryResult<()>;
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+    async fn release_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+    async fn release_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn add_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        hashed_password: String,
+    ) -> RepositoryResult<()>;
 }
 
 #[async_trait]
@@ -76,19 +129,33 @@ impl CreateUserRepository for CreateUserRepositoryImpl {
             .create_user(UserModel::from_entity(user))
             .await
     }
+    async fn index_user(
+        &self,
+        user: User,
+        searchable_attributes: Vec<String>,
+    ) -> RepositoryResult<()> {
+        self.fulltext_search_repo
+            .create(UserSearchDocument::from_entity(user, &searchable_attributes))
+            .await?;
+        Ok(())
+    }
     fn new_user(
         &self,
         c_apporg: CleanAppOrgByClientId,
         user: CreateUserPayload,
         hashed_password: String,
     ) -> User {
-        User::new(
+        let mut new_user = User::new(
             c_apporg.application_id,
             c_apporg.organization_id,
             user.username,
             hashed_password,
             user.email,
-        )
+        );
+        new_user.profile = user.profile.into();
+        new_user.metadata = user.metadata;
+        new_user.normalize(&c_apporg.get_config().unwrap_or_default());
+        new_user
     }
 
     fn new_user_organization(
@@ -101,6 +168,12 @@ impl CreateUserRepository for CreateUserRepositoryImpl {
     fn hash_password(&self, password: String) -> RepositoryResult<String> {
         self.hasher_repo.hash(password.as_str())
     }
+    fn is_supported_hash(&self, hashed: &str) -> bool {
+        self.hasher_repo.is_supported(hashed)
+    }
+    fn is_breached_password(&self, password: &str) -> bool {
+        self.breached_password_repo.contains(password)
+    }
     async fn find_user_by_username(
         &self,
         username: String,
@@ -133,4 +206,64 @@ impl CreateUserRepository for CreateUserRepositoryImpl {
         };
         Ok(Some(FoundUserMapper::to_dto(user)))
     }
+
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.database_repo
+            .reserve_username(organization_id, application_id, username, user_id)
+            .await
+    }
+
+    async fn release_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .release_username(organization_id, application_id, username, user_id)
+            .await
+    }
+
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.database_repo
+            .reserve_email(organization_id, application_id, email, user_id)
+            .await
+    }
+
+    async fn release_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .release_email(organization_id, application_id, email, user_id)
+            .await
+    }
+
+    async fn add_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        hashed_password: String,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .insert_password_history(organization_id, application_id, user_id, hashed_password)
+            .await
+    }
 }
@@ -1,59 +1,283 @@
 use crate::{
     domain::errors::repositories_errors::RepositoryResult,
     infrastructure::{
-        models::user::CleannedUserModel,
-        repositories::database::user_repository::UserDatabaseRepository,
+        models::user::{CleannedUserModel, PendingPurgeUserModel},
+        repositories::{
+            cache::user_permissions_repository::UserPermissionsCacheRepository,
+            cache_layer::refresh_token_repository::RefreshTokenCacheLayerRepository,
+            database::{
+                groups::GroupDatabaseRepository, roles::RoleDatabaseRepository,
+                user_identity::UserIdentityDatabaseRepository,
+                user_repository::UserDatabaseRepository,
+            },
+            fulltext_search::user_fulltext_search::UserFulltextSearchRepository,
+        },
     },
 };
 use async_trait::async_trait;
+use chrono::DateTime;
+use scylla::value::CqlTimestamp;
 use std::sync::Arc;
 use uuid::Uuid;
+
+const REFRESH_TOKEN_PAGE_SIZE: i32 = 100;
+
 pub struct DeleteUserRepositoryImpl {
     database_repo: Arc<dyn UserDatabaseRepository>,
+    role_database_repo: Arc<dyn RoleDatabaseRepository>,
+    refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
+    fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+    identity_database_repo: Arc<dyn UserIdentityDatabaseRepository>,
+    permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+    group_database_repo: Arc<dyn GroupDatabaseRepository>,
 }
 
 impl DeleteUserRepositoryImpl {
-    pub fn new(database_repo: Arc<dyn UserDatabaseRepository>) -> Self {
-        Self { database_repo }
+    pub fn new(
+        database_repo: Arc<dyn UserDatabaseRepository>,
+        role_database_repo: Arc<dyn RoleDatabaseRepository>,
+        refresh_token_cachelayer_repo: Arc<dyn RefreshTokenCacheLayerRepository>,
+        fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+        identity_database_repo: Arc<dyn UserIdentityDatabaseRepository>,
+        permissions_cache_repo: Arc<dyn UserPermissionsCacheRepository>,
+        group_database_repo: Arc<dyn GroupDatabaseRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            role_database_repo,
+            refresh_token_cachelayer_repo,
+            fulltext_search_repo,
+            identity_database_repo,
+            permissions_cache_repo,
+            group_database_repo,
+        }
+    }
+
+    async fn find_refresh_token_ids(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<Uuid>> {
+        let mut token_ids = Vec::new();
+        let mut paging_state = None;
+        loop {
+            let page = self
+                .refresh_token_cachelayer_repo
+                .find_refresh_token_by_user(
+                    organization_id,
+                    application_id,
+                    user_id,
+                    REFRESH_TOKEN_PAGE_SIZE,
+                    paging_state,
+                )
+                .await?;
+            token_ids.extend(page.refresh_tokens.iter().map(|t| t.token_id));
+            let Some(next) = page.paging_state else {
+                break;
+            };
+            paging_state = Some(next);
+        }
+        Ok(token_ids)
     }
 }
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait DeleteUserRepository: Send + Sync {
-    async fn delete_user(
+    async fn find_user(
         &self,
         organization_id: Uuid,
         application_id: Uuid,
         user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>>;
+    fn purge_day(&self, timestamp_millis: i64) -> String;
+    async fn soft_delete_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        deactivated_at: i64,
     ) -> RepositoryResult<()>;
-    async fn find_user(
+    async fn revoke_refresh_tokens(
         &self,
         organization_id: Uuid,
         application_id: Uuid,
         user_id: Uuid,
-    ) -> RepositoryResult<Option<CleannedUserModel>>;
+    ) -> RepositoryResult<()>;
+    /// Removes the user row and everything keyed by it: role memberships,
+    /// refresh tokens, cached tokens, username/email reservations, the user
+    /// counter and the search document.
+    async fn purge_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn find_users_pending_purge(
+        &self,
+        purge_day: String,
+    ) -> RepositoryResult<Vec<PendingPurgeUserModel>>;
+    async fn clear_pending_purge(
+        &self,
+        purge_day: String,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
 }
 
 #[async_trait]
 impl DeleteUserRepository for DeleteUserRepositoryImpl {
-    async fn delete_user(
+    async fn find_user(
         &self,
         organization_id: Uuid,
         application_id: Uuid,
         user_id: Uuid,
+    ) -> RepositoryResult<Option<CleannedUserModel>> {
+        self.database_repo
+            .find_user(application_id, organization_id, user_id)
+            .await
+    }
+
+    fn purge_day(&self, timestamp_millis: i64) -> String {
+        DateTime::from_timestamp_millis(timestamp_millis)
+            .unwrap_or_default()
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    async fn soft_delete_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        deactivated_at: i64,
     ) -> RepositoryResult<()> {
         self.database_repo
-            .delete_user(organization_id, application_id, user_id)
+            .soft_delete_user(
+                organization_id,
+                application_id,
+                user_id,
+                self.purge_day(deactivated_at),
+                CqlTimestamp(deactivated_at),
+            )
             .await
     }
-    async fn find_user(
+
+    async fn revoke_refresh_tokens(
         &self,
         organization_id: Uuid,
         application_id: Uuid,
         user_id: Uuid,
-    ) -> RepositoryResult<Option<CleannedUserModel>> {
+    ) -> RepositoryResult<()> {
+        let token_ids = self
+            .find_refresh_token_ids(organization_id, application_id, user_id)
+            .await?;
+        for token_id in token_ids {
+            self.refresh_token_cachelayer_repo
+                .revoke_refresh_token(organization_id, application_id, token_id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn purge_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let roles = self
+            .role_database_repo
+            .get_roles_by_user(organization_id, application_id, user_id)
+            .await?;
+        for role in roles {
+            self.role_database_repo
+                .remove_user_from_role(organization_id, application_id, role.role_id, user_id)
+                .await?;
+        }
+        self.role_database_repo
+            .remove_roles_of_user(organization_id, application_id, user_id)
+            .await?;
+        let groups = self
+            .group_database_repo
+            .get_groups_of_user(organization_id, application_id, user_id)
+            .await?;
+        for group in groups {
+            self.group_database_repo
+                .remove_member(organization_id, application_id, group.group_id, user_id)
+                .await?;
+        }
+        self.group_database_repo
+            .remove_groups_of_user(organization_id, application_id, user_id)
+            .await?;
+        self.permissions_cache_repo
+            .invalidate_cache(organization_id, application_id, &[user_id])
+            .await?;
+
+        let token_ids = self
+            .find_refresh_token_ids(organization_id, application_id, user_id)
+            .await?;
+        for token_id in token_ids {
+            self.refresh_token_cachelayer_repo
+                .delete_refresh_token(organization_id, application_id, token_id)
+                .await?;
+        }
+
+        let identities = self
+            .identity_database_repo
+            .find_user_identities_by_user(organization_id, application_id, user_id)
+            .await?;
+        for identity in identities {
+            self.identity_database_repo
+                .delete_user_identity(
+                    organization_id,
+                    application_id,
+                    &identity.provider_id,
+                    &identity.subject,
+                )
+                .await?;
+        }
+
+        if let Some(user) = self
+            .database_repo
+            .find_raw_user(application_id, organization_id, user_id)
+            .await?
+        {
+            if let Some(username) = user.username_normalized {
+                self.database_repo
+                    .release_username(organization_id, application_id, username, user_id)
+                    .await?;
+            }
+            if let Some(email) = user.email_normalized {
+                self.database_repo
+                    .release_email(organization_id, application_id, email, user_id)
+                    .await?;
+            }
+        }
         self.database_repo
-            .find_user(application_id, organization_id, user_id)
+            .delete_user(organization_id, application_id, user_id)
+            .await?;
+        self.fulltext_search_repo.delete(user_id).await?;
+        Ok(())
+    }
+
+    async fn find_users_pending_purge(
+        &self,
+        purge_day: String,
+    ) -> RepositoryResult<Vec<PendingPurgeUserModel>> {
+        self.database_repo.find_users_pending_purge(purge_day).await
+    }
+
+    async fn clear_pending_purge(
+        &self,
+        purge_day: String,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .delete_user_pending_purge(purge_day, organization_id, application_id, user_id)
             .await
     }
 }
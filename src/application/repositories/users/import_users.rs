@@ -0,0 +1,38 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::user_import::UserImportJobModel,
+        repositories::cache::user_import_job_repository::UserImportJobCacheRepository,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ImportUsersRepositoryImpl {
+    job_cache_repo: Arc<dyn UserImportJobCacheRepository>,
+}
+
+impl ImportUsersRepositoryImpl {
+    pub fn new(job_cache_repo: Arc<dyn UserImportJobCacheRepository>) -> Self {
+        Self { job_cache_repo }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ImportUsersRepository: Send + Sync {
+    async fn store_job(&self, job: UserImportJobModel) -> RepositoryResult<()>;
+    async fn find_job(&self, job_id: Uuid) -> RepositoryResult<Option<UserImportJobModel>>;
+}
+
+#[async_trait]
+impl ImportUsersRepository for ImportUsersRepositoryImpl {
+    async fn store_job(&self, job: UserImportJobModel) -> RepositoryResult<()> {
+        self.job_cache_repo.store_job(&job).await
+    }
+
+    async fn find_job(&self, job_id: Uuid) -> RepositoryResult<Option<UserImportJobModel>> {
+        self.job_cache_repo.find_job(job_id).await
+    }
+}
@@ -5,5 +5,7 @@ pub mod disable_mfa_user;
 pub mod get_user;
 pub mod get_user_count;
 pub mod get_users;
+pub mod normalize_identifiers;
 pub mod unban_user;
 pub mod update_user;
+pub mod import_users;
@@ -0,0 +1,136 @@
+use crate::{
+    domain::{errors::repositories_errors::RepositoryResult, value_objects::app_config::AppConfig},
+    infrastructure::{
+        models::user::PaginatedUserIdentifiersModel,
+        repositories::database::{
+            application_repository::ApplicationDatabaseRepository,
+            user_repository::UserDatabaseRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct NormalizeIdentifiersRepositoryImpl {
+    database_repo: Arc<dyn UserDatabaseRepository>,
+    application_repo: Arc<dyn ApplicationDatabaseRepository>,
+}
+
+impl NormalizeIdentifiersRepositoryImpl {
+    pub fn new(
+        database_repo: Arc<dyn UserDatabaseRepository>,
+        application_repo: Arc<dyn ApplicationDatabaseRepository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            application_repo,
+        }
+    }
+}
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait NormalizeIdentifiersRepository: Send + Sync {
+    async fn scan_users(
+        &self,
+        page_size: i32,
+        paging_state: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedUserIdentifiersModel>;
+    async fn find_app_config(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<AppConfig>;
+    async fn update_normalized(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        username_normalized: String,
+        email_normalized: Option<String>,
+    ) -> RepositoryResult<()>;
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+}
+
+#[async_trait]
+impl NormalizeIdentifiersRepository for NormalizeIdentifiersRepositoryImpl {
+    async fn scan_users(
+        &self,
+        page_size: i32,
+        paging_state: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedUserIdentifiersModel> {
+        self.database_repo
+            .scan_user_identifiers(page_size, paging_state)
+            .await
+    }
+
+    async fn find_app_config(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<AppConfig> {
+        let app = self
+            .application_repo
+            .find_application(organization_id, application_id)
+            .await?;
+        Ok(app
+            .and_then(|a| AppConfig::from_document(&a.config).ok())
+            .unwrap_or_default())
+    }
+
+    async fn update_normalized(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        username_normalized: String,
+        email_normalized: Option<String>,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .update_user_normalized(
+                organization_id,
+                application_id,
+                user_id,
+                username_normalized,
+                email_normalized,
+            )
+            .await
+    }
+
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.database_repo
+            .reserve_username(organization_id, application_id, username, user_id)
+            .await
+    }
+
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.database_repo
+            .reserve_email(organization_id, application_id, email, user_id)
+            .await
+    }
+}
@@ -5,10 +5,14 @@ use crate::{
     },
     domain::errors::repositories_errors::{RepositoryError, RepositoryResult},
     infrastructure::{
-        models::user::UserModel,
+        models::user::{UserModel, UserSearchDocument},
         repositories::{
             database::user_repository::UserDatabaseRepository,
-            security::argon2_repository::PasswordHasherRepository,
+            fulltext_search::user_fulltext_search::UserFulltextSearchRepository,
+            security::{
+                argon2_repository::PasswordHasherRepository,
+                breached_password_repository::BreachedPasswordRepository,
+            },
         },
     },
 };
@@ -19,16 +23,22 @@ pub struct UpdateUserRepositoryImpl {
     database_repo: Arc<dyn UserDatabaseRepository>,
 
     hasher_repo: Arc<dyn PasswordHasherRepository>,
+    fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+    breached_password_repo: Arc<dyn BreachedPasswordRepository>,
 }
 
 impl UpdateUserRepositoryImpl {
     pub fn new(
         database_repo: Arc<dyn UserDatabaseRepository>,
         hasher_repo: Arc<dyn PasswordHasherRepository>,
+        fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
+        breached_password_repo: Arc<dyn BreachedPasswordRepository>,
     ) -> Self {
         Self {
             database_repo,
             hasher_repo,
+            fulltext_search_repo,
+            breached_password_repo,
         }
     }
 }
@@ -36,6 +46,11 @@ impl UpdateUserRepositoryImpl {
 #[async_trait]
 pub trait UpdateUserRepository: Send + Sync {
     async fn update_user(&self, user: UserModel) -> RepositoryResult<()>;
+    async fn index_user(
+        &self,
+        user: UserModel,
+        searchable_attributes: Vec<String>,
+    ) -> RepositoryResult<()>;
     async fn find_user(
         &self,
         organization_id: Uuid,
@@ -43,6 +58,8 @@ pub trait UpdateUserRepository: Send + Sync {
         user_id: Uuid,
     ) -> RepositoryResult<Option<UserModel>>;
     fn hash_password(&self, password: String) -> RepositoryResult<String>;
+    fn verify_password(&self, hashed: &str, plain: &str) -> RepositoryResult<bool>;
+    fn is_breached_password(&self, password: &str) -> bool;
 
     async fn find_user_by_username(
         &self,
@@ -59,6 +76,49 @@ pub trait UpdateUserRepository: Send + Sync {
     ) -> RepositoryResult<Option<CreateUserResponse>>;
     fn validate_new_email(&self,current: &Option<String>, new: &String) -> RepositoryResult<()>;
     fn validate_new_username(&self,current: &String, new: &String) -> RepositoryResult<()>;
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+    async fn release_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+    async fn release_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    /// The `limit` most recent password hashes, newest first.
+    async fn find_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        limit: usize,
+    ) -> RepositoryResult<Vec<String>>;
+    async fn add_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        hashed_password: String,
+    ) -> RepositoryResult<()>;
 }
 
 #[async_trait]
@@ -77,10 +137,32 @@ impl UpdateUserRepository for UpdateUserRepositoryImpl {
         self.database_repo.update_user(user).await
     }
 
+    async fn index_user(
+        &self,
+        user: UserModel,
+        searchable_attributes: Vec<String>,
+    ) -> RepositoryResult<()> {
+        self.fulltext_search_repo
+            .create(UserSearchDocument::from_entity(
+                user.to_entity(),
+                &searchable_attributes,
+            ))
+            .await?;
+        Ok(())
+    }
+
     fn hash_password(&self, password: String) -> RepositoryResult<String> {
         self.hasher_repo.hash(password.as_str())
     }
 
+    fn verify_password(&self, hashed: &str, plain: &str) -> RepositoryResult<bool> {
+        self.hasher_repo.verify(hashed, plain)
+    }
+
+    fn is_breached_password(&self, password: &str) -> bool {
+        self.breached_password_repo.contains(password)
+    }
+
     async fn find_user_by_username(
         &self,
         username: String,
@@ -132,4 +214,81 @@ impl UpdateUserRepository for UpdateUserRepositoryImpl {
 
         Ok(())
     }
+
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.database_repo
+            .reserve_username(organization_id, application_id, username, user_id)
+            .await
+    }
+
+    async fn release_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .release_username(organization_id, application_id, username, user_id)
+            .await
+    }
+
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        self.database_repo
+            .reserve_email(organization_id, application_id, email, user_id)
+            .await
+    }
+
+    async fn release_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .release_email(organization_id, application_id, email, user_id)
+            .await
+    }
+
+    async fn find_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        limit: usize,
+    ) -> RepositoryResult<Vec<String>> {
+        self.database_repo
+            .find_password_history(
+                organization_id,
+                application_id,
+                user_id,
+                i32::try_from(limit).unwrap_or(i32::MAX),
+            )
+            .await
+    }
+
+    async fn add_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        hashed_password: String,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .insert_password_history(organization_id, application_id, user_id, hashed_password)
+            .await
+    }
 }
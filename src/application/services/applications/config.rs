@@ -0,0 +1,163 @@
+use crate::{
+    application::{
+        dto::response::application::ApplicationConfigResponse,
+        repositories::applications::config::ApplicationConfigRepository,
+        services::applications::update_redirect_uris::validate_redirect_uri,
+    },
+    domain::{
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::app_config::{APP_CONFIG_VERSION, AppConfig},
+    },
+};
+use async_trait::async_trait;
+use serde_json::{Map, Value, json};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Keys a patch can't touch: the version is owned by the server and identity
+/// providers carry encrypted secrets, so they have their own endpoint.
+const READ_ONLY_KEYS: [&str; 2] = ["version", "identity_providers"];
+
+#[derive(Clone)]
+pub struct ApplicationConfigServiceImpl {
+    pub repository: Arc<dyn ApplicationConfigRepository>,
+}
+impl ApplicationConfigServiceImpl {
+    pub fn new(repository: Arc<dyn ApplicationConfigRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+/// RFC 7386 JSON merge patch: objects merge key by key, null removes a key
+/// and anything else replaces the target.
+fn merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(&key);
+            } else {
+                merge_patch(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+fn ensure_application(
+    c_apporg: &CleanAppOrgByClientId,
+    application_id: Uuid,
+) -> RepositoryResult<()> {
+    if c_apporg.application_id != application_id {
+        return Err(RepositoryError::new(
+            "application not found".to_string(),
+            404,
+        ));
+    }
+    Ok(())
+}
+
+fn to_response(config: &AppConfig, application_id: Uuid) -> ApplicationConfigResponse {
+    let mut document = serde_json::to_value(config).unwrap_or_else(|_| json!({}));
+    if let Some(providers) = document
+        .get_mut("identity_providers")
+        .and_then(Value::as_array_mut)
+    {
+        for provider in providers.iter_mut().filter_map(Value::as_object_mut) {
+            provider.remove("encrypted_client_secret");
+        }
+    }
+    ApplicationConfigResponse {
+        application_id,
+        config: document,
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ApplicationConfigService: 'static + Sync + Send {
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        application_id: Uuid,
+    ) -> RepositoryResult<ApplicationConfigResponse>;
+    /// Applies a JSON merge patch to the settings document; the result is
+    /// validated as a whole before anything is written.
+    async fn update(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        application_id: Uuid,
+        patch: Value,
+    ) -> RepositoryResult<ApplicationConfigResponse>;
+}
+
+#[async_trait]
+impl ApplicationConfigService for ApplicationConfigServiceImpl {
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        application_id: Uuid,
+    ) -> RepositoryResult<ApplicationConfigResponse> {
+        ensure_application(&c_apporg, application_id)?;
+        let config = c_apporg.get_config()?;
+        Ok(to_response(&config, application_id))
+    }
+
+    async fn update(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        application_id: Uuid,
+        patch: Value,
+    ) -> RepositoryResult<ApplicationConfigResponse> {
+        ensure_application(&c_apporg, application_id)?;
+        let Value::Object(fields) = &patch else {
+            return Err(RepositoryError::new(
+                "config patch must be a JSON object".to_string(),
+                400,
+            ));
+        };
+        let known = serde_json::to_value(AppConfig::default())?;
+        for key in fields.keys() {
+            if READ_ONLY_KEYS.contains(&key.as_str()) {
+                return Err(RepositoryError::new(format!("{key} is read-only"), 400));
+            }
+            if known.get(key).is_none() {
+                return Err(RepositoryError::new(format!("unknown setting {key}"), 400));
+            }
+        }
+
+        let current = c_apporg.get_config()?;
+        let mut document = serde_json::to_value(&current)?;
+        merge_patch(&mut document, patch);
+        let mut updated: AppConfig = serde_json::from_value(document)
+            .map_err(|e| RepositoryError::new(format!("config is not valid: {e}"), 400))?;
+        updated.version = APP_CONFIG_VERSION;
+
+        let mut errors = Vec::new();
+        let mut redirect_uris: Vec<String> = Vec::with_capacity(updated.redirect_uris.len());
+        for redirect_uri in updated.redirect_uris {
+            if let Err(e) = validate_redirect_uri(&redirect_uri) {
+                errors.push(e);
+            } else if !redirect_uris.contains(&redirect_uri) {
+                redirect_uris.push(redirect_uri);
+            }
+        }
+        updated.redirect_uris = redirect_uris;
+        errors.extend(updated.validate());
+        if !errors.is_empty() {
+            return Err(RepositoryError::new("config is not valid".to_string(), 400)
+                .with_details(json!({ "errors": errors })));
+        }
+
+        self.repository
+            .update_application_config(&c_apporg, &updated)
+            .await?;
+        Ok(to_response(&updated, application_id))
+    }
+}
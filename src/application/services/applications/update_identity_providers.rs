@@ -0,0 +1,127 @@
+use crate::{
+    application::{
+        dto::{
+            payload::application::IdentityProviderPayload,
+            response::application::{IdentityProviderResponse, IdentityProvidersResponse},
+        },
+        repositories::applications::update_identity_providers::UpdateIdentityProvidersRepository,
+        services::applications::update_redirect_uris::LOOPBACK_HOSTS,
+    },
+    domain::{
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::identity_provider::{
+            IdentityProvider, default_scopes, is_valid_provider_id,
+        },
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use url::Url;
+
+#[derive(Clone)]
+pub struct UpdateIdentityProvidersServiceImpl {
+    pub repository: Arc<dyn UpdateIdentityProvidersRepository>,
+}
+impl UpdateIdentityProvidersServiceImpl {
+    pub fn new(repository: Arc<dyn UpdateIdentityProvidersRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+/// Issuers must be https so the ID token can be trusted on the strength of
+/// the TLS connection; plain http is only allowed on the loopback interface.
+fn validate_issuer(issuer: &str) -> Result<(), String> {
+    let url = Url::parse(issuer).map_err(|_| format!("{issuer} is not a valid URL"))?;
+    let is_loopback = url
+        .host_str()
+        .is_some_and(|host| LOOPBACK_HOSTS.contains(&host));
+    if url.scheme() != "https" && !(url.scheme() == "http" && is_loopback) {
+        return Err(format!("{issuer} must use https"));
+    }
+    Ok(())
+}
+
+fn to_response(provider: &IdentityProvider) -> IdentityProviderResponse {
+    IdentityProviderResponse {
+        id: provider.id.clone(),
+        name: provider.name.clone(),
+        issuer: provider.issuer.clone(),
+        client_id: provider.client_id.clone(),
+        scopes: provider.scopes.clone(),
+        link_by_email: provider.link_by_email,
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UpdateIdentityProvidersService: 'static + Sync + Send {
+    /// Replaces the application's identity providers.
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        identity_providers: Vec<IdentityProviderPayload>,
+    ) -> RepositoryResult<IdentityProvidersResponse>;
+}
+
+#[async_trait]
+impl UpdateIdentityProvidersService for UpdateIdentityProvidersServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        identity_providers: Vec<IdentityProviderPayload>,
+    ) -> RepositoryResult<IdentityProvidersResponse> {
+        let mut config = c_apporg.get_config()?;
+        let mut providers: Vec<IdentityProvider> = Vec::with_capacity(identity_providers.len());
+        for payload in identity_providers {
+            if !is_valid_provider_id(&payload.id) {
+                return Err(RepositoryError::new(
+                    format!("{} is not a valid provider id", payload.id),
+                    400,
+                ));
+            }
+            if providers.iter().any(|p| p.id == payload.id) {
+                return Err(RepositoryError::new(
+                    format!("provider id {} is used twice", payload.id),
+                    400,
+                ));
+            }
+            if payload.name.trim().is_empty() || payload.client_id.is_empty() {
+                return Err(RepositoryError::new(
+                    "name and client_id are required".to_string(),
+                    400,
+                ));
+            }
+            validate_issuer(&payload.issuer).map_err(|e| RepositoryError::new(e, 400))?;
+            let encrypted_client_secret = match &payload.client_secret {
+                Some(secret) => self.repository.encrypt_client_secret(secret).await?,
+                None => config
+                    .find_identity_provider(&payload.id)
+                    .map(|p| p.encrypted_client_secret.clone())
+                    .ok_or_else(|| {
+                        RepositoryError::new(
+                            format!("client_secret is required for {}", payload.id),
+                            400,
+                        )
+                    })?,
+            };
+            let provider = IdentityProvider {
+                id: payload.id,
+                name: payload.name,
+                issuer: payload.issuer,
+                client_id: payload.client_id,
+                encrypted_client_secret,
+                scopes: payload.scopes.unwrap_or_else(default_scopes),
+                link_by_email: payload.link_by_email,
+            };
+            providers.push(provider);
+        }
+        config.identity_providers = providers;
+        self.repository
+            .update_application_config(&c_apporg, &config)
+            .await?;
+        Ok(IdentityProvidersResponse {
+            identity_providers: config.identity_providers.iter().map(to_response).collect(),
+        })
+    }
+}
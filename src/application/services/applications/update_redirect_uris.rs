@@ -0,0 +1,76 @@
+use crate::{
+    application::{
+        dto::response::application::RedirectUrisResponse,
+        repositories::applications::update_redirect_uris::UpdateRedirectUrisRepository,
+    },
+    domain::{
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use url::Url;
+
+pub const LOOPBACK_HOSTS: [&str; 3] = ["localhost", "127.0.0.1", "[::1]"];
+
+#[derive(Clone)]
+pub struct UpdateRedirectUrisServiceImpl {
+    pub repository: Arc<dyn UpdateRedirectUrisRepository>,
+}
+impl UpdateRedirectUrisServiceImpl {
+    pub fn new(repository: Arc<dyn UpdateRedirectUrisRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+/// Redirect URIs must be absolute, carry no fragment and use https unless
+/// they point at the loopback interface (native and local development apps).
+pub fn validate_redirect_uri(redirect_uri: &str) -> Result<(), String> {
+    let url = Url::parse(redirect_uri).map_err(|_| format!("{redirect_uri} is not a valid URI"))?;
+    if url.fragment().is_some() {
+        return Err(format!("{redirect_uri} must not contain a fragment"));
+    }
+    let is_loopback = url
+        .host_str()
+        .is_some_and(|host| LOOPBACK_HOSTS.contains(&host));
+    if url.scheme() == "http" && !is_loopback {
+        return Err(format!("{redirect_uri} must use https"));
+    }
+    Ok(())
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UpdateRedirectUrisService: 'static + Sync + Send {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        redirect_uris: Vec<String>,
+    ) -> RepositoryResult<RedirectUrisResponse>;
+}
+
+#[async_trait]
+impl UpdateRedirectUrisService for UpdateRedirectUrisServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        redirect_uris: Vec<String>,
+    ) -> RepositoryResult<RedirectUrisResponse> {
+        let mut registered: Vec<String> = Vec::with_capacity(redirect_uris.len());
+        for redirect_uri in redirect_uris {
+            validate_redirect_uri(&redirect_uri).map_err(|e| RepositoryError::new(e, 400))?;
+            if !registered.contains(&redirect_uri) {
+                registered.push(redirect_uri);
+            }
+        }
+        let mut config = c_apporg.get_config()?;
+        config.redirect_uris = registered.clone();
+        self.repository
+            .update_application_config(&c_apporg, &config)
+            .await?;
+        Ok(RedirectUrisResponse {
+            redirect_uris: registered,
+        })
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    application::{
+        dto::{
+            payload::group::{CreateGroupPayload, UpdateGroupPayload},
+            response::{
+                group::{CreateGroupResponse, GetGroupsResponse, GroupResponse},
+                refresh_token::SimpleResponse,
+            },
+        },
+        repositories::groups::manage::ManageGroupsRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+    infrastructure::models::group::{GroupModel, UpdateGroupModel},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ManageGroupsServiceImpl {
+    pub repository: Arc<dyn ManageGroupsRepository>,
+}
+impl ManageGroupsServiceImpl {
+    pub fn new(repository: Arc<dyn ManageGroupsRepository>) -> Self {
+        Self { repository }
+    }
+
+    async fn find_group(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<GroupModel> {
+        self.repository
+            .get_group(c_apporg.organization_id, c_apporg.application_id, group_id)
+            .await?
+            .ok_or_else(|| RepositoryError::new("group not found".to_string(), 404))
+    }
+}
+
+fn validate_name(name: &str) -> RepositoryResult<()> {
+    if name.trim().is_empty() {
+        return Err(RepositoryError::new("name is required".to_string(), 400));
+    }
+    Ok(())
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ManageGroupsService: 'static + Sync + Send {
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: CreateGroupPayload,
+    ) -> RepositoryResult<CreateGroupResponse>;
+    async fn list(&self, c_apporg: CleanAppOrgByClientId) -> RepositoryResult<GetGroupsResponse>;
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<GroupResponse>;
+    async fn update(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        payload: UpdateGroupPayload,
+    ) -> RepositoryResult<SimpleResponse>;
+    async fn delete(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse>;
+}
+
+#[async_trait]
+impl ManageGroupsService for ManageGroupsServiceImpl {
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: CreateGroupPayload,
+    ) -> RepositoryResult<CreateGroupResponse> {
+        validate_name(&payload.name)?;
+        let group = self.repository.new_group(
+            c_apporg.organization_id,
+            c_apporg.application_id,
+            payload.name,
+            payload.description,
+        );
+        self.repository.create_group(&group).await?;
+        Ok(CreateGroupResponse {
+            group_id: group.group_id,
+        })
+    }
+
+    async fn list(&self, c_apporg: CleanAppOrgByClientId) -> RepositoryResult<GetGroupsResponse> {
+        let groups = self
+            .repository
+            .get_groups(c_apporg.organization_id, c_apporg.application_id)
+            .await?;
+        Ok(GetGroupsResponse {
+            groups: groups.into_iter().map(GroupResponse::from).collect(),
+        })
+    }
+
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<GroupResponse> {
+        let group = self.find_group(&c_apporg, group_id).await?;
+        Ok(GroupResponse::from(group))
+    }
+
+    async fn update(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        payload: UpdateGroupPayload,
+    ) -> RepositoryResult<SimpleResponse> {
+        let group = self.find_group(&c_apporg, group_id).await?;
+        let mut update = UpdateGroupModel {
+            organization_id: group.organization_id,
+            application_id: group.application_id,
+            group_id,
+            name: group.name,
+            description: group.description,
+        };
+        if let Some(name) = payload.name {
+            validate_name(&name)?;
+            update.name = name;
+        }
+        if let Some(description) = payload.description {
+            update.description = Some(description);
+        }
+        self.repository.update_group(&update).await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+
+    async fn delete(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse> {
+        self.find_group(&c_apporg, group_id).await?;
+        self.repository
+            .delete_group(c_apporg.organization_id, c_apporg.application_id, group_id)
+            .await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+}
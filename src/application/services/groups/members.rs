@@ -0,0 +1,131 @@
+use crate::{
+    application::{
+        dto::response::{group::GetGroupMembersResponse, refresh_token::SimpleResponse},
+        repositories::groups::members::GroupMembersRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct GroupMembersServiceImpl {
+    pub repository: Arc<dyn GroupMembersRepository>,
+}
+impl GroupMembersServiceImpl {
+    pub fn new(repository: Arc<dyn GroupMembersRepository>) -> Self {
+        Self { repository }
+    }
+
+    async fn ensure_group(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.repository
+            .get_group(c_apporg.organization_id, c_apporg.application_id, group_id)
+            .await?
+            .ok_or_else(|| RepositoryError::new("group not found".to_string(), 404))?;
+        Ok(())
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GroupMembersService: 'static + Sync + Send {
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        page_size: i32,
+        paging_state: Option<String>,
+    ) -> RepositoryResult<GetGroupMembersResponse>;
+    async fn add(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse>;
+    async fn remove(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse>;
+}
+
+#[async_trait]
+impl GroupMembersService for GroupMembersServiceImpl {
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        page_size: i32,
+        paging_state: Option<String>,
+    ) -> RepositoryResult<GetGroupMembersResponse> {
+        self.ensure_group(&c_apporg, group_id).await?;
+        let paging_state_u8 = match paging_state {
+            Some(state) => Some(self.repository.base64_to_bytes(state)?),
+            None => None,
+        };
+        let page = self
+            .repository
+            .get_members(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                group_id,
+                page_size,
+                paging_state_u8,
+            )
+            .await?;
+        Ok(GetGroupMembersResponse {
+            members: page.members,
+            paging_state: page
+                .paging_state
+                .map(|state| self.repository.bytes_to_base64(state)),
+        })
+    }
+
+    async fn add(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse> {
+        self.ensure_group(&c_apporg, group_id).await?;
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        if !self.repository.user_exists(org_id, app_id, user_id).await? {
+            return Err(RepositoryError::new("user not found".to_string(), 404));
+        }
+        self.repository
+            .add_member(org_id, app_id, group_id, user_id)
+            .await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+
+    async fn remove(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse> {
+        self.ensure_group(&c_apporg, group_id).await?;
+        self.repository
+            .remove_member(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                group_id,
+                user_id,
+            )
+            .await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+}
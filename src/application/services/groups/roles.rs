@@ -0,0 +1,101 @@
+use crate::{
+    application::{
+        dto::response::refresh_token::SimpleResponse,
+        repositories::groups::roles::GroupRolesRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct GroupRolesServiceImpl {
+    pub repository: Arc<dyn GroupRolesRepository>,
+}
+impl GroupRolesServiceImpl {
+    pub fn new(repository: Arc<dyn GroupRolesRepository>) -> Self {
+        Self { repository }
+    }
+
+    async fn ensure_group(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.repository
+            .get_group(c_apporg.organization_id, c_apporg.application_id, group_id)
+            .await?
+            .ok_or_else(|| RepositoryError::new("group not found".to_string(), 404))?;
+        Ok(())
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GroupRolesService: 'static + Sync + Send {
+    /// Every member of the group holds the role for as long as they stay in
+    /// the group.
+    async fn assign(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse>;
+    async fn remove(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse>;
+}
+
+#[async_trait]
+impl GroupRolesService for GroupRolesServiceImpl {
+    async fn assign(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse> {
+        self.ensure_group(&c_apporg, group_id).await?;
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        if self
+            .repository
+            .get_role(org_id, app_id, role_id)
+            .await?
+            .is_none()
+        {
+            return Err(RepositoryError::new("role not found".to_string(), 404));
+        }
+        self.repository
+            .assign_role(org_id, app_id, group_id, role_id)
+            .await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+
+    async fn remove(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse> {
+        self.ensure_group(&c_apporg, group_id).await?;
+        self.repository
+            .remove_role(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                group_id,
+                role_id,
+            )
+            .await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+}
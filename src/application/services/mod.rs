@@ -1,7 +1,14 @@
+pub mod applications;
 pub mod cdc;
+pub mod groups;
 pub mod hello_service;
 pub mod initial_core_service;
+pub mod oauth;
+pub mod permissions;
 pub mod refresh_token;
 pub mod roles;
+pub mod scim;
+pub mod service_accounts;
+pub mod signing_keys;
 pub mod users;
 pub mod validate_bearer_auth_service;
@@ -0,0 +1,231 @@
+use crate::{
+    application::{
+        dto::payload::oauth::AuthorizeQuery, repositories::oauth::authorize::AuthorizeRepository,
+    },
+    domain::{
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::{
+            identifier::{normalize_email, normalize_username},
+            pkce::{CODE_CHALLENGE_METHOD_S256, is_valid_pkce_value},
+        },
+    },
+    infrastructure::models::authorization_code::AuthorizationCodeModel,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use url::Url;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct AuthorizeServiceImpl {
+    pub repository: Arc<dyn AuthorizeRepository>,
+}
+impl AuthorizeServiceImpl {
+    pub fn new(repository: Arc<dyn AuthorizeRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+fn invalid_credentials() -> RepositoryError {
+    RepositoryError::new("invalid username or password".to_string(), 401)
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait AuthorizeService: 'static + Sync + Send {
+    /// Checks the client, redirect URI and PKCE parameters before the user
+    /// is asked for credentials.
+    async fn validate(&self, request: &AuthorizeQuery) -> RepositoryResult<CleanAppOrgByClientId>;
+    /// Checks the user's credentials against the client's application and
+    /// returns the user's id.
+    async fn authenticate(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        username: &str,
+        password: &str,
+    ) -> RepositoryResult<Uuid>;
+    /// Stores a fresh authorization code for an already authenticated user
+    /// and returns the redirect URI carrying it.
+    async fn issue_authorization_code(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        request: AuthorizeQuery,
+        user_id: Uuid,
+    ) -> RepositoryResult<String>;
+    /// Issues the single-use anti-CSRF token embedded in one render of the
+    /// sign-in form.
+    async fn issue_csrf_token(&self, apporg: &CleanAppOrgByClientId) -> RepositoryResult<String>;
+    /// Checks the form's CSRF token, authenticates the user and returns the
+    /// redirect URI carrying a fresh authorization code.
+    async fn execute(
+        &self,
+        request: AuthorizeQuery,
+        csrf_token: String,
+        username: String,
+        password: String,
+    ) -> RepositoryResult<String>;
+}
+
+#[async_trait]
+impl AuthorizeService for AuthorizeServiceImpl {
+    async fn validate(&self, request: &AuthorizeQuery) -> RepositoryResult<CleanAppOrgByClientId> {
+        if request.response_type != "code" {
+            return Err(RepositoryError::new(
+                "unsupported_response_type".to_string(),
+                400,
+            ));
+        }
+        let Some(apporg) = self
+            .repository
+            .find_apporg_by_client_id(request.client_id)
+            .await?
+        else {
+            return Err(RepositoryError::new("unknown client_id".to_string(), 400));
+        };
+        if !apporg.is_active {
+            return Err(RepositoryError::new("client is disabled".to_string(), 400));
+        }
+        if !apporg
+            .get_config()?
+            .is_redirect_uri_registered(&request.redirect_uri)
+        {
+            return Err(RepositoryError::new(
+                "redirect_uri is not registered for this client".to_string(),
+                400,
+            ));
+        }
+        let method = request
+            .code_challenge_method
+            .as_deref()
+            .unwrap_or(CODE_CHALLENGE_METHOD_S256);
+        if method != CODE_CHALLENGE_METHOD_S256 {
+            return Err(RepositoryError::new(
+                "code_challenge_method must be S256".to_string(),
+                400,
+            ));
+        }
+        if !is_valid_pkce_value(&request.code_challenge) {
+            return Err(RepositoryError::new(
+                "invalid code_challenge".to_string(),
+                400,
+            ));
+        }
+        Ok(apporg)
+    }
+
+    async fn authenticate(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        username: &str,
+        password: &str,
+    ) -> RepositoryResult<Uuid> {
+        let config = apporg.get_config()?;
+        let (org_id, app_id) = (apporg.organization_id, apporg.application_id);
+
+        let mut user_id = self
+            .repository
+            .find_user_id_by_username(org_id, app_id, normalize_username(username))
+            .await?;
+        if user_id.is_none() && username.contains('@') {
+            user_id = self
+                .repository
+                .find_user_id_by_email(
+                    org_id,
+                    app_id,
+                    normalize_email(username, &config.email_normalization),
+                )
+                .await?;
+        }
+        let Some(user_id) = user_id else {
+            return Err(invalid_credentials());
+        };
+        let Some(user) = self.repository.find_user(org_id, app_id, user_id).await? else {
+            return Err(invalid_credentials());
+        };
+        if !user.can_sign_in()
+            || !self
+                .repository
+                .verify_password(&user.hashed_password, password)?
+        {
+            return Err(invalid_credentials());
+        }
+        if self.repository.needs_rehash(&user.hashed_password) {
+            // the sign-in already succeeded, a failed upgrade is retried on the next one
+            if let Ok(hashed_password) = self.repository.hash_password(password) {
+                let _ = self
+                    .repository
+                    .update_hashed_password(
+                        org_id,
+                        app_id,
+                        user_id,
+                        user.hashed_password,
+                        hashed_password,
+                    )
+                    .await;
+            }
+        }
+        Ok(user_id)
+    }
+
+    async fn issue_csrf_token(&self, apporg: &CleanAppOrgByClientId) -> RepositoryResult<String> {
+        let token = self.repository.generate_csrf_token()?;
+        self.repository
+            .store_csrf_token(&token, apporg.client_id)
+            .await?;
+        Ok(token)
+    }
+
+    async fn execute(
+        &self,
+        request: AuthorizeQuery,
+        csrf_token: String,
+        username: String,
+        password: String,
+    ) -> RepositoryResult<String> {
+        let apporg = self.validate(&request).await?;
+        if self.repository.take_csrf_token(&csrf_token).await? != Some(apporg.client_id) {
+            return Err(RepositoryError::new(
+                "the sign-in form expired, please try again".to_string(),
+                403,
+            ));
+        }
+        let user_id = self.authenticate(&apporg, &username, &password).await?;
+        self.issue_authorization_code(&apporg, request, user_id)
+            .await
+    }
+
+    async fn issue_authorization_code(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        request: AuthorizeQuery,
+        user_id: Uuid,
+    ) -> RepositoryResult<String> {
+        let (org_id, app_id) = (apporg.organization_id, apporg.application_id);
+        let code = self.repository.generate_authorization_code()?;
+        self.repository
+            .store_authorization_code(&AuthorizationCodeModel {
+                code: code.clone(),
+                client_id: apporg.client_id,
+                application_id: app_id,
+                organization_id: org_id,
+                user_id,
+                redirect_uri: request.redirect_uri.clone(),
+                scope: request.scope,
+                code_challenge: request.code_challenge,
+                code_challenge_method: CODE_CHALLENGE_METHOD_S256.to_string(),
+                nonce: request.nonce,
+                issued_at: Utc::now().timestamp(),
+            })
+            .await?;
+
+        let mut redirect = Url::parse(&request.redirect_uri)
+            .map_err(|_| RepositoryError::new("invalid redirect_uri".to_string(), 400))?;
+        redirect.query_pairs_mut().append_pair("code", &code);
+        if let Some(state) = request.state {
+            redirect.query_pairs_mut().append_pair("state", &state);
+        }
+        Ok(redirect.to_string())
+    }
+}
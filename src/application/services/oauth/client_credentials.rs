@@ -0,0 +1,119 @@
+use crate::{
+    application::{
+        dto::response::oauth::TokenResponse,
+        repositories::oauth::client_credentials::ClientCredentialsRepository,
+    },
+    domain::{
+        entities::{apporg_client_id::HasAppConfig, service_account::ServiceAccount},
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use time::format_description::well_known::Rfc3339;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ClientCredentialsServiceImpl {
+    pub repository: Arc<dyn ClientCredentialsRepository>,
+}
+impl ClientCredentialsServiceImpl {
+    pub fn new(repository: Arc<dyn ClientCredentialsRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+fn invalid_client() -> RepositoryError {
+    RepositoryError::new("invalid_client".to_string(), 401)
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ClientCredentialsService: 'static + Sync + Send {
+    /// `client_credentials` grant (RFC 6749 section 4.4) for service
+    /// accounts; the access token carries the permissions of the account's
+    /// roles and comes without a refresh token.
+    async fn execute(
+        &self,
+        client_id: Uuid,
+        client_secret: String,
+    ) -> RepositoryResult<TokenResponse>;
+}
+
+#[async_trait]
+impl ClientCredentialsService for ClientCredentialsServiceImpl {
+    async fn execute(
+        &self,
+        client_id: Uuid,
+        client_secret: String,
+    ) -> RepositoryResult<TokenResponse> {
+        let Some((client_key, secret)) = ServiceAccount::split_client_secret(&client_secret) else {
+            return Err(invalid_client());
+        };
+        let account = match self
+            .repository
+            .find_service_account_by_client_id(client_id)
+            .await?
+        {
+            Some(account) if account.is_active => account,
+            _ => return Err(invalid_client()),
+        };
+        let Ok(expected) = self
+            .repository
+            .decrypt_client_secret(client_key, &account.encrypted_client_secret)
+            .await
+        else {
+            return Err(invalid_client());
+        };
+        if expected != secret {
+            return Err(invalid_client());
+        }
+        let apporg = match self
+            .repository
+            .find_apporg_by_client_id(account.application_client_id)
+            .await?
+        {
+            Some(apporg) if apporg.is_active => apporg,
+            _ => return Err(invalid_client()),
+        };
+
+        let permissions = self
+            .repository
+            .find_permissions(
+                account.organization_id,
+                account.application_id,
+                account.service_account_id,
+            )
+            .await?;
+        let signing_key = self
+            .repository
+            .signing_key(account.organization_id, account.application_id)
+            .await?;
+        let expires_in = apporg
+            .get_config()?
+            .token_lifetimes
+            .service_account_token_lifetime
+            .max(1);
+        let issued_at = time::OffsetDateTime::now_utc();
+        let expires_at = issued_at + time::Duration::seconds(expires_in);
+        let access_token = self
+            .repository
+            .create_access_token(
+                &signing_key,
+                account.service_account_id,
+                apporg.client_id,
+                permissions,
+                issued_at.format(&Rfc3339)?,
+                expires_at.format(&Rfc3339)?,
+            )
+            .await?;
+        Ok(TokenResponse {
+            access_token,
+            token_type: "Bearer".to_string(),
+            expires_in,
+            refresh_token: None,
+            scope: None,
+            id_token: None,
+        })
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    application::{
+        dto::response::oauth::DeviceAuthorizationResponse,
+        repositories::oauth::device::DeviceAuthorizationRepository,
+        services::oauth::authorize::AuthorizeService,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::device_code::{format_user_code, normalize_user_code},
+    },
+    infrastructure::models::device_authorization::{
+        DeviceAuthorizationDecision, DeviceAuthorizationModel, DeviceAuthorizationStatus,
+    },
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use url::Url;
+use uuid::Uuid;
+
+fn not_found() -> RepositoryError {
+    RepositoryError::new("code is invalid or expired".to_string(), 404)
+}
+
+#[derive(Clone)]
+pub struct DeviceAuthorizationServiceImpl {
+    pub repository: Arc<dyn DeviceAuthorizationRepository>,
+    pub authorize_service: Arc<dyn AuthorizeService>,
+}
+impl DeviceAuthorizationServiceImpl {
+    pub fn new(
+        repository: Arc<dyn DeviceAuthorizationRepository>,
+        authorize_service: Arc<dyn AuthorizeService>,
+    ) -> Self {
+        Self {
+            repository,
+            authorize_service,
+        }
+    }
+
+    async fn find_client(&self, client_id: Uuid) -> RepositoryResult<CleanAppOrgByClientId> {
+        match self.repository.find_apporg_by_client_id(client_id).await? {
+            Some(apporg) if apporg.is_active => Ok(apporg),
+            _ => Err(RepositoryError::new("invalid_client".to_string(), 401)),
+        }
+    }
+
+    /// Stores the answer unless someone answered first, in which case the
+    /// request is no longer pending.
+    async fn decide(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        status: DeviceAuthorizationStatus,
+        user_id: Option<Uuid>,
+    ) -> RepositoryResult<()> {
+        let decision = DeviceAuthorizationDecision { status, user_id };
+        if !self
+            .repository
+            .decide_device_authorization(authorization, &decision)
+            .await?
+        {
+            return Err(not_found());
+        }
+        Ok(())
+    }
+
+    /// Only requests still waiting for a decision can be looked up.
+    async fn find_pending(
+        &self,
+        user_code: &str,
+    ) -> RepositoryResult<(DeviceAuthorizationModel, CleanAppOrgByClientId)> {
+        let user_code = normalize_user_code(user_code).ok_or_else(not_found)?;
+        let authorization = self
+            .repository
+            .find_device_authorization_by_user_code(&user_code)
+            .await?
+            .filter(|a| a.status == DeviceAuthorizationStatus::Pending)
+            .ok_or_else(not_found)?;
+        let apporg = self.find_client(authorization.client_id).await?;
+        Ok((authorization, apporg))
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DeviceAuthorizationService: 'static + Sync + Send {
+    /// Starts a device flow (RFC 8628 3.1) and returns the codes the device
+    /// shows to the user and polls with.
+    async fn authorize(
+        &self,
+        client_id: Uuid,
+        scope: Option<String>,
+    ) -> RepositoryResult<DeviceAuthorizationResponse>;
+    /// Returns the client a pending user code belongs to.
+    async fn find_client_by_user_code(
+        &self,
+        user_code: &str,
+    ) -> RepositoryResult<CleanAppOrgByClientId>;
+    /// Approves the request as the signed-in user.
+    async fn approve(
+        &self,
+        user_code: &str,
+        username: &str,
+        password: &str,
+    ) -> RepositoryResult<()>;
+    /// Denies the request as the signed-in user, so only someone who can
+    /// sign in to the application can turn a device away.
+    async fn deny(&self, user_code: &str, username: &str, password: &str) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl DeviceAuthorizationService for DeviceAuthorizationServiceImpl {
+    async fn authorize(
+        &self,
+        client_id: Uuid,
+        scope: Option<String>,
+    ) -> RepositoryResult<DeviceAuthorizationResponse> {
+        let apporg = self.find_client(client_id).await?;
+        let expires_in = self.repository.device_code_ttl();
+        let interval = self.repository.poll_interval();
+        let authorization = DeviceAuthorizationModel {
+            device_code: self.repository.generate_device_code()?,
+            user_code: self.repository.generate_user_code()?,
+            client_id: apporg.client_id,
+            application_id: apporg.application_id,
+            organization_id: apporg.organization_id,
+            scope,
+            status: DeviceAuthorizationStatus::Pending,
+            user_id: None,
+            interval,
+            expires_at: Utc::now().timestamp() + expires_in as i64,
+        };
+        self.repository
+            .store_device_authorization(&authorization)
+            .await?;
+
+        let user_code = format_user_code(&authorization.user_code);
+        let verification_uri = format!(
+            "{}/oauth/device",
+            self.repository.issuer().trim_end_matches('/')
+        );
+        let mut verification_uri_complete = Url::parse(&verification_uri)
+            .map_err(|_| RepositoryError::new("invalid issuer".to_string(), 500))?;
+        verification_uri_complete
+            .query_pairs_mut()
+            .append_pair("user_code", &user_code);
+        Ok(DeviceAuthorizationResponse {
+            device_code: authorization.device_code,
+            user_code,
+            verification_uri,
+            verification_uri_complete: verification_uri_complete.to_string(),
+            expires_in,
+            interval,
+        })
+    }
+
+    async fn find_client_by_user_code(
+        &self,
+        user_code: &str,
+    ) -> RepositoryResult<CleanAppOrgByClientId> {
+        Ok(self.find_pending(user_code).await?.1)
+    }
+
+    async fn approve(
+        &self,
+        user_code: &str,
+        username: &str,
+        password: &str,
+    ) -> RepositoryResult<()> {
+        let (authorization, apporg) = self.find_pending(user_code).await?;
+        let user_id = self
+            .authorize_service
+            .authenticate(&apporg, username, password)
+            .await?;
+        self.decide(
+            &authorization,
+            DeviceAuthorizationStatus::Approved,
+            Some(user_id),
+        )
+        .await
+    }
+
+    async fn deny(&self, user_code: &str, username: &str, password: &str) -> RepositoryResult<()> {
+        let (authorization, apporg) = self.find_pending(user_code).await?;
+        self.authorize_service
+            .authenticate(&apporg, username, password)
+            .await?;
+        self.decide(&authorization, DeviceAuthorizationStatus::Denied, None)
+            .await
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    application::{
+        dto::response::oauth::{
+            JwksResponse, OpenIdConfigurationResponse, PaserkKey, PaserkKeysResponse,
+        },
+        repositories::oauth::discovery::DiscoveryRepository,
+    },
+    domain::{
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::device_code::DEVICE_CODE_GRANT_TYPE,
+    },
+    infrastructure::repositories::paseto::signing_key::VerificationKey,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct DiscoveryServiceImpl {
+    pub repository: Arc<dyn DiscoveryRepository>,
+}
+impl DiscoveryServiceImpl {
+    pub fn new(repository: Arc<dyn DiscoveryRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Keys are per application, so key sets are published per client.
+    async fn verification_keys(&self, client_id: Uuid) -> RepositoryResult<Vec<VerificationKey>> {
+        match self.repository.find_apporg_by_client_id(client_id).await? {
+            Some(apporg) if apporg.is_active => {
+                self.repository
+                    .verification_keys(apporg.organization_id, apporg.application_id)
+                    .await
+            }
+            _ => Err(RepositoryError::new("client not found".to_string(), 404)),
+        }
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DiscoveryService: 'static + Sync + Send {
+    /// With a `client_id` the document points `jwks_uri` at that client's keys.
+    fn openid_configuration(&self, client_id: Option<Uuid>) -> OpenIdConfigurationResponse;
+    async fn jwks(&self, client_id: Uuid) -> RepositoryResult<JwksResponse>;
+    async fn paserks(&self, client_id: Uuid) -> RepositoryResult<PaserkKeysResponse>;
+}
+
+#[async_trait]
+impl DiscoveryService for DiscoveryServiceImpl {
+    fn openid_configuration(&self, client_id: Option<Uuid>) -> OpenIdConfigurationResponse {
+        let issuer = self.repository.issuer();
+        let base = issuer.trim_end_matches('/');
+        let jwks_uri = match client_id {
+            Some(client_id) => format!("{base}/.well-known/jwks.json?client_id={client_id}"),
+            None => format!("{base}/.well-known/jwks.json"),
+        };
+        OpenIdConfigurationResponse {
+            authorization_endpoint: format!("{base}/oauth/authorize"),
+            token_endpoint: format!("{base}/oauth/token"),
+            device_authorization_endpoint: format!("{base}/oauth/device_authorization"),
+            userinfo_endpoint: format!("{base}/oauth/userinfo"),
+            jwks_uri,
+            issuer,
+            response_types_supported: strings(&["code"]),
+            grant_types_supported: strings(&[
+                "authorization_code",
+                "refresh_token",
+                "client_credentials",
+                DEVICE_CODE_GRANT_TYPE,
+            ]),
+            subject_types_supported: strings(&["public"]),
+            id_token_signing_alg_values_supported: strings(&["EdDSA"]),
+            scopes_supported: strings(&["openid", "profile", "email"]),
+            token_endpoint_auth_methods_supported: strings(&["none", "client_secret_post"]),
+            code_challenge_methods_supported: strings(&["S256"]),
+            claims_supported: strings(&[
+                "sub",
+                "iss",
+                "aud",
+                "exp",
+                "iat",
+                "nonce",
+                "email",
+                "email_verified",
+                "preferred_username",
+                "given_name",
+                "family_name",
+                "locale",
+                "phone_number",
+                "picture",
+            ]),
+        }
+    }
+
+    async fn jwks(&self, client_id: Uuid) -> RepositoryResult<JwksResponse> {
+        let keys = self.verification_keys(client_id).await?;
+        Ok(JwksResponse {
+            keys: keys.iter().map(|k| self.repository.jwk(k)).collect(),
+        })
+    }
+
+    async fn paserks(&self, client_id: Uuid) -> RepositoryResult<PaserkKeysResponse> {
+        let keys = self.verification_keys(client_id).await?;
+        Ok(PaserkKeysResponse {
+            keys: keys
+                .iter()
+                .map(|k| PaserkKey {
+                    kid: k.kid.clone(),
+                    paserk: k.paserk(),
+                })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{
+    application::{
+        dto::payload::{oauth::AuthorizeQuery, user::CreateUserPayload},
+        repositories::oauth::federation::FederationRepository,
+        services::{oauth::authorize::AuthorizeService, users::create::CreateUserService},
+    },
+    domain::{
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::{
+            app_config::AppConfig, identifier::normalize_email,
+            identity_provider::IdentityProvider, pkce::CODE_CHALLENGE_METHOD_S256,
+        },
+    },
+    infrastructure::models::{
+        federation_state::FederationStateModel, upstream_oidc::UpstreamIdTokenClaims,
+        user_identity::UserIdentityModel,
+    },
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use std::sync::Arc;
+use url::Url;
+use uuid::Uuid;
+
+const USERNAME_LENGTH: std::ops::RangeInclusive<usize> = 2..=50;
+
+#[derive(Clone)]
+pub struct FederationServiceImpl {
+    pub repository: Arc<dyn FederationRepository>,
+    pub authorize_service: Arc<dyn AuthorizeService>,
+    pub create_user_service: Arc<dyn CreateUserService>,
+}
+impl FederationServiceImpl {
+    pub fn new(
+        repository: Arc<dyn FederationRepository>,
+        authorize_service: Arc<dyn AuthorizeService>,
+        create_user_service: Arc<dyn CreateUserService>,
+    ) -> Self {
+        Self {
+            repository,
+            authorize_service,
+            create_user_service,
+        }
+    }
+
+    fn find_provider<'a>(
+        config: &'a AppConfig,
+        provider_id: &str,
+    ) -> RepositoryResult<&'a IdentityProvider> {
+        config
+            .find_identity_provider(provider_id)
+            .ok_or_else(|| RepositoryError::new("unknown identity provider".to_string(), 404))
+    }
+
+    /// Usernames to try for a new user, best first; the provider-scoped
+    /// subject always fits and is unique.
+    fn username_candidates(
+        provider: &IdentityProvider,
+        claims: &UpstreamIdTokenClaims,
+    ) -> Vec<String> {
+        let mut candidates: Vec<String> = [claims.preferred_username.clone(), claims.email.clone()]
+            .into_iter()
+            .flatten()
+            .filter(|name| USERNAME_LENGTH.contains(&name.len()))
+            .collect();
+        let fallback: String = format!("{}-{}", provider.id, claims.sub)
+            .chars()
+            .take(*USERNAME_LENGTH.end())
+            .collect();
+        candidates.push(fallback);
+        candidates
+    }
+
+    async fn create_user(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        provider: &IdentityProvider,
+        claims: &UpstreamIdTokenClaims,
+    ) -> RepositoryResult<Uuid> {
+        // an unverified address could claim an email its owner signs up with later
+        let email = claims.email.clone().filter(|_| claims.email_verified);
+        let mut last_error = None;
+        for username in Self::username_candidates(provider, claims) {
+            let payload = CreateUserPayload {
+                username,
+                email: email.clone(),
+                // federated users sign in upstream
+                password: String::new(),
+                profile: Default::default(),
+                metadata: Default::default(),
+            };
+            match self
+                .create_user_service
+                .execute_passwordless(apporg.clone(), payload)
+                .await
+            {
+                Ok(created) => return Ok(Uuid::parse_str(&created.user_id)?),
+                Err(e) if e.message == "username already used" => last_error = Some(e),
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| RepositoryError::new("username already used".into(), 400)))
+    }
+
+    /// Finds the user linked to the upstream subject, linking or creating one
+    /// on first sign-in.
+    async fn resolve_user(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        config: &AppConfig,
+        provider: &IdentityProvider,
+        claims: &UpstreamIdTokenClaims,
+    ) -> RepositoryResult<Uuid> {
+        let (org_id, app_id) = (apporg.organization_id, apporg.application_id);
+        if let Some(user_id) = self
+            .repository
+            .find_identity(org_id, app_id, &provider.id, &claims.sub)
+            .await?
+        {
+            return Ok(user_id);
+        }
+
+        let mut user_id = None;
+        if provider.link_by_email
+            && claims.email_verified
+            && let Some(email) = &claims.email
+        {
+            user_id = self
+                .repository
+                .find_user_id_by_email(
+                    org_id,
+                    app_id,
+                    normalize_email(email, &config.email_normalization),
+                )
+                .await?;
+        }
+        let user_id = match user_id {
+            Some(user_id) => user_id,
+            None => self.create_user(apporg, provider, claims).await?,
+        };
+
+        let linked = self
+            .repository
+            .link_identity(&UserIdentityModel {
+                organization_id: org_id,
+                application_id: app_id,
+                provider_id: provider.id.clone(),
+                subject: claims.sub.clone(),
+                user_id,
+                email: claims.email.clone(),
+                created_at: CqlTimestamp(Utc::now().timestamp_millis()),
+            })
+            .await?;
+        if linked {
+            return Ok(user_id);
+        }
+        // a concurrent first sign-in of the same subject linked it first
+        self.repository
+            .find_identity(org_id, app_id, &provider.id, &claims.sub)
+            .await?
+            .ok_or_else(|| RepositoryError::new("failed to link identity".to_string(), 500))
+    }
+}
+
+fn resume_request(saved: &FederationStateModel) -> AuthorizeQuery {
+    AuthorizeQuery {
+        response_type: "code".to_string(),
+        client_id: saved.client_id,
+        redirect_uri: saved.redirect_uri.clone(),
+        scope: saved.scope.clone(),
+        state: saved.client_state.clone(),
+        code_challenge: saved.code_challenge.clone(),
+        code_challenge_method: saved.code_challenge_method.clone(),
+        nonce: saved.nonce.clone(),
+    }
+}
+
+fn access_denied(request: &AuthorizeQuery) -> RepositoryResult<String> {
+    let mut redirect = Url::parse(&request.redirect_uri)
+        .map_err(|_| RepositoryError::new("invalid redirect_uri".to_string(), 400))?;
+    redirect
+        .query_pairs_mut()
+        .append_pair("error", "access_denied");
+    if let Some(state) = &request.state {
+        redirect.query_pairs_mut().append_pair("state", state);
+    }
+    Ok(redirect.to_string())
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait FederationService: 'static + Sync + Send {
+    /// Returns the upstream authorization URL that continues the client's
+    /// authorization request at `provider_id`.
+    async fn start(&self, request: AuthorizeQuery, provider_id: String)
+    -> RepositoryResult<String>;
+    /// Completes the upstream sign-in and returns the client redirect URI
+    /// carrying an authorization code, or `access_denied` if the user
+    /// cancelled upstream.
+    async fn callback(
+        &self,
+        state: String,
+        code: Option<String>,
+        error: Option<String>,
+    ) -> RepositoryResult<String>;
+}
+
+#[async_trait]
+impl FederationService for FederationServiceImpl {
+    async fn start(
+        &self,
+        request: AuthorizeQuery,
+        provider_id: String,
+    ) -> RepositoryResult<String> {
+        let apporg = self.authorize_service.validate(&request).await?;
+        let config = apporg.get_config()?;
+        let provider = Self::find_provider(&config, &provider_id)?;
+        let metadata = self.repository.discover(&provider.issuer).await?;
+
+        let saved = FederationStateModel {
+            state: self.repository.generate_random()?,
+            provider_id: provider.id.clone(),
+            upstream_nonce: self.repository.generate_random()?,
+            code_verifier: self.repository.generate_random()?,
+            client_id: request.client_id,
+            redirect_uri: request.redirect_uri,
+            scope: request.scope,
+            client_state: request.state,
+            code_challenge: request.code_challenge,
+            code_challenge_method: request.code_challenge_method,
+            nonce: request.nonce,
+        };
+        self.repository.store_state(&saved).await?;
+
+        let mut url = Url::parse(&metadata.authorization_endpoint).map_err(|_| {
+            RepositoryError::new("invalid upstream authorization_endpoint".to_string(), 502)
+        })?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &provider.client_id)
+            .append_pair("redirect_uri", &self.repository.callback_uri())
+            .append_pair("scope", &provider.scope())
+            .append_pair("state", &saved.state)
+            .append_pair("nonce", &saved.upstream_nonce)
+            .append_pair(
+                "code_challenge",
+                &self.repository.code_challenge(&saved.code_verifier),
+            )
+            .append_pair("code_challenge_method", CODE_CHALLENGE_METHOD_S256);
+        Ok(url.to_string())
+    }
+
+    async fn callback(
+        &self,
+        state: String,
+        code: Option<String>,
+        error: Option<String>,
+    ) -> RepositoryResult<String> {
+        let Some(saved) = self.repository.take_state(&state).await? else {
+            return Err(RepositoryError::new(
+                "sign-in request is invalid or expired".to_string(),
+                400,
+            ));
+        };
+        let request = resume_request(&saved);
+        let apporg = self.authorize_service.validate(&request).await?;
+        let code = match (code, error) {
+            (Some(code), None) => code,
+            _ => return access_denied(&request),
+        };
+        let config = apporg.get_config()?;
+        let provider = Self::find_provider(&config, &saved.provider_id)?;
+
+        let metadata = self.repository.discover(&provider.issuer).await?;
+        let client_secret = self
+            .repository
+            .decrypt_client_secret(&provider.encrypted_client_secret)
+            .await?;
+        let id_token = self
+            .repository
+            .exchange_code(
+                &metadata.token_endpoint,
+                &provider.client_id,
+                &client_secret,
+                &code,
+                &saved.code_verifier,
+            )
+            .await?;
+        let claims = self.repository.decode_id_token(&id_token)?;
+        claims
+            .validate(
+                &provider.issuer,
+                &provider.client_id,
+                &saved.upstream_nonce,
+                Utc::now().timestamp(),
+            )
+            .map_err(|e| RepositoryError::new(e, 401))?;
+
+        let user_id = self
+            .resolve_user(&apporg, &config, provider, &claims)
+            .await?;
+        let user = self
+            .repository
+            .find_user(apporg.organization_id, apporg.application_id, user_id)
+            .await?;
+        if !user.is_some_and(|u| u.can_sign_in()) {
+            return Err(RepositoryError::new(
+                "user is not allowed to sign in".to_string(),
+                403,
+            ));
+        }
+        self.authorize_service
+            .issue_authorization_code(&apporg, request, user_id)
+            .await
+    }
+}
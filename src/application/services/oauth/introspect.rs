@@ -0,0 +1,202 @@
+use crate::{
+    application::{
+        dto::{payload::oauth::TokenLookupPayload, response::oauth::IntrospectionResponse},
+        repositories::oauth::introspect::IntrospectRepository,
+    },
+    domain::{
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+        errors::repositories_errors::RepositoryResult,
+        value_objects::token_lifetimes::TokenLifetimes,
+    },
+    infrastructure::repositories::paseto::signing_key::VerificationKey,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn token_lifetimes(c_apporg: &CleanAppOrgByClientId) -> TokenLifetimes {
+    c_apporg
+        .get_config()
+        .map(|config| config.token_lifetimes)
+        .unwrap_or_default()
+}
+
+#[derive(Clone)]
+pub struct IntrospectServiceImpl {
+    pub repository: Arc<dyn IntrospectRepository>,
+}
+impl IntrospectServiceImpl {
+    pub fn new(repository: Arc<dyn IntrospectRepository>) -> Self {
+        Self { repository }
+    }
+
+    async fn introspect_refresh_token(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<Option<IntrospectionResponse>> {
+        let Ok(claims) = self.repository.decrypt_refresh_token(token, key).await else {
+            return Ok(None);
+        };
+        let lifetimes = token_lifetimes(c_apporg);
+        let now = Utc::now().timestamp();
+        if !lifetimes.is_current(now, claims.nbf, claims.exp) {
+            return Ok(None);
+        }
+        let Ok(token_id) = Uuid::parse_str(&claims.jti) else {
+            return Ok(None);
+        };
+        let Some(found) = self
+            .repository
+            .find_refresh_token(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                token_id,
+                &claims.version,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        let session_started_at = found.session_started_at.map_or(now, |t| t.0 / 1000);
+        let idle = found
+            .last_used_at
+            .is_some_and(|t| lifetimes.is_idle(now, t.0 / 1000));
+        if found.revoked
+            || idle
+            || lifetimes.is_past_absolute_lifetime(now, session_started_at)
+            || !self.user_can_sign_in(c_apporg, found.user_id).await?
+        {
+            return Ok(None);
+        }
+        Ok(Some(IntrospectionResponse {
+            active: true,
+            sub: Some(found.user_id.to_string()),
+            client_id: Some(c_apporg.client_id.to_string()),
+            exp: Some(claims.exp),
+            iat: Some(claims.iat),
+            scope: found.scope,
+            jti: Some(claims.jti),
+            token_type: Some("refresh_token".to_string()),
+            ..Default::default()
+        }))
+    }
+
+    async fn introspect_access_token(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<Option<IntrospectionResponse>> {
+        let Ok(claims) = self.repository.decrypt_access_token(token, key).await else {
+            return Ok(None);
+        };
+        let leeway = token_lifetimes(c_apporg).leeway;
+        if Utc::now().timestamp() >= claims.exp + leeway
+            || claims.client_id != c_apporg.client_id.to_string()
+        {
+            return Ok(None);
+        }
+        let Ok(subject_id) = Uuid::parse_str(&claims.sub) else {
+            return Ok(None);
+        };
+        let subject_active = if claims.is_service_account() {
+            self.service_account_is_active(c_apporg, subject_id).await?
+        } else {
+            self.user_can_sign_in(c_apporg, subject_id).await?
+        };
+        if !subject_active {
+            return Ok(None);
+        }
+        Ok(Some(IntrospectionResponse {
+            active: true,
+            sub: Some(claims.sub),
+            client_id: Some(claims.client_id),
+            scope: claims.scope,
+            permissions: claims.permissions,
+            exp: Some(claims.exp),
+            iat: Some(claims.iat),
+            jti: Some(claims.jti),
+            token_type: Some("access_token".to_string()),
+        }))
+    }
+
+    async fn user_can_sign_in(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        Ok(self
+            .repository
+            .find_user(c_apporg.organization_id, c_apporg.application_id, user_id)
+            .await?
+            .is_some_and(|user| user.can_sign_in()))
+    }
+
+    async fn service_account_is_active(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        Ok(self
+            .repository
+            .find_service_account(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                service_account_id,
+            )
+            .await?
+            .is_some_and(|account| account.is_active))
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait IntrospectService: 'static + Sync + Send {
+    /// RFC 7662 introspection; tokens that are unknown, expired, revoked or
+    /// issued to another client are reported as inactive rather than failing.
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: TokenLookupPayload,
+    ) -> RepositoryResult<IntrospectionResponse>;
+}
+
+#[async_trait]
+impl IntrospectService for IntrospectServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: TokenLookupPayload,
+    ) -> RepositoryResult<IntrospectionResponse> {
+        let token = payload.token.trim();
+        let Some(key) = self.repository.find_verification_key(token).await? else {
+            return Ok(IntrospectionResponse::default());
+        };
+        if key.organization_id != c_apporg.organization_id
+            || key.application_id != c_apporg.application_id
+        {
+            return Ok(IntrospectionResponse::default());
+        }
+        let found = if payload.token_type_hint.as_deref() == Some("access_token") {
+            match self.introspect_access_token(&c_apporg, token, &key).await? {
+                Some(found) => Some(found),
+                None => {
+                    self.introspect_refresh_token(&c_apporg, token, &key)
+                        .await?
+                }
+            }
+        } else {
+            match self
+                .introspect_refresh_token(&c_apporg, token, &key)
+                .await?
+            {
+                Some(found) => Some(found),
+                None => self.introspect_access_token(&c_apporg, token, &key).await?,
+            }
+        };
+        Ok(found.unwrap_or_default())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    application::{
+        dto::payload::oauth::TokenLookupPayload, repositories::oauth::revoke::RevokeTokenRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct RevokeTokenServiceImpl {
+    pub repository: Arc<dyn RevokeTokenRepository>,
+}
+impl RevokeTokenServiceImpl {
+    pub fn new(repository: Arc<dyn RevokeTokenRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RevokeTokenService: 'static + Sync + Send {
+    /// RFC 7009 revocation. Invalid tokens and tokens of other clients are
+    /// ignored so that callers cannot probe which tokens exist.
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: TokenLookupPayload,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl RevokeTokenService for RevokeTokenServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: TokenLookupPayload,
+    ) -> RepositoryResult<()> {
+        let token = payload.token.trim();
+        let Some(key) = self.repository.find_verification_key(token).await? else {
+            return Ok(());
+        };
+        if key.organization_id != c_apporg.organization_id
+            || key.application_id != c_apporg.application_id
+        {
+            return Ok(());
+        }
+        if let Ok(claims) = self.repository.decrypt_refresh_token(token, &key).await {
+            let Ok(token_id) = Uuid::parse_str(&claims.jti) else {
+                return Ok(());
+            };
+            return self
+                .repository
+                .revoke_refresh_token(c_apporg.organization_id, c_apporg.application_id, token_id)
+                .await;
+        }
+        if self
+            .repository
+            .decrypt_access_token(token, &key)
+            .await
+            .is_ok()
+        {
+            // access tokens are self-contained and expire on their own
+            return Err(RepositoryError::new(
+                "unsupported_token_type".to_string(),
+                400,
+            ));
+        }
+        Ok(())
+    }
+}
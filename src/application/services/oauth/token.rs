@@ -0,0 +1,290 @@
+use crate::{
+    application::{
+        dto::{payload::oauth::TokenPayload, response::oauth::TokenResponse},
+        repositories::oauth::token::TokenRepository,
+        services::{
+            oauth::client_credentials::ClientCredentialsService,
+            refresh_token::{create::CreateRefreshTokenService, rotate::RotateRefreshTokenService},
+        },
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::{
+            client_metadata::ClientMetadata, device_code::DEVICE_CODE_GRANT_TYPE,
+            pkce::is_valid_pkce_value,
+        },
+    },
+    infrastructure::models::{
+        device_authorization::DeviceAuthorizationStatus, user::CleannedUserModel,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use time::format_description::well_known::Rfc3339;
+use uuid::Uuid;
+
+const OPENID_SCOPE: &str = "openid";
+/// Seconds added to a device's polling interval on `slow_down` (RFC 8628 3.5).
+const SLOW_DOWN_INCREMENT: u64 = 5;
+
+#[derive(Clone)]
+pub struct TokenServiceImpl {
+    pub repository: Arc<dyn TokenRepository>,
+    pub create_refresh_token_service: Arc<dyn CreateRefreshTokenService>,
+    pub rotate_refresh_token_service: Arc<dyn RotateRefreshTokenService>,
+    pub client_credentials_service: Arc<dyn ClientCredentialsService>,
+}
+impl TokenServiceImpl {
+    pub fn new(
+        repository: Arc<dyn TokenRepository>,
+        create_refresh_token_service: Arc<dyn CreateRefreshTokenService>,
+        rotate_refresh_token_service: Arc<dyn RotateRefreshTokenService>,
+        client_credentials_service: Arc<dyn ClientCredentialsService>,
+    ) -> Self {
+        Self {
+            repository,
+            create_refresh_token_service,
+            rotate_refresh_token_service,
+            client_credentials_service,
+        }
+    }
+}
+
+fn invalid_grant(message: &str) -> RepositoryError {
+    RepositoryError::new(format!("invalid_grant: {message}"), 400)
+}
+
+fn device_error(error: &str) -> RepositoryError {
+    RepositoryError::new(error.to_string(), 400)
+}
+
+fn required(value: Option<String>, name: &str) -> RepositoryResult<String> {
+    value.ok_or_else(|| RepositoryError::new(format!("{name} is required"), 400))
+}
+
+impl TokenServiceImpl {
+    async fn find_client(&self, client_id: Uuid) -> RepositoryResult<CleanAppOrgByClientId> {
+        match self.repository.find_apporg_by_client_id(client_id).await? {
+            Some(apporg) if apporg.is_active => Ok(apporg),
+            _ => Err(RepositoryError::new("invalid_client".to_string(), 401)),
+        }
+    }
+
+    async fn find_active_user(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<CleannedUserModel> {
+        let user = self
+            .repository
+            .find_user(apporg.organization_id, apporg.application_id, user_id)
+            .await?;
+        match user {
+            Some(user) if user.can_sign_in() => Ok(user),
+            _ => Err(invalid_grant("user is not allowed to sign in")),
+        }
+    }
+
+    async fn issue_access_token(
+        &self,
+        apporg: &CleanAppOrgByClientId,
+        user: &CleannedUserModel,
+        scope: Option<String>,
+        nonce: Option<String>,
+        refresh_token: String,
+    ) -> RepositoryResult<TokenResponse> {
+        let signing_key = self
+            .repository
+            .signing_key(apporg.organization_id, apporg.application_id)
+            .await?;
+        let expires_in = self.repository.access_token_ttl();
+        let issued_at = time::OffsetDateTime::now_utc();
+        let expires_at = issued_at + time::Duration::seconds(expires_in);
+        let access_token = self
+            .repository
+            .create_access_token(
+                &signing_key,
+                user.user_id,
+                apporg.client_id,
+                scope.clone(),
+                issued_at.format(&Rfc3339)?,
+                expires_at.format(&Rfc3339)?,
+            )
+            .await?;
+        let id_token = match &scope {
+            Some(scope) if scope.split_whitespace().any(|s| s == OPENID_SCOPE) => {
+                Some(self.repository.create_id_token(
+                    &signing_key,
+                    apporg.client_id,
+                    user,
+                    nonce,
+                    issued_at.unix_timestamp(),
+                    expires_at.unix_timestamp(),
+                )?)
+            }
+            _ => None,
+        };
+        Ok(TokenResponse {
+            access_token,
+            token_type: "Bearer".to_string(),
+            expires_in,
+            refresh_token: Some(refresh_token),
+            scope,
+            id_token,
+        })
+    }
+
+    async fn authorization_code_grant(
+        &self,
+        payload: TokenPayload,
+        client: ClientMetadata,
+    ) -> RepositoryResult<TokenResponse> {
+        let code = required(payload.code, "code")?;
+        let redirect_uri = required(payload.redirect_uri, "redirect_uri")?;
+        let code_verifier = required(payload.code_verifier, "code_verifier")?;
+
+        let Some(grant) = self.repository.take_authorization_code(&code).await? else {
+            return Err(invalid_grant("authorization code is invalid or expired"));
+        };
+        if grant.client_id != payload.client_id || grant.redirect_uri != redirect_uri {
+            return Err(invalid_grant(
+                "authorization code was issued to another client",
+            ));
+        }
+        if !is_valid_pkce_value(&code_verifier)
+            || self.repository.code_challenge(&code_verifier) != grant.code_challenge
+        {
+            return Err(invalid_grant("code_verifier does not match code_challenge"));
+        }
+
+        let apporg = self.find_client(grant.client_id).await?;
+        let user = self.find_active_user(&apporg, grant.user_id).await?;
+        let refresh_token = self
+            .create_refresh_token_service
+            .execute(apporg.clone(), grant.user_id, client, grant.scope.clone())
+            .await?
+            .refresh_token;
+        self.issue_access_token(&apporg, &user, grant.scope, grant.nonce, refresh_token)
+            .await
+    }
+
+    async fn refresh_token_grant(
+        &self,
+        payload: TokenPayload,
+        client: ClientMetadata,
+    ) -> RepositoryResult<TokenResponse> {
+        let refresh_token = required(payload.refresh_token, "refresh_token")?;
+        let apporg = self.find_client(payload.client_id).await?;
+        let Some(session) = self
+            .rotate_refresh_token_service
+            .find_session(apporg.clone(), refresh_token.clone())
+            .await?
+        else {
+            return Err(invalid_grant("refresh token is invalid"));
+        };
+        let user = self.find_active_user(&apporg, session.user_id).await?;
+        let refresh_token = self
+            .rotate_refresh_token_service
+            .execute(apporg.clone(), refresh_token, client)
+            .await?
+            .refresh_token;
+        // RFC 6749 6: the new access token carries the originally granted scope
+        self.issue_access_token(&apporg, &user, session.scope, None, refresh_token)
+            .await
+    }
+
+    async fn device_code_grant(
+        &self,
+        payload: TokenPayload,
+        client: ClientMetadata,
+    ) -> RepositoryResult<TokenResponse> {
+        let device_code = required(payload.device_code, "device_code")?;
+        let Some(authorization) = self
+            .repository
+            .find_device_authorization(&device_code)
+            .await?
+        else {
+            return Err(device_error("expired_token"));
+        };
+        if authorization.client_id != payload.client_id {
+            return Err(invalid_grant("device code was issued to another client"));
+        }
+        match authorization.status {
+            DeviceAuthorizationStatus::Pending => {
+                if !self
+                    .repository
+                    .record_device_poll(&device_code, authorization.interval)
+                    .await?
+                {
+                    self.repository
+                        .slow_down_device_polling(&authorization, SLOW_DOWN_INCREMENT)
+                        .await?;
+                    return Err(device_error("slow_down"));
+                }
+                return Err(device_error("authorization_pending"));
+            }
+            DeviceAuthorizationStatus::Denied => {
+                self.repository
+                    .take_device_authorization(&device_code)
+                    .await?;
+                return Err(device_error("access_denied"));
+            }
+            DeviceAuthorizationStatus::Approved => {}
+        }
+
+        // taking the code makes a concurrent poll see `expired_token`
+        let grant = self
+            .repository
+            .take_device_authorization(&device_code)
+            .await?
+            .ok_or_else(|| device_error("expired_token"))?;
+        let user_id = grant
+            .user_id
+            .ok_or_else(|| invalid_grant("device code was not approved"))?;
+        let apporg = self.find_client(grant.client_id).await?;
+        let user = self.find_active_user(&apporg, user_id).await?;
+        let refresh_token = self
+            .create_refresh_token_service
+            .execute(apporg.clone(), user_id, client, grant.scope.clone())
+            .await?
+            .refresh_token;
+        self.issue_access_token(&apporg, &user, grant.scope, None, refresh_token)
+            .await
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TokenService: 'static + Sync + Send {
+    async fn execute(
+        &self,
+        payload: TokenPayload,
+        client: ClientMetadata,
+    ) -> RepositoryResult<TokenResponse>;
+}
+
+#[async_trait]
+impl TokenService for TokenServiceImpl {
+    async fn execute(
+        &self,
+        payload: TokenPayload,
+        client: ClientMetadata,
+    ) -> RepositoryResult<TokenResponse> {
+        match payload.grant_type.as_str() {
+            "authorization_code" => self.authorization_code_grant(payload, client).await,
+            "refresh_token" => self.refresh_token_grant(payload, client).await,
+            DEVICE_CODE_GRANT_TYPE => self.device_code_grant(payload, client).await,
+            "client_credentials" => {
+                let client_secret = required(payload.client_secret, "client_secret")?;
+                self.client_credentials_service
+                    .execute(payload.client_id, client_secret)
+                    .await
+            }
+            _ => Err(RepositoryError::new(
+                "unsupported_grant_type".to_string(),
+                400,
+            )),
+        }
+    }
+}
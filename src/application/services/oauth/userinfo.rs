@@ -0,0 +1,85 @@
+use crate::{
+    application::{
+        dto::response::oauth::UserInfoResponse, repositories::oauth::userinfo::UserInfoRepository,
+    },
+    domain::errors::repositories_errors::{RepositoryError, RepositoryResult},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct UserInfoServiceImpl {
+    pub repository: Arc<dyn UserInfoRepository>,
+}
+impl UserInfoServiceImpl {
+    pub fn new(repository: Arc<dyn UserInfoRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+fn invalid_token() -> RepositoryError {
+    RepositoryError::new("invalid_token".to_string(), 401)
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UserInfoService: 'static + Sync + Send {
+    async fn execute(&self, header: Option<String>) -> RepositoryResult<UserInfoResponse>;
+}
+
+#[async_trait]
+impl UserInfoService for UserInfoServiceImpl {
+    async fn execute(&self, header: Option<String>) -> RepositoryResult<UserInfoResponse> {
+        let header = header.ok_or_else(invalid_token)?;
+        let token = header
+            .strip_prefix("Bearer ")
+            .ok_or_else(invalid_token)?
+            .trim();
+        let Some(key) = self.repository.find_verification_key(token).await? else {
+            return Err(invalid_token());
+        };
+        let claims = self
+            .repository
+            .decrypt_access_token(token, &key)
+            .await
+            .map_err(|_| invalid_token())?;
+        if claims.exp <= Utc::now().timestamp() {
+            return Err(invalid_token());
+        }
+        let user_id = Uuid::parse_str(&claims.sub).map_err(|_| invalid_token())?;
+        let client_id = Uuid::parse_str(&claims.client_id).map_err(|_| invalid_token())?;
+        let Some(apporg) = self.repository.find_apporg_by_client_id(client_id).await? else {
+            return Err(invalid_token());
+        };
+        // the token must be signed by a key of the client it was issued to
+        if key.organization_id != apporg.organization_id
+            || key.application_id != apporg.application_id
+        {
+            return Err(invalid_token());
+        }
+        let Some(user) = self
+            .repository
+            .find_user(apporg.organization_id, apporg.application_id, user_id)
+            .await?
+        else {
+            return Err(invalid_token());
+        };
+        if !apporg.is_active || !user.can_sign_in() {
+            return Err(invalid_token());
+        }
+        Ok(UserInfoResponse {
+            sub: user.user_id.to_string(),
+            preferred_username: user.username,
+            email: user.email,
+            email_verified: user.is_verified,
+            given_name: user.first_name,
+            family_name: user.last_name,
+            locale: user.locale,
+            phone_number: user.phone,
+            picture: user.avatar_url,
+            updated_at: user.updated_at.0 / 1000,
+        })
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    application::{
+        dto::{
+            payload::permission::{CreatePermissionPayload, UpdatePermissionPayload},
+            response::{
+                permission::{
+                    GetPermissionsResponse, GetUnresolvedRolesResponse, PermissionResponse,
+                    UnresolvedRoleResponse,
+                },
+                refresh_token::SimpleResponse,
+            },
+        },
+        mappers::{application::ApplicationMapper, permission::catalog::PermissionCatalogMapper},
+        repositories::permissions::catalog::PermissionCatalogRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::permission_catalog::validate_permission_name,
+    },
+    infrastructure::models::permission::{PermissionModel, UpdatePermissionModel},
+};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PermissionCatalogServiceImpl {
+    pub repository: Arc<dyn PermissionCatalogRepository>,
+}
+impl PermissionCatalogServiceImpl {
+    pub fn new(repository: Arc<dyn PermissionCatalogRepository>) -> Self {
+        Self { repository }
+    }
+
+    async fn find_permission(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        name: &str,
+    ) -> RepositoryResult<PermissionModel> {
+        self.repository
+            .get_permission(c_apporg.organization_id, c_apporg.application_id, name)
+            .await?
+            .ok_or_else(|| RepositoryError::new("permission not found".to_string(), 404))
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PermissionCatalogService: 'static + Sync + Send {
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: CreatePermissionPayload,
+    ) -> RepositoryResult<PermissionResponse>;
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+    ) -> RepositoryResult<GetPermissionsResponse>;
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        name: String,
+    ) -> RepositoryResult<PermissionResponse>;
+    async fn update(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        name: String,
+        payload: UpdatePermissionPayload,
+    ) -> RepositoryResult<SimpleResponse>;
+    /// Roles still relying on the permission show up in `unresolved_roles`
+    /// afterwards; with `strict` the delete is refused with 409 instead. The
+    /// last entry is always refused while roles grant anything, since an
+    /// empty catalog no longer checks roles.
+    async fn delete(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        name: String,
+        strict: bool,
+    ) -> RepositoryResult<SimpleResponse>;
+    async fn unresolved_roles(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+    ) -> RepositoryResult<GetUnresolvedRolesResponse>;
+}
+
+#[async_trait]
+impl PermissionCatalogService for PermissionCatalogServiceImpl {
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: CreatePermissionPayload,
+    ) -> RepositoryResult<PermissionResponse> {
+        validate_permission_name(&payload.name).map_err(|e| RepositoryError::new(e, 400))?;
+        let permission = self.repository.new_permission(
+            c_apporg.organization_id,
+            c_apporg.application_id,
+            payload.name,
+            payload.description,
+            payload.category,
+        );
+        if !self.repository.create_permission(&permission).await? {
+            return Err(RepositoryError::new(
+                "permission already exists".to_string(),
+                409,
+            ));
+        }
+        Ok(PermissionResponse::from(permission))
+    }
+
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+    ) -> RepositoryResult<GetPermissionsResponse> {
+        let permissions = self
+            .repository
+            .get_permissions(c_apporg.organization_id, c_apporg.application_id)
+            .await?;
+        Ok(GetPermissionsResponse {
+            permissions: permissions
+                .into_iter()
+                .map(PermissionResponse::from)
+                .collect(),
+        })
+    }
+
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        name: String,
+    ) -> RepositoryResult<PermissionResponse> {
+        let permission = self.find_permission(&c_apporg, &name).await?;
+        Ok(PermissionResponse::from(permission))
+    }
+
+    async fn update(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        name: String,
+        payload: UpdatePermissionPayload,
+    ) -> RepositoryResult<SimpleResponse> {
+        let permission = self.find_permission(&c_apporg, &name).await?;
+        let update = UpdatePermissionModel {
+            organization_id: permission.organization_id,
+            application_id: permission.application_id,
+            name,
+            description: payload.description.or(permission.description),
+            category: payload.category.or(permission.category),
+        };
+        self.repository.update_permission(&update).await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+
+    async fn delete(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        name: String,
+        strict: bool,
+    ) -> RepositoryResult<SimpleResponse> {
+        self.find_permission(&c_apporg, &name).await?;
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        let catalog =
+            PermissionCatalogMapper::to_dto(self.repository.get_permissions(org_id, app_id).await?);
+        let roles: Vec<Uuid> = self
+            .repository
+            .get_roles(org_id, app_id)
+            .await?
+            .into_iter()
+            .filter(|role| catalog.needed_by(&name, &role.permissions))
+            .map(|role| role.role_id)
+            .collect();
+        if !roles.is_empty() && (strict || catalog.is_only(&name)) {
+            return Err(RepositoryError::new(
+                "permission is still granted by roles".to_string(),
+                409,
+            )
+            .with_details(json!({ "roles": roles })));
+        }
+        self.repository
+            .delete_permission(org_id, app_id, &name)
+            .await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+
+    async fn unresolved_roles(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+    ) -> RepositoryResult<GetUnresolvedRolesResponse> {
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        let catalog =
+            PermissionCatalogMapper::to_dto(self.repository.get_permissions(org_id, app_id).await?);
+        let roles = self.repository.get_roles(org_id, app_id).await?;
+        Ok(GetUnresolvedRolesResponse {
+            roles: roles
+                .into_iter()
+                .filter_map(|role| {
+                    let permissions = catalog.unknown(&role.permissions);
+                    (!permissions.is_empty()).then_some(UnresolvedRoleResponse {
+                        role_id: role.role_id,
+                        name: role.name,
+                        permissions,
+                    })
+                })
+                .collect(),
+        })
+    }
+}
@@ -4,14 +4,21 @@ use crate::{
         repositories::refresh_tokens::create::CreateRefreshTokenRepository,
     },
     domain::{
-        entities::apporg_client_id::CleanAppOrgByClientId,
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
         errors::repositories_errors::{RepositoryError, RepositoryResult},
-    }, infrastructure::repositories::paseto::PASETO_V4_LOCAL_KEY_LEN,
+        value_objects::{
+            client_metadata::ClientMetadata,
+            session_limit::{SessionLimit, SessionLimitAction},
+        },
+    },
 };
 use async_trait::async_trait;
 use std::sync::Arc;
 use time::format_description::well_known::Rfc3339;
 use uuid::Uuid;
+
+const REFRESH_TOKEN_PAGE_SIZE: i32 = 100;
+
 #[derive(Clone)]
 pub struct CreateRefreshTokenServiceImpl {
     pub repository: Arc<dyn CreateRefreshTokenRepository>,
@@ -20,14 +27,74 @@ impl CreateRefreshTokenServiceImpl {
     pub fn new(repository: Arc<dyn CreateRefreshTokenRepository>) -> Self {
         Self { repository }
     }
+
+    /// Makes room for one more session of `user_id` under `limit`, either by
+    /// refusing or by revoking the least recently used sessions.
+    async fn enforce_session_limit(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        user_id: Uuid,
+        limit: SessionLimit,
+    ) -> RepositoryResult<()> {
+        if !limit.is_enabled() {
+            return Ok(());
+        }
+        let now_ms = time::OffsetDateTime::now_utc().unix_timestamp() * 1000;
+        // (last used, token id) of every live session
+        let mut active = Vec::new();
+        let mut paging_state = None;
+        loop {
+            let page = self
+                .repository
+                .find_refresh_tokens_by_user(
+                    c_apporg.organization_id,
+                    c_apporg.application_id,
+                    user_id,
+                    REFRESH_TOKEN_PAGE_SIZE,
+                    paging_state,
+                )
+                .await?;
+            active.extend(
+                page.refresh_tokens
+                    .iter()
+                    .filter(|t| !t.revoked && t.expires_at.0 > now_ms)
+                    .map(|t| (t.last_used_at.unwrap_or(t.issued_at).0, t.token_id)),
+            );
+            let Some(next) = page.paging_state else {
+                break;
+            };
+            paging_state = Some(next);
+        }
+
+        let excess = limit.excess(active.len());
+        if excess == 0 {
+            return Ok(());
+        }
+        if limit.on_limit == SessionLimitAction::Reject {
+            return Err(RepositoryError::new(
+                "maximum number of sessions reached".to_string(),
+                403,
+            ));
+        }
+        active.sort_unstable();
+        for (_, token_id) in active.into_iter().take(excess) {
+            self.repository
+                .revoke_refresh_token(c_apporg.organization_id, c_apporg.application_id, token_id)
+                .await?;
+        }
+        Ok(())
+    }
 }
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait CreateRefreshTokenService: 'static + Sync + Send {
+    /// `scope` is what the user granted; refreshing the session reuses it.
     async fn execute(
         &self,
         c_apporg: CleanAppOrgByClientId,
         user_id: Uuid,
-        private_key: String,
+        client: ClientMetadata,
+        scope: Option<String>,
     ) -> RepositoryResult<CreateTokenResponse>;
 }
 #[async_trait]
@@ -36,8 +103,12 @@ impl CreateRefreshTokenService for CreateRefreshTokenServiceImpl {
         &self,
         c_apporg: CleanAppOrgByClientId,
         user_id: Uuid,
-        private_key: String,
+        client: ClientMetadata,
+        scope: Option<String>,
     ) -> RepositoryResult<CreateTokenResponse> {
+        let config = c_apporg.get_config()?;
+        self.enforce_session_limit(&c_apporg, user_id, config.session_limit)
+            .await?;
         let token_secret = self.repository.generate_token_secret().await?;
         let (secret_key, encrypted_token_secret) = self
             .repository
@@ -45,9 +116,11 @@ impl CreateRefreshTokenService for CreateRefreshTokenServiceImpl {
             .await?;
         let token_version = self.repository.generate_token_version_base64().await?;
 
+        let lifetimes = config.token_lifetimes;
         let issued_at = time::OffsetDateTime::now_utc();
-        let expires_at = issued_at + time::Duration::days(30);
-        let not_before = issued_at + time::Duration::minutes(40);
+        let now = issued_at.unix_timestamp();
+        let expires_at = issued_at + time::Duration::seconds(lifetimes.expires_in(now, now));
+        let not_before = issued_at + time::Duration::seconds(lifetimes.not_before_offset);
         let refresh_token = self.repository.create_refresh_token(
             c_apporg.application_id,
             c_apporg.organization_id,
@@ -57,23 +130,21 @@ impl CreateRefreshTokenService for CreateRefreshTokenServiceImpl {
             issued_at,
             expires_at,
         );
-        self.repository.store_refresh_token(&refresh_token).await?;
-        let dnc_private_key = self.repository.decode_base64(&private_key)?;
-        if dnc_private_key.len() != PASETO_V4_LOCAL_KEY_LEN {
-            return Err(RepositoryError::new(
-                format!(
-                    "Invalid private_key length: expected {}, got {}",
-                    PASETO_V4_LOCAL_KEY_LEN,
-                    dnc_private_key.len()
-                ),
-                400,
-            ));
-        }
+        let refresh_token = refresh_token
+            .with_client(client.sanitized())
+            .with_scope(scope);
+        self.repository
+            .store_refresh_token(&refresh_token, lifetimes.row_ttl(now, now))
+            .await?;
+        let signing_key = self
+            .repository
+            .signing_key(c_apporg.organization_id, c_apporg.application_id)
+            .await?;
 
         let paseto_token = self
             .repository
             .create_pesato_token(
-                &dnc_private_key,
+                &signing_key,
                 refresh_token,
                 self.repository.encode_base64(&token_secret).as_str(),
                 &secret_key,
@@ -0,0 +1,79 @@
+use crate::{
+    application::{
+        dto::response::refresh_token::RevokeAllSessionsResponse,
+        repositories::refresh_tokens::revoke_all::RevokeAllRefreshTokensRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const REFRESH_TOKEN_PAGE_SIZE: i32 = 100;
+
+#[derive(Clone)]
+pub struct RevokeAllSessionsServiceImpl {
+    pub repository: Arc<dyn RevokeAllRefreshTokensRepository>,
+}
+impl RevokeAllSessionsServiceImpl {
+    pub fn new(repository: Arc<dyn RevokeAllRefreshTokensRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RevokeAllSessionsService: 'static + Sync + Send {
+    /// Signs the user out everywhere by revoking every refresh token they hold.
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<RevokeAllSessionsResponse>;
+}
+
+#[async_trait]
+impl RevokeAllSessionsService for RevokeAllSessionsServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<RevokeAllSessionsResponse> {
+        // collect first: revoking while paging would shift the view under us
+        let mut token_ids = Vec::new();
+        let mut paging_state = None;
+        loop {
+            let page = self
+                .repository
+                .find_refresh_tokens_by_user(
+                    c_apporg.organization_id,
+                    c_apporg.application_id,
+                    user_id,
+                    REFRESH_TOKEN_PAGE_SIZE,
+                    paging_state,
+                )
+                .await?;
+            token_ids.extend(
+                page.refresh_tokens
+                    .iter()
+                    .filter(|t| !t.revoked)
+                    .map(|t| t.token_id),
+            );
+            let Some(next) = page.paging_state else {
+                break;
+            };
+            paging_state = Some(next);
+        }
+        for token_id in &token_ids {
+            self.repository
+                .revoke_refresh_token(c_apporg.organization_id, c_apporg.application_id, *token_id)
+                .await?;
+        }
+        Ok(RevokeAllSessionsResponse {
+            revoked: token_ids.len(),
+        })
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::Utc;
+use scylla::value::CqlTimestamp;
 use time::format_description::well_known::Rfc3339;
 use uuid::Uuid;
 
@@ -11,11 +12,16 @@ use crate::{
         repositories::refresh_tokens::rotate::RotateRefreshTokenRepository,
     },
     domain::{
-        entities::apporg_client_id::CleanAppOrgByClientId,
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
         errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::client_metadata::ClientMetadata,
     },
-    infrastructure::repositories::paseto::PASETO_V4_LOCAL_KEY_LEN,
+    infrastructure::models::{refresh_token::FoundRefreshTokenModel, token_claim::TokenClaims},
 };
+fn revoked() -> RepositoryError {
+    RepositoryError::new("the refresh token was revoked".to_string(), 400)
+}
+
 #[derive(Clone)]
 pub struct RotateRefreshTokenServiceImpl {
     pub repository: Arc<dyn RotateRefreshTokenRepository>,
@@ -24,16 +30,41 @@ impl RotateRefreshTokenServiceImpl {
     pub fn new(repository: Arc<dyn RotateRefreshTokenRepository>) -> Self {
         Self { repository }
     }
+
+    /// Checks the signature with the key named in the footer, which must
+    /// belong to the calling application.
+    async fn verify(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        refresh_token: &str,
+    ) -> RepositoryResult<TokenClaims> {
+        let key = self
+            .repository
+            .find_verification_key(refresh_token)
+            .await?
+            .filter(|k| {
+                k.organization_id == c_apporg.organization_id
+                    && k.application_id == c_apporg.application_id
+            })
+            .ok_or_else(|| RepositoryError::new("unknown signing key".to_string(), 401))?;
+        self.repository.decrypt_paseto(refresh_token, &key).await
+    }
 }
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait RotateRefreshTokenService: 'static + Sync + Send {
     async fn execute(
         &self,
         c_apporg: CleanAppOrgByClientId,
         refresh_token: String,
-        public_key: String,
-        private_key: String,
+        client: ClientMetadata,
     ) -> RepositoryResult<CreateTokenResponse>;
+    /// Looks up the stored session of a refresh token, without rotating it.
+    async fn find_session(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        refresh_token: String,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>>;
 }
 #[async_trait]
 impl RotateRefreshTokenService for RotateRefreshTokenServiceImpl {
@@ -41,26 +72,16 @@ impl RotateRefreshTokenService for RotateRefreshTokenServiceImpl {
         &self,
         c_apporg: CleanAppOrgByClientId,
         refresh_token: String,
-        public_key: String,
-        private_key: String,
+        client: ClientMetadata,
     ) -> RepositoryResult<CreateTokenResponse> {
-        let dnc_public_key = self.repository.decode_base64(&public_key)?;
-        if dnc_public_key.len() != 32 {
-            return Err(RepositoryError::new(
-                "public_key must eq 32".to_string(),
-                400,
-            ));
-        }
-        let token = self
-            .repository
-            .decrypt_paseto(refresh_token.as_str(), &dnc_public_key)
-            .await?;
+        let token = self.verify(&c_apporg, &refresh_token).await?;
+        let lifetimes = c_apporg.get_config()?.token_lifetimes;
         let now = Utc::now().timestamp();
 
-        if token.exp <= now {
+        if now >= token.exp + lifetimes.leeway {
             return Err(RepositoryError::new("Token has expired".to_string(), 401));
         }
-        if token.nbf > now {
+        if !lifetimes.is_current(now, token.nbf, token.exp) {
             return Err(RepositoryError::new("Token not valid yet".to_string(), 401));
         }
         let old_token_id = Uuid::parse_str(token.jti.as_str())?;
@@ -81,9 +102,22 @@ impl RotateRefreshTokenService for RotateRefreshTokenServiceImpl {
             ));
         };
         if fetched_token.revoked {
+            return Err(revoked());
+        }
+        // rows from before session tracking count from now on
+        let session_started_at = fetched_token.session_started_at.map_or(now, |t| t.0 / 1000);
+        if let Some(last_used_at) = fetched_token.last_used_at
+            && lifetimes.is_idle(now, last_used_at.0 / 1000)
+        {
             return Err(RepositoryError::new(
-                "the refresh token was revoked".to_string(),
-                400,
+                "the session expired after inactivity".to_string(),
+                401,
+            ));
+        }
+        if lifetimes.is_past_absolute_lifetime(now, session_started_at) {
+            return Err(RepositoryError::new(
+                "the session has reached its maximum lifetime".to_string(),
+                401,
             ));
         }
         let decrypted = self
@@ -96,13 +130,18 @@ impl RotateRefreshTokenService for RotateRefreshTokenServiceImpl {
             return Err(RepositoryError {
                 message: "unauth".to_string(),
                 code: 401,
+                details: None,
             });
         }
         //issue new token
         let token_version = self.repository.genarate_token_version_base64().await?;
         let issued_at = time::OffsetDateTime::now_utc();
-        let expires_at = issued_at + time::Duration::days(30);
-        let not_before = issued_at + time::Duration::minutes(40);
+        let now = issued_at.unix_timestamp();
+        let expires_at =
+            issued_at + time::Duration::seconds(lifetimes.expires_in(now, session_started_at));
+        let not_before = issued_at + time::Duration::seconds(lifetimes.not_before_offset);
+        // the device may omit what it already sent at sign-in
+        let client = client.sanitized().or(fetched_token.client());
         let refresh_token = self.repository.create_refresh_token(
             old_token_id,
             c_apporg.application_id,
@@ -114,22 +153,25 @@ impl RotateRefreshTokenService for RotateRefreshTokenServiceImpl {
             issued_at,
             expires_at,
         );
-        let dnc_private_key = self.repository.decode_base64(&private_key)?;
-        if dnc_private_key.len() != PASETO_V4_LOCAL_KEY_LEN {
-            return Err(RepositoryError::new(
-                format!(
-                    "Invalid private_key length: expected {}, got {}",
-                    PASETO_V4_LOCAL_KEY_LEN,
-                    dnc_private_key.len()
-                ),
-                400,
-            ));
+        let refresh_token = refresh_token
+            .with_client(client)
+            .with_scope(fetched_token.scope)
+            .with_session_started_at(CqlTimestamp(session_started_at * 1000));
+        let signing_key = self
+            .repository
+            .signing_key(c_apporg.organization_id, c_apporg.application_id)
+            .await?;
+        if !self
+            .repository
+            .update_refresh_token(&refresh_token, lifetimes.row_ttl(now, session_started_at))
+            .await?
+        {
+            return Err(revoked());
         }
-        self.repository.update_refresh_token(&refresh_token).await?;
         let paseto_token = self
             .repository
             .create_pesato_token(
-                &dnc_private_key,
+                &signing_key,
                 refresh_token,
                 &token.secret,
                 &token.secret_key,
@@ -142,4 +184,21 @@ impl RotateRefreshTokenService for RotateRefreshTokenServiceImpl {
             refresh_token: paseto_token,
         })
     }
+
+    async fn find_session(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        refresh_token: String,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>> {
+        let token = self.verify(&c_apporg, &refresh_token).await?;
+        let token_id = Uuid::parse_str(&token.jti)?;
+        self.repository
+            .find_refresh_token(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                token_id,
+                &token.version,
+            )
+            .await
+    }
 }
@@ -0,0 +1,67 @@
+use crate::application::dto::response::refresh_token::{GetSessionsResponse, SessionResponse};
+use crate::application::repositories::refresh_tokens::get::GetRefreshTokenRepository;
+use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GetSessionsServiceImpl {
+    pub repository: Arc<dyn GetRefreshTokenRepository>,
+}
+impl GetSessionsServiceImpl {
+    pub fn new(repository: Arc<dyn GetRefreshTokenRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+pub trait GetSessionsService: Send + Sync {
+    /// Lists the user's signed-in devices; revoked tokens are left out, so a
+    /// page may hold fewer entries than `page_size`.
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state: Option<String>,
+    ) -> RepositoryResult<GetSessionsResponse>;
+}
+
+#[async_trait]
+impl GetSessionsService for GetSessionsServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state: Option<String>,
+    ) -> RepositoryResult<GetSessionsResponse> {
+        let paging_state_u8 = match paging_state {
+            Some(state) => Some(self.repository.base64_to_bytes(state)?),
+            None => None,
+        };
+        let page = self
+            .repository
+            .get_refresh_tokens_by_user(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                user_id,
+                page_size,
+                paging_state_u8,
+            )
+            .await?;
+        let sessions = page
+            .refresh_tokens
+            .into_iter()
+            .filter(|t| !t.revoked)
+            .map(SessionResponse::from)
+            .collect();
+        Ok(GetSessionsResponse {
+            sessions,
+            paging_state: page
+                .paging_state
+                .map(|state| self.repository.bytes_to_base64(state)),
+        })
+    }
+}
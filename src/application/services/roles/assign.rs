@@ -4,10 +4,11 @@ use crate::{
     },
     domain::{
         entities::apporg_client_id::CleanAppOrgByClientId,
-        errors::repositories_errors::RepositoryResult,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
     },
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
 #[derive(Clone)]
@@ -19,13 +20,17 @@ impl AssignServiceImpl {
         Self { repository }
     }
 }
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait AssignService: 'static + Sync + Send {
+    /// Assigns the role until `expires_at` (unix millis), or for good when
+    /// it's `None`. Assigning again replaces the previous expiry.
     async fn execute(
         &self,
         c_apporg: CleanAppOrgByClientId,
         role_id: Uuid,
         user_id: Uuid,
+        expires_at: Option<i64>,
     ) -> RepositoryResult<SimpleResponse>;
 }
 #[async_trait]
@@ -35,13 +40,21 @@ impl AssignService for AssignServiceImpl {
         c_apporg: CleanAppOrgByClientId,
         role_id: Uuid,
         user_id: Uuid,
+        expires_at: Option<i64>,
     ) -> RepositoryResult<SimpleResponse> {
+        if expires_at.is_some_and(|e| e <= Utc::now().timestamp_millis()) {
+            return Err(RepositoryError::new(
+                "expires_at must be in the future".to_string(),
+                400,
+            ));
+        }
         self.repository
             .assign(
                 c_apporg.organization_id,
                 c_apporg.application_id,
                 role_id,
                 user_id,
+                expires_at,
             )
             .await?;
         Ok(SimpleResponse {
@@ -1,13 +1,19 @@
 use crate::{
     application::{
-        dto::{payload::role::CreateRolePayload, response::refresh_token::SimpleResponse}, mappers::model::ModelMapper, repositories::roles::create_roles::CreateRoleRepository
+        dto::{payload::role::CreateRolePayload, response::refresh_token::SimpleResponse},
+        mappers::{
+            application::ApplicationMapper, model::ModelMapper,
+            permission::catalog::PermissionCatalogMapper, role::hierarchy::RoleHierarchyMapper,
+        },
+        repositories::roles::create_roles::CreateRoleRepository,
     },
     domain::{
         entities::apporg_client_id::CleanAppOrgByClientId,
-        errors::repositories_errors::RepositoryResult,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
     },
 };
 use async_trait::async_trait;
+use serde_json::json;
 use std::sync::Arc;
 #[derive(Clone)]
 pub struct CreateRoleServiceImpl {
@@ -39,7 +45,28 @@ impl CreateRoleService for CreateRoleServiceImpl {
             payload.name,
             payload.description,
             payload.permissions,
+            payload.parent_role_ids,
         );
+        if !role.parent_role_ids.is_empty() {
+            let roles = self
+                .repository
+                .get_roles(c_apporg.organization_id, c_apporg.application_id)
+                .await?;
+            RoleHierarchyMapper::to_dto(roles)
+                .validate_parents(role.role_id, &role.parent_role_ids)
+                .map_err(|e| RepositoryError::new(e, 400))?;
+        }
+        if !role.permissions.is_empty() {
+            let permissions = self
+                .repository
+                .get_permissions(c_apporg.organization_id, c_apporg.application_id)
+                .await?;
+            let unknown = PermissionCatalogMapper::to_dto(permissions).unknown(&role.permissions);
+            if !unknown.is_empty() {
+                return Err(RepositoryError::new("unknown permissions".to_string(), 400)
+                    .with_details(json!({ "permissions": unknown })));
+            }
+        }
         self.repository.create_role(&role.to_entity()).await?;
         Ok(SimpleResponse {
             message: "success".to_string(),
@@ -0,0 +1,54 @@
+use crate::{
+    application::repositories::roles::expire_assignments::ExpireRoleAssignmentsRepository,
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::models::role::EXPIRY_LOOKBACK_DAYS,
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ExpireRoleAssignmentsServiceImpl {
+    pub repository: Arc<dyn ExpireRoleAssignmentsRepository>,
+}
+impl ExpireRoleAssignmentsServiceImpl {
+    pub fn new(repository: Arc<dyn ExpireRoleAssignmentsRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ExpireRoleAssignmentsService: 'static + Sync + Send {
+    /// Reports role assignments whose `expires_at` has passed and returns
+    /// how many lapsed.
+    async fn execute(&self) -> RepositoryResult<usize>;
+}
+#[async_trait]
+impl ExpireRoleAssignmentsService for ExpireRoleAssignmentsServiceImpl {
+    async fn execute(&self) -> RepositoryResult<usize> {
+        let now = Utc::now();
+        let mut lapsed = 0;
+        for offset in (0..=EXPIRY_LOOKBACK_DAYS).rev() {
+            let day = self
+                .repository
+                .expiry_day((now - Duration::days(offset)).timestamp_millis());
+            let pending = self.repository.get_pending_expiries(day.clone()).await?;
+            for p in pending {
+                if p.expires_at.0 > now.timestamp_millis() {
+                    continue;
+                }
+                // only the job's own removal counts; renewed or deliberately
+                // removed assignments are left alone
+                if self.repository.remove_assignment(&p).await? {
+                    self.repository.lapse(&p).await?;
+                    lapsed += 1;
+                }
+                self.repository
+                    .clear_pending_expiry(day.clone(), &p)
+                    .await?;
+            }
+        }
+        Ok(lapsed)
+    }
+}
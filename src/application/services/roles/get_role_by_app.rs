@@ -43,12 +43,14 @@ impl GetRoleByAppService for GetRoleByAppServiceImpl {
             return Err(RepositoryError {
                 message: "not found".to_string(),
                 code: 404,
+                details: None,
             });
         };
         Ok(GetRoleResponse {
             name: res.name,
             description: res.description,
             permissions: res.permissions,
+            parent_role_ids: res.parent_role_ids,
             created_at: res.created_at,
             updated_at: res.updated_at,
         })
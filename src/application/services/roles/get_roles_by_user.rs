@@ -1,6 +1,6 @@
 use crate::{
     application::{
-        dto::response::role::GetRolesByUserResponse,
+        dto::response::role::{GetRolesByUserResponse, GroupRoleResponse},
         repositories::roles::get_roles_by_user::GetRolesByUserRepository,
     },
     domain::{
@@ -9,7 +9,7 @@ use crate::{
     },
 };
 use async_trait::async_trait;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 use uuid::Uuid;
 #[derive(Clone)]
 pub struct GetRolesByUserServiceImpl {
@@ -35,10 +35,29 @@ impl GetRolesByUserService for GetRolesByUserServiceImpl {
         c_apporg: CleanAppOrgByClientId,
         user_id: Uuid,
     ) -> RepositoryResult<GetRolesByUserResponse> {
-        let res = self
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        let roles = self
             .repository
-            .get_roles_by_user(c_apporg.organization_id, c_apporg.application_id, user_id)
+            .get_roles_by_user(org_id, app_id, user_id)
             .await?;
-        Ok(GetRolesByUserResponse { roles: res })
+        let memberships = self
+            .repository
+            .get_groups_of_user(org_id, app_id, user_id)
+            .await?;
+        let mut group_roles = Vec::new();
+        if !memberships.is_empty() {
+            let group_ids: HashSet<Uuid> = memberships.into_iter().map(|m| m.group_id).collect();
+            for group in self.repository.get_groups(org_id, app_id).await? {
+                if group_ids.contains(&group.group_id) {
+                    group_roles.extend(group.role_ids.into_iter().map(|role_id| {
+                        GroupRoleResponse {
+                            group_id: group.group_id,
+                            role_id,
+                        }
+                    }));
+                }
+            }
+        }
+        Ok(GetRolesByUserResponse { roles, group_roles })
     }
 }
@@ -0,0 +1,95 @@
+use crate::{
+    application::{
+        dto::response::role::GetUserPermissionsResponse,
+        mappers::{application::ApplicationMapper, role::hierarchy::RoleHierarchyMapper},
+        repositories::roles::get_user_permissions::GetUserPermissionsRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+};
+use async_trait::async_trait;
+use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
+#[derive(Clone)]
+pub struct GetUserPermissionsServiceImpl {
+    pub repository: Arc<dyn GetUserPermissionsRepository>,
+}
+impl GetUserPermissionsServiceImpl {
+    pub fn new(repository: Arc<dyn GetUserPermissionsRepository>) -> Self {
+        Self { repository }
+    }
+}
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GetUserPermissionsService: 'static + Sync + Send {
+    /// Union of the permissions of every role assigned to the user, directly
+    /// or through a group, and of the roles those inherit from, sorted.
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<GetUserPermissionsResponse>;
+}
+#[async_trait]
+impl GetUserPermissionsService for GetUserPermissionsServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<GetUserPermissionsResponse> {
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        if let Some(permissions) = self
+            .repository
+            .get_cached_permissions(org_id, app_id, user_id)
+            .await?
+        {
+            return Ok(GetUserPermissionsResponse {
+                user_id,
+                permissions,
+            });
+        }
+        let assignments = self
+            .repository
+            .get_roles_by_user(org_id, app_id, user_id)
+            .await?;
+        // the cached entry must not outlive the first assignment to expire
+        let expires_at = assignments
+            .iter()
+            .filter_map(|a| a.expires_at.map(|e| e.0))
+            .min();
+        let mut role_ids: HashSet<Uuid> = assignments.into_iter().map(|a| a.role_id).collect();
+        let memberships = self
+            .repository
+            .get_groups_of_user(org_id, app_id, user_id)
+            .await?;
+        if !memberships.is_empty() {
+            let group_ids: HashSet<Uuid> = memberships.into_iter().map(|m| m.group_id).collect();
+            let groups = self.repository.get_groups(org_id, app_id).await?;
+            role_ids.extend(
+                groups
+                    .into_iter()
+                    .filter(|g| group_ids.contains(&g.group_id))
+                    .flat_map(|g| g.role_ids),
+            );
+        }
+        let permissions: Vec<String> = if role_ids.is_empty() {
+            Vec::new()
+        } else {
+            // assignments can outlive a deleted role, which then grants nothing
+            let roles = self.repository.get_roles(org_id, app_id).await?;
+            RoleHierarchyMapper::to_dto(roles)
+                .effective_permissions(role_ids)
+                .into_iter()
+                .collect()
+        };
+        self.repository
+            .cache_permissions(org_id, app_id, user_id, &permissions, expires_at)
+            .await?;
+        Ok(GetUserPermissionsResponse {
+            user_id,
+            permissions,
+        })
+    }
+}
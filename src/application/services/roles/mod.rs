@@ -2,7 +2,9 @@ pub mod create_roles;
 pub mod get_role_by_app;
 pub mod get_roles_by_app;
 pub mod get_roles_by_user;
+pub mod get_user_permissions;
 pub mod get_users_by_role;
 pub mod update_role;
 pub mod delete_role;
-pub mod assign;
\ No newline at end of file
+pub mod assign;
+pub mod expire_assignments;
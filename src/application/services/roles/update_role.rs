@@ -1,6 +1,10 @@
 use crate::{
     application::{
         dto::{payload::role::UpdateRolePayload, response::refresh_token::SimpleResponse},
+        mappers::{
+            application::ApplicationMapper, permission::catalog::PermissionCatalogMapper,
+            role::hierarchy::RoleHierarchyMapper,
+        },
         repositories::roles::update_role::UpdateRoleRepository,
     },
     domain::{
@@ -9,6 +13,7 @@ use crate::{
     },
 };
 use async_trait::async_trait;
+use serde_json::json;
 use std::sync::Arc;
 use uuid::Uuid;
 #[derive(Clone)]
@@ -45,6 +50,7 @@ impl UpdateRoleService for UpdateRoleServiceImpl {
             return Err(RepositoryError {
                 message: "not found".to_string(),
                 code: 404,
+                details: None,
             });
         };
         let mut update_model = self.repository.new_model(
@@ -60,8 +66,32 @@ impl UpdateRoleService for UpdateRoleServiceImpl {
         }
         update_model.description = payload.description;
         if let Some(permissions) = payload.permissions {
+            if !permissions.is_empty() {
+                let catalog = self
+                    .repository
+                    .get_permissions(c_apporg.organization_id, c_apporg.application_id)
+                    .await?;
+                let unknown = PermissionCatalogMapper::to_dto(catalog).unknown(&permissions);
+                if !unknown.is_empty() {
+                    return Err(RepositoryError::new("unknown permissions".to_string(), 400)
+                        .with_details(json!({ "permissions": unknown })));
+                }
+            }
             update_model.permissions = permissions;
         }
+        update_model.parent_role_ids = match payload.parent_role_ids {
+            Some(parent_role_ids) => {
+                let roles = self
+                    .repository
+                    .get_roles(c_apporg.organization_id, c_apporg.application_id)
+                    .await?;
+                RoleHierarchyMapper::to_dto(roles)
+                    .validate_parents(role_id, &parent_role_ids)
+                    .map_err(|e| RepositoryError::new(e, 400))?;
+                parent_role_ids
+            }
+            None => fetched_role.parent_role_ids,
+        };
         self.repository.update_role(&update_model).await?;
         Ok(SimpleResponse {
             message: "success".to_string(),
@@ -0,0 +1,457 @@
+use crate::{
+    application::{
+        dto::{
+            payload::scim::{ScimGroupPayload, ScimListQuery, ScimMemberPayload, ScimPatchPayload},
+            response::scim::{ScimGroupResponse, ScimListResponse},
+        },
+        mappers::{application::ApplicationMapper, scim::group::ScimGroupMapper},
+        repositories::scim::groups::ScimGroupsRepository,
+        services::roles::assign::AssignService,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::{
+            repositories_errors::{RepositoryError, RepositoryResult},
+            scim_errors::scim_type_error,
+        },
+        value_objects::scim::{EqFilter, GROUP_SCHEMA, PatchPath},
+    },
+    infrastructure::models::role::SelectedRoleByAppModel,
+};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
+
+fn not_found() -> RepositoryError {
+    RepositoryError::new("group not found".to_string(), 404)
+}
+
+fn parse_path(path: &str) -> RepositoryResult<PatchPath> {
+    let path = path
+        .strip_prefix(GROUP_SCHEMA)
+        .and_then(|path| path.strip_prefix(':'))
+        .unwrap_or(path);
+    PatchPath::parse(path)
+        .ok_or_else(|| scim_type_error("invalidPath", &format!("cannot parse path {path}")))
+}
+
+fn display_name(value: Value) -> RepositoryResult<String> {
+    match value {
+        Value::String(name) if !name.trim().is_empty() => Ok(name),
+        _ => Err(scim_type_error(
+            "invalidValue",
+            "displayName must be a non-empty string",
+        )),
+    }
+}
+
+/// `[{"value": "<user id>"}, ...]`, or a single member object.
+fn member_ids(value: Value) -> RepositoryResult<Vec<Uuid>> {
+    let members: Vec<ScimMemberPayload> = match value {
+        Value::Array(_) => serde_json::from_value(value),
+        value => serde_json::from_value(value).map(|member| vec![member]),
+    }
+    .map_err(|_| scim_type_error("invalidValue", "members must be a list of user ids"))?;
+    Ok(members.into_iter().map(|member| member.value).collect())
+}
+
+/// `members[value eq "<user id>"]`, the path clients use to drop one member.
+fn filtered_member(filter: &EqFilter) -> RepositoryResult<Uuid> {
+    if !filter.is_on("value") {
+        return Err(scim_type_error(
+            "invalidFilter",
+            "members can only be filtered on value",
+        ));
+    }
+    Uuid::parse_str(&filter.value)
+        .map_err(|_| scim_type_error("invalidValue", "member value must be a user id"))
+}
+
+#[derive(Clone)]
+pub struct ScimGroupsServiceImpl {
+    pub repository: Arc<dyn ScimGroupsRepository>,
+    pub assign_service: Arc<dyn AssignService>,
+}
+impl ScimGroupsServiceImpl {
+    pub fn new(
+        repository: Arc<dyn ScimGroupsRepository>,
+        assign_service: Arc<dyn AssignService>,
+    ) -> Self {
+        Self {
+            repository,
+            assign_service,
+        }
+    }
+
+    async fn find_role(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<SelectedRoleByAppModel> {
+        self.repository
+            .get_role(c_apporg.organization_id, c_apporg.application_id, group_id)
+            .await?
+            .ok_or_else(not_found)
+    }
+
+    async fn to_group(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        role: SelectedRoleByAppModel,
+        with_members: bool,
+    ) -> RepositoryResult<ScimGroupResponse> {
+        let members = if with_members {
+            Some(
+                self.repository
+                    .get_members(
+                        c_apporg.organization_id,
+                        c_apporg.application_id,
+                        role.role_id,
+                    )
+                    .await?,
+            )
+        } else {
+            None
+        };
+        Ok(ScimGroupMapper::to_dto((role, members)))
+    }
+
+    async fn member_set(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<HashSet<Uuid>> {
+        Ok(self
+            .repository
+            .get_members(c_apporg.organization_id, c_apporg.application_id, group_id)
+            .await?
+            .into_iter()
+            .map(|member| member.user_id)
+            .collect())
+    }
+
+    /// Role names are not unique in `/roles`, but `displayName` is in SCIM.
+    async fn ensure_name_free(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        name: &str,
+    ) -> RepositoryResult<()> {
+        let taken = self
+            .repository
+            .get_roles(c_apporg.organization_id, c_apporg.application_id)
+            .await?
+            .iter()
+            .any(|role| role.name == name);
+        if taken {
+            return Err(scim_type_error("uniqueness", "displayName already used"));
+        }
+        Ok(())
+    }
+
+    async fn rename(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+        name: String,
+    ) -> RepositoryResult<()> {
+        let role = self.find_role(c_apporg, group_id).await?;
+        if role.name == name {
+            return Ok(());
+        }
+        self.ensure_name_free(c_apporg, &name).await?;
+        self.repository
+            .rename_role(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                role,
+                name,
+            )
+            .await
+    }
+
+    async fn add_members(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+        members: Vec<Uuid>,
+    ) -> RepositoryResult<()> {
+        for user_id in members {
+            if !self
+                .repository
+                .user_exists(c_apporg.organization_id, c_apporg.application_id, user_id)
+                .await?
+            {
+                return Err(scim_type_error(
+                    "invalidValue",
+                    &format!("unknown member {user_id}"),
+                ));
+            }
+            self.assign_service
+                .execute(c_apporg.clone(), group_id, user_id, None)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn remove_members(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+        members: Vec<Uuid>,
+    ) -> RepositoryResult<()> {
+        for user_id in members {
+            self.repository
+                .remove_member(
+                    c_apporg.organization_id,
+                    c_apporg.application_id,
+                    group_id,
+                    user_id,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn set_members(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+        members: Vec<Uuid>,
+    ) -> RepositoryResult<()> {
+        let current = self.member_set(c_apporg, group_id).await?;
+        let wanted: HashSet<Uuid> = members.into_iter().collect();
+        let added: Vec<Uuid> = wanted.difference(&current).copied().collect();
+        let removed: Vec<Uuid> = current.difference(&wanted).copied().collect();
+        self.add_members(c_apporg, group_id, added).await?;
+        self.remove_members(c_apporg, group_id, removed).await
+    }
+
+    async fn apply_attributes(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        group_id: Uuid,
+        replace: bool,
+        value: Option<Value>,
+    ) -> RepositoryResult<()> {
+        let Some(Value::Object(attributes)) = value else {
+            return Err(scim_type_error(
+                "invalidValue",
+                "an operation without a path takes an object",
+            ));
+        };
+        for (attribute, value) in attributes {
+            let path = parse_path(&attribute)?;
+            if path.is("displayName") {
+                self.rename(c_apporg, group_id, display_name(value)?).await?;
+            } else if path.is("members") && replace {
+                self.set_members(c_apporg, group_id, member_ids(value)?)
+                    .await?;
+            } else if path.is("members") {
+                self.add_members(c_apporg, group_id, member_ids(value)?)
+                    .await?;
+            } else {
+                return Err(scim_type_error(
+                    "invalidPath",
+                    &format!("unsupported attribute {attribute}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ScimGroupsService: 'static + Sync + Send {
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        query: ScimListQuery,
+    ) -> RepositoryResult<ScimListResponse<ScimGroupResponse>>;
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        with_members: bool,
+    ) -> RepositoryResult<ScimGroupResponse>;
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: ScimGroupPayload,
+    ) -> RepositoryResult<ScimGroupResponse>;
+    async fn replace(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        payload: ScimGroupPayload,
+    ) -> RepositoryResult<ScimGroupResponse>;
+    async fn patch(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        payload: ScimPatchPayload,
+    ) -> RepositoryResult<ScimGroupResponse>;
+    async fn delete(&self, c_apporg: CleanAppOrgByClientId, group_id: Uuid)
+    -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl ScimGroupsService for ScimGroupsServiceImpl {
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        query: ScimListQuery,
+    ) -> RepositoryResult<ScimListResponse<ScimGroupResponse>> {
+        let mut roles = self
+            .repository
+            .get_roles(c_apporg.organization_id, c_apporg.application_id)
+            .await?;
+        if let Some(filter) = query.filter.as_deref() {
+            let filter = EqFilter::parse(filter).ok_or_else(|| {
+                scim_type_error("invalidFilter", "only `attribute eq \"value\"` is supported")
+            })?;
+            if !filter.is_on("displayName") {
+                return Err(scim_type_error(
+                    "invalidFilter",
+                    &format!("cannot filter on {}", filter.attribute),
+                ));
+            }
+            roles.retain(|role| role.name == filter.value);
+        }
+        let page = ScimListResponse::paginate(roles, query.start_index(), query.count());
+        let mut resources = Vec::with_capacity(page.resources.len());
+        for role in page.resources {
+            resources.push(
+                self.to_group(&c_apporg, role, !query.excludes("members"))
+                    .await?,
+            );
+        }
+        Ok(ScimListResponse::new(
+            page.total_results,
+            page.start_index,
+            resources,
+        ))
+    }
+
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        with_members: bool,
+    ) -> RepositoryResult<ScimGroupResponse> {
+        let role = self.find_role(&c_apporg, group_id).await?;
+        self.to_group(&c_apporg, role, with_members).await
+    }
+
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: ScimGroupPayload,
+    ) -> RepositoryResult<ScimGroupResponse> {
+        let name = display_name(Value::String(payload.display_name))?;
+        self.ensure_name_free(&c_apporg, &name).await?;
+        let group_id = self
+            .repository
+            .create_role(c_apporg.organization_id, c_apporg.application_id, name)
+            .await?;
+        let members = payload.members.into_iter().map(|m| m.value).collect();
+        self.add_members(&c_apporg, group_id, members).await?;
+        self.get(c_apporg, group_id, true).await
+    }
+
+    async fn replace(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        payload: ScimGroupPayload,
+    ) -> RepositoryResult<ScimGroupResponse> {
+        let name = display_name(Value::String(payload.display_name))?;
+        self.rename(&c_apporg, group_id, name).await?;
+        let members = payload.members.into_iter().map(|m| m.value).collect();
+        self.set_members(&c_apporg, group_id, members).await?;
+        self.get(c_apporg, group_id, true).await
+    }
+
+    async fn patch(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+        payload: ScimPatchPayload,
+    ) -> RepositoryResult<ScimGroupResponse> {
+        self.find_role(&c_apporg, group_id).await?;
+        for operation in payload.operations {
+            let op = operation.op.to_ascii_lowercase();
+            let path = operation.path.as_deref().map(parse_path).transpose()?;
+            match (op.as_str(), path) {
+                ("add" | "replace", None) => {
+                    self.apply_attributes(&c_apporg, group_id, op == "replace", operation.value)
+                        .await?;
+                }
+                ("add" | "replace", Some(path)) => {
+                    let value = operation
+                        .value
+                        .ok_or_else(|| scim_type_error("invalidValue", "value is required"))?;
+                    if path.is("displayName") {
+                        self.rename(&c_apporg, group_id, display_name(value)?)
+                            .await?;
+                    } else if path.is("members") && op == "replace" {
+                        self.set_members(&c_apporg, group_id, member_ids(value)?)
+                            .await?;
+                    } else if path.is("members") {
+                        self.add_members(&c_apporg, group_id, member_ids(value)?)
+                            .await?;
+                    } else {
+                        return Err(scim_type_error(
+                            "invalidPath",
+                            &format!("unsupported attribute {}", path.attribute),
+                        ));
+                    }
+                }
+                ("remove", Some(path)) if path.is("members") => {
+                    let members = match (&path.filter, operation.value) {
+                        (Some(filter), _) => vec![filtered_member(filter)?],
+                        (None, Some(value)) => member_ids(value)?,
+                        (None, None) => self
+                            .member_set(&c_apporg, group_id)
+                            .await?
+                            .into_iter()
+                            .collect(),
+                    };
+                    self.remove_members(&c_apporg, group_id, members).await?;
+                }
+                ("remove", Some(path)) => {
+                    return Err(scim_type_error(
+                        "mutability",
+                        &format!("{} cannot be removed", path.attribute),
+                    ));
+                }
+                ("remove", None) => {
+                    return Err(scim_type_error("noTarget", "remove requires a path"));
+                }
+                _ => {
+                    return Err(scim_type_error(
+                        "invalidSyntax",
+                        &format!("unsupported operation {}", operation.op),
+                    ));
+                }
+            }
+        }
+        self.get(c_apporg, group_id, true).await
+    }
+
+    async fn delete(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        group_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.find_role(&c_apporg, group_id).await?;
+        // a deleted group must not leave users holding its role
+        let members = self.member_set(&c_apporg, group_id).await?;
+        self.remove_members(&c_apporg, group_id, members.into_iter().collect())
+            .await?;
+        self.repository
+            .delete_role(c_apporg.organization_id, c_apporg.application_id, group_id)
+            .await
+    }
+}
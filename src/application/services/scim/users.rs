@@ -0,0 +1,567 @@
+use crate::{
+    application::{
+        dto::{
+            payload::{
+                scim::{
+                    ScimListQuery, ScimMultiValuePayload, ScimPatchOperation, ScimPatchPayload,
+                    ScimUserPayload, primary_value,
+                },
+                user::{CreateUserPayload, UpdateUserPayload, UserProfilePayload},
+            },
+            response::scim::{ScimListResponse, ScimUserResponse},
+        },
+        mappers::{
+            application::ApplicationMapper,
+            scim::user::{ScimUserMapper, is_active},
+        },
+        repositories::scim::users::ScimUsersRepository,
+        services::users::{
+            create::CreateUserService, delete::DeleteUserService, update_user::UpdateUserService,
+        },
+    },
+    domain::{
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
+        errors::{
+            repositories_errors::{RepositoryError, RepositoryResult},
+            scim_errors::scim_type_error,
+        },
+        value_objects::{
+            identifier::{normalize_email, normalize_username},
+            scim::{EqFilter, MAX_RESULTS, PatchPath, USER_SCHEMA},
+            user_profile::UserProfile,
+        },
+    },
+    infrastructure::models::user::CleannedUserModel,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+fn not_found() -> RepositoryError {
+    RepositoryError::new("user not found".to_string(), 404)
+}
+
+fn string(value: Value) -> RepositoryResult<String> {
+    match value {
+        Value::String(value) => Ok(value),
+        _ => Err(scim_type_error("invalidValue", "expected a string")),
+    }
+}
+
+/// Some clients send booleans as `"True"`/`"False"`.
+fn boolean(value: Value) -> RepositoryResult<bool> {
+    match value {
+        Value::Bool(value) => Ok(value),
+        Value::String(value) if value.eq_ignore_ascii_case("true") => Ok(true),
+        Value::String(value) if value.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(scim_type_error("invalidValue", "expected a boolean")),
+    }
+}
+
+/// The single value Axcelium keeps for a multi-valued attribute; `None` when
+/// the operation targets a sub-attribute it does not store.
+fn multi_value(path: &PatchPath, value: Value) -> RepositoryResult<Option<String>> {
+    match path.sub_attribute.as_deref() {
+        Some(_) if path.sub_is("value") => Ok(Some(string(value)?)),
+        Some(_) => Ok(None),
+        None => {
+            let values: Vec<ScimMultiValuePayload> = match value {
+                Value::Array(_) => serde_json::from_value(value),
+                value => serde_json::from_value(value).map(|value| vec![value]),
+            }
+            .map_err(|_| scim_type_error("invalidValue", "expected a list of values"))?;
+            primary_value(&values)
+                .map(Some)
+                .ok_or_else(|| scim_type_error("invalidValue", "expected at least one value"))
+        }
+    }
+}
+
+/// What a PUT or PATCH leaves the user looking like; compared with the stored
+/// user to decide which writes are needed.
+#[derive(Debug, Clone)]
+struct UserChanges {
+    user_name: String,
+    email: Option<String>,
+    password: Option<String>,
+    profile: UserProfile,
+    active: bool,
+}
+
+impl UserChanges {
+    fn from_user(user: &CleannedUserModel) -> Self {
+        Self {
+            user_name: user.username.clone(),
+            email: user.email.clone(),
+            password: None,
+            profile: profile_of(user),
+            active: is_active(user),
+        }
+    }
+
+    fn replace(&mut self, payload: ScimUserPayload) {
+        self.user_name = payload.user_name;
+        // sign-in and lookups key on the address, so a PUT without one keeps it
+        if let Some(email) = primary_value(&payload.emails) {
+            self.email = Some(email);
+        }
+        self.password = payload.password;
+        self.profile.first_name = payload.name.given_name;
+        self.profile.last_name = payload.name.family_name;
+        self.profile.locale = payload.locale;
+        self.profile.phone = primary_value(&payload.phone_numbers);
+        if let Some(active) = payload.active {
+            self.active = active;
+        }
+    }
+
+    fn apply(&mut self, operation: ScimPatchOperation) -> RepositoryResult<()> {
+        let op = operation.op.to_ascii_lowercase();
+        match (op.as_str(), operation.path.as_deref()) {
+            ("add" | "replace", None) => {
+                let Some(Value::Object(attributes)) = operation.value else {
+                    return Err(scim_type_error(
+                        "invalidValue",
+                        "an operation without a path takes an object",
+                    ));
+                };
+                for (attribute, value) in attributes {
+                    self.set(&parse_path(&attribute)?, value)?;
+                }
+                Ok(())
+            }
+            ("add" | "replace", Some(path)) => {
+                let value = operation
+                    .value
+                    .ok_or_else(|| scim_type_error("invalidValue", "value is required"))?;
+                self.set(&parse_path(path)?, value)
+            }
+            ("remove", Some(path)) => self.remove(&parse_path(path)?),
+            ("remove", None) => Err(scim_type_error("noTarget", "remove requires a path")),
+            _ => Err(scim_type_error(
+                "invalidSyntax",
+                &format!("unsupported operation {}", operation.op),
+            )),
+        }
+    }
+
+    fn set(&mut self, path: &PatchPath, value: Value) -> RepositoryResult<()> {
+        if path.is("userName") {
+            self.user_name = string(value)?;
+        } else if path.is("password") {
+            self.password = Some(string(value)?);
+        } else if path.is("active") {
+            self.active = boolean(value)?;
+        } else if path.is("locale") {
+            self.profile.locale = Some(string(value)?);
+        } else if path.is("name") {
+            match path.sub_attribute.as_deref() {
+                Some(sub_attribute) => self.set_name(sub_attribute, Some(string(value)?)),
+                None => {
+                    let Value::Object(name) = value else {
+                        return Err(scim_type_error("invalidValue", "name must be an object"));
+                    };
+                    for (sub_attribute, value) in name {
+                        self.set_name(&sub_attribute, Some(string(value)?));
+                    }
+                }
+            }
+        } else if path.is("emails") {
+            if let Some(email) = multi_value(path, value)? {
+                self.email = Some(email);
+            }
+        } else if path.is("phoneNumbers") {
+            if let Some(phone) = multi_value(path, value)? {
+                self.profile.phone = Some(phone);
+            }
+        } else if path.is("photos") {
+            if let Some(photo) = multi_value(path, value)? {
+                self.profile.avatar_url = Some(photo);
+            }
+        } else {
+            return Err(unknown_attribute(path));
+        }
+        Ok(())
+    }
+
+    fn remove(&mut self, path: &PatchPath) -> RepositoryResult<()> {
+        if path.is("userName") || path.is("emails") || path.is("password") || path.is("active") {
+            return Err(scim_type_error(
+                "mutability",
+                &format!("{} cannot be removed", path.attribute),
+            ));
+        }
+        if path.is("locale") {
+            self.profile.locale = None;
+        } else if path.is("name") {
+            match path.sub_attribute.as_deref() {
+                Some(sub_attribute) => self.set_name(sub_attribute, None),
+                None => {
+                    self.profile.first_name = None;
+                    self.profile.last_name = None;
+                }
+            }
+        } else if path.is("phoneNumbers") {
+            self.profile.phone = None;
+        } else if path.is("photos") {
+            self.profile.avatar_url = None;
+        } else {
+            return Err(unknown_attribute(path));
+        }
+        Ok(())
+    }
+
+    /// `formatted`, `middleName` and the like are accepted and dropped.
+    fn set_name(&mut self, sub_attribute: &str, value: Option<String>) {
+        if sub_attribute.eq_ignore_ascii_case("givenName") {
+            self.profile.first_name = value;
+        } else if sub_attribute.eq_ignore_ascii_case("familyName") {
+            self.profile.last_name = value;
+        }
+    }
+}
+
+fn parse_path(path: &str) -> RepositoryResult<PatchPath> {
+    let path = path
+        .strip_prefix(USER_SCHEMA)
+        .and_then(|path| path.strip_prefix(':'))
+        .unwrap_or(path);
+    PatchPath::parse(path)
+        .ok_or_else(|| scim_type_error("invalidPath", &format!("cannot parse path {path}")))
+}
+
+fn unknown_attribute(path: &PatchPath) -> RepositoryError {
+    scim_type_error(
+        "invalidPath",
+        &format!("unsupported attribute {}", path.attribute),
+    )
+}
+
+fn profile_of(user: &CleannedUserModel) -> UserProfile {
+    UserProfile {
+        first_name: user.first_name.clone(),
+        last_name: user.last_name.clone(),
+        locale: user.locale.clone(),
+        phone: user.phone.clone(),
+        avatar_url: user.avatar_url.clone(),
+    }
+}
+
+#[derive(Clone)]
+pub struct ScimUsersServiceImpl {
+    pub repository: Arc<dyn ScimUsersRepository>,
+    pub create_user_service: Arc<dyn CreateUserService>,
+    pub update_user_service: Arc<dyn UpdateUserService>,
+    pub delete_user_service: Arc<dyn DeleteUserService>,
+}
+impl ScimUsersServiceImpl {
+    pub fn new(
+        repository: Arc<dyn ScimUsersRepository>,
+        create_user_service: Arc<dyn CreateUserService>,
+        update_user_service: Arc<dyn UpdateUserService>,
+        delete_user_service: Arc<dyn DeleteUserService>,
+    ) -> Self {
+        Self {
+            repository,
+            create_user_service,
+            update_user_service,
+            delete_user_service,
+        }
+    }
+
+    async fn find_user(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<CleannedUserModel> {
+        self.repository
+            .find_user(c_apporg.organization_id, c_apporg.application_id, user_id)
+            .await?
+            .ok_or_else(not_found)
+    }
+
+    async fn find_filtered(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        filter: &str,
+    ) -> RepositoryResult<Vec<ScimUserResponse>> {
+        let filter = EqFilter::parse(filter).ok_or_else(|| {
+            scim_type_error("invalidFilter", "only `attribute eq \"value\"` is supported")
+        })?;
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        let user_id = if filter.is_on("userName") {
+            self.repository
+                .find_user_id_by_username(org_id, app_id, normalize_username(&filter.value))
+                .await?
+        } else if filter.is_on("emails") || filter.is_on("emails.value") {
+            let config = c_apporg.get_config().unwrap_or_default();
+            self.repository
+                .find_user_id_by_email(
+                    org_id,
+                    app_id,
+                    normalize_email(&filter.value, &config.email_normalization),
+                )
+                .await?
+        } else {
+            return Err(scim_type_error(
+                "invalidFilter",
+                &format!("cannot filter on {}", filter.attribute),
+            ));
+        };
+        let Some(user_id) = user_id else {
+            return Ok(vec![]);
+        };
+        Ok(self
+            .repository
+            .find_user(org_id, app_id, user_id)
+            .await?
+            .map(ScimUserMapper::to_dto)
+            .into_iter()
+            .collect())
+    }
+
+    /// The users table has no offsets; a page is reached by reading past the
+    /// users before it.
+    async fn find_page(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        start_index: usize,
+        count: usize,
+    ) -> RepositoryResult<ScimListResponse<ScimUserResponse>> {
+        let (org_id, app_id) = (c_apporg.organization_id, c_apporg.application_id);
+        let mut skip = start_index - 1;
+        let mut resources = Vec::new();
+        let mut paging_state = None;
+        while resources.len() < count {
+            let page = self
+                .repository
+                .find_users_page(org_id, app_id, MAX_RESULTS as i32, paging_state)
+                .await?;
+            for user in page.users {
+                if skip > 0 {
+                    skip -= 1;
+                } else if resources.len() < count {
+                    resources.push(ScimUserMapper::to_dto(user));
+                }
+            }
+            match page.paging_state {
+                Some(state) => paging_state = Some(state),
+                None => break,
+            }
+        }
+        let counted = self.repository.count_users(org_id, app_id).await?.max(0) as usize;
+        // the counter is maintained separately and may trail the table
+        let total_results = counted.max(start_index - 1 - skip + resources.len());
+        Ok(ScimListResponse::new(total_results, start_index, resources))
+    }
+
+    async fn save(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        current: CleannedUserModel,
+        changes: UserChanges,
+    ) -> RepositoryResult<ScimUserResponse> {
+        let user_id = current.user_id;
+        let update = UpdateUserPayload {
+            username: Some(changes.user_name).filter(|name| *name != current.username),
+            email: changes.email.filter(|email| current.email.as_ref() != Some(email)),
+            password: changes.password,
+            profile: UserProfilePayload::default(),
+            metadata: Default::default(),
+        };
+        if update.username.is_some() || update.email.is_some() || update.password.is_some() {
+            update
+                .validate()
+                .map_err(|e| scim_type_error("invalidValue", &e.to_string()))?;
+            self.update_user_service
+                .execute(c_apporg.clone(), user_id, update)
+                .await?;
+        }
+
+        let active_changed = changes.active != is_active(&current);
+        if changes.profile != profile_of(&current) || active_changed {
+            let mut user = self
+                .repository
+                .find_raw_user(c_apporg.organization_id, c_apporg.application_id, user_id)
+                .await?
+                .ok_or_else(not_found)?;
+            let now = CqlTimestamp(Utc::now().timestamp_millis());
+            user.set_profile(changes.profile);
+            if active_changed && changes.active {
+                // also restores a soft-deleted user the purge job has not reached
+                user.is_active = true;
+                user.is_locked = false;
+                user.deactivated_at = None;
+            } else if active_changed {
+                user.is_locked = true;
+                user.locked_at = Some(now);
+            }
+            user.updated_at = now;
+            self.repository.update_user(user.clone()).await?;
+            // see CreateUserServiceImpl: the search index may lag behind
+            let config = c_apporg.get_config().unwrap_or_default();
+            let _ = self
+                .repository
+                .index_user(user, config.searchable_attributes())
+                .await;
+        }
+        Ok(ScimUserMapper::to_dto(
+            self.find_user(c_apporg, user_id).await?,
+        ))
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ScimUsersService: 'static + Sync + Send {
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        query: ScimListQuery,
+    ) -> RepositoryResult<ScimListResponse<ScimUserResponse>>;
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<ScimUserResponse>;
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: ScimUserPayload,
+    ) -> RepositoryResult<ScimUserResponse>;
+    async fn replace(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+        payload: ScimUserPayload,
+    ) -> RepositoryResult<ScimUserResponse>;
+    async fn patch(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+        payload: ScimPatchPayload,
+    ) -> RepositoryResult<ScimUserResponse>;
+    async fn delete(&self, c_apporg: CleanAppOrgByClientId, user_id: Uuid)
+    -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl ScimUsersService for ScimUsersServiceImpl {
+    async fn list(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        query: ScimListQuery,
+    ) -> RepositoryResult<ScimListResponse<ScimUserResponse>> {
+        let (start_index, count) = (query.start_index(), query.count());
+        match query.filter.as_deref() {
+            Some(filter) => Ok(ScimListResponse::paginate(
+                self.find_filtered(&c_apporg, filter).await?,
+                start_index,
+                count,
+            )),
+            None => self.find_page(&c_apporg, start_index, count).await,
+        }
+    }
+
+    async fn get(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<ScimUserResponse> {
+        Ok(ScimUserMapper::to_dto(
+            self.find_user(&c_apporg, user_id).await?,
+        ))
+    }
+
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: ScimUserPayload,
+    ) -> RepositoryResult<ScimUserResponse> {
+        // without a password the user is expected to sign in through
+        // federation or a reset
+        let passwordless = payload.password.is_none();
+        let create = CreateUserPayload {
+            username: payload.user_name,
+            email: primary_value(&payload.emails),
+            password: payload.password.unwrap_or_default(),
+            profile: UserProfilePayload {
+                first_name: payload.name.given_name,
+                last_name: payload.name.family_name,
+                locale: payload.locale,
+                phone: primary_value(&payload.phone_numbers),
+                avatar_url: None,
+            },
+            metadata: Default::default(),
+        };
+        if let Err(e) = create.validate()
+            && (!passwordless || e.errors().keys().any(|field| *field != "password"))
+        {
+            return Err(scim_type_error("invalidValue", &e.to_string()));
+        }
+        let created = if passwordless {
+            self.create_user_service
+                .execute_passwordless(c_apporg.clone(), create)
+                .await?
+        } else {
+            self.create_user_service
+                .execute(c_apporg.clone(), create)
+                .await?
+        };
+        let user = self
+            .find_user(&c_apporg, Uuid::parse_str(&created.user_id)?)
+            .await?;
+        if payload.active == Some(false) {
+            let mut changes = UserChanges::from_user(&user);
+            changes.active = false;
+            return self.save(&c_apporg, user, changes).await;
+        }
+        Ok(ScimUserMapper::to_dto(user))
+    }
+
+    async fn replace(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+        payload: ScimUserPayload,
+    ) -> RepositoryResult<ScimUserResponse> {
+        let user = self.find_user(&c_apporg, user_id).await?;
+        let mut changes = UserChanges::from_user(&user);
+        changes.replace(payload);
+        self.save(&c_apporg, user, changes).await
+    }
+
+    async fn patch(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+        payload: ScimPatchPayload,
+    ) -> RepositoryResult<ScimUserResponse> {
+        let user = self.find_user(&c_apporg, user_id).await?;
+        let mut changes = UserChanges::from_user(&user);
+        for operation in payload.operations {
+            changes.apply(operation)?;
+        }
+        self.save(&c_apporg, user, changes).await
+    }
+
+    async fn delete(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.delete_user_service
+            .execute(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                user_id,
+                false,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    application::{
+        dto::{
+            payload::service_account::CreateServiceAccountPayload,
+            response::service_account::CreateServiceAccountResponse,
+        },
+        repositories::service_accounts::create::CreateServiceAccountRepository,
+    },
+    domain::{
+        entities::{apporg_client_id::CleanAppOrgByClientId, service_account::ServiceAccount},
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CreateServiceAccountServiceImpl {
+    pub repository: Arc<dyn CreateServiceAccountRepository>,
+}
+impl CreateServiceAccountServiceImpl {
+    pub fn new(repository: Arc<dyn CreateServiceAccountRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CreateServiceAccountService: 'static + Sync + Send {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: CreateServiceAccountPayload,
+    ) -> RepositoryResult<CreateServiceAccountResponse>;
+}
+
+#[async_trait]
+impl CreateServiceAccountService for CreateServiceAccountServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        payload: CreateServiceAccountPayload,
+    ) -> RepositoryResult<CreateServiceAccountResponse> {
+        let name = payload.name.trim().to_string();
+        if name.is_empty() {
+            return Err(RepositoryError::new("name is required".to_string(), 400));
+        }
+        // the base64 form is what gets encrypted, so it decrypts back to
+        // exactly what the client presents
+        let secret = self
+            .repository
+            .encode_base64(&self.repository.generate_client_secret()?);
+        let (client_key, encrypted_client_secret) = self
+            .repository
+            .encrypt_client_secret(secret.as_bytes())
+            .await?;
+        let account = ServiceAccount::new(
+            c_apporg.organization_id,
+            c_apporg.application_id,
+            c_apporg.client_id,
+            name,
+            payload.description,
+            encrypted_client_secret,
+        );
+        let response = CreateServiceAccountResponse {
+            service_account_id: account.service_account_id,
+            client_id: account.client_id,
+            client_secret: ServiceAccount::client_secret(&client_key, &secret),
+        };
+        self.repository.create_service_account(account).await?;
+        Ok(response)
+    }
+}
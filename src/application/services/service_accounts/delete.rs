@@ -0,0 +1,68 @@
+use crate::{
+    application::{
+        dto::response::refresh_token::SimpleResponse,
+        repositories::service_accounts::delete::DeleteServiceAccountRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct DeleteServiceAccountServiceImpl {
+    pub repository: Arc<dyn DeleteServiceAccountRepository>,
+}
+impl DeleteServiceAccountServiceImpl {
+    pub fn new(repository: Arc<dyn DeleteServiceAccountRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DeleteServiceAccountService: 'static + Sync + Send {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse>;
+}
+
+#[async_trait]
+impl DeleteServiceAccountService for DeleteServiceAccountServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<SimpleResponse> {
+        if self
+            .repository
+            .find_service_account(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                service_account_id,
+            )
+            .await?
+            .is_none()
+        {
+            return Err(RepositoryError::new(
+                "service account not found".to_string(),
+                404,
+            ));
+        }
+        self.repository
+            .delete_service_account(
+                c_apporg.organization_id,
+                c_apporg.application_id,
+                service_account_id,
+            )
+            .await?;
+        Ok(SimpleResponse {
+            message: "success".to_string(),
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    application::{
+        dto::response::service_account::{GetServiceAccountsResponse, ServiceAccountResponse},
+        repositories::service_accounts::get::GetServiceAccountsRepository,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::RepositoryResult,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct GetServiceAccountsServiceImpl {
+    pub repository: Arc<dyn GetServiceAccountsRepository>,
+}
+impl GetServiceAccountsServiceImpl {
+    pub fn new(repository: Arc<dyn GetServiceAccountsRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GetServiceAccountsService: 'static + Sync + Send {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+    ) -> RepositoryResult<GetServiceAccountsResponse>;
+}
+
+#[async_trait]
+impl GetServiceAccountsService for GetServiceAccountsServiceImpl {
+    async fn execute(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+    ) -> RepositoryResult<GetServiceAccountsResponse> {
+        let accounts = self
+            .repository
+            .find_service_accounts(c_apporg.organization_id, c_apporg.application_id)
+            .await?;
+        Ok(GetServiceAccountsResponse {
+            service_accounts: accounts
+                .into_iter()
+                .map(ServiceAccountResponse::from)
+                .collect(),
+        })
+    }
+}
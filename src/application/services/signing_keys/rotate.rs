@@ -0,0 +1,99 @@
+use crate::{
+    application::repositories::signing_keys::rotate::RotateSigningKeysRepository,
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::models::signing_key::SigningKeyModel,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+const SCAN_PAGE_SIZE: i32 = 500;
+
+#[derive(Clone)]
+pub struct RotateSigningKeysServiceImpl {
+    pub repository: Arc<dyn RotateSigningKeysRepository>,
+}
+impl RotateSigningKeysServiceImpl {
+    pub fn new(repository: Arc<dyn RotateSigningKeysRepository>) -> Self {
+        Self { repository }
+    }
+
+    async fn keys_by_application(
+        &self,
+    ) -> RepositoryResult<HashMap<(Uuid, Uuid), Vec<SigningKeyModel>>> {
+        let mut keys: HashMap<(Uuid, Uuid), Vec<SigningKeyModel>> = HashMap::new();
+        let mut paging_state = None;
+        loop {
+            let page = self
+                .repository
+                .scan_signing_keys(SCAN_PAGE_SIZE, paging_state)
+                .await?;
+            for key in page.keys {
+                keys.entry((key.organization_id, key.application_id))
+                    .or_default()
+                    .push(key);
+            }
+            let Some(next) = page.paging_state else {
+                break;
+            };
+            paging_state = Some(next);
+        }
+        Ok(keys)
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RotateSigningKeysService: 'static + Sync + Send {
+    /// Replaces every application key that has been signing for longer than
+    /// `rotation_interval` seconds. Replaced keys keep verifying tokens for
+    /// `overlap` seconds and are deleted afterwards. Returns how many
+    /// applications got a new key.
+    async fn execute(&self, rotation_interval: i64, overlap: i64) -> RepositoryResult<usize>;
+}
+
+#[async_trait]
+impl RotateSigningKeysService for RotateSigningKeysServiceImpl {
+    async fn execute(&self, rotation_interval: i64, overlap: i64) -> RepositoryResult<usize> {
+        let now = Utc::now().timestamp_millis();
+        let expires_at = now + overlap * 1000;
+        let mut rotated = 0;
+        for ((organization_id, application_id), keys) in self.keys_by_application().await? {
+            let (expired, live): (Vec<_>, Vec<_>) =
+                keys.into_iter().partition(|k| k.is_expired(now));
+            for key in expired {
+                self.repository
+                    .delete_signing_key(organization_id, application_id, key.kid)
+                    .await?;
+            }
+
+            let mut active: Vec<_> = live
+                .into_iter()
+                .filter(|k| k.retired_at.is_none())
+                .collect();
+            active.sort_by_key(|k| std::cmp::Reverse(k.created_at.0));
+            let Some(newest) = active.first() else {
+                // the next token issued for the application creates one
+                continue;
+            };
+            // replicas racing on the first token can each create a key; only
+            // the newest keeps signing
+            let keep = if now - newest.created_at.0 >= rotation_interval * 1000 {
+                self.repository
+                    .create_signing_key(organization_id, application_id)
+                    .await?;
+                rotated += 1;
+                0
+            } else {
+                1
+            };
+            for key in active.into_iter().skip(keep) {
+                self.repository
+                    .retire_signing_key(organization_id, application_id, key.kid, now, expires_at)
+                    .await?;
+            }
+        }
+        Ok(rotated)
+    }
+}
@@ -6,6 +6,9 @@ use crate::{
     domain::{
         entities::apporg_client_id::CleanAppOrgByClientId,
         entities::apporg_client_id::HasAppConfig,
+        entities::user::{UNUSABLE_PASSWORD, User},
+        value_objects::identifier::{normalize_email, normalize_username},
+        value_objects::password_policy::{PasswordViolation, password_policy_error},
         errors::repositories_errors::{RepositoryError, RepositoryResult},
     },
 };
@@ -20,6 +23,21 @@ impl CreateUserServiceImpl {
     pub fn new(repository: Arc<dyn CreateUserRepository>) -> Self {
         CreateUserServiceImpl { repository }
     }
+
+    async fn release_username(&self, user: &User, reserved: bool) {
+        if !reserved {
+            return;
+        }
+        let _ = self
+            .repository
+            .release_username(
+                user.organization_id,
+                user.application_id,
+                user.username_normalized.clone(),
+                user.user_id,
+            )
+            .await;
+    }
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -30,6 +48,20 @@ pub trait CreateUserService: 'static + Sync + Send {
         c_apporg: CleanAppOrgByClientId,
         user: CreateUserPayload,
     ) -> RepositoryResult<CreateUserResponse>;
+    /// Same checks as `execute`, but `user.password` is a hash produced
+    /// elsewhere (e.g. a bulk import) and is stored as is.
+    async fn execute_hashed(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user: CreateUserPayload,
+    ) -> RepositoryResult<CreateUserResponse>;
+    /// Creates a user who can't sign in with a password until one is set;
+    /// `user.password` is ignored.
+    async fn execute_passwordless(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user: CreateUserPayload,
+    ) -> RepositoryResult<CreateUserResponse>;
 }
 #[async_trait]
 impl CreateUserService for CreateUserServiceImpl {
@@ -37,6 +69,41 @@ impl CreateUserService for CreateUserServiceImpl {
         &self,
         c_apporg: CleanAppOrgByClientId,
         user: CreateUserPayload,
+    ) -> RepositoryResult<CreateUserResponse> {
+        self.create(c_apporg, user, PasswordInput::Plain).await
+    }
+
+    async fn execute_hashed(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user: CreateUserPayload,
+    ) -> RepositoryResult<CreateUserResponse> {
+        self.create(c_apporg, user, PasswordInput::Hashed).await
+    }
+
+    async fn execute_passwordless(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user: CreateUserPayload,
+    ) -> RepositoryResult<CreateUserResponse> {
+        self.create(c_apporg, user, PasswordInput::Unusable).await
+    }
+}
+
+/// What `CreateUserPayload::password` holds.
+#[derive(Clone, Copy, PartialEq)]
+enum PasswordInput {
+    Plain,
+    Hashed,
+    Unusable,
+}
+
+impl CreateUserServiceImpl {
+    async fn create(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        user: CreateUserPayload,
+        password_input: PasswordInput,
     ) -> RepositoryResult<CreateUserResponse> {
         if !(2..=50).contains(&user.username.len()) {
             return Err(RepositoryError::new("username is not valid".into(), 400));
@@ -48,15 +115,33 @@ impl CreateUserService for CreateUserServiceImpl {
             ));
         };
 
-        if !config.can_allow_email_nullable {
-            let Some(email) = user.email.as_ref() else {
-                return Err(RepositoryError::new("email is required".to_string(), 400));
-            };
+        config
+            .validate_user_metadata(&user.metadata)
+            .map_err(|e| RepositoryError::new(e, 400))?;
 
+        if !config.can_allow_email_nullable && user.email.is_none() {
+            return Err(RepositoryError::new("email is required".to_string(), 400));
+        }
+
+        // an imported hash can't be checked, the policy applies from its next change
+        if password_input == PasswordInput::Plain
+            && let Some(policy) = config.password_policy.as_ref()
+        {
+            let mut violations =
+                policy.check(&user.password, &user.username, user.email.as_deref());
+            if policy.reject_breached && self.repository.is_breached_password(&user.password) {
+                violations.push(PasswordViolation::breached());
+            }
+            if !violations.is_empty() {
+                return Err(password_policy_error(violations));
+            }
+        }
+
+        if let Some(email) = user.email.as_ref() {
             let found = self
                 .repository
                 .find_user_by_email(
-                    email.clone(),
+                    normalize_email(email, &config.email_normalization),
                     c_apporg.application_id,
                     c_apporg.organization_id,
                 )
@@ -67,22 +152,115 @@ impl CreateUserService for CreateUserServiceImpl {
             }
         }
 
-        let found = self
-            .repository
-            .find_user_by_username(
-                user.username.clone(),
-                c_apporg.application_id,
-                c_apporg.organization_id,
-            )
-            .await?;
-        if found.is_some() {
-            return Err(RepositoryError::new("username already used".into(), 400));
+        if config.is_must_name_unique {
+            let found = self
+                .repository
+                .find_user_by_username(
+                    normalize_username(&user.username),
+                    c_apporg.application_id,
+                    c_apporg.organization_id,
+                )
+                .await?;
+            if found.is_some() {
+                return Err(RepositoryError::new("username already used".into(), 400));
+            }
         }
-        let hashed_password = self.repository.hash_password(user.password.clone())?;
+        let hashed_password = match password_input {
+            PasswordInput::Plain => self.repository.hash_password(user.password.clone())?,
+            PasswordInput::Hashed => {
+                if !self.repository.is_supported_hash(&user.password) {
+                    return Err(RepositoryError::new(
+                        "password hash is not supported".into(),
+                        400,
+                    ));
+                }
+                user.password.clone()
+            }
+            PasswordInput::Unusable => UNUSABLE_PASSWORD.to_string(),
+        };
         let new_user = self
             .repository
             .new_user(c_apporg.clone(), user, hashed_password);
-        self.repository.create_user(new_user.clone()).await?;
+
+        // the reads above only catch existing users; the reservations are what
+        // stop two concurrent signups from claiming the same name or email
+        if config.is_must_name_unique
+            && !self
+                .repository
+                .reserve_username(
+                    new_user.organization_id,
+                    new_user.application_id,
+                    new_user.username_normalized.clone(),
+                    new_user.user_id,
+                )
+                .await?
+        {
+            return Err(RepositoryError::new("username already used".into(), 400));
+        }
+        if let Some(email) = new_user.email_normalized.clone() {
+            let reserved = self
+                .repository
+                .reserve_email(
+                    new_user.organization_id,
+                    new_user.application_id,
+                    email,
+                    new_user.user_id,
+                )
+                .await;
+            match reserved {
+                Ok(true) => {}
+                Ok(false) => {
+                    self.release_username(&new_user, config.is_must_name_unique)
+                        .await;
+                    return Err(RepositoryError::new("email already used".into(), 400));
+                }
+                Err(e) => {
+                    self.release_username(&new_user, config.is_must_name_unique)
+                        .await;
+                    return Err(e);
+                }
+            }
+        }
+        if let Err(e) = self.repository.create_user(new_user.clone()).await {
+            self.release_username(&new_user, config.is_must_name_unique)
+                .await;
+            if let Some(email) = new_user.email_normalized.clone() {
+                let _ = self
+                    .repository
+                    .release_email(
+                        new_user.organization_id,
+                        new_user.application_id,
+                        email,
+                        new_user.user_id,
+                    )
+                    .await;
+            }
+            return Err(e);
+        }
+        if password_input != PasswordInput::Unusable
+            && config
+                .password_policy
+                .as_ref()
+                .is_some_and(|policy| policy.history_size > 0)
+        {
+            // without this entry the first password could be reused once it
+            // is no longer the current one
+            let _ = self
+                .repository
+                .add_password_history(
+                    new_user.organization_id,
+                    new_user.application_id,
+                    new_user.user_id,
+                    new_user.hashed_password.clone(),
+                )
+                .await;
+        }
+        // the search index is a secondary view; a failed write there must
+        // not undo a signup that is already stored
+        let _ = self
+            .repository
+            .index_user(new_user.clone(), config.searchable_attributes())
+            .await;
         return Ok(CreateUserResponse {
             user_id: new_user.user_id.to_string(),
             username: new_user.username,
@@ -2,9 +2,10 @@ use crate::{
     application::{
         dto::response::user::UpdateUsersResponse, repositories::users::delete::DeleteUserRepository,
     },
-    domain::errors::repositories_errors::RepositoryResult,
+    domain::errors::repositories_errors::{RepositoryError, RepositoryResult},
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -26,6 +27,7 @@ pub trait DeleteUserService: 'static + Sync + Send {
         organization_id: Uuid,
         application_id: Uuid,
         user_id: Uuid,
+        soft: bool,
     ) -> RepositoryResult<UpdateUsersResponse>;
 }
 #[async_trait]
@@ -35,10 +37,37 @@ impl DeleteUserService for DeleteUserServiceImpl {
         organization_id: Uuid,
         application_id: Uuid,
         user_id: Uuid,
+        soft: bool,
     ) -> RepositoryResult<UpdateUsersResponse> {
-        self.repository
-            .delete_user(organization_id, application_id, user_id)
-            .await?;
+        let user = self
+            .repository
+            .find_user(organization_id, application_id, user_id)
+            .await?
+            .ok_or_else(|| RepositoryError::new("not found the user".to_string(), 404))?;
+
+        if soft {
+            if user.deactivated_at.is_some() {
+                return Err(RepositoryError::new(
+                    "user already deactivated".to_string(),
+                    400,
+                ));
+            }
+            self.repository
+                .soft_delete_user(
+                    organization_id,
+                    application_id,
+                    user_id,
+                    Utc::now().timestamp_millis(),
+                )
+                .await?;
+            self.repository
+                .revoke_refresh_tokens(organization_id, application_id, user_id)
+                .await?;
+        } else {
+            self.repository
+                .purge_user(organization_id, application_id, user_id)
+                .await?;
+        }
         Ok(UpdateUsersResponse {
             massage: "success".to_string(),
         })
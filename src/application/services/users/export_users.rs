@@ -0,0 +1,116 @@
+use crate::{
+    application::{
+        dto::response::user::ExportedUserRow, repositories::users::get_users::GetUsersRepository,
+    },
+    domain::{
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::user_transfer::{EXPORT_PAGE_SIZE, TransferFormat},
+    },
+    infrastructure::models::user::CleannedUserModel,
+};
+use actix_web::web::Bytes;
+use futures::stream::{self, BoxStream, StreamExt};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn csv_error(e: impl ToString) -> RepositoryError {
+    RepositoryError::new(e.to_string(), 500)
+}
+
+fn encode_csv<I, T>(records: I) -> RepositoryResult<Bytes>
+where
+    I: IntoIterator<Item = T>,
+    T: IntoIterator,
+    T::Item: AsRef<[u8]>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for record in records {
+        writer.write_record(record).map_err(csv_error)?;
+    }
+    Ok(Bytes::from(writer.into_inner().map_err(csv_error)?))
+}
+
+fn encode_page(format: TransferFormat, users: Vec<CleannedUserModel>) -> RepositoryResult<Bytes> {
+    let rows = users.into_iter().map(ExportedUserRow::from);
+    match format {
+        TransferFormat::Csv => encode_csv(rows.map(|row| row.csv_record())),
+        TransferFormat::Ndjson => {
+            let mut buf = Vec::new();
+            for row in rows {
+                serde_json::to_writer(&mut buf, &row)?;
+                buf.push(b'\n');
+            }
+            Ok(Bytes::from(buf))
+        }
+    }
+}
+
+/// `paging_state` is `None` once the last page has been read.
+async fn next_page(
+    repository: Arc<dyn GetUsersRepository>,
+    organization_id: Uuid,
+    application_id: Uuid,
+    format: TransferFormat,
+    paging_state: Option<Option<Vec<u8>>>,
+) -> RepositoryResult<Option<(Bytes, Option<Option<Vec<u8>>>)>> {
+    let Some(paging_state) = paging_state else {
+        return Ok(None);
+    };
+    let page = repository
+        .find_all_users_paginated(
+            organization_id,
+            application_id,
+            EXPORT_PAGE_SIZE,
+            paging_state,
+        )
+        .await?;
+    let chunk = encode_page(format, page.users)?;
+    Ok(Some((chunk, page.paging_state.map(Some))))
+}
+
+#[derive(Clone)]
+pub struct ExportUsersServiceImpl {
+    pub repository: Arc<dyn GetUsersRepository>,
+}
+impl ExportUsersServiceImpl {
+    pub fn new(repository: Arc<dyn GetUsersRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ExportUsersService: 'static + Sync + Send {
+    /// Encodes the application's users page by page, so only one page is
+    /// held in memory while the response is written.
+    fn execute(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        format: TransferFormat,
+    ) -> BoxStream<'static, RepositoryResult<Bytes>>;
+}
+
+impl ExportUsersService for ExportUsersServiceImpl {
+    fn execute(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        format: TransferFormat,
+    ) -> BoxStream<'static, RepositoryResult<Bytes>> {
+        let header = match format {
+            TransferFormat::Csv => Some(encode_csv([ExportedUserRow::CSV_HEADER])),
+            TransferFormat::Ndjson => None,
+        };
+        let repository = self.repository.clone();
+        let pages = stream::try_unfold(Some(None), move |paging_state| {
+            next_page(
+                repository.clone(),
+                organization_id,
+                application_id,
+                format,
+                paging_state,
+            )
+        });
+        stream::iter(header).chain(pages).boxed()
+    }
+}
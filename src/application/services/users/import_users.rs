@@ -0,0 +1,233 @@
+use crate::{
+    application::{
+        dto::payload::user::{
+            CreateUserPayload, ImportMetadata, ImportUserRow, UserProfilePayload,
+        },
+        repositories::users::import_users::ImportUsersRepository,
+        services::users::create::CreateUserService,
+    },
+    domain::{
+        entities::apporg_client_id::CleanAppOrgByClientId,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::user_transfer::{MAX_REPORTED_ERRORS, PROGRESS_INTERVAL, TransferFormat},
+    },
+    infrastructure::models::user_import::{
+        UserImportJobModel, UserImportRowError, UserImportStatus,
+    },
+};
+use actix_web::web::Bytes;
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::Map;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+type Rows<'a> = Box<dyn Iterator<Item = Result<ImportUserRow, String>> + Send + 'a>;
+
+fn csv_reader(body: &[u8]) -> csv::Reader<&[u8]> {
+    // cells are left untrimmed, a password may start or end with a space
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(body)
+}
+
+fn rows(format: TransferFormat, body: &[u8]) -> Rows<'_> {
+    match format {
+        TransferFormat::Csv => Box::new(
+            csv_reader(body)
+                .into_deserialize::<ImportUserRow>()
+                .map(|row| row.map_err(|e| e.to_string())),
+        ),
+        TransferFormat::Ndjson => Box::new(
+            body.split(|byte| *byte == b'\n')
+                .filter(|line| !line.trim_ascii().is_empty())
+                .map(|line| serde_json::from_slice(line).map_err(|e| e.to_string())),
+        ),
+    }
+}
+
+/// Returns the payload and whether its password is already hashed.
+fn to_create_payload(row: ImportUserRow) -> Result<(CreateUserPayload, bool), String> {
+    let (password, prehashed) = match (row.password, row.password_hash) {
+        (Some(password), None) => (password, false),
+        (None, Some(password_hash)) => (password_hash, true),
+        (Some(_), Some(_)) => {
+            return Err("set either password or password_hash, not both".to_string());
+        }
+        (None, None) => return Err("password or password_hash is required".to_string()),
+    };
+    let metadata = match row.metadata {
+        None => Map::new(),
+        Some(ImportMetadata::Object(metadata)) => metadata,
+        Some(ImportMetadata::Encoded(encoded)) => serde_json::from_str(&encoded)
+            .map_err(|_| "metadata must be a JSON object".to_string())?,
+    };
+    let payload = CreateUserPayload {
+        username: row.username,
+        email: row.email,
+        password,
+        profile: UserProfilePayload {
+            first_name: row.first_name,
+            last_name: row.last_name,
+            locale: row.locale,
+            phone: row.phone,
+            avatar_url: row.avatar_url,
+        },
+        metadata,
+    };
+    Ok((payload, prehashed))
+}
+
+#[derive(Clone)]
+pub struct ImportUsersServiceImpl {
+    pub repository: Arc<dyn ImportUsersRepository>,
+    pub create_user_service: Arc<dyn CreateUserService>,
+}
+impl ImportUsersServiceImpl {
+    pub fn new(
+        repository: Arc<dyn ImportUsersRepository>,
+        create_user_service: Arc<dyn CreateUserService>,
+    ) -> Self {
+        Self {
+            repository,
+            create_user_service,
+        }
+    }
+
+    /// Creates the users in `body` one row at a time; `start` runs this in
+    /// the background.
+    pub async fn run(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        mut job: UserImportJobModel,
+        body: Bytes,
+    ) -> RepositoryResult<UserImportJobModel> {
+        for (index, row) in rows(job.format, &body).enumerate() {
+            let result = match row {
+                Ok(row) => self.import_row(&c_apporg, row).await,
+                Err(message) => Err(message),
+            };
+            job.processed += 1;
+            match result {
+                Ok(()) => job.created += 1,
+                Err(message) => {
+                    job.failed += 1;
+                    if job.errors.len() < MAX_REPORTED_ERRORS {
+                        job.errors.push(UserImportRowError {
+                            row: index + 1,
+                            message,
+                        });
+                    }
+                }
+            }
+            if job.processed.is_multiple_of(PROGRESS_INTERVAL) {
+                // only progress is lost if this fails; the next save catches up
+                let _ = self.repository.store_job(job.clone()).await;
+            }
+        }
+        job.status = UserImportStatus::Completed;
+        job.finished_at = Some(Utc::now().timestamp_millis());
+        self.repository.store_job(job.clone()).await?;
+        Ok(job)
+    }
+
+    async fn import_row(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        row: ImportUserRow,
+    ) -> Result<(), String> {
+        let (user, prehashed) = to_create_payload(row)?;
+        user.validate().map_err(|e| e.to_string())?;
+        let created = if prehashed {
+            self.create_user_service
+                .execute_hashed(c_apporg.clone(), user)
+                .await
+        } else {
+            self.create_user_service.execute(c_apporg.clone(), user).await
+        };
+        created.map(|_| ()).map_err(|e| e.message)
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ImportUsersService: 'static + Sync + Send {
+    /// Checks the body can be read and starts importing it in the background.
+    async fn start(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        format: TransferFormat,
+        body: Bytes,
+    ) -> RepositoryResult<UserImportJobModel>;
+    async fn get_job(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        job_id: Uuid,
+    ) -> RepositoryResult<UserImportJobModel>;
+}
+
+#[async_trait]
+impl ImportUsersService for ImportUsersServiceImpl {
+    async fn start(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        format: TransferFormat,
+        body: Bytes,
+    ) -> RepositoryResult<UserImportJobModel> {
+        if body.iter().all(u8::is_ascii_whitespace) {
+            return Err(RepositoryError::new("import is empty".to_string(), 400));
+        }
+        if format == TransferFormat::Csv {
+            let mut reader = csv_reader(&body);
+            let headers = reader
+                .headers()
+                .map_err(|e| RepositoryError::new(e.to_string(), 400))?;
+            if !headers.iter().any(|header| header == "username") {
+                return Err(RepositoryError::new(
+                    "csv header must include username".to_string(),
+                    400,
+                ));
+            }
+        }
+        let job = UserImportJobModel {
+            job_id: Uuid::new_v4(),
+            organization_id: c_apporg.organization_id,
+            application_id: c_apporg.application_id,
+            format,
+            status: UserImportStatus::Running,
+            processed: 0,
+            created: 0,
+            failed: 0,
+            errors: Vec::new(),
+            started_at: Utc::now().timestamp_millis(),
+            finished_at: None,
+        };
+        self.repository.store_job(job.clone()).await?;
+
+        let service = self.clone();
+        let running = job.clone();
+        tokio::spawn(async move {
+            let job_id = running.job_id;
+            if let Err(e) = service.run(c_apporg, running, body).await {
+                eprintln!("user import {} failed: {}", job_id, e.message);
+            }
+        });
+        Ok(job)
+    }
+
+    async fn get_job(
+        &self,
+        c_apporg: CleanAppOrgByClientId,
+        job_id: Uuid,
+    ) -> RepositoryResult<UserImportJobModel> {
+        self.repository
+            .find_job(job_id)
+            .await?
+            .filter(|job| {
+                job.organization_id == c_apporg.organization_id
+                    && job.application_id == c_apporg.application_id
+            })
+            .ok_or_else(|| RepositoryError::new("import job not found".to_string(), 404))
+    }
+}
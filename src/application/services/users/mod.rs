@@ -2,8 +2,12 @@ pub mod create;
 pub mod delete;
 pub mod get_user;
 pub mod get_users;
+pub mod normalize_identifiers;
 pub mod update_user;
 pub mod get_user_count;
 pub mod ban_user;
 pub mod unban_user;
-pub mod disable_mfa_user;
\ No newline at end of file
+pub mod disable_mfa_user;
+pub mod purge_users;
+pub mod import_users;
+pub mod export_users;
@@ -0,0 +1,129 @@
+use crate::{
+    application::{
+        dto::response::user::{IdentifierCollision, NormalizeIdentifiersResponse},
+        repositories::users::normalize_identifiers::NormalizeIdentifiersRepository,
+    },
+    domain::{
+        errors::repositories_errors::RepositoryResult,
+        value_objects::{
+            app_config::AppConfig,
+            identifier::{normalize_email, normalize_username},
+        },
+    },
+};
+use async_trait::async_trait;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    sync::Arc,
+};
+use uuid::Uuid;
+
+const SCAN_PAGE_SIZE: i32 = 500;
+
+#[derive(Clone)]
+pub struct NormalizeIdentifiersServiceImpl {
+    pub repository: Arc<dyn NormalizeIdentifiersRepository>,
+}
+impl NormalizeIdentifiersServiceImpl {
+    pub fn new(repository: Arc<dyn NormalizeIdentifiersRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait NormalizeIdentifiersService: 'static + Sync + Send {
+    /// Backfills the normalized username/email of every user and claims their
+    /// reservations. Users whose normalized value is already held by someone
+    /// else are reported instead of being merged.
+    async fn execute(&self) -> RepositoryResult<NormalizeIdentifiersResponse>;
+}
+#[async_trait]
+impl NormalizeIdentifiersService for NormalizeIdentifiersServiceImpl {
+    async fn execute(&self) -> RepositoryResult<NormalizeIdentifiersResponse> {
+        let mut configs: HashMap<(Uuid, Uuid), AppConfig> = HashMap::new();
+        let mut report = NormalizeIdentifiersResponse {
+            scanned: 0,
+            updated: 0,
+            collisions: Vec::new(),
+        };
+        let mut paging_state = None;
+        loop {
+            let page = self
+                .repository
+                .scan_users(SCAN_PAGE_SIZE, paging_state)
+                .await?;
+            for user in page.users {
+                report.scanned += 1;
+                let key = (user.organization_id, user.application_id);
+                if let Entry::Vacant(entry) = configs.entry(key) {
+                    entry.insert(
+                        self.repository
+                            .find_app_config(user.organization_id, user.application_id)
+                            .await?,
+                    );
+                }
+                let config = &configs[&key];
+
+                let username = normalize_username(&user.username);
+                let email = user
+                    .email
+                    .as_deref()
+                    .map(|e| normalize_email(e, &config.email_normalization));
+                if user.username_normalized.as_ref() != Some(&username)
+                    || user.email_normalized != email
+                {
+                    self.repository
+                        .update_normalized(
+                            user.organization_id,
+                            user.application_id,
+                            user.user_id,
+                            username.clone(),
+                            email.clone(),
+                        )
+                        .await?;
+                    report.updated += 1;
+                }
+
+                let collision = |field: &str, value: String| IdentifierCollision {
+                    organization_id: user.organization_id,
+                    application_id: user.application_id,
+                    user_id: user.user_id,
+                    field: field.to_string(),
+                    value,
+                };
+                if config.is_must_name_unique
+                    && !self
+                        .repository
+                        .reserve_username(
+                            user.organization_id,
+                            user.application_id,
+                            username.clone(),
+                            user.user_id,
+                        )
+                        .await?
+                {
+                    report.collisions.push(collision("username", username));
+                }
+                if let Some(email) = email
+                    && !self
+                        .repository
+                        .reserve_email(
+                            user.organization_id,
+                            user.application_id,
+                            email.clone(),
+                            user.user_id,
+                        )
+                        .await?
+                {
+                    report.collisions.push(collision("email", email));
+                }
+            }
+            let Some(next) = page.paging_state else {
+                break;
+            };
+            paging_state = Some(next);
+        }
+        Ok(report)
+    }
+}
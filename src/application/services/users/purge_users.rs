@@ -0,0 +1,60 @@
+use crate::{
+    application::repositories::users::delete::DeleteUserRepository,
+    domain::errors::repositories_errors::RepositoryResult,
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// How many days before the retention cutoff are rescanned, so a missed run
+/// (downtime, failed purge) is caught up on the next one.
+const PURGE_LOOKBACK_DAYS: i64 = 7;
+
+#[derive(Clone)]
+pub struct PurgeUsersServiceImpl {
+    pub repository: Arc<dyn DeleteUserRepository>,
+}
+impl PurgeUsersServiceImpl {
+    pub fn new(repository: Arc<dyn DeleteUserRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PurgeUsersService: 'static + Sync + Send {
+    /// Purges users soft deleted more than `retention_days` ago and returns
+    /// how many were removed.
+    async fn execute(&self, retention_days: i64) -> RepositoryResult<usize>;
+}
+#[async_trait]
+impl PurgeUsersService for PurgeUsersServiceImpl {
+    async fn execute(&self, retention_days: i64) -> RepositoryResult<usize> {
+        let cutoff = Utc::now() - Duration::days(retention_days);
+        let mut purged = 0;
+        for offset in (0..=PURGE_LOOKBACK_DAYS).rev() {
+            let day = self
+                .repository
+                .purge_day((cutoff - Duration::days(offset)).timestamp_millis());
+            let pending = self.repository.find_users_pending_purge(day.clone()).await?;
+            for p in pending {
+                let user = self
+                    .repository
+                    .find_user(p.organization_id, p.application_id, p.user_id)
+                    .await?;
+                // skip users that were restored after being soft deleted
+                let still_deactivated = user.as_ref().is_some_and(|u| u.deactivated_at.is_some());
+                if still_deactivated {
+                    self.repository
+                        .purge_user(p.organization_id, p.application_id, p.user_id)
+                        .await?;
+                    purged += 1;
+                }
+                self.repository
+                    .clear_pending_purge(day.clone(), p.organization_id, p.application_id, p.user_id)
+                    .await?;
+            }
+        }
+        Ok(purged)
+    }
+}
@@ -4,9 +4,14 @@ use crate::{
         repositories::users::update_user::UpdateUserRepository,
     },
     domain::{
-        entities::apporg_client_id::CleanAppOrgByClientId,
+        entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig},
         errors::repositories_errors::{RepositoryError, RepositoryResult},
+        value_objects::{
+            identifier::{normalize_email, normalize_username},
+            password_policy::{PasswordPolicy, PasswordViolation, password_policy_error},
+        },
     },
+    infrastructure::models::user::{UserModel, metadata_from_column, metadata_to_column},
 };
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -19,6 +24,114 @@ impl UpdateUserServiceImpl {
     pub fn new(repository: Arc<dyn UpdateUserRepository>) -> Self {
         Self { repository }
     }
+
+    async fn claim_email(
+        &self,
+        c_apporg: &CleanAppOrgByClientId,
+        user_id: Uuid,
+        email_normalized: &str,
+    ) -> RepositoryResult<()> {
+        if self
+            .repository
+            .find_user_by_email(
+                email_normalized.to_string(),
+                c_apporg.application_id,
+                c_apporg.organization_id,
+            )
+            .await?
+            .is_some()
+            || !self
+                .repository
+                .reserve_email(
+                    c_apporg.organization_id,
+                    c_apporg.application_id,
+                    email_normalized.to_string(),
+                    user_id,
+                )
+                .await?
+        {
+            return Err(RepositoryError::new("email already used".into(), 400));
+        }
+        Ok(())
+    }
+
+    /// Best effort: a reservation left behind only blocks the value until an
+    /// operator clears it, it never lets a duplicate through.
+    async fn release(&self, c_apporg: &CleanAppOrgByClientId, user_id: Uuid, values: Claimed) {
+        if let Some(username) = values.username {
+            let _ = self
+                .repository
+                .release_username(
+                    c_apporg.organization_id,
+                    c_apporg.application_id,
+                    username,
+                    user_id,
+                )
+                .await;
+        }
+        if let Some(email) = values.email {
+            let _ = self
+                .repository
+                .release_email(
+                    c_apporg.organization_id,
+                    c_apporg.application_id,
+                    email,
+                    user_id,
+                )
+                .await;
+        }
+    }
+
+    /// Checks `password` against `policy` for `user`, whose username and
+    /// email may be changing in the same update.
+    async fn check_password(
+        &self,
+        policy: &PasswordPolicy,
+        user: &UserModel,
+        username: &str,
+        email: Option<&str>,
+        password: &str,
+    ) -> RepositoryResult<()> {
+        let mut violations = policy.check(password, username, email);
+        if policy.reject_breached && self.repository.is_breached_password(password) {
+            violations.push(PasswordViolation::breached());
+        }
+        if policy.history_size > 0 {
+            let history = self
+                .repository
+                .find_password_history(
+                    user.organization_id,
+                    user.application_id,
+                    user.user_id,
+                    policy.history_size,
+                )
+                .await?;
+            // every newly set hash is recorded, so the current one is
+            // normally the newest entry; it still counts when it predates
+            // the history, but never twice
+            let reused = std::iter::once(&user.hashed_password)
+                .chain(history.iter().filter(|h| **h != user.hashed_password))
+                .take(policy.history_size)
+                .any(|hashed| {
+                    self.repository
+                        .verify_password(hashed, password)
+                        .unwrap_or(false)
+                });
+            if reused {
+                violations.push(PasswordViolation::reused(policy.history_size));
+            }
+        }
+        if !violations.is_empty() {
+            return Err(password_policy_error(violations));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Claimed {
+    username: Option<String>,
+    email: Option<String>,
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -46,43 +159,122 @@ impl UpdateUserService for UpdateUserServiceImpl {
         else {
             return Err(RepositoryError::new("not found user".to_string(), 400));
         };
+        let Ok(config) = c_apporg.get_config() else {
+            return Err(RepositoryError::new(
+                "failed to read config".to_string(),
+                500,
+            ));
+        };
+        let password_changed = update.password.is_some();
         if let Some(password) = update.password {
+            if let Some(policy) = config.password_policy.as_ref() {
+                let username = update.username.as_deref().unwrap_or(&user.username);
+                let email = update.email.as_deref().or(user.email.as_deref());
+                self.check_password(policy, &user, username, email, &password)
+                    .await?;
+            }
             user.hashed_password = self.repository.hash_password(password)?;
         }
-        if let Some(username) = update.username {
+        if let Some(username) = update.username.as_ref() {
             self.repository
-                .validate_new_username(&user.username, &username)?;
-            if self
-                .repository
-                .find_user_by_username(
-                    username.clone(),
-                    c_apporg.application_id,
-                    c_apporg.organization_id,
-                )
-                .await?
-                .is_some()
-            {
-                return Err(RepositoryError::new("username already used".into(), 400));
+                .validate_new_username(&user.username, username)?;
+        }
+        if let Some(email) = update.email.as_ref() {
+            self.repository.validate_new_email(&user.email, email)?;
+        }
+        let mut claimed = Claimed::default();
+        let mut replaced = Claimed::default();
+        if let Some(username) = update.username {
+            let username_normalized = normalize_username(&username);
+            // a change of case only updates the display form
+            let changed = user.username_normalized.as_ref() != Some(&username_normalized);
+            if config.is_must_name_unique && changed {
+                if self
+                    .repository
+                    .find_user_by_username(
+                        username_normalized.clone(),
+                        c_apporg.application_id,
+                        c_apporg.organization_id,
+                    )
+                    .await?
+                    .is_some()
+                    || !self
+                        .repository
+                        .reserve_username(
+                            c_apporg.organization_id,
+                            c_apporg.application_id,
+                            username_normalized.clone(),
+                            user_id,
+                        )
+                        .await?
+                {
+                    return Err(RepositoryError::new("username already used".into(), 400));
+                }
+                claimed.username = Some(username_normalized.clone());
+                replaced.username = user.username_normalized.clone();
             }
             user.username = username;
+            user.username_normalized = Some(username_normalized);
         }
         if let Some(email) = update.email {
-            self.repository.validate_new_email(&user.email, &email)?;
-            if self
+            let email_normalized = normalize_email(&email, &config.email_normalization);
+            if user.email_normalized.as_ref() != Some(&email_normalized) {
+                let claim = self.claim_email(&c_apporg, user_id, &email_normalized).await;
+                if let Err(e) = claim {
+                    self.release(&c_apporg, user_id, claimed).await;
+                    return Err(e);
+                }
+                claimed.email = Some(email_normalized.clone());
+                replaced.email = user.email_normalized.clone();
+            }
+            user.email = Some(email);
+            user.email_normalized = Some(email_normalized);
+        }
+        let mut profile = user.profile();
+        profile.merge(update.profile.into());
+        user.set_profile(profile);
+        if !update.metadata.is_empty() {
+            let mut metadata = metadata_from_column(user.metadata.as_deref());
+            for (key, value) in update.metadata {
+                if value.is_null() {
+                    metadata.remove(&key);
+                } else {
+                    metadata.insert(key, value);
+                }
+            }
+            if let Err(e) = config.validate_user_metadata(&metadata) {
+                self.release(&c_apporg, user_id, claimed).await;
+                return Err(RepositoryError::new(e, 400));
+            }
+            user.metadata = metadata_to_column(&metadata);
+        }
+        if let Err(e) = self.repository.update_user(user.clone()).await {
+            self.release(&c_apporg, user_id, claimed).await;
+            return Err(e);
+        }
+        self.release(&c_apporg, user_id, replaced).await;
+        if password_changed
+            && config
+                .password_policy
+                .as_ref()
+                .is_some_and(|policy| policy.history_size > 0)
+        {
+            let _ = self
                 .repository
-                .find_user_by_email(
-                    email.clone(),
-                    c_apporg.application_id,
+                .add_password_history(
                     c_apporg.organization_id,
+                    c_apporg.application_id,
+                    user_id,
+                    user.hashed_password.clone(),
                 )
-                .await?
-                .is_some()
-            {
-                return Err(RepositoryError::new("email already used".into(), 400));
-            }
-            user.email = Some(email);
+                .await;
         }
-        self.repository.update_user(user).await?;
+        // see CreateUserServiceImpl: search lagging behind is preferable to
+        // reporting a stored update as failed
+        let _ = self
+            .repository
+            .index_user(user, config.searchable_attributes())
+            .await;
         Ok(UpdateUsersResponse {
             massage: "success".to_string(),
         })
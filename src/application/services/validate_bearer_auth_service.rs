@@ -28,6 +28,7 @@ impl ValidateBearerAuthMiddlewareServiceImpl {
             return Err(RepositoryError {
                 message: "invalid credential format".to_string(),
                 code: 400,
+                details: None,
             });
         }
         Ok(parts)
@@ -53,6 +54,7 @@ impl ValidateBearerAuthMiddlewareService for ValidateBearerAuthMiddlewareService
             return Err(RepositoryError {
                 message: "no found".to_string(),
                 code: 404,
+                details: None,
             });
         };
         let decrypted = self
@@ -63,6 +65,7 @@ impl ValidateBearerAuthMiddlewareService for ValidateBearerAuthMiddlewareService
             return Err(RepositoryError {
                 message: "unauth".to_string(),
                 code: 401,
+                details: None,
             });
         }
         let clean_apporg = CleanAppOrgByClientId::from(apporg);
@@ -1,4 +1,9 @@
-use crate::domain::value_objects::app_config::AppConfig;
+use crate::domain::value_objects::{
+    app_config::{APP_CONFIG_VERSION, AppConfig},
+    identifier::EmailNormalization,
+    session_limit::SessionLimit,
+    token_lifetimes::TokenLifetimes,
+};
 use serde::Deserialize;
 use std::{fs, path::Path};
 #[derive(Debug, Deserialize, Clone)]
@@ -15,6 +20,27 @@ pub struct Config {
 
     #[serde(default)]
     pub application: ApplicationConfig,
+
+    #[serde(default)]
+    pub user_deletion: UserDeletionConfig,
+
+    #[serde(default)]
+    pub user_import: UserImportConfig,
+
+    #[serde(default)]
+    pub password_policy: PasswordPolicyConfig,
+
+    #[serde(default)]
+    pub migrations: MigrationsConfig,
+
+    #[serde(default)]
+    pub oauth: OAuthConfig,
+
+    #[serde(default)]
+    pub signing_keys: SigningKeysConfig,
+
+    #[serde(default)]
+    pub role_assignments: RoleAssignmentsConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -22,6 +48,29 @@ pub struct CoreConfig {
     pub secret: String,
     pub generate_core_org_app: bool,
     pub cache_ttl: u64,
+
+    #[serde(default)]
+    pub argon2: Argon2Config,
+}
+
+/// Cost of the Argon2id hashes written for new and rehashed passwords;
+/// existing hashes keep verifying with the parameters they were made with.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Argon2Config {
+    /// Memory in KiB.
+    pub memory_cost: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Config {
+    fn default() -> Self {
+        Self {
+            memory_cost: 19456,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -75,8 +124,16 @@ pub struct ApplicationConfig {
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
+            version: APP_CONFIG_VERSION,
             is_must_name_unique: false,
             can_allow_email_nullable: false,
+            email_normalization: EmailNormalization::default(),
+            user_metadata_schema: None,
+            redirect_uris: Vec::new(),
+            token_lifetimes: TokenLifetimes::default(),
+            session_limit: SessionLimit::default(),
+            identity_providers: Vec::new(),
+            password_policy: None,
         }
     }
 }
@@ -90,6 +147,127 @@ impl Default for ApplicationConfig {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserDeletionConfig {
+    pub retention_days: i64,
+    pub purge_interval: u64,
+}
+
+impl Default for UserDeletionConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: 30,
+            purge_interval: 3600,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserImportConfig {
+    /// Seconds an import report stays readable after its last update.
+    pub job_ttl: u64,
+}
+
+impl Default for UserImportConfig {
+    fn default() -> Self {
+        Self { job_ttl: 86400 }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PasswordPolicyConfig {
+    /// File with one breached or common password per line, checked when an
+    /// application's policy sets `reject_breached`.
+    pub breached_list_path: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MigrationsConfig {
+    /// Backfill normalized usernames/emails on startup and log collisions.
+    /// On by default: users without normalized columns can't be found by
+    /// username or email until it has run. Rows already up to date are
+    /// left alone, so rerunning it is safe.
+    pub normalize_user_identifiers: bool,
+}
+
+impl Default for MigrationsConfig {
+    fn default() -> Self {
+        Self {
+            normalize_user_identifiers: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OAuthConfig {
+    /// Seconds an authorization code stays redeemable.
+    pub authorization_code_ttl: u64,
+    /// Seconds an access and ID token stays valid.
+    pub access_token_ttl: i64,
+    /// Public base URL, used as `iss` and to build the discovery document.
+    pub issuer: String,
+    /// Seconds a device code (RFC 8628) waits for the user's approval.
+    pub device_code_ttl: u64,
+    /// Seconds a device must wait between polls of the token endpoint.
+    pub device_poll_interval: u64,
+    /// Seconds a user has to finish signing in at an upstream provider.
+    pub federation_state_ttl: u64,
+    /// Seconds a rendered sign-in form can still be submitted.
+    pub csrf_token_ttl: u64,
+}
+
+impl Default for OAuthConfig {
+    fn default() -> Self {
+        Self {
+            authorization_code_ttl: 60,
+            access_token_ttl: 900,
+            issuer: "http://127.0.0.1:6969".to_string(),
+            device_code_ttl: 600,
+            device_poll_interval: 5,
+            federation_state_ttl: 600,
+            csrf_token_ttl: 600,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SigningKeysConfig {
+    /// Seconds an application key signs new tokens before it is replaced.
+    pub rotation_interval: i64,
+    /// Seconds a replaced key still verifies tokens. Keep it longer than the
+    /// refresh token lifetime so tokens signed just before rotation survive.
+    pub overlap: i64,
+    /// Seconds between rotation runs.
+    pub check_interval: u64,
+}
+
+impl Default for SigningKeysConfig {
+    fn default() -> Self {
+        Self {
+            rotation_interval: 2_592_000,
+            overlap: 2_678_400,
+            check_interval: 3600,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RoleAssignmentsConfig {
+    /// Seconds between runs reporting lapsed time-bound assignments. Role
+    /// lookups drop a lapsed assignment right away, but cached effective
+    /// permissions and the lapse event can trail it by up to this long.
+    pub expiry_check_interval: u64,
+}
+
+impl Default for RoleAssignmentsConfig {
+    fn default() -> Self {
+        Self {
+            expiry_check_interval: 60,
+        }
+    }
+}
+
 fn interpolate_env_vars(yaml: &str) -> String {
     let re = regex::Regex::new(r"\$\{([A-Z0-9_]+)\}").unwrap();
     re.replace_all(yaml, |caps: &regex::Captures| {
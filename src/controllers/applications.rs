@@ -0,0 +1,40 @@
+use crate::{
+    application::controllers::application_handle::{
+        get_application_config_handle, update_application_config_handle,
+        update_identity_providers_handle, update_redirect_uris_handle,
+    },
+    setup::Container,
+};
+use actix_web::web::{self, ServiceConfig};
+use std::sync::Arc;
+
+pub fn configure(cfg: &mut ServiceConfig, container: Arc<Container>) {
+    let middleware = container.validate_bearer_auth_middleware_service.clone();
+
+    cfg.service(
+        web::scope("/applications")
+            .app_data(web::Data::from(
+                container.update_redirect_uris_service.clone(),
+            ))
+            .app_data(web::Data::from(
+                container.update_identity_providers_service.clone(),
+            ))
+            .app_data(web::Data::from(
+                container.application_config_service.clone(),
+            ))
+            .wrap(middleware)
+            .route("/redirect_uris", web::put().to(update_redirect_uris_handle))
+            .route(
+                "/identity_providers",
+                web::put().to(update_identity_providers_handle),
+            )
+            .route(
+                "/{application_id}/config",
+                web::get().to(get_application_config_handle),
+            )
+            .route(
+                "/{application_id}/config",
+                web::patch().to(update_application_config_handle),
+            ),
+    );
+}
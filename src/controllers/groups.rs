@@ -0,0 +1,47 @@
+use crate::{
+    application::controllers::group_handle::{
+        add_group_member_handler, assign_group_role_handler, create_group_handler,
+        delete_group_handler, get_group_handler, get_group_members_handler, get_groups_handler,
+        remove_group_member_handler, remove_group_role_handler, update_group_handler,
+    },
+    setup::Container,
+};
+use actix_web::web::{self, ServiceConfig};
+use std::sync::Arc;
+
+pub fn configure(cfg: &mut ServiceConfig, container: Arc<Container>) {
+    let middleware = container.validate_bearer_auth_middleware_service.clone();
+
+    cfg.service(
+        web::scope("/groups")
+            .app_data(web::Data::from(container.manage_groups_service.clone()))
+            .app_data(web::Data::from(container.group_members_service.clone()))
+            .app_data(web::Data::from(container.group_roles_service.clone()))
+            .wrap(middleware)
+            .route("", web::post().to(create_group_handler))
+            .route("", web::get().to(get_groups_handler))
+            .route("/{group_id}", web::get().to(get_group_handler))
+            .route("/{group_id}", web::patch().to(update_group_handler))
+            .route("/{group_id}", web::delete().to(delete_group_handler))
+            .route(
+                "/{group_id}/members",
+                web::get().to(get_group_members_handler),
+            )
+            .route(
+                "/{group_id}/members",
+                web::post().to(add_group_member_handler),
+            )
+            .route(
+                "/{group_id}/members/{user_id}",
+                web::delete().to(remove_group_member_handler),
+            )
+            .route(
+                "/{group_id}/roles",
+                web::post().to(assign_group_role_handler),
+            )
+            .route(
+                "/{group_id}/roles/{role_id}",
+                web::delete().to(remove_group_role_handler),
+            ),
+    );
+}
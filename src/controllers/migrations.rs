@@ -0,0 +1,32 @@
+use crate::setup::Container;
+use std::sync::Arc;
+
+pub struct MigrationsImpl {
+    container: Arc<Container>,
+}
+
+impl MigrationsImpl {
+    pub fn new(container: Arc<Container>) -> Self {
+        Self { container }
+    }
+
+    pub async fn normalize_user_identifiers(self) {
+        match self.container.normalize_identifiers_service.execute().await {
+            Ok(report) => {
+                println!(
+                    "normalized user identifiers: scanned {}, updated {}, collisions {}",
+                    report.scanned,
+                    report.updated,
+                    report.collisions.len()
+                );
+                for c in report.collisions {
+                    eprintln!(
+                        "identifier collision: {} {:?} of user {} (org {}, app {})",
+                        c.field, c.value, c.user_id, c.organization_id, c.application_id
+                    );
+                }
+            }
+            Err(e) => eprintln!("normalizing user identifiers failed: {}", e.message),
+        }
+    }
+}
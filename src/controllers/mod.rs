@@ -1,8 +1,18 @@
+pub mod applications;
 pub mod cdc;
 pub mod consumers;
+pub mod groups;
 pub mod hello;
+pub mod migrations;
+pub mod oauth;
+pub mod permissions;
+pub mod purge;
+pub mod role_expiry;
 pub mod refresh_token;
 pub mod roles;
+pub mod scim;
+pub mod service_accounts;
+pub mod signing_keys;
 pub mod users;
 use crate::setup::Container;
 use actix_web::App;
@@ -17,6 +27,12 @@ pub fn configure_routes(cfg: &mut ServiceConfig, container: Arc<Container>) {
     users::configure(cfg, container.clone());
     refresh_token::configure(cfg, container.clone());
     roles::configure(cfg, container.clone());
+    groups::configure(cfg, container.clone());
+    permissions::configure(cfg, container.clone());
+    service_accounts::configure(cfg, container.clone());
+    applications::configure(cfg, container.clone());
+    oauth::configure(cfg, container.clone());
+    scim::configure(cfg, container.clone());
 }
 
 pub fn create_router(
@@ -0,0 +1,69 @@
+use crate::{
+    application::controllers::oauth_handle::{
+        authorize_handle, authorize_submit_handle, device_authorization_handle,
+        device_verification_handle, device_verification_submit_handle, federation_callback_handle,
+        federation_start_handle, introspect_handle, jwks_handle, openid_configuration_handle,
+        paserk_handle, revoke_handle, token_handle, userinfo_handle,
+    },
+    setup::Container,
+};
+use actix_web::web::{self, ServiceConfig};
+use std::sync::Arc;
+
+pub fn configure(cfg: &mut ServiceConfig, container: Arc<Container>) {
+    let middleware = container.validate_bearer_auth_middleware_service.clone();
+
+    // registered ahead of the /oauth scope, which would otherwise claim these paths
+    cfg.service(
+        web::resource("/oauth/introspect")
+            .app_data(web::Data::from(container.introspect_service.clone()))
+            .wrap(middleware.clone())
+            .route(web::post().to(introspect_handle)),
+    );
+    cfg.service(
+        web::resource("/oauth/revoke")
+            .app_data(web::Data::from(container.revoke_token_service.clone()))
+            .wrap(middleware)
+            .route(web::post().to(revoke_handle)),
+    );
+    cfg.service(
+        web::scope("/oauth")
+            .app_data(web::Data::from(container.authorize_service.clone()))
+            .app_data(web::Data::from(container.token_service.clone()))
+            .app_data(web::Data::from(container.userinfo_service.clone()))
+            .app_data(web::Data::from(
+                container.device_authorization_service.clone(),
+            ))
+            .app_data(web::Data::from(container.federation_service.clone()))
+            .route("/authorize", web::get().to(authorize_handle))
+            .route("/authorize", web::post().to(authorize_submit_handle))
+            .route(
+                "/device_authorization",
+                web::post().to(device_authorization_handle),
+            )
+            .route("/device", web::get().to(device_verification_handle))
+            .route("/device", web::post().to(device_verification_submit_handle))
+            // ahead of the provider route, which would otherwise take "callback" as an id
+            .route(
+                "/federation/callback",
+                web::get().to(federation_callback_handle),
+            )
+            .route(
+                "/federation/{provider_id}",
+                web::get().to(federation_start_handle),
+            )
+            .route("/token", web::post().to(token_handle))
+            .route("/userinfo", web::get().to(userinfo_handle))
+            .route("/userinfo", web::post().to(userinfo_handle)),
+    );
+    cfg.service(
+        web::scope("/.well-known")
+            .app_data(web::Data::from(container.discovery_service.clone()))
+            .route(
+                "/openid-configuration",
+                web::get().to(openid_configuration_handle),
+            )
+            .route("/jwks.json", web::get().to(jwks_handle))
+            .route("/paserk.json", web::get().to(paserk_handle)),
+    );
+}
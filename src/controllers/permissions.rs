@@ -0,0 +1,30 @@
+use crate::{
+    application::controllers::permission_handle::{
+        create_permission_handler, delete_permission_handler, get_permission_handler,
+        get_permissions_handler, get_unresolved_roles_handler, update_permission_handler,
+    },
+    setup::Container,
+};
+use actix_web::web::{self, ServiceConfig};
+use std::sync::Arc;
+
+pub fn configure(cfg: &mut ServiceConfig, container: Arc<Container>) {
+    let middleware = container.validate_bearer_auth_middleware_service.clone();
+
+    cfg.service(
+        web::scope("/permissions")
+            .app_data(web::Data::from(
+                container.permission_catalog_service.clone(),
+            ))
+            .wrap(middleware)
+            .route("", web::post().to(create_permission_handler))
+            .route("", web::get().to(get_permissions_handler))
+            .route(
+                "/unresolved-roles",
+                web::get().to(get_unresolved_roles_handler),
+            )
+            .route("/{name}", web::get().to(get_permission_handler))
+            .route("/{name}", web::patch().to(update_permission_handler))
+            .route("/{name}", web::delete().to(delete_permission_handler)),
+    );
+}
@@ -0,0 +1,47 @@
+use crate::{config::UserDeletionConfig, setup::Container};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+pub struct UserPurgeJobImpl {
+    container: Arc<Container>,
+    cfg: UserDeletionConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl UserPurgeJobImpl {
+    pub fn new(
+        container: Arc<Container>,
+        cfg: UserDeletionConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            container,
+            cfg,
+            shutdown_rx,
+        }
+    }
+
+    pub async fn run(mut self) {
+        let mut interval = tokio::time::interval(Duration::from_secs(self.cfg.purge_interval));
+        loop {
+            tokio::select! {
+                _ = self.shutdown_rx.changed() => {
+                    println!("🔴 Shutdown signal received in user purge job");
+                    break;
+                }
+                _ = interval.tick() => {
+                    match self
+                        .container
+                        .purge_users_service
+                        .execute(self.cfg.retention_days)
+                        .await
+                    {
+                        Ok(0) => {}
+                        Ok(purged) => println!("purged {} deactivated users", purged),
+                        Err(e) => eprintln!("user purge failed: {}", e.message),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{config::RoleAssignmentsConfig, setup::Container};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+pub struct RoleExpiryJobImpl {
+    container: Arc<Container>,
+    cfg: RoleAssignmentsConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl RoleExpiryJobImpl {
+    pub fn new(
+        container: Arc<Container>,
+        cfg: RoleAssignmentsConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            container,
+            cfg,
+            shutdown_rx,
+        }
+    }
+
+    pub async fn run(mut self) {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.cfg.expiry_check_interval));
+        loop {
+            tokio::select! {
+                _ = self.shutdown_rx.changed() => {
+                    println!("🔴 Shutdown signal received in role expiry job");
+                    break;
+                }
+                _ = interval.tick() => {
+                    match self.container.expire_role_assignments_service.execute().await {
+                        Ok(0) => {}
+                        Ok(lapsed) => println!("{} role assignments lapsed", lapsed),
+                        Err(e) => eprintln!("role assignment expiry failed: {}", e.message),
+                    }
+                }
+            }
+        }
+    }
+}
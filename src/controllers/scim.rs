@@ -0,0 +1,60 @@
+use crate::{
+    application::controllers::scim_handle::{
+        create_group_handle, create_user_handle, delete_group_handle, delete_user_handle,
+        get_group_handle, get_user_handle, list_groups_handle, list_users_handle,
+        patch_group_handle, patch_user_handle, replace_group_handle, replace_user_handle,
+        resource_type_handle, resource_types_handle, schema_handle, schemas_handle,
+        service_provider_config_handle,
+    },
+    domain::errors::scim_errors::ScimError,
+    setup::Container,
+};
+use actix_web::web::{self, ServiceConfig};
+use std::sync::Arc;
+
+pub fn configure(cfg: &mut ServiceConfig, container: Arc<Container>) {
+    let middleware = container.validate_bearer_auth_middleware_service.clone();
+
+    // discovery is public and registered ahead of the /scim/v2 scope, which
+    // would otherwise claim these paths
+    cfg.service(
+        web::resource("/scim/v2/ServiceProviderConfig")
+            .route(web::get().to(service_provider_config_handle)),
+    );
+    cfg.service(web::resource("/scim/v2/Schemas").route(web::get().to(schemas_handle)));
+    cfg.service(web::resource("/scim/v2/Schemas/{id}").route(web::get().to(schema_handle)));
+    cfg.service(
+        web::resource("/scim/v2/ResourceTypes").route(web::get().to(resource_types_handle)),
+    );
+    cfg.service(
+        web::resource("/scim/v2/ResourceTypes/{id}").route(web::get().to(resource_type_handle)),
+    );
+    cfg.service(
+        web::scope("/scim/v2")
+            .app_data(web::Data::from(container.scim_users_service.clone()))
+            .app_data(web::Data::from(container.scim_groups_service.clone()))
+            // SCIM clients parse error bodies, so extractor failures use the SCIM shape too
+            .app_data(web::JsonConfig::default().error_handler(|err, _| {
+                ScimError::new(400, Some("invalidSyntax"), err.to_string()).into()
+            }))
+            .app_data(web::QueryConfig::default().error_handler(|err, _| {
+                ScimError::new(400, Some("invalidValue"), err.to_string()).into()
+            }))
+            .app_data(web::PathConfig::default().error_handler(|_, _| {
+                ScimError::new(404, None, "resource not found".to_string()).into()
+            }))
+            .wrap(middleware)
+            .route("/Users", web::get().to(list_users_handle))
+            .route("/Users", web::post().to(create_user_handle))
+            .route("/Users/{id}", web::get().to(get_user_handle))
+            .route("/Users/{id}", web::put().to(replace_user_handle))
+            .route("/Users/{id}", web::patch().to(patch_user_handle))
+            .route("/Users/{id}", web::delete().to(delete_user_handle))
+            .route("/Groups", web::get().to(list_groups_handle))
+            .route("/Groups", web::post().to(create_group_handle))
+            .route("/Groups/{id}", web::get().to(get_group_handle))
+            .route("/Groups/{id}", web::put().to(replace_group_handle))
+            .route("/Groups/{id}", web::patch().to(patch_group_handle))
+            .route("/Groups/{id}", web::delete().to(delete_group_handle)),
+    );
+}
@@ -0,0 +1,32 @@
+use crate::{
+    application::controllers::service_account_handle::{
+        create_service_account_handle, delete_service_account_handle, get_service_accounts_handle,
+    },
+    setup::Container,
+};
+use actix_web::web::{self, ServiceConfig};
+use std::sync::Arc;
+
+pub fn configure(cfg: &mut ServiceConfig, container: Arc<Container>) {
+    let middleware = container.validate_bearer_auth_middleware_service.clone();
+
+    cfg.service(
+        web::scope("/service-accounts")
+            .app_data(web::Data::from(
+                container.create_service_account_service.clone(),
+            ))
+            .app_data(web::Data::from(
+                container.get_service_accounts_service.clone(),
+            ))
+            .app_data(web::Data::from(
+                container.delete_service_account_service.clone(),
+            ))
+            .wrap(middleware)
+            .route("/", web::post().to(create_service_account_handle))
+            .route("/", web::get().to(get_service_accounts_handle))
+            .route(
+                "/{service_account_id}",
+                web::delete().to(delete_service_account_handle),
+            ),
+    );
+}
@@ -0,0 +1,47 @@
+use crate::{config::SigningKeysConfig, setup::Container};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+pub struct SigningKeyRotationJobImpl {
+    container: Arc<Container>,
+    cfg: SigningKeysConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl SigningKeyRotationJobImpl {
+    pub fn new(
+        container: Arc<Container>,
+        cfg: SigningKeysConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            container,
+            cfg,
+            shutdown_rx,
+        }
+    }
+
+    pub async fn run(mut self) {
+        let mut interval = tokio::time::interval(Duration::from_secs(self.cfg.check_interval));
+        loop {
+            tokio::select! {
+                _ = self.shutdown_rx.changed() => {
+                    println!("🔴 Shutdown signal received in signing key rotation job");
+                    break;
+                }
+                _ = interval.tick() => {
+                    match self
+                        .container
+                        .rotate_signing_keys_service
+                        .execute(self.cfg.rotation_interval, self.cfg.overlap)
+                        .await
+                    {
+                        Ok(0) => {}
+                        Ok(rotated) => println!("rotated signing keys of {} applications", rotated),
+                        Err(e) => eprintln!("signing key rotation failed: {}", e.message),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,15 @@
+use crate::application::controllers::refresh_token_handle::{
+    get_sessions_handle, revoke_all_sessions_handle,
+};
+use crate::application::controllers::role_handle::{
+    get_roles_by_user_handler, get_user_permissions_handler,
+};
 use crate::application::controllers::user_handle::{
     ban_user_handle, create_user_handle, delate_user_handle, disable_mfa_user_handle,
-    get_user_count_handle, get_user_handle, get_users_handle, unban_user_handle,
-    update_user_handle,
+    export_users_handle, get_import_job_handle, get_user_count_handle, get_user_handle,
+    get_users_handle, import_users_handle, unban_user_handle, update_user_handle,
 };
+use crate::domain::value_objects::user_transfer::MAX_IMPORT_SIZE;
 use crate::setup::Container;
 use actix_web::web::{self, ServiceConfig};
 use std::sync::Arc;
@@ -21,15 +28,40 @@ pub fn configure(cfg: &mut ServiceConfig, container: Arc<Container>) {
             .app_data(web::Data::from(container.ban_user_count_service.clone()))
             .app_data(web::Data::from(container.unban_user_count_service.clone()))
             .app_data(web::Data::from(container.disable_mfa_user_service.clone()))
+            .app_data(web::Data::from(container.get_sessions_service.clone()))
+            .app_data(web::Data::from(container.revoke_all_sessions_service.clone()))
+            .app_data(web::Data::from(container.import_users_service.clone()))
+            .app_data(web::Data::from(container.export_users_service.clone()))
+            .app_data(web::Data::from(container.get_roles_by_user_service.clone()))
+            .app_data(web::Data::from(
+                container.get_user_permissions_service.clone(),
+            ))
             .wrap(middleware)
             .route("", web::post().to(create_user_handle))
             .route("", web::get().to(get_users_handle))
             .route("/count", web::get().to(get_user_count_handle))
+            .service(
+                web::resource("/import")
+                    .app_data(web::PayloadConfig::new(MAX_IMPORT_SIZE))
+                    .route(web::post().to(import_users_handle)),
+            )
+            .route("/import/{job_id}", web::get().to(get_import_job_handle))
+            .route("/export", web::get().to(export_users_handle))
             .route("/{user_id}", web::get().to(get_user_handle))
             .route("/{user_id}", web::patch().to(update_user_handle))
             .route("/{user_id}", web::delete().to(delate_user_handle))
             .route("/{user_id}/ban", web::post().to(ban_user_handle))
             .route("/{user_id}/unban", web::post().to(unban_user_handle))
-            .route("/{user_id}/mfa", web::delete().to(disable_mfa_user_handle)),
+            .route("/{user_id}/mfa", web::delete().to(disable_mfa_user_handle))
+            .route("/{user_id}/sessions", web::get().to(get_sessions_handle))
+            .route("/{user_id}/roles", web::get().to(get_roles_by_user_handler))
+            .route(
+                "/{user_id}/permissions",
+                web::get().to(get_user_permissions_handler),
+            )
+            .route(
+                "/{user_id}/sessions/revoke-all",
+                web::post().to(revoke_all_sessions_handle),
+            ),
     );
 }
@@ -30,7 +30,7 @@ pub struct CleanAppOrgByClientId {
 
 impl HasAppConfig for CleanAppOrgByClientId {
     fn get_config(&self) -> Result<AppConfig, serde_json::Error> {
-        serde_json::from_str(&self.application_config)
+        AppConfig::from_document(&self.application_config)
     }
 
     fn set_config(&mut self, config: &AppConfig) -> Result<(), serde_json::Error> {
@@ -80,7 +80,7 @@ impl AppOrgByClientId {
 
 impl HasAppConfig for AppOrgByClientId {
     fn get_config(&self) -> Result<AppConfig, serde_json::Error> {
-        serde_json::from_str(&self.application_config)
+        AppConfig::from_document(&self.application_config)
     }
 
     fn set_config(&mut self, config: &AppConfig) -> Result<(), serde_json::Error> {
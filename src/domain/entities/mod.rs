@@ -6,5 +6,6 @@ pub mod refresh_token;
 pub mod role_by_app;
 pub mod role_user_by_role;
 pub mod role_user_by_user;
+pub mod service_account;
 pub mod user;
 pub mod user_organization;
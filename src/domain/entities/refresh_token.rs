@@ -1,3 +1,4 @@
+use crate::domain::value_objects::client_metadata::ClientMetadata;
 use uuid::Uuid;
 use scylla::value::CqlTimestamp;
 #[derive(Debug, Clone)]
@@ -12,4 +13,27 @@ pub struct RefreshToken {
     pub issued_at: CqlTimestamp,
     pub expires_at: CqlTimestamp,
     pub revoked: bool,
-}
\ No newline at end of file
+    pub last_used_at: CqlTimestamp,
+    pub session_started_at: CqlTimestamp,
+    pub client: ClientMetadata,
+    /// Scope granted when the session started; rotation keeps it.
+    pub scope: Option<String>,
+}
+
+impl RefreshToken {
+    pub fn with_client(mut self, client: ClientMetadata) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn with_scope(mut self, scope: Option<String>) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Rotation keeps the start of the session the token belongs to.
+    pub fn with_session_started_at(mut self, session_started_at: CqlTimestamp) -> Self {
+        self.session_started_at = session_started_at;
+        self
+    }
+}
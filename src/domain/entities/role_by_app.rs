@@ -10,6 +10,8 @@ pub struct RoleByApp {
     pub name: String,
     pub description: Option<String>,
     pub permissions: HashSet<String>,
+    /// Roles whose permissions this role inherits.
+    pub parent_role_ids: HashSet<Uuid>,
     pub created_at: CqlTimestamp,
     pub updated_at: CqlTimestamp,
 }
@@ -0,0 +1,64 @@
+use chrono::Utc;
+use rand_core::{OsRng, TryRngCore};
+use scylla::value::CqlTimestamp;
+use uuid::Uuid;
+
+/// A non-human identity of an application. It signs in with its own client
+/// id and secret through the `client_credentials` grant, and its
+/// `service_account_id` takes the place of a user id in role assignments.
+#[derive(Debug, Clone)]
+pub struct ServiceAccount {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub service_account_id: Uuid,
+    pub application_client_id: Uuid,
+    pub client_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub encrypted_client_secret: String,
+    pub is_active: bool,
+    pub created_at: CqlTimestamp,
+    pub updated_at: CqlTimestamp,
+}
+
+impl ServiceAccount {
+    pub fn new(
+        organization_id: Uuid,
+        application_id: Uuid,
+        application_client_id: Uuid,
+        name: String,
+        description: Option<String>,
+        encrypted_client_secret: String,
+    ) -> Self {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        Self {
+            organization_id,
+            application_id,
+            service_account_id: Uuid::new_v4(),
+            application_client_id,
+            client_id: Uuid::new_v4(),
+            name,
+            description,
+            encrypted_client_secret,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+    pub fn gen_client_secret() -> Result<Vec<u8>, rand_core::OsError> {
+        let mut secret = vec![0u8; 32];
+        OsRng.try_fill_bytes(&mut secret)?;
+        Ok(secret)
+    }
+
+    /// The secret handed to the caller: the AES key of the stored secret and
+    /// the base64 secret itself, joined like the parts of a client token.
+    pub fn client_secret(client_key: &str, encoded_secret: &str) -> String {
+        format!("{}.{}", client_key, encoded_secret)
+    }
+    pub fn split_client_secret(client_secret: &str) -> Option<(&str, &str)> {
+        client_secret
+            .split_once('.')
+            .filter(|(key, secret)| !key.is_empty() && !secret.is_empty())
+    }
+}
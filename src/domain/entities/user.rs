@@ -2,6 +2,17 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::value_objects::{
+    app_config::AppConfig,
+    identifier::{EmailNormalization, normalize_email, normalize_username},
+    user_profile::UserProfile,
+};
+use serde_json::{Map, Value};
+
+/// Stored in place of a hash for users who sign in elsewhere, e.g. through
+/// federation; no password verifies against it.
+pub const UNUSABLE_PASSWORD: &str = "!";
+
 pub trait UserValidation {
     fn validate_name(&self) -> bool;
 }
@@ -14,8 +25,15 @@ pub struct User {
 
     pub username: String,
     pub email: Option<String>,
+    pub username_normalized: String,
+    pub email_normalized: Option<String>,
     pub hashed_password: String,
 
+    #[serde(default)]
+    pub profile: UserProfile,
+    #[serde(default)]
+    pub metadata: Map<String, Value>,
+
     pub created_at: i64,
     pub updated_at: i64,
 
@@ -36,13 +54,21 @@ impl User {
         email: Option<String>,
     ) -> Self {
         let now = Utc::now().timestamp_millis();
+        let username_normalized = normalize_username(&username);
+        let email_normalized = email
+            .as_deref()
+            .map(|e| normalize_email(e, &EmailNormalization::default()));
         Self {
             user_id: Uuid::new_v4(),
             application_id,
             organization_id,
             username,
             email,
+            username_normalized,
+            email_normalized,
             hashed_password,
+            profile: UserProfile::default(),
+            metadata: Map::new(),
             created_at: now,
             updated_at: now,
             is_active: true,
@@ -63,6 +89,16 @@ impl User {
         self.email.clone().unwrap_or_default()
     }
 
+    /// Recomputes the lookup keys with the application's email rules; the
+    /// display forms in `username` and `email` are left untouched.
+    pub fn normalize(&mut self, config: &AppConfig) {
+        self.username_normalized = normalize_username(&self.username);
+        self.email_normalized = self
+            .email
+            .as_deref()
+            .map(|e| normalize_email(e, &config.email_normalization));
+    }
+
     pub fn touch_updated(&mut self) {
         self.updated_at = Utc::now().timestamp_millis();
     }
@@ -1 +1,2 @@
 pub mod repositories_errors;
+pub mod scim_errors;
@@ -1,3 +1,4 @@
+use crate::infrastructure::errors::{fulltext_search::FulltextSearchError, queue::ProducerError};
 use actix_web::{HttpResponse, ResponseError, http::StatusCode};
 use redis::RedisError;
 use rusty_paseto::prelude::{GenericBuilderError, GenericParserError, PasetoClaimError};
@@ -6,11 +7,14 @@ use scylla::errors::{
     PrepareError, RowsError,
 };
 use serde::Serialize;
+use serde_json::Value;
 use std::{fmt, string::FromUtf8Error};
 #[derive(Debug, Serialize)]
 pub struct CommonError {
     pub message: String,
     pub code: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Value>,
 }
 
 impl fmt::Display for CommonError {
@@ -26,6 +30,7 @@ impl CommonError {
             403 => StatusCode::FORBIDDEN,
             404 => StatusCode::NOT_FOUND,
             500 => StatusCode::INTERNAL_SERVER_ERROR,
+            502 => StatusCode::BAD_GATEWAY,
             _ => StatusCode::BAD_REQUEST,
         }
     }
@@ -35,17 +40,30 @@ impl CommonError {
 pub struct RepositoryError {
     pub message: String,
     pub code: i16,
+    /// Machine-readable context returned next to `message`, e.g. which
+    /// password policy rules failed.
+    pub details: Option<Value>,
 }
 
 impl RepositoryError {
     pub fn new(message: String, code: i16) -> Self {
-        Self { message, code }
+        Self {
+            message,
+            code,
+            details: None,
+        }
+    }
+
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
     }
 
     fn to_common(&self) -> CommonError {
         CommonError {
             message: self.message.clone(),
             code: self.code,
+            details: self.details.clone(),
         }
     }
 }
@@ -71,7 +89,11 @@ pub struct ApiError(CommonError);
 
 impl ApiError {
     pub fn new(message: String, code: i16) -> Self {
-        Self(CommonError { message, code })
+        Self(CommonError {
+            message,
+            code,
+            details: None,
+        })
     }
 }
 
@@ -232,8 +254,25 @@ impl From<RedisError> for RepositoryError {
         RepositoryError::new("caching error failed.".to_string(), 500)
     }
 }
+impl From<FulltextSearchError> for RepositoryError {
+    fn from(e: FulltextSearchError) -> Self {
+        println!("{e}");
+        RepositoryError::new("fulltext search failed.".to_string(), 500)
+    }
+}
+impl From<ProducerError> for RepositoryError {
+    fn from(e: ProducerError) -> Self {
+        println!("{e}");
+        RepositoryError::new("queue publish failed.".to_string(), 500)
+    }
+}
 impl From<serde_json::Error> for RepositoryError {
     fn from(_: serde_json::Error) -> Self {
         RepositoryError::new("convert error".to_string(), 500)
     }
 }
+impl From<reqwest::Error> for RepositoryError {
+    fn from(e: reqwest::Error) -> Self {
+        RepositoryError::new(format!("identity provider error: {e}"), 502)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::domain::{
+    errors::repositories_errors::RepositoryError,
+    value_objects::scim::{ERROR_SCHEMA, SCIM_CONTENT_TYPE},
+};
+use actix_web::{HttpResponse, ResponseError, http::StatusCode};
+use serde::Serialize;
+use std::fmt;
+
+/// `scimType` values (RFC 7644 3.12) a service can put in front of an error
+/// message, the same way OAuth errors carry their `error` code.
+const SCIM_TYPES: [&str; 8] = [
+    "invalidFilter",
+    "invalidPath",
+    "invalidSyntax",
+    "invalidValue",
+    "mutability",
+    "noTarget",
+    "tooMany",
+    "uniqueness",
+];
+
+/// A service error tagged with `scim_type`, e.g. `invalidFilter: ...`.
+pub fn scim_type_error(scim_type: &str, detail: &str) -> RepositoryError {
+    RepositoryError::new(format!("{scim_type}: {detail}"), 400)
+}
+
+/// Error body of the SCIM endpoints, which clients parse instead of
+/// [`ApiError`](super::repositories_errors::ApiError).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimError {
+    schemas: [&'static str; 1],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scim_type: Option<String>,
+    detail: String,
+    status: String,
+}
+
+impl ScimError {
+    pub fn new(code: u16, scim_type: Option<&str>, detail: String) -> Self {
+        Self {
+            schemas: [ERROR_SCHEMA],
+            scim_type: scim_type.map(str::to_string),
+            detail,
+            status: code.to_string(),
+        }
+    }
+}
+
+impl From<RepositoryError> for ScimError {
+    fn from(err: RepositoryError) -> Self {
+        let (scim_type, detail) = match err.message.split_once(": ") {
+            Some((scim_type, detail)) if SCIM_TYPES.contains(&scim_type) => {
+                (Some(scim_type), detail.to_string())
+            }
+            // raised by the user services shared with `/users`
+            _ if err.message.ends_with("already used") => (Some("uniqueness"), err.message),
+            _ => (None, err.message),
+        };
+        let code = match (scim_type, err.code) {
+            (Some("uniqueness"), _) => 409,
+            (_, code @ (401 | 403 | 404 | 500 | 502)) => code as u16,
+            _ => 400,
+        };
+        Self::new(code, scim_type, detail)
+    }
+}
+
+impl fmt::Display for ScimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error: {}, Status: {}", self.detail, self.status)
+    }
+}
+
+impl ResponseError for ScimError {
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type(SCIM_CONTENT_TYPE)
+            .json(self)
+    }
+
+    fn status_code(&self) -> StatusCode {
+        self.status
+            .parse()
+            .ok()
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .unwrap_or(StatusCode::BAD_REQUEST)
+    }
+}
@@ -1,15 +1,171 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::{
+    identifier::EmailNormalization,
+    identity_provider::{IdentityProvider, is_valid_provider_id},
+    metadata_schema::MetadataSchema,
+    password_policy::PasswordPolicy,
+    session_limit::SessionLimit,
+    token_lifetimes::TokenLifetimes,
+};
+
+/// Upper bound on the serialized metadata of one user.
+const MAX_USER_METADATA_BYTES: usize = 8 * 1024;
+const MAX_PASSWORD_LENGTH: usize = 1024;
+const MAX_PASSWORD_HISTORY: usize = 24;
+
+/// Version written by this build; bump it together with a new `MIGRATIONS`
+/// entry whenever stored documents need rewriting to keep their meaning.
+pub const APP_CONFIG_VERSION: u32 = 1;
+
+/// `MIGRATIONS[n]` turns a version `n` document into version `n + 1`.
+/// Fields that only gained a default don't need one, serde fills them in.
+const MIGRATIONS: [fn(&mut Map<String, Value>); APP_CONFIG_VERSION as usize] = [
+    // version 0 is every document written before versioning; an explicit
+    // null there meant "not set", which the defaults now express
+    |document| document.retain(|_, value| !value.is_null()),
+];
+
+/// Settings document of one application, stored as JSON next to it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
+    pub version: u32,
     pub is_must_name_unique: bool,
     pub can_allow_email_nullable: bool,
+    #[serde(default)]
+    pub email_normalization: EmailNormalization,
+    #[serde(default)]
+    pub user_metadata_schema: Option<MetadataSchema>,
+    #[serde(default)]
+    pub redirect_uris: Vec<String>,
+    #[serde(default)]
+    pub token_lifetimes: TokenLifetimes,
+    #[serde(default)]
+    pub session_limit: SessionLimit,
+    #[serde(default)]
+    pub identity_providers: Vec<IdentityProvider>,
+    /// Without a policy only the payload's 8 character minimum applies.
+    #[serde(default)]
+    pub password_policy: Option<PasswordPolicy>,
 }
 impl AppConfig {
+    /// Reads a stored document of any version up to `APP_CONFIG_VERSION`,
+    /// migrating older ones; newer ones are read as far as this build
+    /// understands them.
+    pub fn from_document(document: &str) -> Result<Self, serde_json::Error> {
+        let mut document: Map<String, Value> = serde_json::from_str(document)?;
+        Self::migrate(&mut document);
+        serde_json::from_value(Value::Object(document))
+    }
+
+    fn migrate(document: &mut Map<String, Value>) {
+        let version = document.get("version").and_then(Value::as_u64).unwrap_or(0);
+        if version >= APP_CONFIG_VERSION as u64 {
+            return;
+        }
+        for migration in &MIGRATIONS[version as usize..] {
+            migration(document);
+        }
+        document.insert("version".to_string(), APP_CONFIG_VERSION.into());
+    }
+
+    /// Checks the settings that can't be expressed in their types; every
+    /// problem is returned, not just the first.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let lifetimes = &self.token_lifetimes;
+        if lifetimes.refresh_token_lifetime <= 0 {
+            errors.push("token_lifetimes.refresh_token_lifetime must be positive".to_string());
+        }
+        if lifetimes.service_account_token_lifetime <= 0 {
+            errors.push(
+                "token_lifetimes.service_account_token_lifetime must be positive".to_string(),
+            );
+        }
+        for (name, value) in [
+            ("idle_timeout", lifetimes.idle_timeout),
+            ("absolute_lifetime", lifetimes.absolute_lifetime),
+            ("not_before_offset", lifetimes.not_before_offset),
+            ("leeway", lifetimes.leeway),
+        ] {
+            if value < 0 {
+                errors.push(format!("token_lifetimes.{name} must not be negative"));
+            }
+        }
+        if let Some(policy) = &self.password_policy {
+            if policy.min_length < 8 {
+                errors.push("password_policy.min_length must be at least 8".to_string());
+            }
+            if policy.max_length < policy.min_length || policy.max_length > MAX_PASSWORD_LENGTH {
+                errors.push(format!(
+                    "password_policy.max_length must be between min_length and {MAX_PASSWORD_LENGTH}"
+                ));
+            }
+            if policy.history_size > MAX_PASSWORD_HISTORY {
+                errors.push(format!(
+                    "password_policy.history_size must be at most {MAX_PASSWORD_HISTORY}"
+                ));
+            }
+        }
+        for (index, provider) in self.identity_providers.iter().enumerate() {
+            if !is_valid_provider_id(&provider.id) {
+                errors.push(format!("{} is not a valid provider id", provider.id));
+            }
+            if self.identity_providers[..index]
+                .iter()
+                .any(|p| p.id == provider.id)
+            {
+                errors.push(format!("provider id {} is used twice", provider.id));
+            }
+        }
+        errors
+    }
+
     pub fn new(is_must_name_unique: bool, can_allow_email_nullable: bool) -> Self {
         Self {
+            version: APP_CONFIG_VERSION,
             is_must_name_unique,
             can_allow_email_nullable,
+            email_normalization: EmailNormalization::default(),
+            user_metadata_schema: None,
+            redirect_uris: Vec::new(),
+            token_lifetimes: TokenLifetimes::default(),
+            session_limit: SessionLimit::default(),
+            identity_providers: Vec::new(),
+            password_policy: None,
+        }
+    }
+
+    /// Redirect URIs are compared verbatim, as required for OAuth clients.
+    pub fn is_redirect_uri_registered(&self, redirect_uri: &str) -> bool {
+        self.redirect_uris.iter().any(|uri| uri == redirect_uri)
+    }
+
+    pub fn find_identity_provider(&self, provider_id: &str) -> Option<&IdentityProvider> {
+        self.identity_providers.iter().find(|p| p.id == provider_id)
+    }
+
+    pub fn validate_user_metadata(&self, metadata: &Map<String, Value>) -> Result<(), String> {
+        let size = serde_json::to_vec(metadata).map(|m| m.len()).unwrap_or(0);
+        if size > MAX_USER_METADATA_BYTES {
+            return Err(format!(
+                "metadata must be at most {} bytes",
+                MAX_USER_METADATA_BYTES
+            ));
         }
+        match &self.user_metadata_schema {
+            Some(schema) => schema.validate(metadata),
+            None => Ok(()),
+        }
+    }
+
+    pub fn searchable_attributes(&self) -> Vec<String> {
+        self.user_metadata_schema
+            .as_ref()
+            .map(|s| s.searchable_attributes())
+            .unwrap_or_default()
     }
 }
 impl ToString for AppConfig {
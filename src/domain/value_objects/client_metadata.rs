@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// What we know about the device a session was issued to. Values are
+/// informational only: they come from the caller and are never trusted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClientMetadata {
+    pub device_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl ClientMetadata {
+    const MAX_FIELD_LEN: usize = 512;
+
+    /// Fills every missing field from `fallback`.
+    pub fn or(self, fallback: ClientMetadata) -> Self {
+        Self {
+            device_name: self.device_name.or(fallback.device_name),
+            ip_address: self.ip_address.or(fallback.ip_address),
+            user_agent: self.user_agent.or(fallback.user_agent),
+        }
+    }
+
+    /// Drops blank values and caps the length of the rest so a client cannot
+    /// bloat the session rows.
+    pub fn sanitized(self) -> Self {
+        let clean = |value: Option<String>| {
+            value
+                .map(|v| {
+                    v.trim()
+                        .chars()
+                        .take(Self::MAX_FIELD_LEN)
+                        .collect::<String>()
+                })
+                .filter(|v| !v.is_empty())
+        };
+        Self {
+            device_name: clean(self.device_name),
+            ip_address: clean(self.ip_address),
+            user_agent: clean(self.user_agent),
+        }
+    }
+}
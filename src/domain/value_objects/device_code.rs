@@ -0,0 +1,26 @@
+/// `grant_type` a device polls `/oauth/token` with.
+pub const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// RFC 8628 6.1: consonants only, so codes cannot spell words, and no
+/// easily confused letters.
+pub const USER_CODE_ALPHABET: &[u8] = b"BCDFGHJKLMNPQRSTVWXZ";
+pub const USER_CODE_LENGTH: usize = 8;
+
+/// `BDFHJKLM` is shown to the user as `BDFH-JKLM`.
+pub fn format_user_code(code: &str) -> String {
+    let (head, tail) = code.split_at(code.len() / 2);
+    format!("{head}-{tail}")
+}
+
+/// Accepts what a user is likely to type: any case, with or without the
+/// dash and surrounding spaces.
+pub fn normalize_user_code(input: &str) -> Option<String> {
+    let code: String = input
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let valid =
+        code.len() == USER_CODE_LENGTH && code.bytes().all(|b| USER_CODE_ALPHABET.contains(&b));
+    valid.then_some(code)
+}
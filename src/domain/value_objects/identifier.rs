@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// Per-application rules for treating different spellings of an email
+/// address as the same mailbox, the way Gmail does.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmailNormalization {
+    /// `j.doe@example.com` and `jdoe@example.com` are the same address.
+    #[serde(default)]
+    pub ignore_dots: bool,
+    /// `jdoe+news@example.com` and `jdoe@example.com` are the same address.
+    #[serde(default)]
+    pub ignore_plus_tag: bool,
+}
+
+/// NFKC with case folding, so visually identical names compare equal.
+fn fold(value: &str) -> String {
+    value
+        .trim()
+        .nfkc()
+        .collect::<String>()
+        .to_lowercase()
+        .nfkc()
+        .collect()
+}
+
+pub fn normalize_username(username: &str) -> String {
+    fold(username)
+}
+
+pub fn normalize_email(email: &str, rules: &EmailNormalization) -> String {
+    let email = fold(email);
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return email;
+    };
+    let mut local = local;
+    if rules.ignore_plus_tag {
+        local = local.split('+').next().unwrap_or(local);
+    }
+    if rules.ignore_dots {
+        return format!("{}@{}", local.replace('.', ""), domain);
+    }
+    format!("{}@{}", local, domain)
+}
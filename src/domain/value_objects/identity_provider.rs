@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+const MAX_PROVIDER_ID_LENGTH: usize = 32;
+/// Path segment taken by the federation callback route.
+const RESERVED_PROVIDER_IDS: [&str; 1] = ["callback"];
+
+pub fn default_scopes() -> Vec<String> {
+    ["openid", "email", "profile"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// An upstream OIDC provider the users of an application can sign in with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProvider {
+    /// Short name used in URLs and to key linked identities; changing it
+    /// orphans the identities linked so far.
+    pub id: String,
+    /// Shown on the sign-in button.
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    /// `nonce.ciphertext`, encrypted with the core secret.
+    pub encrypted_client_secret: String,
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<String>,
+    /// On first sign-in, link to an existing user with the same email when
+    /// the provider reports it as verified, instead of creating a new user.
+    #[serde(default)]
+    pub link_by_email: bool,
+}
+
+impl IdentityProvider {
+    /// Scopes to request upstream; `openid` is always included.
+    pub fn scope(&self) -> String {
+        let mut scopes = vec!["openid"];
+        scopes.extend(
+            self.scopes
+                .iter()
+                .map(String::as_str)
+                .filter(|s| *s != "openid"),
+        );
+        scopes.join(" ")
+    }
+}
+
+pub fn is_valid_provider_id(id: &str) -> bool {
+    (1..=MAX_PROVIDER_ID_LENGTH).contains(&id.len())
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_')
+        && !RESERVED_PROVIDER_IDS.contains(&id)
+}
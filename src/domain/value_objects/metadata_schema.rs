@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttributeType {
+    String,
+    Number,
+    Integer,
+    Boolean,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttributeDefinition {
+    #[serde(rename = "type")]
+    pub kind: AttributeType,
+    #[serde(default)]
+    pub required: bool,
+    /// Indexed into the search engine alongside the user.
+    #[serde(default)]
+    pub searchable: bool,
+    #[serde(default)]
+    pub max_length: Option<usize>,
+}
+
+/// Declares the metadata attributes an application accepts on its users.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetadataSchema {
+    #[serde(default)]
+    pub properties: HashMap<String, AttributeDefinition>,
+    /// Whether keys that are not declared in `properties` are accepted.
+    #[serde(default)]
+    pub additional_properties: bool,
+}
+
+impl AttributeDefinition {
+    fn check(&self, name: &str, value: &Value) -> Result<(), String> {
+        let matches = match self.kind {
+            AttributeType::String => value.is_string(),
+            AttributeType::Number => value.is_number(),
+            AttributeType::Integer => value.is_i64() || value.is_u64(),
+            AttributeType::Boolean => value.is_boolean(),
+        };
+        if !matches {
+            return Err(format!("metadata.{} must be a {:?}", name, self.kind).to_lowercase());
+        }
+        if let (Some(max), Some(s)) = (self.max_length, value.as_str())
+            && s.chars().count() > max
+        {
+            return Err(format!("metadata.{} is longer than {}", name, max));
+        }
+        Ok(())
+    }
+}
+
+impl MetadataSchema {
+    pub fn validate(&self, metadata: &Map<String, Value>) -> Result<(), String> {
+        for (name, definition) in &self.properties {
+            match metadata.get(name) {
+                None | Some(Value::Null) if definition.required => {
+                    return Err(format!("metadata.{} is required", name));
+                }
+                None | Some(Value::Null) => {}
+                Some(value) => definition.check(name, value)?,
+            }
+        }
+        if !self.additional_properties
+            && let Some(name) = metadata.keys().find(|k| !self.properties.contains_key(*k))
+        {
+            return Err(format!("metadata.{} is not declared", name));
+        }
+        Ok(())
+    }
+
+    pub fn searchable_attributes(&self) -> Vec<String> {
+        self.properties
+            .iter()
+            .filter(|(_, d)| d.searchable)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
@@ -1 +1,15 @@
 pub mod app_config;
+pub mod client_metadata;
+pub mod device_code;
+pub mod identifier;
+pub mod identity_provider;
+pub mod metadata_schema;
+pub mod password_policy;
+pub mod permission_catalog;
+pub mod pkce;
+pub mod role_hierarchy;
+pub mod scim;
+pub mod session_limit;
+pub mod token_lifetimes;
+pub mod user_profile;
+pub mod user_transfer;
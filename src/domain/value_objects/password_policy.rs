@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::domain::errors::repositories_errors::RepositoryError;
+
+/// Shorter usernames or email local parts would match too many passwords.
+const MIN_IDENTIFIER_LEN: usize = 3;
+
+/// Whether a character class is required, how to spot it, the rule it
+/// reports and how it's named in the message.
+type CharClass = (bool, fn(char) -> bool, PasswordRule, &'static str);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordRule {
+    MinLength,
+    MaxLength,
+    Uppercase,
+    Lowercase,
+    Digit,
+    Symbol,
+    ContainsIdentifier,
+    Breached,
+    Reused,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PasswordViolation {
+    pub rule: PasswordRule,
+    pub message: String,
+}
+
+impl PasswordViolation {
+    fn new(rule: PasswordRule, message: impl Into<String>) -> Self {
+        Self {
+            rule,
+            message: message.into(),
+        }
+    }
+
+    pub fn breached() -> Self {
+        Self::new(
+            PasswordRule::Breached,
+            "password is too common or has appeared in a breach",
+        )
+    }
+
+    pub fn reused(history_size: usize) -> Self {
+        Self::new(
+            PasswordRule::Reused,
+            format!("password must differ from the last {history_size} passwords"),
+        )
+    }
+}
+
+/// One 400 listing every failed rule, so a client can show them all at once.
+pub fn password_policy_error(violations: Vec<PasswordViolation>) -> RepositoryError {
+    RepositoryError::new("password does not meet the policy".to_string(), 400)
+        .with_details(json!({ "violations": violations }))
+}
+
+/// Password rules of one application, on top of the 8 characters every
+/// payload already requires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    /// Both lengths count characters, not bytes.
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    /// Anything that is not a letter or a digit.
+    pub require_symbol: bool,
+    /// Rejects passwords containing the username or the email's local part,
+    /// compared case-insensitively.
+    pub reject_identifiers: bool,
+    /// Rejects passwords on the server's breached/common password list.
+    pub reject_breached: bool,
+    /// How many recent passwords, the current one included, can't be set
+    /// again; 0 disables.
+    pub history_size: usize,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_symbol: false,
+            reject_identifiers: true,
+            reject_breached: true,
+            history_size: 0,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Checks the rules that only need the password and the user's
+    /// identifiers; the breached list and history are checked by the caller.
+    pub fn check(
+        &self,
+        password: &str,
+        username: &str,
+        email: Option<&str>,
+    ) -> Vec<PasswordViolation> {
+        let mut violations = Vec::new();
+        let length = password.chars().count();
+        if length < self.min_length {
+            violations.push(PasswordViolation::new(
+                PasswordRule::MinLength,
+                format!("password must be at least {} characters", self.min_length),
+            ));
+        }
+        if length > self.max_length {
+            violations.push(PasswordViolation::new(
+                PasswordRule::MaxLength,
+                format!("password must be at most {} characters", self.max_length),
+            ));
+        }
+        let classes: [CharClass; 4] = [
+            (
+                self.require_uppercase,
+                char::is_uppercase,
+                PasswordRule::Uppercase,
+                "an uppercase letter",
+            ),
+            (
+                self.require_lowercase,
+                char::is_lowercase,
+                PasswordRule::Lowercase,
+                "a lowercase letter",
+            ),
+            (
+                self.require_digit,
+                |c: char| c.is_ascii_digit(),
+                PasswordRule::Digit,
+                "a digit",
+            ),
+            (
+                self.require_symbol,
+                |c: char| !c.is_alphanumeric(),
+                PasswordRule::Symbol,
+                "a symbol",
+            ),
+        ];
+        for (required, matches, rule, name) in classes {
+            if required && !password.chars().any(matches) {
+                violations.push(PasswordViolation::new(
+                    rule,
+                    format!("password must contain {name}"),
+                ));
+            }
+        }
+        if self.reject_identifiers && contains_identifier(password, username, email) {
+            violations.push(PasswordViolation::new(
+                PasswordRule::ContainsIdentifier,
+                "password must not contain the username or email",
+            ));
+        }
+        violations
+    }
+}
+
+fn contains_identifier(password: &str, username: &str, email: Option<&str>) -> bool {
+    let password = password.to_lowercase();
+    let local_part = email.map(|email| email.split('@').next().unwrap_or(email));
+    [Some(username), local_part]
+        .into_iter()
+        .flatten()
+        .map(str::to_lowercase)
+        .filter(|identifier| identifier.chars().count() >= MIN_IDENTIFIER_LEN)
+        .any(|identifier| password.contains(&identifier))
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeSet;
+
+const MAX_PERMISSION_NAME_LENGTH: usize = 128;
+
+/// Permissions an application declares. Roles may reference a declared
+/// name or a wildcard pattern: `*` alone, or a prefix ending in `:*` such as
+/// `users:*`, which covers every declared permission under that prefix.
+#[derive(Default)]
+pub struct PermissionCatalog {
+    names: BTreeSet<String>,
+}
+
+impl PermissionCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, name: String) {
+        self.names.insert(name);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Whether `name` is the only declared permission.
+    pub fn is_only(&self, name: &str) -> bool {
+        self.names.len() == 1 && self.names.contains(name)
+    }
+
+    /// Whether `permission` is declared, or is a wildcard covering at least
+    /// one declared permission.
+    pub fn resolves(&self, permission: &str) -> bool {
+        match permission.strip_suffix('*') {
+            Some(prefix) if is_wildcard_prefix(prefix) => {
+                self.names.iter().any(|name| name.starts_with(prefix))
+            }
+            Some(_) => false,
+            None => self.names.contains(permission),
+        }
+    }
+
+    /// Whether removing `name` would leave any of `permissions` unchecked:
+    /// one of them only resolves through `name`, or `name` is the last entry
+    /// and the catalog would go back to accepting everything.
+    pub fn needed_by<'a>(
+        &self,
+        name: &str,
+        permissions: impl IntoIterator<Item = &'a String>,
+    ) -> bool {
+        let mut remaining = Self {
+            names: self.names.clone(),
+        };
+        remaining.names.remove(name);
+        permissions.into_iter().any(|permission| {
+            remaining.is_empty() || (self.resolves(permission) && !remaining.resolves(permission))
+        })
+    }
+
+    /// Permissions that don't resolve, sorted. An application that hasn't
+    /// declared any permission accepts everything, as it did before catalogs
+    /// existed.
+    pub fn unknown<'a>(&self, permissions: impl IntoIterator<Item = &'a String>) -> Vec<String> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        permissions
+            .into_iter()
+            .filter(|permission| !self.resolves(permission))
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+fn is_wildcard_prefix(prefix: &str) -> bool {
+    prefix.is_empty() || (prefix.ends_with(':') && !prefix.contains('*'))
+}
+
+/// Catalog entries are `:`-separated segments of ASCII letters, digits, `_`,
+/// `-` or `.`; wildcards only make sense in roles.
+pub fn validate_permission_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > MAX_PERMISSION_NAME_LENGTH {
+        return Err(format!(
+            "permission name must be 1 to {MAX_PERMISSION_NAME_LENGTH} characters"
+        ));
+    }
+    let valid = name.split(':').all(|segment| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    });
+    if !valid {
+        return Err(format!("{name} is not a valid permission name"));
+    }
+    Ok(())
+}
@@ -0,0 +1,11 @@
+/// The only PKCE transform we accept; `plain` would let an intercepted
+/// authorization request be replayed as-is.
+pub const CODE_CHALLENGE_METHOD_S256: &str = "S256";
+
+/// RFC 7636 4.1: 43 to 128 characters from the unreserved URI set.
+pub fn is_valid_pkce_value(value: &str) -> bool {
+    (43..=128).contains(&value.len())
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~'))
+}
@@ -0,0 +1,103 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use uuid::Uuid;
+
+struct RoleNode {
+    parent_role_ids: HashSet<Uuid>,
+    permissions: HashSet<String>,
+}
+
+/// The roles of one application and the parents each inherits from. Parents
+/// that no longer exist are ignored, so deleting a role never breaks the
+/// roles below it.
+#[derive(Default)]
+pub struct RoleHierarchy {
+    roles: HashMap<Uuid, RoleNode>,
+}
+
+impl RoleHierarchy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(
+        &mut self,
+        role_id: Uuid,
+        parent_role_ids: HashSet<Uuid>,
+        permissions: HashSet<String>,
+    ) {
+        self.roles.insert(
+            role_id,
+            RoleNode {
+                parent_role_ids,
+                permissions,
+            },
+        );
+    }
+
+    /// `role_id` and every role it inherits from, directly or not.
+    pub fn ancestors(&self, role_id: Uuid) -> HashSet<Uuid> {
+        let mut seen = HashSet::new();
+        let mut pending = vec![role_id];
+        while let Some(id) = pending.pop() {
+            let Some(node) = self.roles.get(&id) else {
+                continue;
+            };
+            if seen.insert(id) {
+                pending.extend(node.parent_role_ids.iter().copied());
+            }
+        }
+        seen
+    }
+
+    /// `role_id` and every role inheriting from it, directly or not.
+    pub fn descendants(&self, role_id: Uuid) -> HashSet<Uuid> {
+        let mut seen = HashSet::from([role_id]);
+        let mut pending = vec![role_id];
+        while let Some(id) = pending.pop() {
+            for (child_id, node) in &self.roles {
+                if node.parent_role_ids.contains(&id) && seen.insert(*child_id) {
+                    pending.push(*child_id);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Permissions granted by the given roles and everything they inherit.
+    pub fn effective_permissions(
+        &self,
+        role_ids: impl IntoIterator<Item = Uuid>,
+    ) -> BTreeSet<String> {
+        let mut roles = HashSet::new();
+        for role_id in role_ids {
+            roles.extend(self.ancestors(role_id));
+        }
+        roles
+            .iter()
+            .filter_map(|id| self.roles.get(id))
+            .flat_map(|node| node.permissions.iter().cloned())
+            .collect()
+    }
+
+    /// Checks that `role_id` can inherit from `parent_role_ids`: every parent
+    /// exists and none of them already inherits from `role_id`.
+    pub fn validate_parents(
+        &self,
+        role_id: Uuid,
+        parent_role_ids: &HashSet<Uuid>,
+    ) -> Result<(), String> {
+        for parent_id in parent_role_ids {
+            if *parent_id == role_id {
+                return Err("a role can't inherit from itself".to_string());
+            }
+            if !self.roles.contains_key(parent_id) {
+                return Err(format!("parent role {parent_id} does not exist"));
+            }
+            if self.ancestors(*parent_id).contains(&role_id) {
+                return Err(format!("inheriting from {parent_id} would create a cycle"));
+            }
+        }
+        Ok(())
+    }
+}
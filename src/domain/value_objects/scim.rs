@@ -0,0 +1,89 @@
+pub const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const PATCH_OP_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+pub const ERROR_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:Error";
+pub const SERVICE_PROVIDER_CONFIG_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig";
+pub const SCHEMA_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Schema";
+pub const RESOURCE_TYPE_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:ResourceType";
+
+pub const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+
+/// Largest page a list request can ask for, advertised as `filter.maxResults`.
+pub const MAX_RESULTS: usize = 200;
+
+/// `attribute eq "value"`, the only filter provisioning clients need to look
+/// up a resource before creating it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EqFilter {
+    pub attribute: String,
+    pub value: String,
+}
+
+impl EqFilter {
+    pub fn parse(filter: &str) -> Option<Self> {
+        let (attribute, rest) = filter.trim().split_once(char::is_whitespace)?;
+        let (operator, value) = rest.trim_start().split_once(char::is_whitespace)?;
+        if !operator.eq_ignore_ascii_case("eq") {
+            return None;
+        }
+        // RFC 7644 3.4.2.2: string values are JSON strings
+        let value: String = serde_json::from_str(value.trim()).ok()?;
+        Some(Self {
+            attribute: attribute.to_string(),
+            value,
+        })
+    }
+
+    pub fn is_on(&self, attribute: &str) -> bool {
+        self.attribute.eq_ignore_ascii_case(attribute)
+    }
+}
+
+/// A PATCH `path`: `attr`, `attr.sub`, `attr[filter]` or `attr[filter].sub`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchPath {
+    pub attribute: String,
+    pub filter: Option<EqFilter>,
+    pub sub_attribute: Option<String>,
+}
+
+impl PatchPath {
+    pub fn parse(path: &str) -> Option<Self> {
+        let path = path.trim();
+        let (attribute, filter, sub_attribute) = match path.split_once('[') {
+            Some((attribute, rest)) => {
+                let (filter, rest) = rest.split_once(']')?;
+                let sub_attribute = match rest {
+                    "" => None,
+                    rest => Some(rest.strip_prefix('.')?),
+                };
+                (attribute, Some(EqFilter::parse(filter)?), sub_attribute)
+            }
+            None => match path.split_once('.') {
+                Some((attribute, sub_attribute)) => (attribute, None, Some(sub_attribute)),
+                None => (path, None, None),
+            },
+        };
+        if attribute.is_empty() || sub_attribute == Some("") {
+            return None;
+        }
+        let sub_attribute = sub_attribute.map(str::to_string);
+        Some(Self {
+            attribute: attribute.to_string(),
+            filter,
+            sub_attribute,
+        })
+    }
+
+    pub fn is(&self, attribute: &str) -> bool {
+        self.attribute.eq_ignore_ascii_case(attribute)
+    }
+
+    pub fn sub_is(&self, sub_attribute: &str) -> bool {
+        self.sub_attribute
+            .as_deref()
+            .is_some_and(|sub| sub.eq_ignore_ascii_case(sub_attribute))
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// What happens when a user signs in while already at `max_sessions`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionLimitAction {
+    /// Refuse the new sign-in.
+    #[default]
+    Reject,
+    /// Sign out the session that was refreshed longest ago.
+    RevokeLeastRecentlyUsed,
+}
+
+/// Cap on concurrent refresh-token sessions per user of one application.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionLimit {
+    /// 0 disables the limit.
+    pub max_sessions: u32,
+    pub on_limit: SessionLimitAction,
+}
+
+impl SessionLimit {
+    pub fn is_enabled(&self) -> bool {
+        self.max_sessions > 0
+    }
+
+    /// How many of `active` sessions must end before one more can start.
+    pub fn excess(&self, active: usize) -> usize {
+        if !self.is_enabled() {
+            return 0;
+        }
+        (active + 1).saturating_sub(self.max_sessions as usize)
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+/// Refresh-token timing of one application, all in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TokenLifetimes {
+    /// How long each issued or rotated refresh token stays valid.
+    pub refresh_token_lifetime: i64,
+    /// A session that has not been refreshed for this long ends; 0 disables.
+    pub idle_timeout: i64,
+    /// A session ends this long after sign-in no matter how often it is
+    /// refreshed; 0 disables.
+    pub absolute_lifetime: i64,
+    /// Delay before a newly issued refresh token may be used.
+    pub not_before_offset: i64,
+    /// Clock skew tolerated when checking `exp` and `nbf`.
+    pub leeway: i64,
+    /// Lifetime of access tokens from the `client_credentials` grant; these
+    /// come without a refresh token, so they are kept short.
+    pub service_account_token_lifetime: i64,
+}
+
+impl Default for TokenLifetimes {
+    fn default() -> Self {
+        Self {
+            refresh_token_lifetime: 30 * 24 * 3600,
+            idle_timeout: 0,
+            absolute_lifetime: 0,
+            not_before_offset: 40 * 60,
+            leeway: 0,
+            service_account_token_lifetime: 10 * 60,
+        }
+    }
+}
+
+impl TokenLifetimes {
+    /// Seconds until a token issued at `now` expires; never past the end of
+    /// the session.
+    pub fn expires_in(&self, now: i64, session_started_at: i64) -> i64 {
+        let lifetime = self.refresh_token_lifetime.max(1);
+        if self.absolute_lifetime > 0 {
+            lifetime.min(session_started_at + self.absolute_lifetime - now)
+        } else {
+            lifetime
+        }
+    }
+
+    /// Row TTL matching `expires_in`; Scylla treats 0 as "keep forever".
+    pub fn row_ttl(&self, now: i64, session_started_at: i64) -> i32 {
+        self.expires_in(now, session_started_at)
+            .clamp(1, i32::MAX as i64) as i32
+    }
+
+    pub fn is_current(&self, now: i64, not_before: i64, expires_at: i64) -> bool {
+        not_before - self.leeway <= now && now < expires_at + self.leeway
+    }
+
+    pub fn is_idle(&self, now: i64, last_used_at: i64) -> bool {
+        self.idle_timeout > 0 && now - last_used_at > self.idle_timeout + self.leeway
+    }
+
+    pub fn is_past_absolute_lifetime(&self, now: i64, session_started_at: i64) -> bool {
+        self.absolute_lifetime > 0
+            && now - session_started_at > self.absolute_lifetime + self.leeway
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserProfile {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub locale: Option<String>,
+    pub phone: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+impl UserProfile {
+    /// Overwrites the fields that are set in `update`, keeping the others.
+    pub fn merge(&mut self, update: UserProfile) {
+        if update.first_name.is_some() {
+            self.first_name = update.first_name;
+        }
+        if update.last_name.is_some() {
+            self.last_name = update.last_name;
+        }
+        if update.locale.is_some() {
+            self.locale = update.locale;
+        }
+        if update.phone.is_some() {
+            self.phone = update.phone;
+        }
+        if update.avatar_url.is_some() {
+            self.avatar_url = update.avatar_url;
+        }
+    }
+}
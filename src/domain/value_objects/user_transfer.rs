@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Largest body `POST /users/import` accepts.
+pub const MAX_IMPORT_SIZE: usize = 64 * 1024 * 1024;
+
+/// Users read per `find_all_users_paginated` call while exporting.
+pub const EXPORT_PAGE_SIZE: i32 = 500;
+
+/// Row errors kept in an import report; the rest are only counted.
+pub const MAX_REPORTED_ERRORS: usize = 1000;
+
+/// Rows processed between two saves of an import's progress.
+pub const PROGRESS_INTERVAL: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    Csv,
+    Ndjson,
+}
+
+impl TransferFormat {
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let essence = content_type.split(';').next().unwrap_or_default().trim();
+        match essence.to_ascii_lowercase().as_str() {
+            "text/csv" => Some(Self::Csv),
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => {
+                Some(Self::Ndjson)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+        }
+    }
+}
@@ -6,4 +6,7 @@ pub enum FulltextSearchError {
 
     #[error("Elasticsearch indexing failed with status: {0}")]
     IndexingFailed(String),
+
+    #[error("Elasticsearch deletion failed with status: {0}")]
+    DeletionFailed(String),
 }
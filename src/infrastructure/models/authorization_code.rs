@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationCodeModel {
+    pub code: String,
+    pub client_id: Uuid,
+    pub application_id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub code_challenge: String,
+    pub code_challenge_method: String,
+    pub nonce: Option<String>,
+    pub issued_at: i64,
+}
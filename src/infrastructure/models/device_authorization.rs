@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceAuthorizationStatus {
+    Pending,
+    Approved,
+    Denied,
+}
+
+/// A device authorization request (RFC 8628) waiting for the user to
+/// approve it on another device; `user_id` is set once approved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceAuthorizationModel {
+    pub device_code: String,
+    pub user_code: String,
+    pub client_id: Uuid,
+    pub application_id: Uuid,
+    pub organization_id: Uuid,
+    pub scope: Option<String>,
+    pub status: DeviceAuthorizationStatus,
+    pub user_id: Option<Uuid>,
+    /// Seconds the client must wait between polls.
+    pub interval: u64,
+    pub expires_at: i64,
+}
+
+/// The user's answer to a device request. It is stored apart from the
+/// request, so the first answer wins and no stale copy can overwrite it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceAuthorizationDecision {
+    pub status: DeviceAuthorizationStatus,
+    pub user_id: Option<Uuid>,
+}
+
+impl DeviceAuthorizationModel {
+    pub fn with_decision(mut self, decision: DeviceAuthorizationDecision) -> Self {
+        self.status = decision.status;
+        self.user_id = decision.user_id;
+        self
+    }
+}
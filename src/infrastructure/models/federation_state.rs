@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What is kept while the user signs in at an upstream provider: the
+/// client's own authorization request, to resume once they come back, and
+/// the values that bind the upstream response to this attempt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederationStateModel {
+    pub state: String,
+    pub provider_id: String,
+    pub upstream_nonce: String,
+    pub code_verifier: String,
+    pub client_id: Uuid,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub client_state: Option<String>,
+    pub code_challenge: String,
+    pub code_challenge_method: Option<String>,
+    pub nonce: Option<String>,
+}
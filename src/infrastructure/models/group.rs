@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+
+use scylla::{DeserializeRow, SerializeRow, value::CqlTimestamp};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::infrastructure::repositories::database::scylla_serialize::{
+    deserialize_cql_timestamp, serialize_cql_timestamp,
+};
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct GroupModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub group_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub role_ids: HashSet<Uuid>,
+
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub created_at: CqlTimestamp,
+
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub updated_at: CqlTimestamp,
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct UpdateGroupModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub group_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct GroupMembershipModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct GroupMemberModel {
+    pub user_id: Uuid,
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub added_at: CqlTimestamp,
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct UserGroupModel {
+    pub group_id: Uuid,
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub added_at: CqlTimestamp,
+}
+
+pub struct PaginatedGroupMembersModel {
+    pub members: Vec<GroupMemberModel>,
+    pub paging_state: Option<Vec<u8>>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Public half of an Ed25519 key as an RFC 8037 OKP JSON Web Key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub alg: String,
+    #[serde(rename = "use")]
+    pub key_use: String,
+    pub kid: String,
+    pub x: String,
+}
@@ -1,11 +1,22 @@
 pub mod application;
 pub mod apporg_client_id;
+pub mod authorization_code;
+pub mod device_authorization;
+pub mod federation_state;
+pub mod group;
+pub mod jwk;
 pub mod organization;
+pub mod permission;
 pub mod refresh_token;
 pub mod role;
+pub mod service_account;
+pub mod signing_key;
 pub mod timestamp;
 pub mod token_claim;
+pub mod upstream_oidc;
 pub mod user;
+pub mod user_identity;
+pub mod user_import;
 pub mod user_organization;
 pub mod queue;
 
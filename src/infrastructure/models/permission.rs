@@ -0,0 +1,37 @@
+use scylla::{DeserializeRow, SerializeRow, value::CqlTimestamp};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::infrastructure::repositories::database::scylla_serialize::{
+    deserialize_cql_timestamp, serialize_cql_timestamp,
+};
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct PermissionModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub created_at: CqlTimestamp,
+
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub updated_at: CqlTimestamp,
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct UpdatePermissionModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+}
@@ -1,2 +1,3 @@
 pub mod queue_payload;
+pub mod roles;
 pub mod users;
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::infrastructure::models::queue::queue_payload::QueueOperation;
+
+#[derive(Serialize, Deserialize)]
+pub struct QueueRoleAssignment {
+    pub operation: String,
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub role_id: Uuid,
+    pub user_id: Uuid,
+    /// Unix millis.
+    pub expires_at: Option<i64>,
+}
+impl QueueOperation for QueueRoleAssignment {
+    fn operation(&self) -> &str {
+        &self.operation
+    }
+}
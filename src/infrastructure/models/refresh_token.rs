@@ -1,6 +1,8 @@
 use crate::domain::entities::refresh_token::RefreshToken;
+use crate::domain::value_objects::client_metadata::ClientMetadata;
 use crate::infrastructure::repositories::database::scylla_serialize::{
-    deserialize_cql_timestamp, serialize_cql_timestamp,
+    deserialize_cql_timestamp, deserialize_optional_cql_timestamp, serialize_cql_timestamp,
+    serialize_optional_cql_timestamp,
 };
 use scylla::value::CqlTimestamp;
 use scylla::{DeserializeRow, SerializeRow};
@@ -28,6 +30,22 @@ pub struct RefreshTokenModel {
     )]
     pub expires_at: CqlTimestamp,
     pub revoked: bool,
+
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub last_used_at: CqlTimestamp,
+
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub session_started_at: CqlTimestamp,
+    pub device_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub scope: Option<String>,
 }
 impl From<RefreshToken> for RefreshTokenModel {
     fn from(token: RefreshToken) -> Self {
@@ -42,6 +60,12 @@ impl From<RefreshToken> for RefreshTokenModel {
             issued_at: token.issued_at,
             expires_at: token.expires_at,
             revoked: token.revoked,
+            last_used_at: token.last_used_at,
+            session_started_at: token.session_started_at,
+            device_name: token.client.device_name,
+            ip_address: token.client.ip_address,
+            user_agent: token.client.user_agent,
+            scope: token.scope,
         }
     }
 }
@@ -59,6 +83,14 @@ impl From<RefreshTokenModel> for RefreshToken {
             issued_at: model.issued_at,
             expires_at: model.expires_at,
             revoked: model.revoked,
+            last_used_at: model.last_used_at,
+            session_started_at: model.session_started_at,
+            client: ClientMetadata {
+                device_name: model.device_name,
+                ip_address: model.ip_address,
+                user_agent: model.user_agent,
+            },
+            scope: model.scope,
         }
     }
 }
@@ -75,11 +107,19 @@ impl From<&RefreshToken> for RefreshTokenModel {
             issued_at: token.issued_at,
             expires_at: token.expires_at,
             revoked: token.revoked,
+            last_used_at: token.last_used_at,
+            session_started_at: token.session_started_at,
+            device_name: token.client.device_name.clone(),
+            ip_address: token.client.ip_address.clone(),
+            user_agent: token.client.user_agent.clone(),
+            scope: token.scope.clone(),
         }
     }
 }
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
 pub struct FoundRefreshTokenModel {
+    pub token_id: Uuid,
+    pub token_version: String,
     pub application_id: Uuid,
     pub organization_id: Uuid,
     pub user_id: Uuid,
@@ -98,6 +138,54 @@ pub struct FoundRefreshTokenModel {
     )]
     pub expires_at: CqlTimestamp,
     pub revoked: bool,
+    /// Unset on rows written before sessions were tracked.
+    #[serde(
+        serialize_with = "serialize_optional_cql_timestamp",
+        deserialize_with = "deserialize_optional_cql_timestamp"
+    )]
+    pub last_used_at: Option<CqlTimestamp>,
+    #[serde(
+        serialize_with = "serialize_optional_cql_timestamp",
+        deserialize_with = "deserialize_optional_cql_timestamp"
+    )]
+    pub session_started_at: Option<CqlTimestamp>,
+    pub device_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub scope: Option<String>,
+}
+
+impl From<&RefreshTokenModel> for FoundRefreshTokenModel {
+    fn from(token: &RefreshTokenModel) -> Self {
+        Self {
+            token_id: token.token_id,
+            token_version: token.token_version.clone(),
+            application_id: token.application_id,
+            organization_id: token.organization_id,
+            user_id: token.user_id,
+            encrypted_token_secret: token.encrypted_token_secret.clone(),
+            parent_version: token.parent_version.clone(),
+            issued_at: token.issued_at,
+            expires_at: token.expires_at,
+            revoked: token.revoked,
+            last_used_at: Some(token.last_used_at),
+            session_started_at: Some(token.session_started_at),
+            device_name: token.device_name.clone(),
+            ip_address: token.ip_address.clone(),
+            user_agent: token.user_agent.clone(),
+            scope: token.scope.clone(),
+        }
+    }
+}
+
+impl FoundRefreshTokenModel {
+    pub fn client(&self) -> ClientMetadata {
+        ClientMetadata {
+            device_name: self.device_name.clone(),
+            ip_address: self.ip_address.clone(),
+            user_agent: self.user_agent.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
@@ -120,36 +208,56 @@ pub struct FoundRefreshTokenModelByUser {
     )]
     pub expires_at: CqlTimestamp,
     pub revoked: bool,
+    #[serde(
+        serialize_with = "serialize_optional_cql_timestamp",
+        deserialize_with = "deserialize_optional_cql_timestamp"
+    )]
+    pub last_used_at: Option<CqlTimestamp>,
+    pub device_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
-#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
-pub struct UpdateRefreshTokenQuery {
+/// A full refresh-token row plus the TTL it is written with, so every cell
+/// of the row expires together.
+#[derive(Debug, Clone, SerializeRow)]
+pub struct StoreRefreshTokenQuery {
     pub token_id: Uuid,
     pub application_id: Uuid,
     pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub encrypted_token_secret: String,
     pub token_version: String,
     pub parent_version: Option<String>,
-    #[serde(
-        serialize_with = "serialize_cql_timestamp",
-        deserialize_with = "deserialize_cql_timestamp"
-    )]
     pub issued_at: CqlTimestamp,
-    #[serde(
-        serialize_with = "serialize_cql_timestamp",
-        deserialize_with = "deserialize_cql_timestamp"
-    )]
     pub expires_at: CqlTimestamp,
+    pub revoked: bool,
+    pub last_used_at: CqlTimestamp,
+    pub session_started_at: CqlTimestamp,
+    pub device_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub row_ttl: i32,
 }
 
-impl From<&RefreshTokenModel> for UpdateRefreshTokenQuery {
-    fn from(token: &RefreshTokenModel) -> Self {
+impl StoreRefreshTokenQuery {
+    pub fn new(token: &RefreshTokenModel, row_ttl: i32) -> Self {
         Self {
             token_id: token.token_id,
             application_id: token.application_id,
             organization_id: token.organization_id,
+            user_id: token.user_id,
+            encrypted_token_secret: token.encrypted_token_secret.clone(),
             token_version: token.token_version.clone(),
             parent_version: token.parent_version.clone(),
             issued_at: token.issued_at,
             expires_at: token.expires_at,
+            revoked: token.revoked,
+            last_used_at: token.last_used_at,
+            session_started_at: token.session_started_at,
+            device_name: token.device_name.clone(),
+            ip_address: token.ip_address.clone(),
+            user_agent: token.user_agent.clone(),
+            row_ttl,
         }
     }
 }
@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::infrastructure::repositories::database::scylla_serialize::{
-    deserialize_cql_timestamp, serialize_cql_timestamp,
+    deserialize_cql_timestamp, deserialize_optional_cql_timestamp, serialize_cql_timestamp,
+    serialize_optional_cql_timestamp,
 };
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
 pub struct RoleModel {
@@ -15,6 +16,7 @@ pub struct RoleModel {
     pub name: String,
     pub description: String,
     pub permissions: HashSet<String>,
+    pub parent_role_ids: HashSet<Uuid>,
 
     #[serde(
         serialize_with = "serialize_cql_timestamp",
@@ -35,6 +37,13 @@ pub struct RoleAssignmentModel {
     pub application_id: Uuid,
     pub role_id: Uuid,
     pub user_id: Uuid,
+    #[serde(
+        serialize_with = "serialize_optional_cql_timestamp",
+        deserialize_with = "deserialize_optional_cql_timestamp"
+    )]
+    pub expires_at: Option<CqlTimestamp>,
+    /// Seconds until both assignment rows are dropped, 0 for none.
+    pub ttl: i32,
 }
 
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
@@ -45,6 +54,7 @@ pub struct UpdateRoleModel {
     pub name: String,
     pub description: Option<String>,
     pub permissions: HashSet<String>,
+    pub parent_role_ids: HashSet<Uuid>,
 }
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
 pub struct UserRoleModel {
@@ -54,6 +64,11 @@ pub struct UserRoleModel {
         deserialize_with = "deserialize_cql_timestamp"
     )]
     pub assigned_at: CqlTimestamp,
+    #[serde(
+        serialize_with = "serialize_optional_cql_timestamp",
+        deserialize_with = "deserialize_optional_cql_timestamp"
+    )]
+    pub expires_at: Option<CqlTimestamp>,
 }
 
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
@@ -64,12 +79,18 @@ pub struct RoleUserModel {
         deserialize_with = "deserialize_cql_timestamp"
     )]
     pub assigned_at: CqlTimestamp,
+    #[serde(
+        serialize_with = "serialize_optional_cql_timestamp",
+        deserialize_with = "deserialize_optional_cql_timestamp"
+    )]
+    pub expires_at: Option<CqlTimestamp>,
 }
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
 pub struct SelectedRoleByIdModel {
     pub name: String,
     pub description: Option<String>,
     pub permissions: HashSet<String>,
+    pub parent_role_ids: HashSet<Uuid>,
 
     #[serde(
         serialize_with = "serialize_cql_timestamp",
@@ -90,6 +111,7 @@ pub struct SelectedRoleByAppModel {
     pub name: String,
     pub description: Option<String>,
     pub permissions: HashSet<String>,
+    pub parent_role_ids: HashSet<Uuid>,
 
     #[serde(
         serialize_with = "serialize_cql_timestamp",
@@ -102,4 +124,31 @@ pub struct SelectedRoleByAppModel {
         deserialize_with = "deserialize_cql_timestamp"
     )]
     pub updated_at: CqlTimestamp,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct PendingRoleExpiryModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub expires_at: CqlTimestamp,
+}
+
+/// How many days back the expiry job rescans; expiring assignment rows are
+/// kept at least this long past `expires_at` so the job removes them itself.
+pub const EXPIRY_LOOKBACK_DAYS: i64 = 7;
+
+impl PendingRoleExpiryModel {
+    /// Partition holding the expiries due on the UTC day of `timestamp_millis`.
+    pub fn day(timestamp_millis: i64) -> String {
+        chrono::DateTime::from_timestamp_millis(timestamp_millis)
+            .unwrap_or_default()
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+}
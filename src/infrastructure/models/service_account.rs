@@ -0,0 +1,65 @@
+use crate::application::mappers::model::ModelMapper;
+use crate::domain::entities::service_account::ServiceAccount;
+use crate::infrastructure::repositories::database::scylla_serialize::{
+    deserialize_cql_timestamp, serialize_cql_timestamp,
+};
+use scylla::value::CqlTimestamp;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct ServiceAccountModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub service_account_id: Uuid,
+    pub application_client_id: Uuid,
+    pub client_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub encrypted_client_secret: String,
+    pub is_active: bool,
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub created_at: CqlTimestamp,
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub updated_at: CqlTimestamp,
+}
+
+impl ModelMapper<ServiceAccount> for ServiceAccountModel {
+    fn from_entity(entity: ServiceAccount) -> Self {
+        Self {
+            organization_id: entity.organization_id,
+            application_id: entity.application_id,
+            service_account_id: entity.service_account_id,
+            application_client_id: entity.application_client_id,
+            client_id: entity.client_id,
+            name: entity.name,
+            description: entity.description,
+            encrypted_client_secret: entity.encrypted_client_secret,
+            is_active: entity.is_active,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+    fn to_entity(&self) -> ServiceAccount {
+        ServiceAccount {
+            organization_id: self.organization_id,
+            application_id: self.application_id,
+            service_account_id: self.service_account_id,
+            application_client_id: self.application_client_id,
+            client_id: self.client_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            encrypted_client_secret: self.encrypted_client_secret.clone(),
+            is_active: self.is_active,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use scylla::value::CqlTimestamp;
+use scylla::{DeserializeRow, SerializeRow};
+use uuid::Uuid;
+
+/// A per-application signing key. The private half is stored AES-GCM
+/// encrypted with the core secret; `retired_at` is set once a newer key takes
+/// over signing and `expires_at` once the overlap window is scheduled.
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct SigningKeyModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub kid: String,
+    pub public_key: String,
+    pub encrypted_private_key: String,
+    pub private_key_nonce: String,
+    pub created_at: CqlTimestamp,
+    pub retired_at: Option<CqlTimestamp>,
+    pub expires_at: Option<CqlTimestamp>,
+}
+
+impl SigningKeyModel {
+    pub fn is_expired(&self, now_millis: i64) -> bool {
+        self.expires_at.is_some_and(|e| e.0 <= now_millis)
+    }
+}
+
+pub struct PaginatedSigningKeysModel {
+    pub keys: Vec<SigningKeyModel>,
+    pub paging_state: Option<Vec<u8>>,
+}
@@ -15,3 +15,38 @@ pub struct TokenClaims {
     #[serde(deserialize_with = "from_iso8601_to_timestamp")]
     pub nbf: i64,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    pub jti: String,
+    pub sub: String,
+    pub client_id: String,
+    pub scope: Option<String>,
+    /// Only present on tokens issued to service accounts.
+    #[serde(default)]
+    pub permissions: Option<Vec<String>>,
+    #[serde(deserialize_with = "from_iso8601_to_timestamp")]
+    pub iat: i64,
+
+    #[serde(deserialize_with = "from_iso8601_to_timestamp")]
+    pub exp: i64,
+}
+
+impl AccessTokenClaims {
+    pub fn is_service_account(&self) -> bool {
+        self.permissions.is_some()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub aud: String,
+    pub iat: i64,
+    pub exp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: bool,
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use crate::domain::entities::user::User;
+use crate::domain::value_objects::{identifier::normalize_username, user_profile::UserProfile};
 use crate::infrastructure::repositories::database::scylla_serialize::{
     deserialize_cql_timestamp, deserialize_optional_cql_timestamp, serialize_cql_timestamp,
     serialize_optional_cql_timestamp,
@@ -9,7 +10,21 @@ use chrono::Utc;
 use scylla::value::{CqlTimestamp, CqlValue};
 use scylla::{DeserializeRow, SerializeRow, SerializeValue};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use uuid::Uuid;
+pub fn metadata_to_column(metadata: &Map<String, Value>) -> Option<String> {
+    if metadata.is_empty() {
+        return None;
+    }
+    serde_json::to_string(metadata).ok()
+}
+
+pub fn metadata_from_column(column: Option<&str>) -> Map<String, Value> {
+    column
+        .and_then(|m| serde_json::from_str(m).ok())
+        .unwrap_or_default()
+}
+
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
 pub struct UserModel {
     pub user_id: Uuid,
@@ -17,7 +32,16 @@ pub struct UserModel {
     pub application_id: Uuid,
     pub username: String,
     pub email: Option<String>,
+    pub username_normalized: Option<String>,
+    pub email_normalized: Option<String>,
     pub hashed_password: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub locale: Option<String>,
+    pub phone: Option<String>,
+    pub avatar_url: Option<String>,
+    /// JSON object of application-defined attributes.
+    pub metadata: Option<String>,
     #[serde(
         serialize_with = "serialize_cql_timestamp",
         deserialize_with = "deserialize_cql_timestamp"
@@ -58,7 +82,15 @@ impl UserModel {
             application_id: entity.application_id,
             username: entity.username,
             email: entity.email,
+            username_normalized: Some(entity.username_normalized),
+            email_normalized: entity.email_normalized,
             hashed_password: entity.hashed_password,
+            first_name: entity.profile.first_name,
+            last_name: entity.profile.last_name,
+            locale: entity.profile.locale,
+            phone: entity.profile.phone,
+            avatar_url: entity.profile.avatar_url,
+            metadata: metadata_to_column(&entity.metadata),
             created_at: CqlTimestamp(entity.created_at),
             updated_at: CqlTimestamp(entity.updated_at),
             is_active: entity.is_active,
@@ -77,7 +109,14 @@ impl UserModel {
             application_id: self.application_id,
             username: self.username.clone(),
             email: self.email.clone(),
+            username_normalized: self
+                .username_normalized
+                .clone()
+                .unwrap_or_else(|| normalize_username(&self.username)),
+            email_normalized: self.email_normalized.clone(),
             hashed_password: self.hashed_password.clone(),
+            profile: self.profile(),
+            metadata: metadata_from_column(self.metadata.as_deref()),
             created_at: self.created_at.0,
             updated_at: self.updated_at.0,
             is_active: self.is_active,
@@ -89,6 +128,28 @@ impl UserModel {
             locked_at: self.locked_at.map(|a| a.0),
         }
     }
+    pub fn profile(&self) -> UserProfile {
+        UserProfile {
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            locale: self.locale.clone(),
+            phone: self.phone.clone(),
+            avatar_url: self.avatar_url.clone(),
+        }
+    }
+
+    pub fn set_profile(&mut self, profile: UserProfile) {
+        self.first_name = profile.first_name;
+        self.last_name = profile.last_name;
+        self.locale = profile.locale;
+        self.phone = profile.phone;
+        self.avatar_url = profile.avatar_url;
+    }
+
+    pub fn can_sign_in(&self) -> bool {
+        self.is_active && !self.is_locked
+    }
+
     pub fn to_bind_map(&self) -> HashMap<&'static str, CqlValue> {
         let mut map = HashMap::new();
 
@@ -101,10 +162,36 @@ impl UserModel {
         } else {
             map.insert("email", CqlValue::Empty);
         }
+        if let Some(ref username_normalized) = self.username_normalized {
+            map.insert(
+                "username_normalized",
+                CqlValue::Text(username_normalized.clone()),
+            );
+        } else {
+            map.insert("username_normalized", CqlValue::Empty);
+        }
+        if let Some(ref email_normalized) = self.email_normalized {
+            map.insert("email_normalized", CqlValue::Text(email_normalized.clone()));
+        } else {
+            map.insert("email_normalized", CqlValue::Empty);
+        }
         map.insert(
             "hashed_password",
             CqlValue::Text(self.hashed_password.clone()),
         );
+        for (column, value) in [
+            ("first_name", &self.first_name),
+            ("last_name", &self.last_name),
+            ("locale", &self.locale),
+            ("phone", &self.phone),
+            ("avatar_url", &self.avatar_url),
+            ("metadata", &self.metadata),
+        ] {
+            map.insert(
+                column,
+                value.clone().map(CqlValue::Text).unwrap_or(CqlValue::Empty),
+            );
+        }
         map.insert("created_at", CqlValue::Timestamp(self.created_at));
         map.insert("updated_at", CqlValue::Timestamp(self.updated_at));
         map.insert("is_active", CqlValue::Boolean(self.is_active));
@@ -136,6 +223,12 @@ pub struct CleannedUserModel {
     pub application_id: Uuid,
     pub username: String,
     pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub locale: Option<String>,
+    pub phone: Option<String>,
+    pub avatar_url: Option<String>,
+    pub metadata: Option<String>,
     #[serde(
         serialize_with = "serialize_cql_timestamp",
         deserialize_with = "deserialize_cql_timestamp"
@@ -166,6 +259,11 @@ pub struct CleannedUserModel {
     )]
     pub locked_at: Option<CqlTimestamp>,
 }
+impl CleannedUserModel {
+    pub fn can_sign_in(&self) -> bool {
+        self.is_active && !self.is_locked
+    }
+}
 #[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
 pub struct FoundUserModel {
     pub user_id: Uuid,
@@ -204,3 +302,69 @@ impl UpdateUserModel {
         }
     }
 }
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct PendingPurgeUserModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub user_id: Uuid,
+    #[serde(
+        serialize_with = "serialize_cql_timestamp",
+        deserialize_with = "deserialize_cql_timestamp"
+    )]
+    pub deactivated_at: CqlTimestamp,
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow, Serialize, Deserialize)]
+pub struct UserIdentifierModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub user_id: Uuid,
+    pub username: String,
+    pub email: Option<String>,
+    pub username_normalized: Option<String>,
+    pub email_normalized: Option<String>,
+}
+
+pub struct PaginatedUserIdentifiersModel {
+    pub users: Vec<UserIdentifierModel>,
+    pub paging_state: Option<Vec<u8>>,
+}
+
+/// What is sent to the search index for a user. Credentials and metadata
+/// that the application did not declare searchable stay in the database.
+#[derive(Debug, Clone, Serialize)]
+pub struct UserSearchDocument {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub username: String,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub attributes: Map<String, Value>,
+    pub is_active: bool,
+    pub created_at: i64,
+}
+
+impl UserSearchDocument {
+    pub fn from_entity(user: User, searchable_attributes: &[String]) -> Self {
+        let attributes = user
+            .metadata
+            .into_iter()
+            .filter(|(k, _)| searchable_attributes.contains(k))
+            .collect();
+        Self {
+            user_id: user.user_id,
+            organization_id: user.organization_id,
+            application_id: user.application_id,
+            username: user.username,
+            email: user.email,
+            first_name: user.profile.first_name,
+            last_name: user.profile.last_name,
+            attributes,
+            is_active: user.is_active,
+            created_at: user.created_at,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use scylla::value::CqlTimestamp;
+use scylla::{DeserializeRow, SerializeRow};
+use uuid::Uuid;
+
+/// Links a subject at an upstream identity provider to a local user.
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct UserIdentityModel {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub provider_id: String,
+    pub subject: String,
+    pub user_id: Uuid,
+    pub email: Option<String>,
+    pub created_at: CqlTimestamp,
+}
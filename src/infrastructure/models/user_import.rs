@@ -0,0 +1,35 @@
+use crate::domain::value_objects::user_transfer::TransferFormat;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserImportStatus {
+    Running,
+    Completed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserImportRowError {
+    /// 1-based position of the record, header excluded.
+    pub row: usize,
+    pub message: String,
+}
+
+/// Progress and outcome of a `POST /users/import`, kept in the cache for
+/// `user_import.job_ttl` seconds after the last update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserImportJobModel {
+    pub job_id: Uuid,
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub format: TransferFormat,
+    pub status: UserImportStatus,
+    pub processed: usize,
+    pub created: usize,
+    pub failed: usize,
+    /// The first `MAX_REPORTED_ERRORS` failures; `failed` counts all of them.
+    pub errors: Vec<UserImportRowError>,
+    pub started_at: i64,
+    pub finished_at: Option<i64>,
+}
@@ -0,0 +1,58 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::infrastructure::models::authorization_code::AuthorizationCodeModel;
+use async_trait::async_trait;
+use redis::{AsyncCommands, Client};
+use std::sync::Arc;
+
+pub struct AuthorizationCodeCacheImpl {
+    cache: Arc<Client>,
+    ttl: u64,
+}
+
+impl AuthorizationCodeCacheImpl {
+    pub fn new(cache: Arc<Client>, ttl: u64) -> Self {
+        Self { cache, ttl }
+    }
+}
+
+#[async_trait]
+pub trait AuthorizationCodeCacheRepository: Send + Sync {
+    async fn store_authorization_code(&self, code: &AuthorizationCodeModel)
+    -> RepositoryResult<()>;
+    /// Reads and deletes the code in one round trip, so a code can be
+    /// redeemed at most once even when two requests race.
+    async fn take_authorization_code(
+        &self,
+        code: &str,
+    ) -> RepositoryResult<Option<AuthorizationCodeModel>>;
+}
+
+#[async_trait]
+impl AuthorizationCodeCacheRepository for AuthorizationCodeCacheImpl {
+    async fn store_authorization_code(
+        &self,
+        code: &AuthorizationCodeModel,
+    ) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let key = format!("oauth_code:{}", code.code);
+        let value = serde_json::to_string(code)?;
+        let _: () = conn.set_ex(key, value, self.ttl).await?;
+        Ok(())
+    }
+
+    async fn take_authorization_code(
+        &self,
+        code: &str,
+    ) -> RepositoryResult<Option<AuthorizationCodeModel>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let key = format!("oauth_code:{}", code);
+        let result: Option<String> = conn.get_del(key).await?;
+        match result {
+            Some(json) => {
+                let code = serde_json::from_str(&json)?;
+                Ok(Some(code))
+            }
+            None => Ok(None),
+        }
+    }
+}
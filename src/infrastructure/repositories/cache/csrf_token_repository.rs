@@ -0,0 +1,44 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use async_trait::async_trait;
+use redis::{AsyncCommands, Client};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct CsrfTokenCacheImpl {
+    cache: Arc<Client>,
+    ttl: u64,
+}
+
+impl CsrfTokenCacheImpl {
+    pub fn new(cache: Arc<Client>, ttl: u64) -> Self {
+        Self { cache, ttl }
+    }
+}
+
+#[async_trait]
+pub trait CsrfTokenCacheRepository: Send + Sync {
+    async fn store_csrf_token(&self, token: &str, client_id: Uuid) -> RepositoryResult<()>;
+    /// Reads and deletes the token, so a rendered form can be submitted once.
+    async fn take_csrf_token(&self, token: &str) -> RepositoryResult<Option<Uuid>>;
+}
+
+#[async_trait]
+impl CsrfTokenCacheRepository for CsrfTokenCacheImpl {
+    async fn store_csrf_token(&self, token: &str, client_id: Uuid) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let _: () = conn
+            .set_ex(
+                format!("oauth_csrf:{}", token),
+                client_id.to_string(),
+                self.ttl,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn take_csrf_token(&self, token: &str) -> RepositoryResult<Option<Uuid>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let result: Option<String> = conn.get_del(format!("oauth_csrf:{}", token)).await?;
+        Ok(result.and_then(|id| Uuid::parse_str(&id).ok()))
+    }
+}
@@ -0,0 +1,224 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::infrastructure::models::device_authorization::{
+    DeviceAuthorizationDecision, DeviceAuthorizationModel,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use redis::{AsyncCommands, Client, ExistenceCheck, SetExpiry, SetOptions};
+use std::sync::Arc;
+
+pub struct DeviceAuthorizationCacheImpl {
+    cache: Arc<Client>,
+    ttl: u64,
+    interval: u64,
+}
+
+impl DeviceAuthorizationCacheImpl {
+    pub fn new(cache: Arc<Client>, ttl: u64, interval: u64) -> Self {
+        Self {
+            cache,
+            ttl,
+            interval,
+        }
+    }
+
+    fn remaining_ttl(authorization: &DeviceAuthorizationModel) -> u64 {
+        (authorization.expires_at - Utc::now().timestamp()).max(1) as u64
+    }
+
+    /// Applies the decision and slow_down increments kept under their own keys.
+    fn merge(
+        json: &str,
+        decision: Option<String>,
+        extra_interval: Option<u64>,
+    ) -> RepositoryResult<DeviceAuthorizationModel> {
+        let mut authorization: DeviceAuthorizationModel = serde_json::from_str(json)?;
+        if let Some(decision) = decision {
+            let decision: DeviceAuthorizationDecision = serde_json::from_str(&decision)?;
+            authorization = authorization.with_decision(decision);
+        }
+        authorization.interval += extra_interval.unwrap_or_default();
+        Ok(authorization)
+    }
+}
+
+#[async_trait]
+pub trait DeviceAuthorizationCacheRepository: Send + Sync {
+    /// Seconds a device code stays valid.
+    fn ttl(&self) -> u64;
+    /// Minimum seconds between two polls of the token endpoint.
+    fn interval(&self) -> u64;
+    /// Stores a new request under both its device code and its user code.
+    /// The stored copy is never rewritten: the decision and slow_down
+    /// increments are kept under their own keys.
+    async fn store_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+    ) -> RepositoryResult<()>;
+    async fn find_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>>;
+    async fn find_device_authorization_by_user_code(
+        &self,
+        user_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>>;
+    /// Reads and deletes the request, so an approval is redeemed only once.
+    async fn take_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>>;
+    /// Records a poll; false when the previous one was less than `interval`
+    /// seconds ago.
+    async fn record_poll(&self, device_code: &str, interval: u64) -> RepositoryResult<bool>;
+    /// Records the user's answer unless the request was already answered;
+    /// false when another answer came first.
+    async fn decide_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        decision: &DeviceAuthorizationDecision,
+    ) -> RepositoryResult<bool>;
+    /// Adds `seconds` to the interval the device must wait between polls.
+    async fn slow_down(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        seconds: u64,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl DeviceAuthorizationCacheRepository for DeviceAuthorizationCacheImpl {
+    fn ttl(&self) -> u64 {
+        self.ttl
+    }
+
+    fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    async fn store_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+    ) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let ttl = Self::remaining_ttl(authorization);
+        let value = serde_json::to_string(authorization)?;
+        let _: () = redis::pipe()
+            .atomic()
+            .set_ex(
+                format!("oauth_device:{}", authorization.device_code),
+                value,
+                ttl,
+            )
+            .set_ex(
+                format!("oauth_user_code:{}", authorization.user_code),
+                &authorization.device_code,
+                ttl,
+            )
+            .query_async(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let (json, decision, extra_interval): (Option<String>, Option<String>, Option<u64>) =
+            redis::pipe()
+                .get(format!("oauth_device:{}", device_code))
+                .get(format!("oauth_device_decision:{}", device_code))
+                .get(format!("oauth_device_interval:{}", device_code))
+                .query_async(&mut conn)
+                .await?;
+        match json {
+            Some(json) => Ok(Some(Self::merge(&json, decision, extra_interval)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_device_authorization_by_user_code(
+        &self,
+        user_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let device_code: Option<String> =
+            conn.get(format!("oauth_user_code:{}", user_code)).await?;
+        match device_code {
+            Some(device_code) => self.find_device_authorization(&device_code).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn take_device_authorization(
+        &self,
+        device_code: &str,
+    ) -> RepositoryResult<Option<DeviceAuthorizationModel>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let (json, decision, extra_interval): (Option<String>, Option<String>, Option<u64>) =
+            redis::pipe()
+                .atomic()
+                .get_del(format!("oauth_device:{}", device_code))
+                .get_del(format!("oauth_device_decision:{}", device_code))
+                .get_del(format!("oauth_device_interval:{}", device_code))
+                .query_async(&mut conn)
+                .await?;
+        let Some(json) = json else {
+            return Ok(None);
+        };
+        let authorization = Self::merge(&json, decision, extra_interval)?;
+        let _: () = conn
+            .del(format!("oauth_user_code:{}", authorization.user_code))
+            .await?;
+        Ok(Some(authorization))
+    }
+
+    async fn record_poll(&self, device_code: &str, interval: u64) -> RepositoryResult<bool> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let options = SetOptions::default()
+            .conditional_set(ExistenceCheck::NX)
+            .with_expiration(SetExpiry::EX(interval.max(1)));
+        let set: Option<String> = conn
+            .set_options(format!("oauth_device_poll:{}", device_code), 1, options)
+            .await?;
+        Ok(set.is_some())
+    }
+
+    async fn decide_device_authorization(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        decision: &DeviceAuthorizationDecision,
+    ) -> RepositoryResult<bool> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let options = SetOptions::default()
+            .conditional_set(ExistenceCheck::NX)
+            .with_expiration(SetExpiry::EX(Self::remaining_ttl(authorization)));
+        let set: Option<String> = conn
+            .set_options(
+                format!("oauth_device_decision:{}", authorization.device_code),
+                serde_json::to_string(decision)?,
+                options,
+            )
+            .await?;
+        Ok(set.is_some())
+    }
+
+    async fn slow_down(
+        &self,
+        authorization: &DeviceAuthorizationModel,
+        seconds: u64,
+    ) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let key = format!("oauth_device_interval:{}", authorization.device_code);
+        let _: () = redis::pipe()
+            .atomic()
+            .incr(&key, seconds)
+            .ignore()
+            .expire(&key, Self::remaining_ttl(authorization) as i64)
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+        Ok(())
+    }
+}
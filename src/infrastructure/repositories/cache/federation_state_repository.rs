@@ -0,0 +1,50 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::infrastructure::models::federation_state::FederationStateModel;
+use async_trait::async_trait;
+use redis::{AsyncCommands, Client};
+use std::sync::Arc;
+
+pub struct FederationStateCacheImpl {
+    cache: Arc<Client>,
+    ttl: u64,
+}
+
+impl FederationStateCacheImpl {
+    pub fn new(cache: Arc<Client>, ttl: u64) -> Self {
+        Self { cache, ttl }
+    }
+}
+
+#[async_trait]
+pub trait FederationStateCacheRepository: Send + Sync {
+    async fn store_federation_state(&self, state: &FederationStateModel) -> RepositoryResult<()>;
+    /// Reads and deletes the state, so a callback is accepted only once.
+    async fn take_federation_state(
+        &self,
+        state: &str,
+    ) -> RepositoryResult<Option<FederationStateModel>>;
+}
+
+#[async_trait]
+impl FederationStateCacheRepository for FederationStateCacheImpl {
+    async fn store_federation_state(&self, state: &FederationStateModel) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let value = serde_json::to_string(state)?;
+        let _: () = conn
+            .set_ex(format!("oauth_federation:{}", state.state), value, self.ttl)
+            .await?;
+        Ok(())
+    }
+
+    async fn take_federation_state(
+        &self,
+        state: &str,
+    ) -> RepositoryResult<Option<FederationStateModel>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let result: Option<String> = conn.get_del(format!("oauth_federation:{}", state)).await?;
+        match result {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -1,3 +1,10 @@
 pub mod applications_organization_by_client_id_repository;
+pub mod authorization_code_repository;
+pub mod csrf_token_repository;
+pub mod device_authorization_repository;
+pub mod federation_state_repository;
 pub mod redis;
-pub mod refresh_token_repository;
\ No newline at end of file
+pub mod refresh_token_repository;
+pub mod user_import_job_repository;
+pub mod user_permissions_repository;
+
@@ -1,5 +1,5 @@
 use crate::domain::errors::repositories_errors::RepositoryResult;
-use crate::infrastructure::models::refresh_token::RefreshTokenModel;
+use crate::infrastructure::models::refresh_token::FoundRefreshTokenModel;
 use async_trait::async_trait;
 use redis::{AsyncCommands, Client};
 use std::sync::Arc;
@@ -16,17 +16,35 @@ impl RefreshTokenCacheImpl {
     }
 }
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait RefreshTokenCacheRepository: Send + Sync {
-    async fn cache_refresh_token(&self, token: &RefreshTokenModel) -> RepositoryResult<()>;
-    async fn get_cached_refresh_token(&self, token_id: Uuid)
-        -> RepositoryResult<Option<RefreshTokenModel>>;
+    async fn cache_refresh_token(&self, token: &FoundRefreshTokenModel) -> RepositoryResult<()>;
+    async fn get_cached_refresh_token(
+        &self,
+        token_id: Uuid,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>>;
     async fn invalidate_refresh_token(&self, token_id: Uuid) -> RepositoryResult<()>;
+    /// Marks a token as revoked for every replica, even before the
+    /// revocation has reached the database views.
+    async fn deny_refresh_token(&self, token_id: Uuid) -> RepositoryResult<()>;
+    /// Denies a single version of a token, so a rotated-away version is
+    /// refused while the token it was rotated into stays valid.
+    async fn deny_refresh_token_version(
+        &self,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<()>;
+    async fn is_refresh_token_denied(
+        &self,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<bool>;
 }
 
 #[async_trait]
 impl RefreshTokenCacheRepository for RefreshTokenCacheImpl {
-    async fn cache_refresh_token(&self, token: &RefreshTokenModel) -> RepositoryResult<()> {
+    async fn cache_refresh_token(&self, token: &FoundRefreshTokenModel) -> RepositoryResult<()> {
         let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
         let key = format!("refresh_token:{}", token.token_id);
         let value = serde_json::to_string(token)?;
@@ -37,7 +55,7 @@ impl RefreshTokenCacheRepository for RefreshTokenCacheImpl {
     async fn get_cached_refresh_token(
         &self,
         token_id: Uuid,
-    ) -> RepositoryResult<Option<RefreshTokenModel>> {
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>> {
         let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
         let key = format!("refresh_token:{}", token_id);
         let result: Option<String> = conn.get(key).await?;
@@ -56,4 +74,36 @@ impl RefreshTokenCacheRepository for RefreshTokenCacheImpl {
         let _: () = conn.del(key).await?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn deny_refresh_token(&self, token_id: Uuid) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let key = format!("refresh_token_denied:{}", token_id);
+        let _: () = conn.set_ex(key, 1, self.ttl).await?;
+        Ok(())
+    }
+
+    async fn deny_refresh_token_version(
+        &self,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let key = format!("refresh_token_denied:{}:{}", token_id, token_version);
+        let _: () = conn.set_ex(key, 1, self.ttl).await?;
+        Ok(())
+    }
+
+    async fn is_refresh_token_denied(
+        &self,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<bool> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let keys = [
+            format!("refresh_token_denied:{}", token_id),
+            format!("refresh_token_denied:{}:{}", token_id, token_version),
+        ];
+        let denied: u32 = conn.exists(&keys).await?;
+        Ok(denied > 0)
+    }
+}
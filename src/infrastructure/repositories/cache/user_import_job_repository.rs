@@ -0,0 +1,45 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::infrastructure::models::user_import::UserImportJobModel;
+use async_trait::async_trait;
+use redis::{AsyncCommands, Client};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct UserImportJobCacheImpl {
+    cache: Arc<Client>,
+    ttl: u64,
+}
+
+impl UserImportJobCacheImpl {
+    pub fn new(cache: Arc<Client>, ttl: u64) -> Self {
+        Self { cache, ttl }
+    }
+}
+
+#[async_trait]
+pub trait UserImportJobCacheRepository: Send + Sync {
+    /// Saves the job, restarting its expiry.
+    async fn store_job(&self, job: &UserImportJobModel) -> RepositoryResult<()>;
+    async fn find_job(&self, job_id: Uuid) -> RepositoryResult<Option<UserImportJobModel>>;
+}
+
+#[async_trait]
+impl UserImportJobCacheRepository for UserImportJobCacheImpl {
+    async fn store_job(&self, job: &UserImportJobModel) -> RepositoryResult<()> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let value = serde_json::to_string(job)?;
+        let _: () = conn
+            .set_ex(format!("user_import_job:{}", job.job_id), value, self.ttl)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_job(&self, job_id: Uuid) -> RepositoryResult<Option<UserImportJobModel>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let result: Option<String> = conn.get(format!("user_import_job:{}", job_id)).await?;
+        match result {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use async_trait::async_trait;
+use chrono::Utc;
+use redis::{AsyncCommands, Client};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct UserPermissionsCacheImpl {
+    cache: Arc<Client>,
+    ttl: u64,
+}
+
+impl UserPermissionsCacheImpl {
+    pub fn new(cache: Arc<Client>, ttl: u64) -> Self {
+        Self { cache, ttl }
+    }
+}
+
+fn permissions_key(organization_id: Uuid, application_id: Uuid, user_id: Uuid) -> String {
+    format!("user_permissions:{organization_id}:{application_id}:{user_id}")
+}
+
+/// Effective permissions of a user, merged over all of their roles. Anything
+/// that changes a role or an assignment must invalidate the users it affects.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UserPermissionsCacheRepository: Send + Sync {
+    /// Entries never outlive `expires_at`, the earliest expiry among the
+    /// assignments the permissions were resolved from.
+    async fn cache_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        permissions: &[String],
+        expires_at: Option<i64>,
+    ) -> RepositoryResult<()>;
+    async fn get_cached_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<Vec<String>>>;
+    async fn invalidate_cache(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_ids: &[Uuid],
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl UserPermissionsCacheRepository for UserPermissionsCacheImpl {
+    async fn cache_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        permissions: &[String],
+        expires_at: Option<i64>,
+    ) -> RepositoryResult<()> {
+        let ttl = expires_at.map_or(self.ttl, |e| {
+            let millis = (e - Utc::now().timestamp_millis()).max(1);
+            self.ttl.min(((millis + 999) / 1000) as u64)
+        });
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let key = permissions_key(organization_id, application_id, user_id);
+        let value = serde_json::to_string(permissions)?;
+        let _: () = conn.set_ex(key, value, ttl).await?;
+        Ok(())
+    }
+
+    async fn get_cached_permissions(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<Vec<String>>> {
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let key = permissions_key(organization_id, application_id, user_id);
+        let result: Option<String> = conn.get(key).await?;
+        match result {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn invalidate_cache(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_ids: &[Uuid],
+    ) -> RepositoryResult<()> {
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+        let mut conn = self.cache.get_multiplexed_tokio_connection().await?;
+        let keys: Vec<String> = user_ids
+            .iter()
+            .map(|user_id| permissions_key(organization_id, application_id, *user_id))
+            .collect();
+        let _: () = conn.del(keys).await?;
+        Ok(())
+    }
+}
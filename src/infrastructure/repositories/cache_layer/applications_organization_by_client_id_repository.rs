@@ -4,6 +4,7 @@ use crate::infrastructure::models::apporg_client_id::AppOrgModel;
 use crate::infrastructure::repositories::cache::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheRepository;
 use crate::infrastructure::repositories::database::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdDatabaseRepository;
 use async_trait::async_trait;
+use scylla::value::CqlTimestamp;
 use std::sync::Arc;
 use uuid::Uuid;
 pub struct ApplicationsOrganizationByClientIdCacheLayerImpl {
@@ -22,6 +23,7 @@ impl ApplicationsOrganizationByClientIdCacheLayerImpl {
         }
     }
 }
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait ApplicationsOrganizationByClientIdCacheLayerRepository: Send + Sync {
     async fn find_apporg_by_client_id(
@@ -29,6 +31,15 @@ pub trait ApplicationsOrganizationByClientIdCacheLayerRepository: Send + Sync {
         client_id: Uuid,
     ) -> RepositoryResult<Option<AppOrgModel>>;
     async fn create_apporg_by_client_id(&self, apporg: AppOrgModel) -> RepositoryResult<()>;
+    /// Conditional on `expected_updated_at`; the cached copy is dropped
+    /// either way so a retry reads the stored document.
+    async fn update_application_config(
+        &self,
+        client_id: Uuid,
+        application_config: String,
+        updated_at: CqlTimestamp,
+        expected_updated_at: CqlTimestamp,
+    ) -> RepositoryResult<bool>;
 }
 
 #[async_trait]
@@ -49,4 +60,24 @@ impl ApplicationsOrganizationByClientIdCacheLayerRepository
     async fn create_apporg_by_client_id(&self, apporg: AppOrgModel) -> RepositoryResult<()> {
         self.database_repo.create_apporg_by_client_id(apporg).await
     }
+
+    async fn update_application_config(
+        &self,
+        client_id: Uuid,
+        application_config: String,
+        updated_at: CqlTimestamp,
+        expected_updated_at: CqlTimestamp,
+    ) -> RepositoryResult<bool> {
+        let applied = self
+            .database_repo
+            .update_application_config(
+                client_id,
+                application_config,
+                updated_at,
+                expected_updated_at,
+            )
+            .await?;
+        self.cache_repo.invalidate_cache(client_id).await?;
+        Ok(applied)
+    }
 }
@@ -1 +1,2 @@
-pub mod applications_organization_by_client_id_repository;
\ No newline at end of file
+pub mod applications_organization_by_client_id_repository;
+pub mod refresh_token_repository;
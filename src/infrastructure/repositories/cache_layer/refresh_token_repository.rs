@@ -0,0 +1,184 @@
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::infrastructure::models::refresh_token::{
+    FoundRefreshTokenModel, PaginatedRefreshTokensByUserModel, RefreshTokenModel,
+};
+use crate::infrastructure::repositories::cache::refresh_token_repository::RefreshTokenCacheRepository;
+use crate::infrastructure::repositories::database::refresh_token::RefreshTokenDatabaseRepository;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RefreshTokenCacheLayerImpl {
+    cache_repo: Arc<dyn RefreshTokenCacheRepository>,
+    database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+}
+
+impl RefreshTokenCacheLayerImpl {
+    pub fn new(
+        cache_repo: Arc<dyn RefreshTokenCacheRepository>,
+        database_repo: Arc<dyn RefreshTokenDatabaseRepository>,
+    ) -> Self {
+        Self {
+            cache_repo,
+            database_repo,
+        }
+    }
+
+    async fn find_current_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>> {
+        if let Some(cached) = self.cache_repo.get_cached_refresh_token(token_id).await?
+            && cached.organization_id == org_id
+            && cached.application_id == app_id
+            && cached.token_version == token_version
+        {
+            return Ok(Some(cached));
+        }
+        // a version mismatch may be a replayed token, so only the database
+        // answers it
+        let Some(found) = self
+            .database_repo
+            .find_refresh_token(org_id, app_id, token_id, &token_version.to_string())
+            .await?
+        else {
+            return Ok(None);
+        };
+        self.cache_repo.cache_refresh_token(&found).await?;
+        Ok(Some(found))
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RefreshTokenCacheLayerRepository: Send + Sync {
+    async fn create_refresh_token(&self, rt: &RefreshTokenModel, ttl: i32) -> RepositoryResult<()>;
+    async fn update_refresh_token(
+        &self,
+        rt: &RefreshTokenModel,
+        ttl: i32,
+    ) -> RepositoryResult<bool>;
+    async fn find_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>>;
+    async fn revoke_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn find_refresh_token_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedRefreshTokensByUserModel>;
+    async fn delete_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl RefreshTokenCacheLayerRepository for RefreshTokenCacheLayerImpl {
+    async fn create_refresh_token(&self, rt: &RefreshTokenModel, ttl: i32) -> RepositoryResult<()> {
+        self.database_repo.create_refresh_token(rt, ttl).await?;
+        self.cache_repo
+            .cache_refresh_token(&FoundRefreshTokenModel::from(rt))
+            .await
+    }
+
+    async fn update_refresh_token(
+        &self,
+        rt: &RefreshTokenModel,
+        ttl: i32,
+    ) -> RepositoryResult<bool> {
+        let applied = self.database_repo.update_refresh_token(rt, ttl).await?;
+        if applied {
+            // the rotated-away version may still be cached on other replicas
+            if let Some(parent_version) = &rt.parent_version {
+                self.cache_repo
+                    .deny_refresh_token_version(rt.token_id, parent_version)
+                    .await?;
+            }
+            self.cache_repo
+                .cache_refresh_token(&FoundRefreshTokenModel::from(rt))
+                .await?;
+        } else {
+            self.cache_repo
+                .invalidate_refresh_token(rt.token_id)
+                .await?;
+        }
+        Ok(applied)
+    }
+
+    async fn find_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+        token_version: &str,
+    ) -> RepositoryResult<Option<FoundRefreshTokenModel>> {
+        let found = self
+            .find_current_refresh_token(org_id, app_id, token_id, token_version)
+            .await?;
+        if found.as_ref().is_some_and(|t| !t.revoked)
+            && self
+                .cache_repo
+                .is_refresh_token_denied(token_id, token_version)
+                .await?
+        {
+            return Ok(found.map(|t| FoundRefreshTokenModel { revoked: true, ..t }));
+        }
+        Ok(found)
+    }
+
+    async fn revoke_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.cache_repo.deny_refresh_token(token_id).await?;
+        self.database_repo
+            .revoke_refresh_token(org_id, app_id, token_id)
+            .await?;
+        self.cache_repo.invalidate_refresh_token(token_id).await
+    }
+
+    async fn find_refresh_token_by_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedRefreshTokensByUserModel> {
+        self.database_repo
+            .find_refresh_token_by_user(org_id, app_id, user_id, page_size, paging_state_u8)
+            .await
+    }
+
+    async fn delete_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database_repo
+            .delete_refresh_token(org_id, app_id, token_id)
+            .await?;
+        self.cache_repo.invalidate_refresh_token(token_id).await
+    }
+}
@@ -3,8 +3,9 @@ use crate::{
     infrastructure::models::application::AppcalitionModel,
 };
 use async_trait::async_trait;
-use scylla::client::session::Session;
+use scylla::{client::session::Session, value::CqlTimestamp};
 use std::sync::Arc;
+use uuid::Uuid;
 pub struct ApplicationDatabaseRepositoryImpl {
     pub database: Arc<Session>,
 }
@@ -15,9 +16,22 @@ impl ApplicationDatabaseRepositoryImpl {
     }
 }
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait ApplicationDatabaseRepository: Send + Sync {
     async fn create_application(&self, app: AppcalitionModel) -> RepositoryResult<()>;
+    async fn find_application(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Option<AppcalitionModel>>;
+    async fn update_application_config(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        config: String,
+        updated_at: CqlTimestamp,
+    ) -> RepositoryResult<()>;
 }
 
 #[async_trait]
@@ -39,4 +53,40 @@ impl ApplicationDatabaseRepository for ApplicationDatabaseRepositoryImpl {
         self.database.query_unpaged(query, &app).await?;
         Ok(())
     }
+
+    async fn find_application(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Option<AppcalitionModel>> {
+        let query = "
+        SELECT organization_id, application_id, name, description, client_id,
+            encrypted_client_secret, config, created_at, updated_at
+        FROM axcelium.applications
+        WHERE organization_id = ? AND application_id = ?;
+    ";
+        let result = self
+            .database
+            .query_unpaged(query, (organization_id, application_id))
+            .await?
+            .into_rows_result()?;
+        Ok(result.maybe_first_row::<AppcalitionModel>()?)
+    }
+
+    async fn update_application_config(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        config: String,
+        updated_at: CqlTimestamp,
+    ) -> RepositoryResult<()> {
+        let query = "
+        UPDATE axcelium.applications SET config = ?, updated_at = ?
+        WHERE organization_id = ? AND application_id = ?;
+    ";
+        self.database
+            .query_unpaged(query, (config, updated_at, organization_id, application_id))
+            .await?;
+        Ok(())
+    }
 }
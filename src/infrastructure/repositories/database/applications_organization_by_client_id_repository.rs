@@ -3,9 +3,12 @@ use crate::{
     infrastructure::models::apporg_client_id::AppOrgModel,
 };
 use async_trait::async_trait;
-use scylla::client::session::Session;
+use scylla::{client::session::Session, value::CqlTimestamp};
 use std::sync::Arc;
 use uuid::Uuid;
+
+use super::user_repository::is_applied;
+
 pub struct ApplicationsOrganizationByClientIdDatabaseRepositoryImpl {
     pub database: Arc<Session>,
 }
@@ -22,6 +25,15 @@ pub trait ApplicationsOrganizationByClientIdDatabaseRepository: Send + Sync {
         &self,
         client_id: Uuid,
     ) -> RepositoryResult<Option<AppOrgModel>>;
+    /// Writes the document only if the row still carries
+    /// `expected_updated_at`; returns whether the write was applied.
+    async fn update_application_config(
+        &self,
+        client_id: Uuid,
+        application_config: String,
+        updated_at: CqlTimestamp,
+        expected_updated_at: CqlTimestamp,
+    ) -> RepositoryResult<bool>;
 }
 
 #[async_trait]
@@ -71,4 +83,32 @@ impl ApplicationsOrganizationByClientIdDatabaseRepository
 
         Ok(row)
     }
+
+    async fn update_application_config(
+        &self,
+        client_id: Uuid,
+        application_config: String,
+        updated_at: CqlTimestamp,
+        expected_updated_at: CqlTimestamp,
+    ) -> RepositoryResult<bool> {
+        let query = "
+            UPDATE axcelium.applications_organization_by_client_id
+            SET application_config = ?, updated_at = ?
+            WHERE client_id = ?
+            IF updated_at = ?;
+        ";
+        let result = self
+            .database
+            .query_unpaged(
+                query,
+                (
+                    application_config,
+                    updated_at,
+                    client_id,
+                    expected_updated_at,
+                ),
+            )
+            .await?;
+        is_applied(result)
+    }
 }
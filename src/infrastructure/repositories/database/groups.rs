@@ -0,0 +1,364 @@
+use async_trait::async_trait;
+use scylla::{
+    client::session::Session,
+    response::PagingState,
+    statement::{batch::Batch, prepared::PreparedStatement},
+};
+use std::{collections::HashSet, ops::ControlFlow, sync::Arc};
+use uuid::Uuid;
+
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::models::group::{
+        GroupMemberModel, GroupMembershipModel, GroupModel, PaginatedGroupMembersModel,
+        UpdateGroupModel, UserGroupModel,
+    },
+};
+
+use super::query::{
+    group_members::{
+        ADD_GROUP_TO_USER, ADD_MEMBER_TO_GROUP, LIST_GROUPS_OF_USER, LIST_MEMBERS_OF_GROUP,
+        REMOVE_GROUP_OF_USER, REMOVE_GROUPS_OF_USER, REMOVE_MEMBER_FROM_GROUP,
+        REMOVE_MEMBERS_OF_GROUP,
+    },
+    groups_by_app::{
+        ADD_ROLE_TO_GROUP, DELETE_GROUP_BY_ID, INSERT_GROUP_BY_APP, REMOVE_ROLE_FROM_GROUP,
+        SELECT_GROUP_BY_ID, SELECT_GROUPS_BY_APP, UPDATE_GROUP_BY_APP,
+    },
+};
+
+pub struct GroupDatabaseRepositoryImpl {
+    pub database: Arc<Session>,
+    insert_group_stmt: PreparedStatement,
+    update_group_stmt: PreparedStatement,
+    delete_group_stmt: PreparedStatement,
+    get_group_stmt: PreparedStatement,
+    get_groups_stmt: PreparedStatement,
+    add_role_stmt: PreparedStatement,
+    remove_role_stmt: PreparedStatement,
+    add_member_stmt: Batch,
+    remove_member_stmt: Batch,
+    get_members_stmt: PreparedStatement,
+    get_groups_of_user_stmt: PreparedStatement,
+    remove_members_of_group_stmt: PreparedStatement,
+    remove_groups_of_user_stmt: PreparedStatement,
+}
+
+impl GroupDatabaseRepositoryImpl {
+    pub async fn new(database: Arc<Session>) -> Self {
+        let insert_group_stmt = database.prepare(INSERT_GROUP_BY_APP).await.unwrap();
+        let update_group_stmt = database.prepare(UPDATE_GROUP_BY_APP).await.unwrap();
+        let delete_group_stmt = database.prepare(DELETE_GROUP_BY_ID).await.unwrap();
+        let get_group_stmt = database.prepare(SELECT_GROUP_BY_ID).await.unwrap();
+        let get_groups_stmt = database.prepare(SELECT_GROUPS_BY_APP).await.unwrap();
+        let add_role_stmt = database.prepare(ADD_ROLE_TO_GROUP).await.unwrap();
+        let remove_role_stmt = database.prepare(REMOVE_ROLE_FROM_GROUP).await.unwrap();
+        let mut add_batch: Batch = Default::default();
+        add_batch.append_statement(ADD_MEMBER_TO_GROUP);
+        add_batch.append_statement(ADD_GROUP_TO_USER);
+        let add_member_stmt: Batch = database.prepare_batch(&add_batch).await.unwrap();
+        let mut remove_batch: Batch = Default::default();
+        remove_batch.append_statement(REMOVE_MEMBER_FROM_GROUP);
+        remove_batch.append_statement(REMOVE_GROUP_OF_USER);
+        let remove_member_stmt: Batch = database.prepare_batch(&remove_batch).await.unwrap();
+        let get_members_stmt = database.prepare(LIST_MEMBERS_OF_GROUP).await.unwrap();
+        let get_groups_of_user_stmt = database.prepare(LIST_GROUPS_OF_USER).await.unwrap();
+        let remove_members_of_group_stmt = database.prepare(REMOVE_MEMBERS_OF_GROUP).await.unwrap();
+        let remove_groups_of_user_stmt = database.prepare(REMOVE_GROUPS_OF_USER).await.unwrap();
+
+        Self {
+            database,
+            insert_group_stmt,
+            update_group_stmt,
+            delete_group_stmt,
+            get_group_stmt,
+            get_groups_stmt,
+            add_role_stmt,
+            remove_role_stmt,
+            add_member_stmt,
+            remove_member_stmt,
+            get_members_stmt,
+            get_groups_of_user_stmt,
+            remove_members_of_group_stmt,
+            remove_groups_of_user_stmt,
+        }
+    }
+}
+
+/// Groups of an application. Membership is written to both
+/// `group_members_by_group` and `groups_by_user` so it can be read from
+/// either side.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GroupDatabaseRepository: Send + Sync {
+    async fn create_group(&self, group: &GroupModel) -> RepositoryResult<()>;
+    async fn update_group(&self, update: &UpdateGroupModel) -> RepositoryResult<()>;
+    async fn delete_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>>;
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>>;
+    async fn add_role_to_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn remove_role_from_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn add_member(&self, membership: &GroupMembershipModel) -> RepositoryResult<()>;
+    async fn remove_member(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn get_members_paginated(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedGroupMembersModel>;
+    async fn get_members(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Vec<GroupMemberModel>>;
+    async fn get_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserGroupModel>>;
+    async fn remove_members_of_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn remove_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl GroupDatabaseRepository for GroupDatabaseRepositoryImpl {
+    async fn create_group(&self, group: &GroupModel) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.insert_group_stmt, group)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_group(&self, update: &UpdateGroupModel) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.update_group_stmt, update)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.delete_group_stmt, (org_id, app_id, group_id))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Option<GroupModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.get_group_stmt, (org_id, app_id, group_id))
+            .await?;
+        Ok(res.into_rows_result()?.maybe_first_row()?)
+    }
+
+    async fn get_groups(&self, org_id: Uuid, app_id: Uuid) -> RepositoryResult<Vec<GroupModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.get_groups_stmt, (org_id, app_id))
+            .await?;
+        Ok(res
+            .into_rows_result()?
+            .rows::<GroupModel>()?
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn add_role_to_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.add_role_stmt,
+                (HashSet::from([role_id]), org_id, app_id, group_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_role_from_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.remove_role_stmt,
+                (HashSet::from([role_id]), org_id, app_id, group_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn add_member(&self, membership: &GroupMembershipModel) -> RepositoryResult<()> {
+        self.database
+            .batch(&self.add_member_stmt, (membership, membership))
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_member(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .batch(
+                &self.remove_member_stmt,
+                (
+                    (org_id, app_id, group_id, user_id),
+                    (org_id, app_id, user_id, group_id),
+                ),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_members_paginated(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedGroupMembersModel> {
+        let mut prepared = self.get_members_stmt.clone();
+        prepared.set_page_size(page_size);
+        let paging_state = paging_state_u8
+            .map(PagingState::new_from_raw_bytes)
+            .unwrap_or_else(PagingState::start);
+        let (res, paging_state_response) = self
+            .database
+            .execute_single_page(&prepared, &(org_id, app_id, group_id), paging_state)
+            .await?;
+        let members = res
+            .into_rows_result()?
+            .rows::<GroupMemberModel>()?
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_page_state = match paging_state_response.into_paging_control_flow() {
+            ControlFlow::Break(()) => None,
+            ControlFlow::Continue(state) => state.as_bytes_slice().map(|arc| arc.as_ref().to_vec()),
+        };
+        Ok(PaginatedGroupMembersModel {
+            members,
+            paging_state: next_page_state,
+        })
+    }
+
+    async fn get_members(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<Vec<GroupMemberModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.get_members_stmt, (org_id, app_id, group_id))
+            .await?;
+        Ok(res
+            .into_rows_result()?
+            .rows::<GroupMemberModel>()?
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn get_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserGroupModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.get_groups_of_user_stmt, (org_id, app_id, user_id))
+            .await?;
+        Ok(res
+            .into_rows_result()?
+            .rows::<UserGroupModel>()?
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn remove_members_of_group(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        group_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.remove_members_of_group_stmt,
+                (org_id, app_id, group_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_groups_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.remove_groups_of_user_stmt, (org_id, app_id, user_id))
+            .await?;
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
 pub mod application_repository;
 pub mod applications_organization_by_client_id_repository;
+pub mod groups;
 pub mod organization_repository;
+pub mod permissions;
 pub mod query;
 pub mod refresh_token;
 pub mod roles;
 pub mod scylla_serialize;
 pub mod scylladb;
+pub mod service_account;
+pub mod signing_key;
+pub mod user_identity;
 pub mod user_repository;
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use scylla::{
+    client::session::Session,
+    statement::prepared::PreparedStatement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::models::permission::{PermissionModel, UpdatePermissionModel},
+};
+
+use super::query::permissions_by_app::{
+    DELETE_PERMISSION_BY_NAME, INSERT_PERMISSION_BY_APP, SELECT_PERMISSION_BY_NAME,
+    SELECT_PERMISSIONS_BY_APP, UPDATE_PERMISSION_BY_APP,
+};
+use super::user_repository::is_applied;
+
+pub struct PermissionDatabaseRepositoryImpl {
+    pub database: Arc<Session>,
+    insert_permission_stmt: PreparedStatement,
+    update_permission_stmt: PreparedStatement,
+    get_permission_stmt: PreparedStatement,
+    get_permissions_stmt: PreparedStatement,
+    delete_permission_stmt: PreparedStatement,
+}
+
+impl PermissionDatabaseRepositoryImpl {
+    pub async fn new(database: Arc<Session>) -> Self {
+        let insert_permission_stmt = database.prepare(INSERT_PERMISSION_BY_APP).await.unwrap();
+        let update_permission_stmt = database.prepare(UPDATE_PERMISSION_BY_APP).await.unwrap();
+        let get_permission_stmt = database.prepare(SELECT_PERMISSION_BY_NAME).await.unwrap();
+        let get_permissions_stmt = database.prepare(SELECT_PERMISSIONS_BY_APP).await.unwrap();
+        let delete_permission_stmt = database.prepare(DELETE_PERMISSION_BY_NAME).await.unwrap();
+
+        Self {
+            database,
+            insert_permission_stmt,
+            update_permission_stmt,
+            get_permission_stmt,
+            get_permissions_stmt,
+            delete_permission_stmt,
+        }
+    }
+}
+
+#[async_trait]
+pub trait PermissionDatabaseRepository: Send + Sync {
+    /// Inserts unless the name is already declared; returns whether the
+    /// insert was applied.
+    async fn create_permission(&self, permission: &PermissionModel) -> RepositoryResult<bool>;
+    async fn update_permission(&self, update: &UpdatePermissionModel) -> RepositoryResult<()>;
+    async fn get_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<Option<PermissionModel>>;
+    async fn get_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>>;
+    async fn delete_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl PermissionDatabaseRepository for PermissionDatabaseRepositoryImpl {
+    async fn create_permission(&self, permission: &PermissionModel) -> RepositoryResult<bool> {
+        let result = self
+            .database
+            .execute_unpaged(&self.insert_permission_stmt, permission)
+            .await?;
+        is_applied(result)
+    }
+
+    async fn update_permission(&self, update: &UpdatePermissionModel) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.update_permission_stmt, update)
+            .await?;
+        Ok(())
+    }
+
+    async fn get_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<Option<PermissionModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.get_permission_stmt, (org_id, app_id, name))
+            .await?;
+        Ok(res.into_rows_result()?.maybe_first_row()?)
+    }
+
+    async fn get_permissions(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+    ) -> RepositoryResult<Vec<PermissionModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.get_permissions_stmt, (org_id, app_id))
+            .await?;
+        Ok(res
+            .into_rows_result()?
+            .rows::<PermissionModel>()?
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn delete_permission(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        name: &str,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.delete_permission_stmt, (org_id, app_id, name))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+pub const ADD_MEMBER_TO_GROUP: &str = r#"
+INSERT INTO axcelium.group_members_by_group (
+    organization_id, application_id, group_id,
+    user_id, added_at
+) VALUES (
+    :organization_id, :application_id, :group_id,
+    :user_id, toTimestamp(now())
+);"#;
+
+pub const ADD_GROUP_TO_USER: &str = r#"
+INSERT INTO axcelium.groups_by_user (
+    organization_id, application_id, user_id,
+    group_id, added_at
+) VALUES (
+    :organization_id, :application_id, :user_id,
+    :group_id, toTimestamp(now())
+);"#;
+
+pub const REMOVE_MEMBER_FROM_GROUP: &str = r#"
+DELETE FROM axcelium.group_members_by_group
+WHERE organization_id = ? AND application_id = ? AND group_id = ? AND user_id = ?;
+"#;
+
+pub const REMOVE_GROUP_OF_USER: &str = r#"
+DELETE FROM axcelium.groups_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ? AND group_id = ?;
+"#;
+
+pub const LIST_MEMBERS_OF_GROUP: &str = r#"
+SELECT user_id, added_at
+FROM axcelium.group_members_by_group
+WHERE organization_id = ? AND application_id = ? AND group_id = ?;
+"#;
+
+pub const LIST_GROUPS_OF_USER: &str = r#"
+SELECT group_id, added_at
+FROM axcelium.groups_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ?;
+"#;
+
+pub const REMOVE_MEMBERS_OF_GROUP: &str = r#"
+DELETE FROM axcelium.group_members_by_group
+WHERE organization_id = ? AND application_id = ? AND group_id = ?;
+"#;
+
+pub const REMOVE_GROUPS_OF_USER: &str = r#"
+DELETE FROM axcelium.groups_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ?;
+"#;
@@ -0,0 +1,56 @@
+pub const INSERT_GROUP_BY_APP: &str = r#"
+INSERT INTO axcelium.groups_by_app (
+    organization_id, application_id, group_id,
+    name, description, role_ids,
+    created_at, updated_at
+) VALUES (
+    :organization_id, :application_id, :group_id,
+    :name, :description, :role_ids,
+    :created_at, :updated_at
+);"#;
+
+pub const UPDATE_GROUP_BY_APP: &str = r#"
+UPDATE axcelium.groups_by_app SET
+    name = :name,
+    description = :description,
+    updated_at = toTimestamp(now())
+WHERE organization_id = :organization_id
+  AND application_id = :application_id
+  AND group_id = :group_id;"#;
+
+pub const ADD_ROLE_TO_GROUP: &str = r#"
+UPDATE axcelium.groups_by_app SET
+    role_ids = role_ids + ?,
+    updated_at = toTimestamp(now())
+WHERE organization_id = ? AND application_id = ? AND group_id = ?;
+"#;
+
+pub const REMOVE_ROLE_FROM_GROUP: &str = r#"
+UPDATE axcelium.groups_by_app SET
+    role_ids = role_ids - ?,
+    updated_at = toTimestamp(now())
+WHERE organization_id = ? AND application_id = ? AND group_id = ?;
+"#;
+
+pub const SELECT_GROUP_BY_ID: &str = r#"
+SELECT
+    organization_id, application_id, group_id,
+    name, description, role_ids,
+    created_at, updated_at
+FROM axcelium.groups_by_app
+WHERE organization_id = ? AND application_id = ? AND group_id = ?;
+"#;
+
+pub const SELECT_GROUPS_BY_APP: &str = r#"
+SELECT
+    organization_id, application_id, group_id,
+    name, description, role_ids,
+    created_at, updated_at
+FROM axcelium.groups_by_app
+WHERE organization_id = ? AND application_id = ?;
+"#;
+
+pub const DELETE_GROUP_BY_ID: &str = r#"
+DELETE FROM axcelium.groups_by_app
+WHERE organization_id = ? AND application_id = ? AND group_id = ?;
+"#;
@@ -2,4 +2,13 @@ pub mod users;
 pub mod refresh_token;
 pub mod roles_by_app;
 pub mod role_users_by_role;
-pub mod user_roles_by_user;
\ No newline at end of file
+pub mod user_roles_by_user;
+pub mod user_reservations;
+pub mod service_accounts;
+pub mod signing_keys;
+pub mod user_identities;
+
+pub mod groups_by_app;
+pub mod group_members;
+pub mod permissions_by_app;
+pub mod role_assignments_pending_expiry;
@@ -0,0 +1,42 @@
+pub const INSERT_PERMISSION_BY_APP: &str = r#"
+INSERT INTO axcelium.permissions_by_app (
+    organization_id, application_id, name,
+    description, category,
+    created_at, updated_at
+) VALUES (
+    :organization_id, :application_id, :name,
+    :description, :category,
+    :created_at, :updated_at
+) IF NOT EXISTS;"#;
+
+pub const UPDATE_PERMISSION_BY_APP: &str = r#"
+UPDATE axcelium.permissions_by_app SET
+    description = :description,
+    category = :category,
+    updated_at = toTimestamp(now())
+WHERE organization_id = :organization_id
+  AND application_id = :application_id
+  AND name = :name;"#;
+
+pub const SELECT_PERMISSION_BY_NAME: &str = r#"
+SELECT
+    organization_id, application_id, name,
+    description, category,
+    created_at, updated_at
+FROM axcelium.permissions_by_app
+WHERE organization_id = ? AND application_id = ? AND name = ?;
+"#;
+
+pub const SELECT_PERMISSIONS_BY_APP: &str = r#"
+SELECT
+    organization_id, application_id, name,
+    description, category,
+    created_at, updated_at
+FROM axcelium.permissions_by_app
+WHERE organization_id = ? AND application_id = ?;
+"#;
+
+pub const DELETE_PERMISSION_BY_NAME: &str = r#"
+DELETE FROM axcelium.permissions_by_app
+WHERE organization_id = ? AND application_id = ? AND name = ?;
+"#;
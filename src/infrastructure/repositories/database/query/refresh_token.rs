@@ -2,26 +2,41 @@ pub const INSERT_REFRESH_TOKEN: &str = r#"
 INSERT INTO axcelium.refresh_tokens (
     token_id, application_id, organization_id, user_id,
     encrypted_token_secret, token_version, parent_version,
-    issued_at, expires_at, revoked
+    issued_at, expires_at, revoked, last_used_at,
+    session_started_at, device_name, ip_address, user_agent, scope
 ) VALUES (
     :token_id, :application_id, :organization_id,
     :user_id, :encrypted_token_secret, :token_version,
-    :parent_version, :issued_at, :expires_at, :revoked
-) USING TTL 2592000;"#;
+    :parent_version, :issued_at, :expires_at, :revoked,
+    :last_used_at, :session_started_at, :device_name,
+    :ip_address, :user_agent, :scope
+) USING TTL :row_ttl;"#;
 pub const UPDATE_REFRESH_TOKEN: &str = r#"
-UPDATE axcelium.refresh_tokens USING TTL 2592000 SET
+UPDATE axcelium.refresh_tokens USING TTL :row_ttl SET
+    user_id = :user_id,
+    encrypted_token_secret = :encrypted_token_secret,
     token_version = :token_version,
     parent_version = :parent_version,
     issued_at = :issued_at,
-    expires_at = :expires_at
+    expires_at = :expires_at,
+    revoked = :revoked,
+    last_used_at = :last_used_at,
+    session_started_at = :session_started_at,
+    device_name = :device_name,
+    ip_address = :ip_address,
+    user_agent = :user_agent,
+    scope = :scope
 WHERE
     token_id = :token_id AND
     application_id = :application_id AND
-    organization_id = :organization_id;"#;
+    organization_id = :organization_id
+IF revoked = false;"#;
 pub const QUERY_REFRESH_TOKEN: &str = r#"
 SELECT
-    application_id, organization_id, user_id, encrypted_token_secret,
-    parent_version, issued_at, expires_at, revoked
+    token_id, token_version, application_id, organization_id, user_id,
+    encrypted_token_secret, parent_version, issued_at, expires_at, revoked,
+    last_used_at, session_started_at, device_name, ip_address, user_agent,
+    scope
 FROM axcelium.refresh_tokens_with_token_version
 WHERE organization_id = ? AND application_id = ?AND token_id = ? AND token_version=?;
 "#;
@@ -34,7 +49,12 @@ pub const REVOKE_REFRESH_TOKEN: &str = r#"
 pub const FIND_REFRESH_TOKEN_BY_USER_PAGINATED: &str = r#"
 SELECT
     application_id, organization_id, token_id, encrypted_token_secret,
-    parent_version, issued_at, expires_at, revoked
+    parent_version, issued_at, expires_at, revoked,
+    last_used_at, device_name, ip_address, user_agent
 FROM axcelium.refresh_tokens_by_user
 WHERE organization_id = ? AND application_id = ?AND user_id = ? ;
-"#;
\ No newline at end of file
+"#;
+pub const DELETE_REFRESH_TOKEN: &str = r#"
+    DELETE FROM axcelium.refresh_tokens
+    WHERE organization_id = ? AND application_id = ? AND token_id = ?;
+"#;
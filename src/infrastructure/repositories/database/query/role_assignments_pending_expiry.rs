@@ -0,0 +1,16 @@
+pub const INSERT_PENDING_EXPIRY: &str = r#"
+    INSERT INTO axcelium.role_assignments_pending_expiry (
+        expires_day, organization_id, application_id, user_id, role_id, expires_at
+    ) VALUES (?, ?, ?, ?, ?, ?)
+"#;
+
+pub const QUERY_PENDING_EXPIRIES: &str = r#"
+    SELECT organization_id, application_id, user_id, role_id, expires_at
+    FROM axcelium.role_assignments_pending_expiry
+    WHERE expires_day = ?
+"#;
+
+pub const DELETE_PENDING_EXPIRY: &str = r#"
+    DELETE FROM axcelium.role_assignments_pending_expiry
+    WHERE expires_day = ? AND organization_id = ? AND application_id = ? AND user_id = ? AND role_id = ?
+"#;
@@ -1,11 +1,11 @@
 pub const INSERT_ROLE_BY_APP: &str = r#"
 INSERT INTO axcelium.roles_by_app (
     organization_id, application_id, role_id,
-    name, description, permissions,
+    name, description, permissions, parent_role_ids,
     created_at, updated_at
 ) VALUES (
     :organization_id, :application_id, :role_id,
-    :name, :description, :permissions,
+    :name, :description, :permissions, :parent_role_ids,
     :created_at, :updated_at
 );"#;
 
@@ -14,6 +14,7 @@ UPDATE axcelium.roles_by_app SET
     name = :name,
     description = :description,
     permissions = :permissions,
+    parent_role_ids = :parent_role_ids,
     updated_at = toTimestamp(now())
 WHERE organization_id = :organization_id
   AND application_id = :application_id
@@ -21,7 +22,7 @@ WHERE organization_id = :organization_id
 
 pub const SELECT_ROLE_BY_ID: &str = r#"
 SELECT
-    name, description, permissions,
+    name, description, permissions, parent_role_ids,
     created_at, updated_at
 FROM axcelium.roles_by_app
 WHERE organization_id = ? AND application_id = ? AND role_id = ?;
@@ -29,7 +30,7 @@ WHERE organization_id = ? AND application_id = ? AND role_id = ?;
 
 pub const SELECT_ROLES_BY_ID: &str = r#"
 SELECT
-    role_id, name, description, permissions,
+    role_id, name, description, permissions, parent_role_ids,
     created_at, updated_at
 FROM axcelium.roles_by_app
 WHERE organization_id = ? AND application_id = ? ;
@@ -0,0 +1,37 @@
+pub const INSERT_SERVICE_ACCOUNT: &str = r#"
+INSERT INTO axcelium.service_accounts (
+    organization_id, application_id, service_account_id, application_client_id, client_id,
+    name, description, encrypted_client_secret, is_active, created_at, updated_at
+) VALUES (
+    :organization_id, :application_id, :service_account_id, :application_client_id, :client_id,
+    :name, :description, :encrypted_client_secret, :is_active, :created_at, :updated_at
+);"#;
+
+pub const QUERY_SERVICE_ACCOUNTS_BY_APP: &str = r#"
+SELECT
+    organization_id, application_id, service_account_id, application_client_id, client_id,
+    name, description, encrypted_client_secret, is_active, created_at, updated_at
+FROM axcelium.service_accounts
+WHERE organization_id = ? AND application_id = ?;
+"#;
+
+pub const QUERY_SERVICE_ACCOUNT: &str = r#"
+SELECT
+    organization_id, application_id, service_account_id, application_client_id, client_id,
+    name, description, encrypted_client_secret, is_active, created_at, updated_at
+FROM axcelium.service_accounts
+WHERE organization_id = ? AND application_id = ? AND service_account_id = ?;
+"#;
+
+pub const QUERY_SERVICE_ACCOUNT_BY_CLIENT_ID: &str = r#"
+SELECT
+    organization_id, application_id, service_account_id, application_client_id, client_id,
+    name, description, encrypted_client_secret, is_active, created_at, updated_at
+FROM axcelium.service_accounts_by_client_id
+WHERE client_id = ?;
+"#;
+
+pub const DELETE_SERVICE_ACCOUNT: &str = r#"
+DELETE FROM axcelium.service_accounts
+WHERE organization_id = ? AND application_id = ? AND service_account_id = ?;
+"#;
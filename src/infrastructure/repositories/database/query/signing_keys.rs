@@ -0,0 +1,41 @@
+pub const INSERT_SIGNING_KEY: &str = r#"
+INSERT INTO axcelium.application_signing_keys (
+    organization_id, application_id, kid, public_key,
+    encrypted_private_key, private_key_nonce, created_at, retired_at, expires_at
+) VALUES (
+    :organization_id, :application_id, :kid, :public_key,
+    :encrypted_private_key, :private_key_nonce, :created_at, :retired_at, :expires_at
+);"#;
+
+pub const QUERY_SIGNING_KEYS_BY_APP: &str = r#"
+SELECT
+    organization_id, application_id, kid, public_key,
+    encrypted_private_key, private_key_nonce, created_at, retired_at, expires_at
+FROM axcelium.application_signing_keys
+WHERE organization_id = ? AND application_id = ?;
+"#;
+
+pub const QUERY_SIGNING_KEY_BY_KID: &str = r#"
+SELECT
+    organization_id, application_id, kid, public_key,
+    encrypted_private_key, private_key_nonce, created_at, retired_at, expires_at
+FROM axcelium.application_signing_keys_by_kid
+WHERE kid = ?;
+"#;
+
+pub const QUERY_SCAN_SIGNING_KEYS: &str = r#"
+SELECT
+    organization_id, application_id, kid, public_key,
+    encrypted_private_key, private_key_nonce, created_at, retired_at, expires_at
+FROM axcelium.application_signing_keys
+"#;
+
+pub const RETIRE_SIGNING_KEY: &str = r#"
+UPDATE axcelium.application_signing_keys SET retired_at = ?, expires_at = ?
+WHERE organization_id = ? AND application_id = ? AND kid = ?;
+"#;
+
+pub const DELETE_SIGNING_KEY: &str = r#"
+DELETE FROM axcelium.application_signing_keys
+WHERE organization_id = ? AND application_id = ? AND kid = ?;
+"#;
@@ -0,0 +1,25 @@
+pub const INSERT_USER_IDENTITY: &str = r#"
+INSERT INTO axcelium.user_identities (
+    organization_id, application_id, provider_id, subject, user_id, email, created_at
+) VALUES (
+    :organization_id, :application_id, :provider_id, :subject, :user_id, :email, :created_at
+) IF NOT EXISTS;"#;
+
+pub const QUERY_USER_IDENTITY: &str = r#"
+SELECT
+    organization_id, application_id, provider_id, subject, user_id, email, created_at
+FROM axcelium.user_identities
+WHERE organization_id = ? AND application_id = ? AND provider_id = ? AND subject = ?;
+"#;
+
+pub const QUERY_USER_IDENTITIES_BY_USER: &str = r#"
+SELECT
+    organization_id, application_id, provider_id, subject, user_id, email, created_at
+FROM axcelium.user_identities_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ?;
+"#;
+
+pub const DELETE_USER_IDENTITY: &str = r#"
+DELETE FROM axcelium.user_identities
+WHERE organization_id = ? AND application_id = ? AND provider_id = ? AND subject = ?;
+"#;
@@ -0,0 +1,21 @@
+pub const RESERVE_USERNAME: &str = r#"
+    INSERT INTO axcelium.username_reservations (organization_id, application_id, username_normalized, user_id)
+    VALUES (?, ?, ?, ?) IF NOT EXISTS
+"#;
+
+pub const RELEASE_USERNAME: &str = r#"
+    DELETE FROM axcelium.username_reservations
+    WHERE organization_id = ? AND application_id = ? AND username_normalized = ?
+    IF user_id = ?
+"#;
+
+pub const RESERVE_EMAIL: &str = r#"
+    INSERT INTO axcelium.email_reservations (organization_id, application_id, email_normalized, user_id)
+    VALUES (?, ?, ?, ?) IF NOT EXISTS
+"#;
+
+pub const RELEASE_EMAIL: &str = r#"
+    DELETE FROM axcelium.email_reservations
+    WHERE organization_id = ? AND application_id = ? AND email_normalized = ?
+    IF user_id = ?
+"#;
@@ -1,14 +1,32 @@
 pub const ASSIGN_ROLE_TO_USER: &str = r#"
 INSERT INTO axcelium.user_roles_by_user (
     organization_id, application_id, user_id,
-    role_id, assigned_at
+    role_id, assigned_at, expires_at
 ) VALUES (
     :organization_id, :application_id, :user_id,
-    :role_id, toTimestamp(now())
-);"#;
+    :role_id, toTimestamp(now()), :expires_at
+) USING TTL :ttl;"#;
 
 pub const LIST_ROLES_OF_USER: &str = r#"
-SELECT role_id, assigned_at
+SELECT role_id, assigned_at, expires_at
 FROM axcelium.user_roles_by_user
 WHERE organization_id = ? AND application_id = ? AND user_id = ?;
-"#;
\ No newline at end of file
+"#;
+pub const SELECT_ROLE_OF_USER: &str = r#"
+SELECT role_id, assigned_at, expires_at
+FROM axcelium.user_roles_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ? AND role_id = ?;
+"#;
+pub const REMOVE_ROLES_OF_USER: &str = r#"
+DELETE FROM axcelium.user_roles_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ?;
+"#;
+pub const REMOVE_ROLE_OF_USER: &str = r#"
+DELETE FROM axcelium.user_roles_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ? AND role_id = ?;
+"#;
+pub const LAPSE_ROLE_OF_USER: &str = r#"
+DELETE FROM axcelium.user_roles_by_user
+WHERE organization_id = ? AND application_id = ? AND user_id = ? AND role_id = ?
+IF expires_at = ?;
+"#;
@@ -15,34 +15,37 @@ WHERE organization_id = ? AND application_id = ?;
 pub const INSERT_USER: &str = r#"
     INSERT INTO axcelium.users (
         user_id, organization_id, application_id,
-        username, email, hashed_password, locked_at,
+        username, email, username_normalized, email_normalized, hashed_password, locked_at,
+        first_name, last_name, locale, phone, avatar_url, metadata,
         created_at, updated_at,
         is_active, is_verified, is_locked, mfa_enabled, last_login, deactivated_at
-    ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) IF NOT EXISTS
+    ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) IF NOT EXISTS
 "#;
 
 pub const QUERY_FIND_USER_BY_EMAIL: &str = r#"
     SELECT username, user_id, email
     FROM axcelium.users_by_email_app_org
-    WHERE email = ? AND application_id = ? AND organization_id = ?
+    WHERE email_normalized = ? AND application_id = ? AND organization_id = ?
 "#;
 
 pub const QUERY_FIND_USER_BY_USERNAME: &str = r#"
     SELECT username, user_id, email
     FROM axcelium.users_by_username
-    WHERE username = ? AND application_id = ? AND organization_id = ?
+    WHERE username_normalized = ? AND application_id = ? AND organization_id = ?
 "#;
 pub const QUERY_FIND_ALL_USERS_PAGINATED: &str = r#"
     SELECT user_id, organization_id, application_id, username,
         email, created_at, updated_at, is_active, is_verified, is_locked,
-        last_login, mfa_enabled, deactivated_at,locked_at
+        last_login, mfa_enabled, deactivated_at,locked_at,
+        first_name, last_name, locale, phone, avatar_url, metadata
     FROM axcelium.users_by_app
     WHERE organization_id = ? AND application_id = ?
 "#;
 pub const QUERY_FIND_USER: &str = r#"
     SELECT user_id, organization_id, application_id, username,
         email, created_at, updated_at, is_active, is_verified, is_locked,locked_at,
-        last_login, mfa_enabled, deactivated_at
+        last_login, mfa_enabled, deactivated_at,
+        first_name, last_name, locale, phone, avatar_url, metadata
     FROM axcelium.users
     WHERE organization_id = ? AND application_id = ? AND user_id = ?
 "#;
@@ -50,7 +53,9 @@ pub const QUERY_FIND_USER: &str = r#"
 pub const QUERY_FIND_RAW_USER: &str = r#"
     SELECT user_id, organization_id, application_id, username,
         email, created_at, updated_at, is_active, is_verified, is_locked,locked_at,
-        last_login, mfa_enabled, deactivated_at, hashed_password
+        last_login, mfa_enabled, deactivated_at, hashed_password,
+        username_normalized, email_normalized,
+        first_name, last_name, locale, phone, avatar_url, metadata
     FROM axcelium.users
     WHERE organization_id = ? AND application_id = ? AND user_id = ?
 "#;
@@ -58,6 +63,9 @@ pub const QUERY_FIND_RAW_USER: &str = r#"
 pub const UPDATE_USER: &str = r#"
     UPDATE axcelium.users SET
         username = :username, email = :email, hashed_password = :hashed_password,
+        username_normalized = :username_normalized, email_normalized = :email_normalized,
+        first_name = :first_name, last_name = :last_name, locale = :locale, phone = :phone,
+        avatar_url = :avatar_url, metadata = :metadata,
         locked_at = :locked_at, created_at = :created_at, updated_at = :updated_at,
         is_active = :is_active, is_verified = :is_verified, is_locked = :is_locked,
         mfa_enabled = :mfa_enabled, last_login = :last_login, deactivated_at = :deactivated_at
@@ -82,4 +90,59 @@ pub const UNBAN_USER: &str = r#"
 pub const DISABLE_MFA_USER: &str = r#"
     UPDATE axcelium.users SET mfa_enabled=false, updated_at =  toTimestamp(now())
     WHERE organization_id = :organization_id AND application_id = :application_id AND user_id = :user_id;
-"#;
\ No newline at end of file
+"#;
+pub const SOFT_DELETE_USER: &str = r#"
+    UPDATE axcelium.users SET is_active=false, deactivated_at = ?, updated_at = ?
+    WHERE organization_id = ? AND application_id = ? AND user_id = ?;
+"#;
+
+pub const INSERT_USER_PENDING_PURGE: &str = r#"
+    INSERT INTO axcelium.users_pending_purge (
+        deactivated_day, organization_id, application_id, user_id, deactivated_at
+    ) VALUES (?, ?, ?, ?, ?)
+"#;
+
+pub const QUERY_USERS_PENDING_PURGE: &str = r#"
+    SELECT organization_id, application_id, user_id, deactivated_at
+    FROM axcelium.users_pending_purge
+    WHERE deactivated_day = ?
+"#;
+
+pub const DELETE_USER_PENDING_PURGE: &str = r#"
+    DELETE FROM axcelium.users_pending_purge
+    WHERE deactivated_day = ? AND organization_id = ? AND application_id = ? AND user_id = ?
+"#;
+
+pub const INSERT_PASSWORD_HISTORY: &str = r#"
+    INSERT INTO axcelium.user_password_history (
+        organization_id, application_id, user_id, changed_at, hashed_password
+    ) VALUES (?, ?, ?, ?, ?)
+"#;
+
+pub const QUERY_PASSWORD_HISTORY: &str = r#"
+    SELECT hashed_password FROM axcelium.user_password_history
+    WHERE organization_id = ? AND application_id = ? AND user_id = ?
+    LIMIT ?
+"#;
+
+pub const DELETE_PASSWORD_HISTORY: &str = r#"
+    DELETE FROM axcelium.user_password_history
+    WHERE organization_id = ? AND application_id = ? AND user_id = ?
+"#;
+
+pub const QUERY_SCAN_USER_IDENTIFIERS: &str = r#"
+    SELECT organization_id, application_id, user_id, username, email,
+        username_normalized, email_normalized
+    FROM axcelium.users
+"#;
+
+pub const UPDATE_USER_NORMALIZED: &str = r#"
+    UPDATE axcelium.users SET username_normalized = ?, email_normalized = ?
+    WHERE organization_id = ? AND application_id = ? AND user_id = ?
+"#;
+
+pub const UPDATE_HASHED_PASSWORD: &str = r#"
+    UPDATE axcelium.users SET hashed_password = ?
+    WHERE organization_id = ? AND application_id = ? AND user_id = ?
+    IF hashed_password = ?
+"#;
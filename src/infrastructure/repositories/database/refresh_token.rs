@@ -2,20 +2,23 @@ use crate::{
     domain::errors::repositories_errors::RepositoryResult,
     infrastructure::models::refresh_token::{
         FoundRefreshTokenModel, FoundRefreshTokenModelByUser, PaginatedRefreshTokensByUserModel,
-        RefreshTokenModel, UpdateRefreshTokenQuery,
+        RefreshTokenModel, StoreRefreshTokenQuery,
     },
 };
 use async_trait::async_trait;
 use scylla::{
-    client::session::Session, response::PagingState, statement::prepared::PreparedStatement,
+    client::session::Session,
+    response::PagingState,
+    statement::prepared::PreparedStatement,
 };
 use std::{ops::ControlFlow, sync::Arc};
 use uuid::Uuid;
 
 use super::query::refresh_token::{
-    FIND_REFRESH_TOKEN_BY_USER_PAGINATED, INSERT_REFRESH_TOKEN, QUERY_REFRESH_TOKEN,
-    REVOKE_REFRESH_TOKEN, UPDATE_REFRESH_TOKEN,
+    DELETE_REFRESH_TOKEN, FIND_REFRESH_TOKEN_BY_USER_PAGINATED, INSERT_REFRESH_TOKEN,
+    QUERY_REFRESH_TOKEN, REVOKE_REFRESH_TOKEN, UPDATE_REFRESH_TOKEN,
 };
+use super::user_repository::is_applied;
 pub struct RefreshTokenDatabaseRepositoryImpl {
     pub database: Arc<Session>,
     insert_refresh_token: PreparedStatement,
@@ -23,6 +26,7 @@ pub struct RefreshTokenDatabaseRepositoryImpl {
     query_refresh_token: PreparedStatement,
     revoke_refresh_token: PreparedStatement,
     find_refresh_token_by_user: PreparedStatement,
+    delete_refresh_token: PreparedStatement,
 }
 
 impl RefreshTokenDatabaseRepositoryImpl {
@@ -35,6 +39,7 @@ impl RefreshTokenDatabaseRepositoryImpl {
             .prepare(FIND_REFRESH_TOKEN_BY_USER_PAGINATED)
             .await
             .unwrap();
+        let delete_refresh_token = database.prepare(DELETE_REFRESH_TOKEN).await.unwrap();
         Self {
             database,
             insert_refresh_token,
@@ -42,15 +47,21 @@ impl RefreshTokenDatabaseRepositoryImpl {
             query_refresh_token,
             revoke_refresh_token,
             find_refresh_token_by_user,
+            delete_refresh_token,
         }
     }
 }
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait RefreshTokenDatabaseRepository: Send + Sync {
-    async fn create_refresh_token(&self, rt: &RefreshTokenModel) -> RepositoryResult<()>;
+    async fn create_refresh_token(&self, rt: &RefreshTokenModel, ttl: i32)
+    -> RepositoryResult<()>;
 
-    async fn update_refresh_token(&self, rt: &RefreshTokenModel) -> RepositoryResult<()>;
+    /// Rewrites the whole row with a fresh TTL unless it has been revoked in
+    /// the meantime; returns whether the write was applied.
+    async fn update_refresh_token(&self, rt: &RefreshTokenModel, ttl: i32)
+    -> RepositoryResult<bool>;
     async fn find_refresh_token(
         &self,
         org_id: Uuid,
@@ -73,25 +84,43 @@ pub trait RefreshTokenDatabaseRepository: Send + Sync {
         page_size: i32,
         paging_state_u8: Option<Vec<u8>>,
     ) -> RepositoryResult<PaginatedRefreshTokensByUserModel>;
+    async fn delete_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()>;
 }
 
 #[async_trait]
 impl RefreshTokenDatabaseRepository for RefreshTokenDatabaseRepositoryImpl {
-    async fn create_refresh_token(&self, rt: &RefreshTokenModel) -> RepositoryResult<()> {
+    async fn create_refresh_token(
+        &self,
+        rt: &RefreshTokenModel,
+        ttl: i32,
+    ) -> RepositoryResult<()> {
         self.database
-            .execute_unpaged(&self.insert_refresh_token, rt)
+            .execute_unpaged(
+                &self.insert_refresh_token,
+                StoreRefreshTokenQuery::new(rt, ttl),
+            )
             .await?;
         Ok(())
     }
 
-    async fn update_refresh_token(&self, rt: &RefreshTokenModel) -> RepositoryResult<()> {
-        self.database
+    async fn update_refresh_token(
+        &self,
+        rt: &RefreshTokenModel,
+        ttl: i32,
+    ) -> RepositoryResult<bool> {
+        let result = self
+            .database
             .execute_unpaged(
                 &self.prepared_update_refresh_token,
-                UpdateRefreshTokenQuery::from(rt),
+                StoreRefreshTokenQuery::new(rt, ttl),
             )
             .await?;
-        Ok(())
+        is_applied(result)
     }
     async fn find_refresh_token(
         &self,
@@ -154,4 +183,16 @@ impl RefreshTokenDatabaseRepository for RefreshTokenDatabaseRepositoryImpl {
             .await?;
         Ok(())
     }
+
+    async fn delete_refresh_token(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        token_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.delete_refresh_token, (org_id, app_id, token_id))
+            .await?;
+        Ok(())
+    }
 }
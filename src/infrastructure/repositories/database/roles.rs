@@ -1,7 +1,9 @@
 use async_trait::async_trait;
+use chrono::Utc;
 use scylla::{
     client::session::Session,
     statement::{batch::Batch, prepared::PreparedStatement},
+    value::CqlTimestamp,
 };
 use std::sync::Arc;
 use uuid::Uuid;
@@ -9,18 +11,26 @@ use uuid::Uuid;
 use crate::{
     domain::errors::repositories_errors::RepositoryResult,
     infrastructure::models::role::{
-        RoleAssignmentModel, RoleModel, RoleUserModel, SelectedRoleByAppModel, SelectedRoleByIdModel, UpdateRoleModel, UserRoleModel
+        PendingRoleExpiryModel, RoleAssignmentModel, RoleModel, RoleUserModel,
+        SelectedRoleByAppModel, SelectedRoleByIdModel, UpdateRoleModel, UserRoleModel,
     },
 };
 
 use super::query::{
-    role_users_by_role::{ASSIGN_USER_TO_ROLE, LIST_USERS_IN_ROLE},
+    role_assignments_pending_expiry::{
+        DELETE_PENDING_EXPIRY, INSERT_PENDING_EXPIRY, QUERY_PENDING_EXPIRIES,
+    },
+    role_users_by_role::{ASSIGN_USER_TO_ROLE, LIST_USERS_IN_ROLE, REMOVE_USER_FROM_ROLE},
     roles_by_app::{
         DELETE_ROLE_BY_ID, INSERT_ROLE_BY_APP, SELECT_ROLE_BY_ID, SELECT_ROLES_BY_ID,
         UPDATE_ROLE_BY_APP,
     },
-    user_roles_by_user::{ASSIGN_ROLE_TO_USER, LIST_ROLES_OF_USER},
+    user_roles_by_user::{
+        ASSIGN_ROLE_TO_USER, LAPSE_ROLE_OF_USER, LIST_ROLES_OF_USER, REMOVE_ROLE_OF_USER,
+        REMOVE_ROLES_OF_USER, SELECT_ROLE_OF_USER,
+    },
 };
+use super::user_repository::is_applied;
 
 pub struct RoleDatabaseRepositoryImpl {
     pub database: Arc<Session>,
@@ -32,6 +42,20 @@ pub struct RoleDatabaseRepositoryImpl {
     get_roles_stmt: PreparedStatement,
     delete_role_stmt: PreparedStatement,
     update_role_stmt: PreparedStatement,
+    remove_user_from_role_stmt: Batch,
+    remove_roles_of_user_stmt: PreparedStatement,
+    lapse_role_of_user_stmt: PreparedStatement,
+    remove_role_user_stmt: PreparedStatement,
+    get_role_of_user_stmt: PreparedStatement,
+    insert_pending_expiry_stmt: PreparedStatement,
+    find_pending_expiries_stmt: PreparedStatement,
+    delete_pending_expiry_stmt: PreparedStatement,
+}
+
+/// Expiring rows are kept for a grace period past `expires_at` until the
+/// expiry job removes them, so reads must drop them as soon as it passes.
+fn not_lapsed(expires_at: Option<CqlTimestamp>, now: i64) -> bool {
+    expires_at.is_none_or(|e| e.0 > now)
 }
 
 impl RoleDatabaseRepositoryImpl {
@@ -47,6 +71,17 @@ impl RoleDatabaseRepositoryImpl {
         let assign_user_to_role_stmt: Batch = database.prepare_batch(&assign_batch).await.unwrap();
         let update_role_stmt = database.prepare(UPDATE_ROLE_BY_APP).await.unwrap();
         let delete_role_stmt = database.prepare(DELETE_ROLE_BY_ID).await.unwrap();
+        let mut remove_batch: Batch = Default::default();
+        remove_batch.append_statement(REMOVE_USER_FROM_ROLE);
+        remove_batch.append_statement(REMOVE_ROLE_OF_USER);
+        let remove_user_from_role_stmt: Batch = database.prepare_batch(&remove_batch).await.unwrap();
+        let remove_roles_of_user_stmt = database.prepare(REMOVE_ROLES_OF_USER).await.unwrap();
+        let lapse_role_of_user_stmt = database.prepare(LAPSE_ROLE_OF_USER).await.unwrap();
+        let remove_role_user_stmt = database.prepare(REMOVE_USER_FROM_ROLE).await.unwrap();
+        let get_role_of_user_stmt = database.prepare(SELECT_ROLE_OF_USER).await.unwrap();
+        let insert_pending_expiry_stmt = database.prepare(INSERT_PENDING_EXPIRY).await.unwrap();
+        let find_pending_expiries_stmt = database.prepare(QUERY_PENDING_EXPIRIES).await.unwrap();
+        let delete_pending_expiry_stmt = database.prepare(DELETE_PENDING_EXPIRY).await.unwrap();
 
         Self {
             database,
@@ -58,14 +93,25 @@ impl RoleDatabaseRepositoryImpl {
             get_role_stmt,
             update_role_stmt,
             delete_role_stmt,
+            remove_user_from_role_stmt,
+            remove_roles_of_user_stmt,
+            lapse_role_of_user_stmt,
+            remove_role_user_stmt,
+            get_role_of_user_stmt,
+            insert_pending_expiry_stmt,
+            find_pending_expiries_stmt,
+            delete_pending_expiry_stmt,
         }
     }
 }
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait RoleDatabaseRepository: Send + Sync {
     async fn create_role(&self, role: &RoleModel) -> RepositoryResult<()>;
     async fn update_role(&self, update: &UpdateRoleModel) -> RepositoryResult<()>;
     async fn delete_role(&self, org_id: Uuid, app_id: Uuid, role: Uuid) -> RepositoryResult<()>;
+    /// Writes both assignment rows and, when the assignment expires, queues
+    /// it for the expiry job.
     async fn assign_user_to_role(&self, assignment: &RoleAssignmentModel) -> RepositoryResult<()>;
     async fn get_role(
         &self,
@@ -91,6 +137,50 @@ pub trait RoleDatabaseRepository: Send + Sync {
         app_id: Uuid,
         role_id: Uuid,
     ) -> RepositoryResult<Vec<RoleUserModel>>;
+    async fn get_role_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Option<UserRoleModel>>;
+    /// Also forgets a pending expiry, so removing an assignment by hand
+    /// doesn't report it as lapsed later.
+    async fn remove_user_from_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        role_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    async fn remove_roles_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+    /// Removes the assignment only if it still expires at `expires_at`;
+    /// returns false when it was renewed or removed in the meantime.
+    async fn lapse_assignment(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+        expires_at: CqlTimestamp,
+    ) -> RepositoryResult<bool>;
+    async fn get_pending_expiries(
+        &self,
+        expires_day: String,
+    ) -> RepositoryResult<Vec<PendingRoleExpiryModel>>;
+    async fn delete_pending_expiry(
+        &self,
+        expires_day: String,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()>;
 }
 #[async_trait]
 impl RoleDatabaseRepository for RoleDatabaseRepositoryImpl {
@@ -118,6 +208,21 @@ impl RoleDatabaseRepository for RoleDatabaseRepositoryImpl {
         self.database
             .batch(&self.assign_user_to_role_stmt, (assignment, assignment))
             .await?;
+        if let Some(expires_at) = assignment.expires_at {
+            self.database
+                .execute_unpaged(
+                    &self.insert_pending_expiry_stmt,
+                    (
+                        PendingRoleExpiryModel::day(expires_at.0),
+                        assignment.organization_id,
+                        assignment.application_id,
+                        assignment.user_id,
+                        assignment.role_id,
+                        expires_at,
+                    ),
+                )
+                .await?;
+        }
         Ok(())
     }
 
@@ -158,10 +263,15 @@ impl RoleDatabaseRepository for RoleDatabaseRepositoryImpl {
             .database
             .execute_unpaged(&self.get_roles_by_user_stmt, (org_id, app_id, user_id))
             .await?;
-        Ok(res
+        let now = Utc::now().timestamp_millis();
+        let roles: Vec<UserRoleModel> = res
             .into_rows_result()?
             .rows::<UserRoleModel>()?
-            .collect::<Result<_, _>>()?)
+            .collect::<Result<_, _>>()?;
+        Ok(roles
+            .into_iter()
+            .filter(|r| not_lapsed(r.expires_at, now))
+            .collect())
     }
 
     async fn get_users_by_role(
@@ -174,9 +284,132 @@ impl RoleDatabaseRepository for RoleDatabaseRepositoryImpl {
             .database
             .execute_unpaged(&self.get_users_by_role_stmt, (org_id, app_id, role_id))
             .await?;
-        Ok(res
+        let now = Utc::now().timestamp_millis();
+        let users: Vec<RoleUserModel> = res
             .into_rows_result()?
             .rows::<RoleUserModel>()?
+            .collect::<Result<_, _>>()?;
+        Ok(users
+            .into_iter()
+            .filter(|u| not_lapsed(u.expires_at, now))
+            .collect())
+    }
+
+    async fn get_role_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<Option<UserRoleModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.get_role_of_user_stmt, (org_id, app_id, user_id, role_id))
+            .await?;
+        let role: Option<UserRoleModel> = res.into_rows_result()?.maybe_first_row()?;
+        let now = Utc::now().timestamp_millis();
+        Ok(role.filter(|r| not_lapsed(r.expires_at, now)))
+    }
+
+    async fn remove_user_from_role(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        role_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let assignment = self
+            .get_role_of_user(org_id, app_id, user_id, role_id)
+            .await?;
+        self.database
+            .batch(
+                &self.remove_user_from_role_stmt,
+                (
+                    (org_id, app_id, role_id, user_id),
+                    (org_id, app_id, user_id, role_id),
+                ),
+            )
+            .await?;
+        if let Some(expires_at) = assignment.and_then(|a| a.expires_at) {
+            self.delete_pending_expiry(
+                PendingRoleExpiryModel::day(expires_at.0),
+                org_id,
+                app_id,
+                user_id,
+                role_id,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn remove_roles_of_user(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.remove_roles_of_user_stmt, (org_id, app_id, user_id))
+            .await?;
+        Ok(())
+    }
+
+    async fn lapse_assignment(
+        &self,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+        expires_at: CqlTimestamp,
+    ) -> RepositoryResult<bool> {
+        let result = self
+            .database
+            .execute_unpaged(
+                &self.lapse_role_of_user_stmt,
+                (org_id, app_id, user_id, role_id, expires_at),
+            )
+            .await?;
+        let applied = is_applied(result)?;
+        if applied {
+            self.database
+                .execute_unpaged(
+                    &self.remove_role_user_stmt,
+                    (org_id, app_id, role_id, user_id),
+                )
+                .await?;
+        }
+        Ok(applied)
+    }
+
+    async fn get_pending_expiries(
+        &self,
+        expires_day: String,
+    ) -> RepositoryResult<Vec<PendingRoleExpiryModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.find_pending_expiries_stmt, (expires_day,))
+            .await?;
+        Ok(res
+            .into_rows_result()?
+            .rows::<PendingRoleExpiryModel>()?
             .collect::<Result<_, _>>()?)
     }
+
+    async fn delete_pending_expiry(
+        &self,
+        expires_day: String,
+        org_id: Uuid,
+        app_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.delete_pending_expiry_stmt,
+                (expires_day, org_id, app_id, user_id, role_id),
+            )
+            .await?;
+        Ok(())
+    }
 }
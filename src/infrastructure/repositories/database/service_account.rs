@@ -0,0 +1,152 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::models::service_account::ServiceAccountModel,
+};
+use async_trait::async_trait;
+use scylla::{
+    client::session::Session,
+    statement::{Consistency, prepared::PreparedStatement},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::query::service_accounts::{
+    DELETE_SERVICE_ACCOUNT, INSERT_SERVICE_ACCOUNT, QUERY_SERVICE_ACCOUNT,
+    QUERY_SERVICE_ACCOUNT_BY_CLIENT_ID, QUERY_SERVICE_ACCOUNTS_BY_APP,
+};
+
+pub struct ServiceAccountDatabaseRepositoryImpl {
+    pub database: Arc<Session>,
+    insert_service_account: PreparedStatement,
+    find_service_accounts: PreparedStatement,
+    find_service_account: PreparedStatement,
+    find_service_account_by_client_id: PreparedStatement,
+    delete_service_account: PreparedStatement,
+}
+
+impl ServiceAccountDatabaseRepositoryImpl {
+    pub async fn new(database: Arc<Session>) -> Self {
+        let mut insert_service_account = database.prepare(INSERT_SERVICE_ACCOUNT).await.unwrap();
+        insert_service_account.set_consistency(Consistency::Quorum);
+        let mut find_service_accounts = database
+            .prepare(QUERY_SERVICE_ACCOUNTS_BY_APP)
+            .await
+            .unwrap();
+        find_service_accounts.set_consistency(Consistency::Quorum);
+        let mut find_service_account = database.prepare(QUERY_SERVICE_ACCOUNT).await.unwrap();
+        find_service_account.set_consistency(Consistency::Quorum);
+        let mut find_service_account_by_client_id = database
+            .prepare(QUERY_SERVICE_ACCOUNT_BY_CLIENT_ID)
+            .await
+            .unwrap();
+        find_service_account_by_client_id.set_consistency(Consistency::One);
+        let mut delete_service_account = database.prepare(DELETE_SERVICE_ACCOUNT).await.unwrap();
+        delete_service_account.set_consistency(Consistency::Quorum);
+        Self {
+            database,
+            insert_service_account,
+            find_service_accounts,
+            find_service_account,
+            find_service_account_by_client_id,
+            delete_service_account,
+        }
+    }
+}
+
+#[async_trait]
+pub trait ServiceAccountDatabaseRepository: Send + Sync {
+    async fn create_service_account(&self, account: &ServiceAccountModel) -> RepositoryResult<()>;
+    async fn find_service_accounts(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<ServiceAccountModel>>;
+    async fn find_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>>;
+    async fn find_service_account_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>>;
+    async fn delete_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl ServiceAccountDatabaseRepository for ServiceAccountDatabaseRepositoryImpl {
+    async fn create_service_account(&self, account: &ServiceAccountModel) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.insert_service_account, account)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_service_accounts(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<ServiceAccountModel>> {
+        let accounts = self
+            .database
+            .execute_unpaged(
+                &self.find_service_accounts,
+                (organization_id, application_id),
+            )
+            .await?
+            .into_rows_result()?
+            .rows::<ServiceAccountModel>()?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(accounts)
+    }
+
+    async fn find_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>> {
+        let result = self
+            .database
+            .execute_unpaged(
+                &self.find_service_account,
+                (organization_id, application_id, service_account_id),
+            )
+            .await?
+            .into_rows_result()?;
+        Ok(result.maybe_first_row::<ServiceAccountModel>()?)
+    }
+
+    async fn find_service_account_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> RepositoryResult<Option<ServiceAccountModel>> {
+        let result = self
+            .database
+            .execute_unpaged(&self.find_service_account_by_client_id, (client_id,))
+            .await?
+            .into_rows_result()?;
+        Ok(result.maybe_first_row::<ServiceAccountModel>()?)
+    }
+
+    async fn delete_service_account(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        service_account_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.delete_service_account,
+                (organization_id, application_id, service_account_id),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::models::signing_key::{PaginatedSigningKeysModel, SigningKeyModel},
+};
+use async_trait::async_trait;
+use scylla::{
+    client::session::Session,
+    response::PagingState,
+    statement::{Consistency, prepared::PreparedStatement},
+    value::CqlTimestamp,
+};
+use std::{ops::ControlFlow, sync::Arc};
+use uuid::Uuid;
+
+use super::query::signing_keys::{
+    DELETE_SIGNING_KEY, INSERT_SIGNING_KEY, QUERY_SCAN_SIGNING_KEYS, QUERY_SIGNING_KEY_BY_KID,
+    QUERY_SIGNING_KEYS_BY_APP, RETIRE_SIGNING_KEY,
+};
+
+pub struct SigningKeyDatabaseRepositoryImpl {
+    pub database: Arc<Session>,
+    insert_signing_key: PreparedStatement,
+    find_signing_keys: PreparedStatement,
+    find_signing_key_by_kid: PreparedStatement,
+    scan_signing_keys: PreparedStatement,
+    retire_signing_key: PreparedStatement,
+    delete_signing_key: PreparedStatement,
+}
+
+impl SigningKeyDatabaseRepositoryImpl {
+    pub async fn new(database: Arc<Session>) -> Self {
+        let mut insert_signing_key = database.prepare(INSERT_SIGNING_KEY).await.unwrap();
+        insert_signing_key.set_consistency(Consistency::Quorum);
+        let mut find_signing_keys = database.prepare(QUERY_SIGNING_KEYS_BY_APP).await.unwrap();
+        find_signing_keys.set_consistency(Consistency::Quorum);
+        let mut find_signing_key_by_kid = database.prepare(QUERY_SIGNING_KEY_BY_KID).await.unwrap();
+        find_signing_key_by_kid.set_consistency(Consistency::One);
+        let mut scan_signing_keys = database.prepare(QUERY_SCAN_SIGNING_KEYS).await.unwrap();
+        scan_signing_keys.set_consistency(Consistency::One);
+        let mut retire_signing_key = database.prepare(RETIRE_SIGNING_KEY).await.unwrap();
+        retire_signing_key.set_consistency(Consistency::Quorum);
+        let mut delete_signing_key = database.prepare(DELETE_SIGNING_KEY).await.unwrap();
+        delete_signing_key.set_consistency(Consistency::Quorum);
+        Self {
+            database,
+            insert_signing_key,
+            find_signing_keys,
+            find_signing_key_by_kid,
+            scan_signing_keys,
+            retire_signing_key,
+            delete_signing_key,
+        }
+    }
+}
+
+#[async_trait]
+pub trait SigningKeyDatabaseRepository: Send + Sync {
+    async fn create_signing_key(&self, key: &SigningKeyModel) -> RepositoryResult<()>;
+    async fn find_signing_keys(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<SigningKeyModel>>;
+    async fn find_signing_key_by_kid(&self, kid: &str)
+    -> RepositoryResult<Option<SigningKeyModel>>;
+    async fn scan_signing_keys(
+        &self,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedSigningKeysModel>;
+    async fn retire_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: &str,
+        retired_at: CqlTimestamp,
+        expires_at: CqlTimestamp,
+    ) -> RepositoryResult<()>;
+    async fn delete_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: &str,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl SigningKeyDatabaseRepository for SigningKeyDatabaseRepositoryImpl {
+    async fn create_signing_key(&self, key: &SigningKeyModel) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(&self.insert_signing_key, key)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_signing_keys(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<SigningKeyModel>> {
+        let keys = self
+            .database
+            .execute_unpaged(&self.find_signing_keys, (organization_id, application_id))
+            .await?
+            .into_rows_result()?
+            .rows::<SigningKeyModel>()?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(keys)
+    }
+
+    async fn find_signing_key_by_kid(
+        &self,
+        kid: &str,
+    ) -> RepositoryResult<Option<SigningKeyModel>> {
+        let result = self
+            .database
+            .execute_unpaged(&self.find_signing_key_by_kid, (kid,))
+            .await?
+            .into_rows_result()?;
+        Ok(result.maybe_first_row::<SigningKeyModel>()?)
+    }
+
+    async fn scan_signing_keys(
+        &self,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedSigningKeysModel> {
+        let mut prepared = self.scan_signing_keys.clone();
+        prepared.set_page_size(page_size);
+        let paging_state = paging_state_u8
+            .map(PagingState::new_from_raw_bytes)
+            .unwrap_or_else(PagingState::start);
+        let (res, paging_state_response) = self
+            .database
+            .execute_single_page(&prepared, &(), paging_state)
+            .await?;
+        let keys = res
+            .into_rows_result()?
+            .rows::<SigningKeyModel>()?
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_page_state = match paging_state_response.into_paging_control_flow() {
+            ControlFlow::Break(()) => None,
+            ControlFlow::Continue(state) => state.as_bytes_slice().map(|arc| arc.as_ref().to_vec()),
+        };
+        Ok(PaginatedSigningKeysModel {
+            keys,
+            paging_state: next_page_state,
+        })
+    }
+
+    async fn retire_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: &str,
+        retired_at: CqlTimestamp,
+        expires_at: CqlTimestamp,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.retire_signing_key,
+                (retired_at, expires_at, organization_id, application_id, kid),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_signing_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: &str,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.delete_signing_key,
+                (organization_id, application_id, kid),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::models::user_identity::UserIdentityModel,
+};
+use async_trait::async_trait;
+use scylla::{
+    client::session::Session,
+    statement::{Consistency, SerialConsistency, prepared::PreparedStatement},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::query::user_identities::{
+    DELETE_USER_IDENTITY, INSERT_USER_IDENTITY, QUERY_USER_IDENTITIES_BY_USER, QUERY_USER_IDENTITY,
+};
+use super::user_repository::is_claimed_by;
+
+pub struct UserIdentityDatabaseRepositoryImpl {
+    pub database: Arc<Session>,
+    insert_user_identity: PreparedStatement,
+    find_user_identity: PreparedStatement,
+    find_user_identities_by_user: PreparedStatement,
+    delete_user_identity: PreparedStatement,
+}
+
+impl UserIdentityDatabaseRepositoryImpl {
+    pub async fn new(database: Arc<Session>) -> Self {
+        let mut insert_user_identity = database.prepare(INSERT_USER_IDENTITY).await.unwrap();
+        insert_user_identity.set_consistency(Consistency::Quorum);
+        insert_user_identity.set_serial_consistency(Some(SerialConsistency::Serial));
+        let mut find_user_identity = database.prepare(QUERY_USER_IDENTITY).await.unwrap();
+        find_user_identity.set_consistency(Consistency::Quorum);
+        let mut find_user_identities_by_user = database
+            .prepare(QUERY_USER_IDENTITIES_BY_USER)
+            .await
+            .unwrap();
+        find_user_identities_by_user.set_consistency(Consistency::One);
+        let mut delete_user_identity = database.prepare(DELETE_USER_IDENTITY).await.unwrap();
+        delete_user_identity.set_consistency(Consistency::Quorum);
+        Self {
+            database,
+            insert_user_identity,
+            find_user_identity,
+            find_user_identities_by_user,
+            delete_user_identity,
+        }
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UserIdentityDatabaseRepository: Send + Sync {
+    /// Returns false when the upstream subject is already linked to another
+    /// user.
+    async fn create_user_identity(&self, identity: &UserIdentityModel) -> RepositoryResult<bool>;
+    async fn find_user_identity(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        provider_id: &str,
+        subject: &str,
+    ) -> RepositoryResult<Option<UserIdentityModel>>;
+    async fn find_user_identities_by_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserIdentityModel>>;
+    async fn delete_user_identity(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        provider_id: &str,
+        subject: &str,
+    ) -> RepositoryResult<()>;
+}
+
+#[async_trait]
+impl UserIdentityDatabaseRepository for UserIdentityDatabaseRepositoryImpl {
+    async fn create_user_identity(&self, identity: &UserIdentityModel) -> RepositoryResult<bool> {
+        let result = self
+            .database
+            .execute_unpaged(&self.insert_user_identity, identity)
+            .await?;
+        is_claimed_by(result, identity.user_id)
+    }
+
+    async fn find_user_identity(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        provider_id: &str,
+        subject: &str,
+    ) -> RepositoryResult<Option<UserIdentityModel>> {
+        let result = self
+            .database
+            .execute_unpaged(
+                &self.find_user_identity,
+                (organization_id, application_id, provider_id, subject),
+            )
+            .await?
+            .into_rows_result()?;
+        Ok(result.maybe_first_row::<UserIdentityModel>()?)
+    }
+
+    async fn find_user_identities_by_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<Vec<UserIdentityModel>> {
+        let identities = self
+            .database
+            .execute_unpaged(
+                &self.find_user_identities_by_user,
+                (organization_id, application_id, user_id),
+            )
+            .await?
+            .into_rows_result()?
+            .rows::<UserIdentityModel>()?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(identities)
+    }
+
+    async fn delete_user_identity(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        provider_id: &str,
+        subject: &str,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.delete_user_identity,
+                (organization_id, application_id, provider_id, subject),
+            )
+            .await?;
+        Ok(())
+    }
+}
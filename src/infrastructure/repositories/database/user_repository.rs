@@ -1,23 +1,32 @@
 use crate::{
-    domain::errors::repositories_errors::RepositoryResult,
+    domain::errors::repositories_errors::{RepositoryError, RepositoryResult},
     infrastructure::models::user::{
-        CleannedUserModel, FoundUserModel, PaginatedUsersModel, UserModel,
+        CleannedUserModel, FoundUserModel, PaginatedUserIdentifiersModel, PaginatedUsersModel,
+        PendingPurgeUserModel, UserIdentifierModel, UserModel,
     },
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use scylla::{
     client::session::Session,
-    response::PagingState,
+    response::{PagingState, query_result::QueryResult},
     statement::{Consistency, SerialConsistency, prepared::PreparedStatement},
-    value::{self, CqlValue},
+    value::{self, CqlTimestamp, CqlValue, Row},
 };
 use std::{collections::HashMap, ops::ControlFlow, sync::Arc};
 use uuid::Uuid;
 
+use super::query::user_reservations::{
+    RELEASE_EMAIL, RELEASE_USERNAME, RESERVE_EMAIL, RESERVE_USERNAME,
+};
+
 use super::query::users::{
-    BAN_USER, DECREASE_USER, DELETE_USER, DISABLE_MFA_USER, INCREASE_USER, INSERT_USER,
-    QUERY_FIND_ALL_USERS_PAGINATED, QUERY_FIND_RAW_USER, QUERY_FIND_USER, QUERY_FIND_USER_BY_EMAIL,
-    QUERY_FIND_USER_BY_USERNAME, SELECT_USER_COUNT, UNBAN_USER, UPDATE_USER,
+    BAN_USER, DECREASE_USER, DELETE_PASSWORD_HISTORY, DELETE_USER, DELETE_USER_PENDING_PURGE,
+    DISABLE_MFA_USER, INCREASE_USER, INSERT_PASSWORD_HISTORY, INSERT_USER,
+    INSERT_USER_PENDING_PURGE, QUERY_FIND_ALL_USERS_PAGINATED, QUERY_FIND_RAW_USER,
+    QUERY_PASSWORD_HISTORY, QUERY_FIND_USER, QUERY_FIND_USER_BY_EMAIL, QUERY_FIND_USER_BY_USERNAME,
+    QUERY_SCAN_USER_IDENTIFIERS, QUERY_USERS_PENDING_PURGE, SELECT_USER_COUNT, SOFT_DELETE_USER,
+    UNBAN_USER, UPDATE_HASHED_PASSWORD, UPDATE_USER, UPDATE_USER_NORMALIZED,
 };
 pub struct UserDatabaseRepositoryImpl {
     database: Arc<Session>,
@@ -35,6 +44,20 @@ pub struct UserDatabaseRepositoryImpl {
     ban_user: PreparedStatement,
     unban_user: PreparedStatement,
     disable_mfa_user: PreparedStatement,
+    soft_delete_user: PreparedStatement,
+    insert_pending_purge: PreparedStatement,
+    find_pending_purge: PreparedStatement,
+    delete_pending_purge: PreparedStatement,
+    reserve_username: PreparedStatement,
+    release_username: PreparedStatement,
+    reserve_email: PreparedStatement,
+    release_email: PreparedStatement,
+    scan_user_identifiers: PreparedStatement,
+    update_user_normalized: PreparedStatement,
+    update_hashed_password: PreparedStatement,
+    insert_password_history: PreparedStatement,
+    find_password_history: PreparedStatement,
+    delete_password_history: PreparedStatement,
 }
 
 impl UserDatabaseRepositoryImpl {
@@ -81,6 +104,56 @@ impl UserDatabaseRepositoryImpl {
 
         let mut disable_mfa_user = database.prepare(DISABLE_MFA_USER).await.unwrap();
         disable_mfa_user.set_consistency(Consistency::Quorum);
+
+        let mut soft_delete_user = database.prepare(SOFT_DELETE_USER).await.unwrap();
+        soft_delete_user.set_consistency(Consistency::Quorum);
+
+        let mut insert_pending_purge = database.prepare(INSERT_USER_PENDING_PURGE).await.unwrap();
+        insert_pending_purge.set_consistency(Consistency::Quorum);
+
+        let mut find_pending_purge = database.prepare(QUERY_USERS_PENDING_PURGE).await.unwrap();
+        find_pending_purge.set_consistency(Consistency::Quorum);
+
+        let mut delete_pending_purge = database.prepare(DELETE_USER_PENDING_PURGE).await.unwrap();
+        delete_pending_purge.set_consistency(Consistency::Quorum);
+
+        let mut reserve_username = database.prepare(RESERVE_USERNAME).await.unwrap();
+        reserve_username.set_consistency(Consistency::Quorum);
+        reserve_username.set_serial_consistency(Some(SerialConsistency::Serial));
+
+        let mut release_username = database.prepare(RELEASE_USERNAME).await.unwrap();
+        release_username.set_consistency(Consistency::Quorum);
+        release_username.set_serial_consistency(Some(SerialConsistency::Serial));
+
+        let mut reserve_email = database.prepare(RESERVE_EMAIL).await.unwrap();
+        reserve_email.set_consistency(Consistency::Quorum);
+        reserve_email.set_serial_consistency(Some(SerialConsistency::Serial));
+
+        let mut release_email = database.prepare(RELEASE_EMAIL).await.unwrap();
+        release_email.set_consistency(Consistency::Quorum);
+        release_email.set_serial_consistency(Some(SerialConsistency::Serial));
+
+        let mut scan_user_identifiers = database
+            .prepare(QUERY_SCAN_USER_IDENTIFIERS)
+            .await
+            .unwrap();
+        scan_user_identifiers.set_consistency(Consistency::One);
+
+        let mut update_user_normalized = database.prepare(UPDATE_USER_NORMALIZED).await.unwrap();
+        update_user_normalized.set_consistency(Consistency::Quorum);
+
+        let mut update_hashed_password = database.prepare(UPDATE_HASHED_PASSWORD).await.unwrap();
+        update_hashed_password.set_consistency(Consistency::Quorum);
+        update_hashed_password.set_serial_consistency(Some(SerialConsistency::Serial));
+
+        let mut insert_password_history = database.prepare(INSERT_PASSWORD_HISTORY).await.unwrap();
+        insert_password_history.set_consistency(Consistency::Quorum);
+
+        let mut find_password_history = database.prepare(QUERY_PASSWORD_HISTORY).await.unwrap();
+        find_password_history.set_consistency(Consistency::Quorum);
+
+        let mut delete_password_history = database.prepare(DELETE_PASSWORD_HISTORY).await.unwrap();
+        delete_password_history.set_consistency(Consistency::Quorum);
         Self {
             database,
             insert_user,
@@ -97,9 +170,49 @@ impl UserDatabaseRepositoryImpl {
             ban_user,
             unban_user,
             disable_mfa_user,
+            soft_delete_user,
+            insert_pending_purge,
+            find_pending_purge,
+            delete_pending_purge,
+            reserve_username,
+            release_username,
+            reserve_email,
+            release_email,
+            scan_user_identifiers,
+            update_user_normalized,
+            update_hashed_password,
+            insert_password_history,
+            find_password_history,
+            delete_password_history,
         }
     }
 }
+
+/// Reads the `[applied]` column of a lightweight transaction result.
+pub(super) fn is_applied(result: QueryResult) -> RepositoryResult<bool> {
+    let row = result.into_rows_result()?.maybe_first_row::<Row>()?;
+    Ok(matches!(
+        row.and_then(|r| r.columns.into_iter().next().flatten()),
+        Some(CqlValue::Boolean(true))
+    ))
+}
+
+/// Like `is_applied`, but a reservation already held by `user_id` also
+/// counts, so claiming the same value twice is idempotent.
+pub(super) fn is_claimed_by(result: QueryResult, user_id: Uuid) -> RepositoryResult<bool> {
+    let rows = result.into_rows_result()?;
+    let holder = rows.column_specs().get_by_name("user_id").map(|(i, _)| i);
+    let Some(row) = rows.maybe_first_row::<Row>()? else {
+        return Ok(false);
+    };
+    if matches!(row.columns.first(), Some(Some(CqlValue::Boolean(true)))) {
+        return Ok(true);
+    }
+    Ok(holder
+        .and_then(|i| row.columns.get(i))
+        .is_some_and(|held| matches!(held, Some(CqlValue::Uuid(id)) if *id == user_id)))
+}
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait UserDatabaseRepository: Send + Sync {
     async fn create_user(&self, user: UserModel) -> RepositoryResult<()>;
@@ -171,13 +284,119 @@ pub trait UserDatabaseRepository: Send + Sync {
         organization_id: Uuid,
         application_id: Uuid,
     ) -> RepositoryResult<()>;
+
+    async fn soft_delete_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        deactivated_day: String,
+        deactivated_at: CqlTimestamp,
+    ) -> RepositoryResult<()>;
+
+    async fn find_users_pending_purge(
+        &self,
+        deactivated_day: String,
+    ) -> RepositoryResult<Vec<PendingPurgeUserModel>>;
+
+    async fn delete_user_pending_purge(
+        &self,
+        deactivated_day: String,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+
+    /// Claims `username` for `user_id`; returns false when another user holds it.
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+
+    /// Releases `username` only if it is still held by `user_id`.
+    async fn release_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+
+    /// Claims `email` for `user_id`; returns false when another user holds it.
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool>;
+
+    /// Releases `email` only if it is still held by `user_id`.
+    async fn release_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()>;
+
+    /// Pages through every user of every application.
+    async fn scan_user_identifiers(
+        &self,
+        page_size: i32,
+        paging_state: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedUserIdentifiersModel>;
+
+    async fn update_user_normalized(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        username_normalized: String,
+        email_normalized: Option<String>,
+    ) -> RepositoryResult<()>;
+
+    /// Swaps the stored hash for `hashed_password` only while it still
+    /// equals `previous`; returns false when the password changed meanwhile.
+    async fn update_hashed_password(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        previous: String,
+        hashed_password: String,
+    ) -> RepositoryResult<bool>;
+
+    async fn insert_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        hashed_password: String,
+    ) -> RepositoryResult<()>;
+
+    /// The `limit` most recent hashes, newest first.
+    async fn find_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        limit: i32,
+    ) -> RepositoryResult<Vec<String>>;
 }
 #[async_trait]
 impl UserDatabaseRepository for UserDatabaseRepositoryImpl {
     async fn create_user(&self, user: UserModel) -> RepositoryResult<()> {
-        self.database
+        let result = self
+            .database
             .execute_unpaged(&self.insert_user, &user)
             .await?;
+        if !is_applied(result)? {
+            return Err(RepositoryError::new("user already exists".into(), 400));
+        }
         self.database
             .execute_unpaged(
                 &self.increase_user,
@@ -306,6 +525,12 @@ impl UserDatabaseRepository for UserDatabaseRepositoryImpl {
         self.database
             .execute_unpaged(&self.delete_user, &del_user_bind)
             .await?;
+        self.database
+            .execute_unpaged(
+                &self.delete_password_history,
+                (organization_id, application_id, user_id),
+            )
+            .await?;
         self.database
             .execute_unpaged(&self.decrease_user, (organization_id, application_id))
             .await?;
@@ -365,4 +590,254 @@ impl UserDatabaseRepository for UserDatabaseRepositoryImpl {
             .await?;
         Ok(())
     }
+
+    async fn soft_delete_user(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        deactivated_day: String,
+        deactivated_at: CqlTimestamp,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.soft_delete_user,
+                (
+                    deactivated_at,
+                    deactivated_at,
+                    organization_id,
+                    application_id,
+                    user_id,
+                ),
+            )
+            .await?;
+        self.database
+            .execute_unpaged(
+                &self.insert_pending_purge,
+                (
+                    deactivated_day,
+                    organization_id,
+                    application_id,
+                    user_id,
+                    deactivated_at,
+                ),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn find_users_pending_purge(
+        &self,
+        deactivated_day: String,
+    ) -> RepositoryResult<Vec<PendingPurgeUserModel>> {
+        let res = self
+            .database
+            .execute_unpaged(&self.find_pending_purge, (deactivated_day,))
+            .await?;
+        Ok(res
+            .into_rows_result()?
+            .rows::<PendingPurgeUserModel>()?
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn delete_user_pending_purge(
+        &self,
+        deactivated_day: String,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.delete_pending_purge,
+                (deactivated_day, organization_id, application_id, user_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn reserve_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        let result = self
+            .database
+            .execute_unpaged(
+                &self.reserve_username,
+                (organization_id, application_id, username, user_id),
+            )
+            .await?;
+        is_claimed_by(result, user_id)
+    }
+
+    async fn release_username(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        username: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.release_username,
+                (organization_id, application_id, username, user_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn reserve_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<bool> {
+        let result = self
+            .database
+            .execute_unpaged(
+                &self.reserve_email,
+                (organization_id, application_id, email, user_id),
+            )
+            .await?;
+        is_claimed_by(result, user_id)
+    }
+
+    async fn release_email(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        email: String,
+        user_id: Uuid,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.release_email,
+                (organization_id, application_id, email, user_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn scan_user_identifiers(
+        &self,
+        page_size: i32,
+        paging_state_u8: Option<Vec<u8>>,
+    ) -> RepositoryResult<PaginatedUserIdentifiersModel> {
+        let mut prepared = self.scan_user_identifiers.clone();
+        prepared.set_page_size(page_size);
+        let paging_state = paging_state_u8
+            .map(PagingState::new_from_raw_bytes)
+            .unwrap_or_else(PagingState::start);
+        let (res, paging_state_response) = self
+            .database
+            .execute_single_page(&prepared, &(), paging_state)
+            .await?;
+        let users = res
+            .into_rows_result()?
+            .rows::<UserIdentifierModel>()?
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_page_state = match paging_state_response.into_paging_control_flow() {
+            ControlFlow::Break(()) => None,
+            ControlFlow::Continue(state) => state.as_bytes_slice().map(|arc| arc.as_ref().to_vec()),
+        };
+        Ok(PaginatedUserIdentifiersModel {
+            users,
+            paging_state: next_page_state,
+        })
+    }
+
+    async fn update_user_normalized(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        username_normalized: String,
+        email_normalized: Option<String>,
+    ) -> RepositoryResult<()> {
+        self.database
+            .execute_unpaged(
+                &self.update_user_normalized,
+                (
+                    username_normalized,
+                    email_normalized,
+                    organization_id,
+                    application_id,
+                    user_id,
+                ),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn update_hashed_password(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        previous: String,
+        hashed_password: String,
+    ) -> RepositoryResult<bool> {
+        let result = self
+            .database
+            .execute_unpaged(
+                &self.update_hashed_password,
+                (
+                    hashed_password,
+                    organization_id,
+                    application_id,
+                    user_id,
+                    previous,
+                ),
+            )
+            .await?;
+        is_applied(result)
+    }
+
+    async fn insert_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        hashed_password: String,
+    ) -> RepositoryResult<()> {
+        let changed_at = CqlTimestamp(Utc::now().timestamp_millis());
+        self.database
+            .execute_unpaged(
+                &self.insert_password_history,
+                (
+                    organization_id,
+                    application_id,
+                    user_id,
+                    changed_at,
+                    hashed_password,
+                ),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn find_password_history(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+        user_id: Uuid,
+        limit: i32,
+    ) -> RepositoryResult<Vec<String>> {
+        let hashes = self
+            .database
+            .execute_unpaged(
+                &self.find_password_history,
+                (organization_id, application_id, user_id, limit),
+            )
+            .await?
+            .into_rows_result()?
+            .rows::<(String,)>()?
+            .map(|row| row.map(|(hashed,)| hashed))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(hashes)
+    }
 }
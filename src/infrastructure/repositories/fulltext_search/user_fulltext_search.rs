@@ -1,9 +1,10 @@
-use crate::{
-    domain::entities::user::User, infrastructure::errors::fulltext_search::FulltextSearchError,
+use crate::infrastructure::{
+    errors::fulltext_search::FulltextSearchError, models::user::UserSearchDocument,
 };
 use async_trait::async_trait;
 use elasticsearch::Elasticsearch;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct UserFulltextSearchRepositoryImpl {
     fulltext_search_client: Arc<Elasticsearch>,
@@ -17,21 +18,24 @@ impl UserFulltextSearchRepositoryImpl {
         }
     }
 }
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait UserFulltextSearchRepository: Send + Sync {
-    async fn create(&self, user: User) -> Result<(), FulltextSearchError>;
+    /// Indexes or replaces the user's document.
+    async fn create(&self, document: UserSearchDocument) -> Result<(), FulltextSearchError>;
+    async fn delete(&self, user_id: Uuid) -> Result<(), FulltextSearchError>;
 }
 
 #[async_trait]
 impl UserFulltextSearchRepository for UserFulltextSearchRepositoryImpl {
-    async fn create(&self, user: User) -> Result<(), FulltextSearchError> {
+    async fn create(&self, document: UserSearchDocument) -> Result<(), FulltextSearchError> {
         let res = self
             .fulltext_search_client
             .index(elasticsearch::IndexParts::IndexId(
                 self.index.as_str(),
-                &user.user_id.to_string(),
+                &document.user_id.to_string(),
             ))
-            .body(user)
+            .body(document)
             .send()
             .await?;
         if !res.status_code().is_success() {
@@ -41,4 +45,22 @@ impl UserFulltextSearchRepository for UserFulltextSearchRepositoryImpl {
         };
         Ok(())
     }
+
+    async fn delete(&self, user_id: Uuid) -> Result<(), FulltextSearchError> {
+        let res = self
+            .fulltext_search_client
+            .delete(elasticsearch::DeleteParts::IndexId(
+                self.index.as_str(),
+                &user_id.to_string(),
+            ))
+            .send()
+            .await?;
+        // a user that was never indexed is already gone
+        if !res.status_code().is_success() && res.status_code().as_u16() != 404 {
+            return Err(FulltextSearchError::DeletionFailed(
+                res.status_code().as_str().to_string(),
+            ));
+        };
+        Ok(())
+    }
 }
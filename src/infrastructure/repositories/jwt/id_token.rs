@@ -0,0 +1,71 @@
+use crate::domain::errors::repositories_errors::{RepositoryError, RepositoryResult};
+use crate::infrastructure::models::{jwk::Jwk, token_claim::IdTokenClaims};
+use crate::infrastructure::repositories::paseto::signing_key::{SigningKeyPair, VerificationKey};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::json;
+
+/// OIDC requires ID tokens to be JWS, so they are signed as compact JWTs
+/// (EdDSA) with the same application key that signs its PASETO access tokens.
+pub struct IdTokenJwtRepositoryImpl {
+    issuer: String,
+}
+
+impl IdTokenJwtRepositoryImpl {
+    pub fn new(issuer: String) -> Self {
+        Self { issuer }
+    }
+}
+
+pub trait IdTokenJwtRepository: Send + Sync {
+    fn sign(
+        &self,
+        signing_key: &SigningKeyPair,
+        claims: &IdTokenClaims,
+    ) -> RepositoryResult<String>;
+    fn issuer(&self) -> String;
+    fn jwk(&self, key: &VerificationKey) -> Jwk;
+}
+
+impl IdTokenJwtRepository for IdTokenJwtRepositoryImpl {
+    fn sign(
+        &self,
+        signing_key: &SigningKeyPair,
+        claims: &IdTokenClaims,
+    ) -> RepositoryResult<String> {
+        let header = json!({ "alg": "EdDSA", "typ": "JWT", "kid": signing_key.kid });
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?),
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?)
+        );
+        let keypair: [u8; 64] = signing_key
+            .private_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| RepositoryError::new("invalid signing key".to_string(), 500))?;
+        let signer = SigningKey::from_keypair_bytes(&keypair)
+            .map_err(|_| RepositoryError::new("invalid signing key".to_string(), 500))?;
+        let signature = signer.sign(signing_input.as_bytes());
+        Ok(format!(
+            "{}.{}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        ))
+    }
+
+    fn issuer(&self) -> String {
+        self.issuer.clone()
+    }
+
+    fn jwk(&self, key: &VerificationKey) -> Jwk {
+        Jwk {
+            kty: "OKP".to_string(),
+            crv: "Ed25519".to_string(),
+            alg: "EdDSA".to_string(),
+            key_use: "sig".to_string(),
+            kid: key.kid.clone(),
+            x: URL_SAFE_NO_PAD.encode(&key.public_key),
+        }
+    }
+}
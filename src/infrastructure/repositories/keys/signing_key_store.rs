@@ -0,0 +1,166 @@
+use crate::{
+    domain::errors::repositories_errors::RepositoryResult,
+    infrastructure::{
+        models::signing_key::SigningKeyModel,
+        repositories::{
+            cipher::{
+                aes_gcm_repository::AesGcmCipherRepository, base64_repository::Base64Repository,
+            },
+            database::signing_key::SigningKeyDatabaseRepository,
+            paseto::{
+                footer_kid,
+                signing_key::{SigningKeyPair, VerificationKey},
+            },
+        },
+    },
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Per-application signing keys. Private keys are only ever decrypted here,
+/// right before a token is signed.
+pub struct SigningKeyStoreImpl {
+    database_repo: Arc<dyn SigningKeyDatabaseRepository>,
+    aes_repo: Arc<dyn AesGcmCipherRepository>,
+    base64_repo: Arc<dyn Base64Repository>,
+}
+
+impl SigningKeyStoreImpl {
+    pub fn new(
+        database_repo: Arc<dyn SigningKeyDatabaseRepository>,
+        aes_repo: Arc<dyn AesGcmCipherRepository>,
+        base64_repo: Arc<dyn Base64Repository>,
+    ) -> Self {
+        Self {
+            database_repo,
+            aes_repo,
+            base64_repo,
+        }
+    }
+
+    fn to_verification_key(&self, key: &SigningKeyModel) -> RepositoryResult<VerificationKey> {
+        Ok(VerificationKey {
+            organization_id: key.organization_id,
+            application_id: key.application_id,
+            kid: key.kid.clone(),
+            public_key: self.base64_repo.decode(&key.public_key)?,
+        })
+    }
+}
+
+#[async_trait]
+pub trait SigningKeyStore: Send + Sync {
+    /// The key new tokens of the application are signed with. The first call
+    /// for an application generates it.
+    async fn active_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair>;
+    /// Generates a key pair and stores it with the private half encrypted.
+    async fn create_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyModel>;
+    /// Resolves the key named by the token's `kid` footer, unless it has
+    /// passed the end of its overlap window.
+    async fn find_verification_key(&self, token: &str)
+    -> RepositoryResult<Option<VerificationKey>>;
+    /// Every key of the application that tokens may still be verified with.
+    async fn verification_keys(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<VerificationKey>>;
+}
+
+#[async_trait]
+impl SigningKeyStore for SigningKeyStoreImpl {
+    async fn active_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyPair> {
+        let active = self
+            .database_repo
+            .find_signing_keys(organization_id, application_id)
+            .await?
+            .into_iter()
+            .filter(|k| k.retired_at.is_none())
+            .max_by_key(|k| k.created_at.0);
+        let key = match active {
+            Some(key) => key,
+            None => self.create_key(organization_id, application_id).await?,
+        };
+        let private_key = self
+            .aes_repo
+            .decrypt(&key.private_key_nonce, &key.encrypted_private_key)
+            .await?;
+        Ok(SigningKeyPair {
+            private_key: self.base64_repo.decode(&private_key)?,
+            public_key: self.base64_repo.decode(&key.public_key)?,
+            kid: key.kid,
+        })
+    }
+
+    async fn create_key(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<SigningKeyModel> {
+        let pair = SigningKeyPair::generate()?;
+        // the cipher hands back text, so the key is encrypted in its base64 form
+        let (private_key_nonce, encrypted_private_key) = self
+            .aes_repo
+            .encrypt(self.base64_repo.encode(&pair.private_key).as_bytes())
+            .await?;
+        let key = SigningKeyModel {
+            organization_id,
+            application_id,
+            kid: pair.kid,
+            public_key: self.base64_repo.encode(&pair.public_key),
+            encrypted_private_key,
+            private_key_nonce,
+            created_at: CqlTimestamp(Utc::now().timestamp_millis()),
+            retired_at: None,
+            expires_at: None,
+        };
+        self.database_repo.create_signing_key(&key).await?;
+        Ok(key)
+    }
+
+    async fn find_verification_key(
+        &self,
+        token: &str,
+    ) -> RepositoryResult<Option<VerificationKey>> {
+        let Some(kid) = footer_kid(token) else {
+            return Ok(None);
+        };
+        let Some(key) = self.database_repo.find_signing_key_by_kid(&kid).await? else {
+            return Ok(None);
+        };
+        if key.is_expired(Utc::now().timestamp_millis()) {
+            return Ok(None);
+        }
+        Ok(Some(self.to_verification_key(&key)?))
+    }
+
+    async fn verification_keys(
+        &self,
+        organization_id: Uuid,
+        application_id: Uuid,
+    ) -> RepositoryResult<Vec<VerificationKey>> {
+        let now = Utc::now().timestamp_millis();
+        self.database_repo
+            .find_signing_keys(organization_id, application_id)
+            .await?
+            .iter()
+            .filter(|k| !k.is_expired(now))
+            .map(|k| self.to_verification_key(k))
+            .collect()
+    }
+}
@@ -4,6 +4,9 @@ pub mod cdc;
 pub mod cipher;
 pub mod database;
 pub mod fulltext_search;
+pub mod jwt;
+pub mod keys;
+pub mod oidc;
 pub mod paseto;
 pub mod queue;
 pub mod security;
@@ -0,0 +1,120 @@
+use crate::{
+    domain::errors::repositories_errors::{RepositoryError, RepositoryResult},
+    infrastructure::models::upstream_oidc::{ProviderMetadata, UpstreamIdTokenClaims},
+};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::Deserialize;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Deserialize)]
+struct UpstreamTokenResponse {
+    id_token: Option<String>,
+}
+
+/// HTTP client for the OIDC providers applications federate sign-in to.
+pub struct UpstreamOidcRepositoryImpl {
+    client: reqwest::Client,
+}
+
+impl UpstreamOidcRepositoryImpl {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("failed to build HTTP client");
+        Self { client }
+    }
+}
+
+impl Default for UpstreamOidcRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn provider_error(message: &str) -> RepositoryError {
+    RepositoryError::new(format!("identity provider error: {message}"), 502)
+}
+
+#[async_trait]
+pub trait UpstreamOidcRepository: Send + Sync {
+    /// Fetches `{issuer}/.well-known/openid-configuration`.
+    async fn discover(&self, issuer: &str) -> RepositoryResult<ProviderMetadata>;
+    /// Redeems an authorization code and returns the ID token.
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: &str,
+    ) -> RepositoryResult<String>;
+    /// Reads the claims of an ID token without checking them.
+    fn decode_id_token(&self, id_token: &str) -> RepositoryResult<UpstreamIdTokenClaims>;
+}
+
+#[async_trait]
+impl UpstreamOidcRepository for UpstreamOidcRepositoryImpl {
+    async fn discover(&self, issuer: &str) -> RepositoryResult<ProviderMetadata> {
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+        let metadata: ProviderMetadata = self
+            .client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if metadata.issuer.trim_end_matches('/') != issuer.trim_end_matches('/') {
+            return Err(provider_error("discovery document names another issuer"));
+        }
+        Ok(metadata)
+    }
+
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: &str,
+    ) -> RepositoryResult<String> {
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+            ("code_verifier", code_verifier),
+        ];
+        let response: UpstreamTokenResponse = self
+            .client
+            .post(token_endpoint)
+            .form(&form)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        response
+            .id_token
+            .ok_or_else(|| provider_error("token response has no id_token"))
+    }
+
+    fn decode_id_token(&self, id_token: &str) -> RepositoryResult<UpstreamIdTokenClaims> {
+        let payload = id_token
+            .split('.')
+            .nth(1)
+            .ok_or_else(|| provider_error("id_token is not a JWT"))?;
+        let claims = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('='))?;
+        serde_json::from_slice(&claims).map_err(|_| provider_error("id_token claims are invalid"))
+    }
+}
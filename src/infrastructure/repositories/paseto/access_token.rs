@@ -0,0 +1,129 @@
+use super::kid_footer;
+use super::signing_key::{SigningKeyPair, VerificationKey};
+use crate::domain::errors::repositories_errors::RepositoryError;
+use crate::domain::errors::repositories_errors::RepositoryResult;
+use crate::infrastructure::models::token_claim::AccessTokenClaims;
+use async_trait::async_trait;
+use rusty_paseto::core::*;
+use rusty_paseto::prelude::*;
+use uuid::Uuid;
+
+/// Mints access tokens with the application's signing key; `ttl` is in seconds.
+pub struct AccessTokenPasetoRepositoryImpl {
+    ttl: i64,
+}
+
+impl AccessTokenPasetoRepositoryImpl {
+    pub fn new(ttl: i64) -> Self {
+        Self { ttl }
+    }
+    fn parse_json_to_model(value: serde_json::Value) -> RepositoryResult<AccessTokenClaims> {
+        serde_json::from_value::<AccessTokenClaims>(value)
+            .map_err(|e| RepositoryError::new(e.to_string(), 400))
+    }
+}
+
+#[async_trait]
+pub trait AccessTokenPasetoRepository: Send + Sync {
+    async fn encrypt(
+        &self,
+        signing_key: &SigningKeyPair,
+        user_id: Uuid,
+        client_id: Uuid,
+        scope: Option<String>,
+        issued_at: String,
+        expire: String,
+    ) -> RepositoryResult<String>;
+    /// Token of a service account: `sub` is the service account id and the
+    /// `permissions` claim carries what its roles grant.
+    async fn encrypt_for_service_account(
+        &self,
+        signing_key: &SigningKeyPair,
+        service_account_id: Uuid,
+        client_id: Uuid,
+        permissions: Vec<String>,
+        issued_at: String,
+        expire: String,
+    ) -> RepositoryResult<String>;
+    async fn decrypt(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims>;
+    fn ttl(&self) -> i64;
+}
+
+#[async_trait]
+impl AccessTokenPasetoRepository for AccessTokenPasetoRepositoryImpl {
+    async fn encrypt(
+        &self,
+        signing_key: &SigningKeyPair,
+        user_id: Uuid,
+        client_id: Uuid,
+        scope: Option<String>,
+        issued_at: String,
+        expire: String,
+    ) -> RepositoryResult<String> {
+        let private_key = Key::<64>::from(signing_key.private_key.as_slice());
+        let private_key = PasetoAsymmetricPrivateKey::<V4, Public>::from(private_key.as_slice());
+        let token_id = Uuid::new_v4().to_string();
+        let subject = user_id.to_string();
+        let footer = kid_footer(&signing_key.kid);
+        let mut builder = PasetoBuilder::<V4, Public>::default();
+        builder
+            .set_footer(Footer::from(footer.as_str()))
+            .set_claim(TokenIdentifierClaim::from(token_id.as_str()))
+            .set_claim(SubjectClaim::from(subject.as_str()))
+            .set_claim(CustomClaim::try_from(("client_id", client_id.to_string()))?)
+            .set_claim(IssuedAtClaim::try_from(issued_at)?)
+            .set_claim(ExpirationClaim::try_from(expire)?);
+        if let Some(scope) = scope {
+            builder.set_claim(CustomClaim::try_from(("scope", scope))?);
+        }
+        Ok(builder.build(&private_key)?)
+    }
+
+    async fn encrypt_for_service_account(
+        &self,
+        signing_key: &SigningKeyPair,
+        service_account_id: Uuid,
+        client_id: Uuid,
+        permissions: Vec<String>,
+        issued_at: String,
+        expire: String,
+    ) -> RepositoryResult<String> {
+        let private_key = Key::<64>::from(signing_key.private_key.as_slice());
+        let private_key = PasetoAsymmetricPrivateKey::<V4, Public>::from(private_key.as_slice());
+        let token_id = Uuid::new_v4().to_string();
+        let subject = service_account_id.to_string();
+        let footer = kid_footer(&signing_key.kid);
+        let mut builder = PasetoBuilder::<V4, Public>::default();
+        builder
+            .set_footer(Footer::from(footer.as_str()))
+            .set_claim(TokenIdentifierClaim::from(token_id.as_str()))
+            .set_claim(SubjectClaim::from(subject.as_str()))
+            .set_claim(CustomClaim::try_from(("client_id", client_id.to_string()))?)
+            .set_claim(CustomClaim::try_from(("permissions", permissions))?)
+            .set_claim(IssuedAtClaim::try_from(issued_at)?)
+            .set_claim(ExpirationClaim::try_from(expire)?);
+        Ok(builder.build(&private_key)?)
+    }
+
+    async fn decrypt(
+        &self,
+        token: &str,
+        key: &VerificationKey,
+    ) -> RepositoryResult<AccessTokenClaims> {
+        let public_key = Key::<32>::from(key.public_key.as_slice());
+        let public_key = PasetoAsymmetricPublicKey::<V4, Public>::from(&public_key);
+        let footer = kid_footer(&key.kid);
+        let json: serde_json::Value = PasetoParser::<V4, Public>::default()
+            .set_footer(Footer::from(footer.as_str()))
+            .parse(token, &public_key)?;
+        Self::parse_json_to_model(json)
+    }
+
+    fn ttl(&self) -> i64 {
+        self.ttl
+    }
+}
@@ -1,2 +1,20 @@
+pub mod access_token;
 pub mod refresh_token;
-pub const PASETO_V4_LOCAL_KEY_LEN: usize = 64;
\ No newline at end of file
+pub mod signing_key;
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+
+/// Footer attached to every token so the verifier knows which key signed it.
+/// Parsing requires the exact same bytes, so build and parse go through here.
+pub fn kid_footer(kid: &str) -> String {
+    serde_json::json!({ "kid": kid }).to_string()
+}
+
+/// Reads the `kid` from a `v4.public` token footer without verifying it; the
+/// footer is authenticated once the token is parsed with the matching key.
+pub fn footer_kid(token: &str) -> Option<String> {
+    let footer = token.splitn(4, '.').nth(3)?;
+    let footer = URL_SAFE_NO_PAD.decode(footer).ok()?;
+    let footer: serde_json::Value = serde_json::from_slice(&footer).ok()?;
+    footer.get("kid")?.as_str().map(str::to_string)
+}
@@ -2,6 +2,10 @@ use crate::domain::entities::refresh_token::RefreshToken;
 use crate::domain::errors::repositories_errors::RepositoryError;
 use crate::domain::errors::repositories_errors::RepositoryResult;
 use crate::infrastructure::models::token_claim::TokenClaims;
+use crate::infrastructure::repositories::paseto::{
+    kid_footer,
+    signing_key::{SigningKeyPair, VerificationKey},
+};
 use async_trait::async_trait;
 use rusty_paseto::core::*;
 use rusty_paseto::prelude::*;
@@ -22,7 +26,7 @@ impl PasetoRepositoryImpl {
 pub trait PasetoRepository: Send + Sync {
     async fn encrypt(
         &self,
-        private_key: &Vec<u8>,
+        signing_key: &SigningKeyPair,
         rt: RefreshToken,
         secret: &str,
         secret_key: &str,
@@ -30,14 +34,14 @@ pub trait PasetoRepository: Send + Sync {
         expire: String,
         notbefore: String,
     ) -> RepositoryResult<String>;
-    async fn decrypt(&self, token: &str, public_key: &Vec<u8>) -> RepositoryResult<TokenClaims>;
+    async fn decrypt(&self, token: &str, key: &VerificationKey) -> RepositoryResult<TokenClaims>;
 }
 
 #[async_trait]
 impl PasetoRepository for PasetoRepositoryImpl {
     async fn encrypt(
         &self,
-        private_key: &Vec<u8>,
+        signing_key: &SigningKeyPair,
         rt: RefreshToken,
         secret: &str,
         secret_key: &str,
@@ -45,10 +49,11 @@ impl PasetoRepository for PasetoRepositoryImpl {
         expire: String,
         notbefore: String,
     ) -> RepositoryResult<String> {
-        let private_key = Key::<64>::try_from(private_key.as_slice())
+        let private_key = Key::<64>::try_from(signing_key.private_key.as_slice())
             .map_err(|e| RepositoryError::new(format!("invalid hex key: {e}"), 500))?;
         let pk: &[u8] = private_key.as_slice();
         let private_key = PasetoAsymmetricPrivateKey::<V4, Public>::from(pk);
+        let footer = kid_footer(&signing_key.kid);
         let token_id = rt.token_id.clone().to_string();
         let token = PasetoBuilder::<V4, Public>::default()
             .set_claim(TokenIdentifierClaim::from(token_id.as_str()))
@@ -58,17 +63,22 @@ impl PasetoRepository for PasetoRepositoryImpl {
             .set_claim(IssuedAtClaim::try_from(issued_at)?)
             .set_claim(ExpirationClaim::try_from(expire)?)
             .set_claim(NotBeforeClaim::try_from(notbefore)?)
+            .set_footer(Footer::from(footer.as_str()))
             .build(&private_key)?;
 
         Ok(token)
     }
 
-    async fn decrypt(&self, token: &str, public_key: &Vec<u8>) -> RepositoryResult<TokenClaims> {
-        let public_key = Key::<32>::try_from(public_key.as_slice())
+    async fn decrypt(&self, token: &str, key: &VerificationKey) -> RepositoryResult<TokenClaims> {
+        let public_key = Key::<32>::try_from(key.public_key.as_slice())
             .map_err(|e| RepositoryError::new(format!("invalid hex key: {e}"), 500))?;
         let public_key = PasetoAsymmetricPublicKey::<V4, Public>::from(&public_key);
-        let json: serde_json::Value =
-            PasetoParser::<V4, Public>::default().parse(&token, &public_key)?;
+        let footer = kid_footer(&key.kid);
+        // no built-in exp/nbf checks: callers validate the time claims, so a
+        // token that is not valid yet can still be introspected or revoked
+        let json: serde_json::Value = PasetoParser::<V4, Public>::new()
+            .set_footer(Footer::from(footer.as_str()))
+            .parse(&token, &public_key)?;
         let model = Self::parse_json_to_model(json)?;
         Ok(model)
     }
@@ -0,0 +1,47 @@
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use ed25519_dalek::SigningKey;
+use rand_core::{OsRng, TryRngCore};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::errors::repositories_errors::RepositoryResult;
+
+/// Ed25519 key pair in the byte layout rusty_paseto expects for v4.public:
+/// a 64 byte secret (seed followed by public key) and a 32 byte public key.
+#[derive(Clone)]
+pub struct SigningKeyPair {
+    pub kid: String,
+    pub private_key: Vec<u8>,
+    pub public_key: Vec<u8>,
+}
+
+impl SigningKeyPair {
+    pub fn generate() -> RepositoryResult<Self> {
+        let mut seed = [0u8; 32];
+        OsRng.try_fill_bytes(&mut seed)?;
+        let signing_key = SigningKey::from_bytes(&seed);
+        let public_key = signing_key.verifying_key().to_bytes();
+        Ok(Self {
+            kid: URL_SAFE_NO_PAD.encode(&Sha256::digest(public_key)[..12]),
+            private_key: signing_key.to_keypair_bytes().to_vec(),
+            public_key: public_key.to_vec(),
+        })
+    }
+}
+
+/// The public half of an application's signing key, as published in the key
+/// sets and used to verify tokens that name it in their `kid` footer.
+#[derive(Clone, Debug)]
+pub struct VerificationKey {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub kid: String,
+    pub public_key: Vec<u8>,
+}
+
+impl VerificationKey {
+    /// The public key serialized as a PASERK (`k4.public.<base64url>`).
+    pub fn paserk(&self) -> String {
+        format!("k4.public.{}", URL_SAFE_NO_PAD.encode(&self.public_key))
+    }
+}
@@ -1,4 +1,5 @@
 pub mod consumer;
 pub mod producer;
+pub mod producer_roles_repository;
 pub mod producer_users_repository;
 pub mod topics;
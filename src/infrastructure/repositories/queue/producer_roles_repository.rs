@@ -0,0 +1,42 @@
+use crate::infrastructure::{
+    errors::queue::ProducerError,
+    models::{queue::roles::QueueRoleAssignment, role::PendingRoleExpiryModel},
+    repositories::queue::{producer::ProducerRepository, topics::ROLE_TOPIC},
+};
+use std::sync::{Arc, Mutex};
+
+pub struct RoleProducerRepositoryImpl {
+    producer_repo: Arc<Mutex<dyn ProducerRepository>>,
+}
+
+impl RoleProducerRepositoryImpl {
+    pub fn new(producer_repo: Arc<Mutex<dyn ProducerRepository>>) -> Self {
+        producer_repo
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .set_topic(ROLE_TOPIC);
+        Self { producer_repo }
+    }
+}
+#[cfg_attr(test, mockall::automock)]
+pub trait RoleProducerRepository: Send + Sync {
+    fn assignment_expired(&self, expiry: &PendingRoleExpiryModel) -> Result<(), ProducerError>;
+}
+impl RoleProducerRepository for RoleProducerRepositoryImpl {
+    fn assignment_expired(&self, expiry: &PendingRoleExpiryModel) -> Result<(), ProducerError> {
+        let data = QueueRoleAssignment {
+            operation: "assignment_expired".to_string(),
+            organization_id: expiry.organization_id,
+            application_id: expiry.application_id,
+            role_id: expiry.role_id,
+            user_id: expiry.user_id,
+            expires_at: Some(expiry.expires_at.0),
+        };
+        let json_string = serde_json::to_string(&data).unwrap();
+        self.producer_repo
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .send_data_to_topic(json_string)?;
+        Ok(())
+    }
+}
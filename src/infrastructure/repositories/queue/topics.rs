@@ -1 +1,2 @@
 pub(crate) const USER_TOPIC: &str = r#"axcelium-users"#;
+pub(crate) const ROLE_TOPIC: &str = r#"axcelium-roles"#;
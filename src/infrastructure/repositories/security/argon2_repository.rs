@@ -1,37 +1,119 @@
 use argon2::{
-    Argon2, PasswordHash,
+    Algorithm, Argon2, Params, PasswordHash, Version,
     password_hash::{PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
 };
 use async_trait::async_trait;
+use pbkdf2::Pbkdf2;
+use scrypt::Scrypt;
 
-use crate::domain::errors::repositories_errors::RepositoryResult;
-pub struct PasswordHasherImpl;
+use crate::{
+    config::Argon2Config,
+    domain::{
+        entities::user::UNUSABLE_PASSWORD,
+        errors::repositories_errors::{RepositoryError, RepositoryResult},
+    },
+};
+
+const ARGON2_ALGORITHMS: [&str; 3] = ["argon2id", "argon2i", "argon2d"];
+const PBKDF2_ALGORITHMS: [&str; 3] = ["pbkdf2-sha256", "pbkdf2-sha512", "pbkdf2"];
+const BCRYPT_PREFIXES: [&str; 4] = ["$2a$", "$2b$", "$2x$", "$2y$"];
+
+/// bcrypt predates PHC strings: `$2b$<cost>$<22 salt chars><31 hash chars>`.
+fn is_bcrypt(hashed: &str) -> bool {
+    hashed.len() == 60
+        && BCRYPT_PREFIXES
+            .iter()
+            .any(|prefix| hashed.starts_with(prefix))
+}
+
+pub struct PasswordHasherImpl {
+    argon2: Argon2<'static>,
+}
 
 impl PasswordHasherImpl {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(config: &Argon2Config) -> Self {
+        let params = Params::new(
+            config.memory_cost,
+            config.iterations,
+            config.parallelism,
+            None,
+        )
+        .expect("invalid argon2 parameters");
+        Self {
+            argon2: Argon2::new(Algorithm::Argon2id, Version::V0x13, params),
+        }
     }
 }
 
 #[async_trait]
 pub trait PasswordHasherRepository: Send + Sync {
     fn hash(&self, password: &str) -> RepositoryResult<String>;
+    /// Checks Argon2, scrypt and PBKDF2 PHC strings as well as bcrypt hashes.
     fn verify(&self, hashed: &str, plain: &str) -> RepositoryResult<bool>;
+    /// Whether `hashed` is in a format `verify` can check.
+    fn is_supported(&self, hashed: &str) -> bool;
+    /// Whether `hashed` should be replaced by a fresh `hash` of the same
+    /// password: it is not Argon2id or was made with other parameters.
+    fn needs_rehash(&self, hashed: &str) -> bool;
 }
 #[async_trait]
 impl PasswordHasherRepository for PasswordHasherImpl {
     fn hash(&self, password: &str) -> RepositoryResult<String> {
         let salt = SaltString::generate(&mut OsRng);
-        let hash = Argon2::default()
+        let hash = self
+            .argon2
             .hash_password(password.as_bytes(), &salt)?
             .to_string();
         Ok(hash)
     }
 
     fn verify(&self, hashed: &str, plain: &str) -> RepositoryResult<bool> {
+        if hashed == UNUSABLE_PASSWORD {
+            return Ok(false);
+        }
+        if is_bcrypt(hashed) {
+            return bcrypt::verify(plain, hashed)
+                .map_err(|e| RepositoryError::new(format!("bcrypt error: {e}"), 500));
+        }
         let parsed = PasswordHash::new(hashed)?;
-        Ok(Argon2::default()
-            .verify_password(plain.as_bytes(), &parsed)
-            .is_ok())
+        let algorithm = parsed.algorithm.as_str();
+        let verifier: &dyn PasswordVerifier = if ARGON2_ALGORITHMS.contains(&algorithm) {
+            &self.argon2
+        } else if algorithm == "scrypt" {
+            &Scrypt
+        } else if PBKDF2_ALGORITHMS.contains(&algorithm) {
+            &Pbkdf2
+        } else {
+            return Err(argon2::password_hash::Error::Algorithm.into());
+        };
+        Ok(verifier.verify_password(plain.as_bytes(), &parsed).is_ok())
+    }
+
+    fn is_supported(&self, hashed: &str) -> bool {
+        hashed == UNUSABLE_PASSWORD
+            || is_bcrypt(hashed)
+            || PasswordHash::new(hashed).is_ok_and(|parsed| {
+                let algorithm = parsed.algorithm.as_str();
+                ARGON2_ALGORITHMS.contains(&algorithm)
+                    || algorithm == "scrypt"
+                    || PBKDF2_ALGORITHMS.contains(&algorithm)
+            })
+    }
+
+    fn needs_rehash(&self, hashed: &str) -> bool {
+        let Ok(parsed) = PasswordHash::new(hashed) else {
+            return true;
+        };
+        if parsed.algorithm != Algorithm::Argon2id.ident()
+            || parsed.version != Some(Version::V0x13.into())
+        {
+            return true;
+        }
+        let current = self.argon2.params();
+        !Params::try_from(&parsed).is_ok_and(|params| {
+            params.m_cost() == current.m_cost()
+                && params.t_cost() == current.t_cost()
+                && params.p_cost() == current.p_cost()
+        })
     }
 }
@@ -0,0 +1,34 @@
+use std::{collections::HashSet, fs};
+
+/// Passwords known from breaches or too common to allow, read once at
+/// startup from a file with one password per line.
+pub struct BreachedPasswordListImpl {
+    passwords: HashSet<String>,
+}
+
+impl BreachedPasswordListImpl {
+    /// Without a file the list is empty and `contains` is always false.
+    pub fn new(path: Option<&str>) -> Self {
+        let passwords = match path {
+            Some(path) => fs::read_to_string(path)
+                .unwrap_or_else(|e| panic!("failed to read breached password list {path}: {e}"))
+                .lines()
+                .map(|line| line.trim().to_lowercase())
+                .filter(|line| !line.is_empty())
+                .collect(),
+            None => HashSet::new(),
+        };
+        Self { passwords }
+    }
+}
+
+pub trait BreachedPasswordRepository: Send + Sync {
+    /// Case-insensitive, so "Password1" matches a listed "password1".
+    fn contains(&self, password: &str) -> bool;
+}
+
+impl BreachedPasswordRepository for BreachedPasswordListImpl {
+    fn contains(&self, password: &str) -> bool {
+        self.passwords.contains(&password.to_lowercase())
+    }
+}
@@ -1 +1,2 @@
 pub mod argon2_repository;
+pub mod breached_password_repository;
@@ -1,6 +1,10 @@
 use axcelium::{
     config,
-    controllers::{cdc::CDCControllerImpl, consumers::QueueConsumerImpl},
+    controllers::{
+        cdc::CDCControllerImpl, consumers::QueueConsumerImpl, migrations::MigrationsImpl,
+        purge::UserPurgeJobImpl, role_expiry::RoleExpiryJobImpl,
+        signing_keys::SigningKeyRotationJobImpl,
+    },
     infrastructure::repositories::{
         cache::redis::get_redis_client, database::scylladb::get_db_pool,
         fulltext_search::init_fulltext_search,
@@ -36,10 +40,28 @@ async fn main() -> std::io::Result<()> {
     let container_for_server = services.clone();
     let server_future = create_http_server(container_for_server)?;
     let mut c = CDCControllerImpl::new(database, services.clone()).await;
+    let purge_job =
+        UserPurgeJobImpl::new(services.clone(), cfg.user_deletion.clone(), shutdown_rx.clone());
+    let rotation_job = SigningKeyRotationJobImpl::new(
+        services.clone(),
+        cfg.signing_keys.clone(),
+        shutdown_rx.clone(),
+    );
+    let role_expiry_job = RoleExpiryJobImpl::new(
+        services.clone(),
+        cfg.role_assignments.clone(),
+        shutdown_rx.clone(),
+    );
     // FIX: temp
     let consumer_controller = QueueConsumerImpl::new(cfg.queue, shutdown_rx).unwrap();
     // Wait for consumer to end
     tokio::spawn(async move { c.handle().await });
+    tokio::spawn(purge_job.run());
+    tokio::spawn(rotation_job.run());
+    tokio::spawn(role_expiry_job.run());
+    if cfg.migrations.normalize_user_identifiers {
+        tokio::spawn(MigrationsImpl::new(services.clone()).normalize_user_identifiers());
+    }
     tokio::spawn(async move {
         consumer_controller.wait().await;
     });
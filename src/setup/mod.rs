@@ -2,22 +2,50 @@ use crate::{
     application::{
         middlewares::bearer_auth::ValidateBearerAuth,
         services::{
+            applications::{
+                config::ApplicationConfigService,
+                update_identity_providers::UpdateIdentityProvidersService,
+                update_redirect_uris::UpdateRedirectUrisService,
+            },
             cdc::{printer::PrinterConsumerService, replicator::ReplicatorConsumerService},
+            groups::{
+                manage::ManageGroupsService, members::GroupMembersService,
+                roles::GroupRolesService,
+            },
             hello_service::HelloService,
+            permissions::catalog::PermissionCatalogService,
+            oauth::{
+                authorize::AuthorizeService, device::DeviceAuthorizationService,
+                discovery::DiscoveryService, federation::FederationService,
+                introspect::IntrospectService,
+                revoke::RevokeTokenService, token::TokenService, userinfo::UserInfoService,
+            },
             refresh_token::{
                 create::CreateRefreshTokenService, get::GetRefreshTokenService,
-                revoke::RevokeRefreshTokenService, rotate::RotateRefreshTokenService,
+                revoke::RevokeRefreshTokenService, revoke_all::RevokeAllSessionsService,
+                rotate::RotateRefreshTokenService, sessions::GetSessionsService,
             },
             roles::{
                 assign::AssignService, create_roles::CreateRoleService,
-                delete_role::DeleteRoleService, get_role_by_app::GetRoleByAppService,
-                get_roles_by_app::GetRolesByAppService, get_users_by_role::GetUsersByRoleService,
+                delete_role::DeleteRoleService, expire_assignments::ExpireRoleAssignmentsService,
+                get_role_by_app::GetRoleByAppService,
+                get_roles_by_app::GetRolesByAppService, get_roles_by_user::GetRolesByUserService,
+                get_user_permissions::GetUserPermissionsService,
+                get_users_by_role::GetUsersByRoleService,
                 update_role::UpdateRoleService,
             },
+            service_accounts::{
+                create::CreateServiceAccountService, delete::DeleteServiceAccountService,
+                get::GetServiceAccountsService,
+            },
+            scim::{groups::ScimGroupsService, users::ScimUsersService},
+            signing_keys::rotate::RotateSigningKeysService,
             users::{
                 ban_user::BanUserService, create::CreateUserService, delete::DeleteUserService,
-                disable_mfa_user::DisableMFAUserService, get_user::GetUserService,
-                get_user_count::GetUserCountService, get_users::GetUsersService,
+                disable_mfa_user::DisableMFAUserService, export_users::ExportUsersService,
+                get_user::GetUserService, get_user_count::GetUserCountService,
+                get_users::GetUsersService, import_users::ImportUsersService,
+                normalize_identifiers::NormalizeIdentifiersService, purge_users::PurgeUsersService,
                 unban_user::UnbanUserService, update_user::UpdateUserService,
             },
         },
@@ -40,6 +68,10 @@ pub struct Container {
     pub get_user_service: Arc<dyn GetUserService>,
     pub update_user_service: Arc<dyn UpdateUserService>,
     pub del_user_service: Arc<dyn DeleteUserService>,
+    pub import_users_service: Arc<dyn ImportUsersService>,
+    pub export_users_service: Arc<dyn ExportUsersService>,
+    pub purge_users_service: Arc<dyn PurgeUsersService>,
+    pub normalize_identifiers_service: Arc<dyn NormalizeIdentifiersService>,
     pub validate_bearer_auth_middleware_service: Arc<ValidateBearerAuth>,
     pub get_user_count_service: Arc<dyn GetUserCountService>,
     pub ban_user_count_service: Arc<dyn BanUserService>,
@@ -49,15 +81,41 @@ pub struct Container {
     pub rotate_refresh_token_service: Arc<dyn RotateRefreshTokenService>,
     pub revoke_refresh_token_service: Arc<dyn RevokeRefreshTokenService>,
     pub get_refresh_tokens_by_user_service: Arc<dyn GetRefreshTokenService>,
+    pub get_sessions_service: Arc<dyn GetSessionsService>,
+    pub revoke_all_sessions_service: Arc<dyn RevokeAllSessionsService>,
     pub create_role_service: Arc<dyn CreateRoleService>,
     pub get_role_by_app_service: Arc<dyn GetRoleByAppService>,
     pub get_roles_by_app_service: Arc<dyn GetRolesByAppService>,
     pub get_users_by_role_service: Arc<dyn GetUsersByRoleService>,
+    pub get_roles_by_user_service: Arc<dyn GetRolesByUserService>,
+    pub get_user_permissions_service: Arc<dyn GetUserPermissionsService>,
     pub update_role_service: Arc<dyn UpdateRoleService>,
     pub delete_role_service: Arc<dyn DeleteRoleService>,
     pub assign_service: Arc<dyn AssignService>,
+    pub expire_role_assignments_service: Arc<dyn ExpireRoleAssignmentsService>,
+    pub manage_groups_service: Arc<dyn ManageGroupsService>,
+    pub group_members_service: Arc<dyn GroupMembersService>,
+    pub group_roles_service: Arc<dyn GroupRolesService>,
+    pub permission_catalog_service: Arc<dyn PermissionCatalogService>,
     pub printer_service: Arc<Mutex<dyn PrinterConsumerService>>,
     pub replicator_service: Arc<Mutex<dyn ReplicatorConsumerService>>,
+    pub update_redirect_uris_service: Arc<dyn UpdateRedirectUrisService>,
+    pub update_identity_providers_service: Arc<dyn UpdateIdentityProvidersService>,
+    pub application_config_service: Arc<dyn ApplicationConfigService>,
+    pub authorize_service: Arc<dyn AuthorizeService>,
+    pub token_service: Arc<dyn TokenService>,
+    pub device_authorization_service: Arc<dyn DeviceAuthorizationService>,
+    pub federation_service: Arc<dyn FederationService>,
+    pub discovery_service: Arc<dyn DiscoveryService>,
+    pub userinfo_service: Arc<dyn UserInfoService>,
+    pub introspect_service: Arc<dyn IntrospectService>,
+    pub revoke_token_service: Arc<dyn RevokeTokenService>,
+    pub rotate_signing_keys_service: Arc<dyn RotateSigningKeysService>,
+    pub create_service_account_service: Arc<dyn CreateServiceAccountService>,
+    pub get_service_accounts_service: Arc<dyn GetServiceAccountsService>,
+    pub delete_service_account_service: Arc<dyn DeleteServiceAccountService>,
+    pub scim_users_service: Arc<dyn ScimUsersService>,
+    pub scim_groups_service: Arc<dyn ScimGroupsService>,
 }
 
 impl Container {
@@ -70,6 +128,11 @@ impl Container {
         let get_user_service = services::create_get_user_service(&repos);
         let update_user_service = services::create_update_user_service(&repos);
         let del_user_service = services::create_delete_user_service(&repos);
+        let import_users_service =
+            services::create_import_users_service(&repos, create_user_service.clone());
+        let export_users_service = services::create_export_users_service(&repos);
+        let purge_users_service = services::create_purge_users_service(&repos);
+        let normalize_identifiers_service = services::create_normalize_identifiers_service(&repos);
         let get_user_count_service = services::create_get_user_count_service(&repos);
         let ban_user_count_service = services::create_ban_user_service(&repos);
         let unban_user_count_service = services::create_unban_user_service(&repos);
@@ -79,13 +142,58 @@ impl Container {
         let revoke_refresh_token_service = services::create_revoke_refresh_token_service(&repos);
         let get_refresh_tokens_by_user_service =
             services::create_get_refresh_tokens_by_user_service(&repos);
+        let get_sessions_service = services::create_get_sessions_service(&repos);
+        let revoke_all_sessions_service = services::create_revoke_all_sessions_service(&repos);
         let create_role_service = services::create_create_role_service(&repos);
         let get_role_by_app_service = services::create_get_role_by_app_service(&repos);
         let get_roles_by_app_service = services::create_get_roles_by_app_service(&repos);
         let get_users_by_role_service = services::create_get_users_by_role_service(&repos);
+        let get_roles_by_user_service = services::create_get_roles_by_user_service(&repos);
+        let get_user_permissions_service = services::create_get_user_permissions_service(&repos);
         let update_role_service = services::create_update_role_service(&repos);
         let delete_role_service = services::create_delete_role_service(&repos);
         let assign_service = services::create_assign_service(&repos);
+        let expire_role_assignments_service =
+            services::create_expire_role_assignments_service(&repos);
+        let manage_groups_service = services::create_manage_groups_service(&repos);
+        let group_members_service = services::create_group_members_service(&repos);
+        let group_roles_service = services::create_group_roles_service(&repos);
+        let permission_catalog_service = services::create_permission_catalog_service(&repos);
+        let update_redirect_uris_service = services::create_update_redirect_uris_service(&repos);
+        let update_identity_providers_service =
+            services::create_update_identity_providers_service(&repos);
+        let application_config_service = services::create_application_config_service(&repos);
+        let authorize_service = services::create_authorize_service(&repos);
+        let token_service = services::create_token_service(
+            &repos,
+            create_refresh_token_service.clone(),
+            rotate_refresh_token_service.clone(),
+        );
+        let device_authorization_service =
+            services::create_device_authorization_service(&repos, authorize_service.clone());
+        let federation_service = services::create_federation_service(
+            &repos,
+            authorize_service.clone(),
+            create_user_service.clone(),
+        );
+        let discovery_service = services::create_discovery_service(&repos);
+        let userinfo_service = services::create_userinfo_service(&repos);
+        let introspect_service = services::create_introspect_service(&repos);
+        let revoke_token_service = services::create_revoke_token_service(&repos);
+        let rotate_signing_keys_service = services::create_rotate_signing_keys_service(&repos);
+        let create_service_account_service =
+            services::create_create_service_account_service(&repos);
+        let get_service_accounts_service = services::create_get_service_accounts_service(&repos);
+        let delete_service_account_service =
+            services::create_delete_service_account_service(&repos);
+        let scim_users_service = services::create_scim_users_service(
+            &repos,
+            create_user_service.clone(),
+            update_user_service.clone(),
+            del_user_service.clone(),
+        );
+        let scim_groups_service =
+            services::create_scim_groups_service(&repos, assign_service.clone());
 
         let validate_bearer_auth_middleware_service = Arc::new(ValidateBearerAuth::new(
             middlewares::create_validate_bearer_auth_service(&repos),
@@ -102,6 +210,10 @@ impl Container {
             update_user_service,
             validate_bearer_auth_middleware_service,
             del_user_service,
+            import_users_service,
+            export_users_service,
+            purge_users_service,
+            normalize_identifiers_service,
             get_user_count_service,
             ban_user_count_service,
             unban_user_count_service,
@@ -110,15 +222,41 @@ impl Container {
             rotate_refresh_token_service,
             revoke_refresh_token_service,
             get_refresh_tokens_by_user_service,
+            get_sessions_service,
+            revoke_all_sessions_service,
             create_role_service,
             get_role_by_app_service,
             get_roles_by_app_service,
             get_users_by_role_service,
+            get_roles_by_user_service,
+            get_user_permissions_service,
             update_role_service,
             delete_role_service,
             assign_service,
+            expire_role_assignments_service,
+            manage_groups_service,
+            group_members_service,
+            group_roles_service,
+            permission_catalog_service,
             printer_service,
             replicator_service,
+            update_redirect_uris_service,
+            update_identity_providers_service,
+            application_config_service,
+            authorize_service,
+            token_service,
+            device_authorization_service,
+            federation_service,
+            discovery_service,
+            userinfo_service,
+            introspect_service,
+            revoke_token_service,
+            rotate_signing_keys_service,
+            create_service_account_service,
+            get_service_accounts_service,
+            delete_service_account_service,
+            scim_users_service,
+            scim_groups_service,
         }
     }
 }
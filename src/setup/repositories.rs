@@ -4,42 +4,110 @@ use crate::{
             printer::{PrinterConsumerRepository, PrinterConsumerRepositoryImpl},
             replicator::{ReplicatorRepository, ReplicatorRepositoryImpl},
         },
+        applications::{
+            config::{ApplicationConfigRepository, ApplicationConfigRepositoryImpl},
+            update_identity_providers::{
+                UpdateIdentityProvidersRepository, UpdateIdentityProvidersRepositoryImpl,
+            },
+            update_redirect_uris::{UpdateRedirectUrisRepository, UpdateRedirectUrisRepositoryImpl},
+        },
+        groups::{
+            manage::{ManageGroupsRepository, ManageGroupsRepositoryImpl},
+            members::{GroupMembersRepository, GroupMembersRepositoryImpl},
+            roles::{GroupRolesRepository, GroupRolesRepositoryImpl},
+        },
         initial_core::{InitialCoreRepository, InitialCoreRepositoryImpl},
+        permissions::catalog::{PermissionCatalogRepository, PermissionCatalogRepositoryImpl},
+        oauth::{
+            authorize::{AuthorizeRepository, AuthorizeRepositoryImpl},
+            client_credentials::{ClientCredentialsRepository, ClientCredentialsRepositoryImpl},
+            device::{DeviceAuthorizationRepository, DeviceAuthorizationRepositoryImpl},
+            federation::{FederationRepository, FederationRepositoryImpl},
+            discovery::{DiscoveryRepository, DiscoveryRepositoryImpl},
+            introspect::{IntrospectRepository, IntrospectRepositoryImpl},
+            revoke::{RevokeTokenRepository, RevokeTokenRepositoryImpl},
+            token::{TokenRepository, TokenRepositoryImpl},
+            userinfo::{UserInfoRepository, UserInfoRepositoryImpl},
+        },
         refresh_tokens::{
             get::{GetRefreshTokenRepository, GetRefreshTokenRepositoryImpl},
             revoke::{RevokeRefreshTokenRepository, RevokeRefreshTokenRepositoryImpl},
+            revoke_all::{
+                RevokeAllRefreshTokensRepository, RevokeAllRefreshTokensRepositoryImpl,
+            },
             rotate::{RotateRefreshTokenRepository, RotateRefreshTokenRepositoryImpl},
         },
         roles::{
             assign::{AssignRepository, AssignRepositoryImpl},
             create_roles::{CreateRoleRepository, CreateRoleRepositoryImpl},
             delete_role::{DeleteRoleRepository, DeleteRoleRepositoryImpl},
+            expire_assignments::{
+                ExpireRoleAssignmentsRepository, ExpireRoleAssignmentsRepositoryImpl,
+            },
             get_role_by_app::{GetRoleByAppRepository, GetRoleByAppRepositoryImpl},
             get_roles_by_app::{GetRolesByAppRepository, GetRolesByAppRepositoryImpl},
+            get_roles_by_user::{GetRolesByUserRepository, GetRolesByUserRepositoryImpl},
+            get_user_permissions::{
+                GetUserPermissionsRepository, GetUserPermissionsRepositoryImpl,
+            },
             get_users_by_role::{GetUsersByRoleRepository, GetUsersByRoleRepositoryImpl},
             update_role::{UpdateRoleRepository, UpdateRoleRepositoryImpl},
         },
+        service_accounts::{
+            create::{CreateServiceAccountRepository, CreateServiceAccountRepositoryImpl},
+            delete::{DeleteServiceAccountRepository, DeleteServiceAccountRepositoryImpl},
+            get::{GetServiceAccountsRepository, GetServiceAccountsRepositoryImpl},
+        },
+        scim::{
+            groups::{ScimGroupsRepository, ScimGroupsRepositoryImpl},
+            users::{ScimUsersRepository, ScimUsersRepositoryImpl},
+        },
+        signing_keys::rotate::{RotateSigningKeysRepository, RotateSigningKeysRepositoryImpl},
     },
     infrastructure::repositories::{
-        cache::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheImpl,
+        cache::{
+            applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheImpl,
+            authorization_code_repository::AuthorizationCodeCacheImpl,
+            csrf_token_repository::CsrfTokenCacheImpl,
+            device_authorization_repository::DeviceAuthorizationCacheImpl,
+            federation_state_repository::FederationStateCacheImpl,
+            refresh_token_repository::RefreshTokenCacheImpl,
+            user_import_job_repository::UserImportJobCacheImpl,
+            user_permissions_repository::UserPermissionsCacheImpl,
+        },
         cache_layer::applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdCacheLayerImpl,
+        cache_layer::refresh_token_repository::RefreshTokenCacheLayerImpl,
         cipher::{aes_gcm_repository::AesGcmCipherImpl, base64_repository::Base64RepositoryImpl},
         database::{
             application_repository::ApplicationDatabaseRepositoryImpl,
             applications_organization_by_client_id_repository::ApplicationsOrganizationByClientIdDatabaseRepositoryImpl,
+            groups::GroupDatabaseRepositoryImpl,
             organization_repository::OrganizationDatabaseRepositoryImpl,
-            roles::RoleDatabaseRepositoryImpl, user_repository::UserDatabaseRepositoryImpl,
+            permissions::PermissionDatabaseRepositoryImpl,
+            roles::RoleDatabaseRepositoryImpl, service_account::ServiceAccountDatabaseRepositoryImpl,
+            signing_key::SigningKeyDatabaseRepositoryImpl,
+            user_identity::UserIdentityDatabaseRepositoryImpl,
+            user_repository::UserDatabaseRepositoryImpl,
         },
         fulltext_search::user_fulltext_search::{
             UserFulltextSearchRepository, UserFulltextSearchRepositoryImpl,
         },
-        paseto::refresh_token::PasetoRepositoryImpl,
+        jwt::id_token::IdTokenJwtRepositoryImpl,
+        keys::signing_key_store::SigningKeyStoreImpl,
+        oidc::upstream::UpstreamOidcRepositoryImpl,
+        paseto::{
+            access_token::AccessTokenPasetoRepositoryImpl, refresh_token::PasetoRepositoryImpl,
+        },
         queue::{
             producer::ProducerRepositoryImpl,
+            producer_roles_repository::RoleProducerRepositoryImpl,
             producer_users_repository::{UserProducerRepository, UserProducerRepositoryImpl},
             topics::USER_TOPIC,
         },
-        security::argon2_repository::PasswordHasherImpl,
+        security::{
+            argon2_repository::PasswordHasherImpl,
+            breached_password_repository::BreachedPasswordListImpl,
+        },
     },
 };
 use crate::{
@@ -53,6 +121,10 @@ use crate::{
             get_user::{GetUserRepository, GetUserRepositoryImpl},
             get_user_count::{GetUserCountRepository, GetUserCountRepositoryImpl},
             get_users::{GetUsersRepository, GetUsersRepositoryImpl},
+            import_users::{ImportUsersRepository, ImportUsersRepositoryImpl},
+            normalize_identifiers::{
+                NormalizeIdentifiersRepository, NormalizeIdentifiersRepositoryImpl,
+            },
             unban_user::{UnbanUserRepository, UnbanUserRepositoryImpl},
             update_user::{UpdateUserRepository, UpdateUserRepositoryImpl},
         },
@@ -75,6 +147,7 @@ pub struct Repositories {
     pub auth_repo: Arc<dyn ValidateBearerAuthMiddlewareRepository>,
     pub update_user_repo: Arc<dyn UpdateUserRepository>,
     pub del_user_repo: Arc<dyn DeleteUserRepository>,
+    pub normalize_identifiers_repo: Arc<dyn NormalizeIdentifiersRepository>,
     pub get_user_count_repo: Arc<dyn GetUserCountRepository>,
     pub ban_user_repo: Arc<dyn BanUserRepository>,
     pub unban_user_repo: Arc<dyn UnbanUserRepository>,
@@ -83,18 +156,45 @@ pub struct Repositories {
     pub rotate_refresh_token_repo: Arc<dyn RotateRefreshTokenRepository>,
     pub revoke_refresh_token_repo: Arc<dyn RevokeRefreshTokenRepository>,
     pub get_refresh_tokens_by_user: Arc<dyn GetRefreshTokenRepository>,
+    pub revoke_all_refresh_tokens_repo: Arc<dyn RevokeAllRefreshTokensRepository>,
     pub create_role_repo: Arc<dyn CreateRoleRepository>,
     pub get_role_by_app_repo: Arc<dyn GetRoleByAppRepository>,
     pub get_roles_by_app_repo: Arc<dyn GetRolesByAppRepository>,
     pub get_users_by_role_repo: Arc<dyn GetUsersByRoleRepository>,
+    pub get_roles_by_user_repo: Arc<dyn GetRolesByUserRepository>,
+    pub get_user_permissions_repo: Arc<dyn GetUserPermissionsRepository>,
     pub update_role_repo: Arc<dyn UpdateRoleRepository>,
     pub delete_role_repo: Arc<dyn DeleteRoleRepository>,
     pub assign_repo: Arc<dyn AssignRepository>,
+    pub expire_role_assignments_repo: Arc<dyn ExpireRoleAssignmentsRepository>,
+    pub manage_groups_repo: Arc<dyn ManageGroupsRepository>,
+    pub group_members_repo: Arc<dyn GroupMembersRepository>,
+    pub group_roles_repo: Arc<dyn GroupRolesRepository>,
+    pub permission_catalog_repo: Arc<dyn PermissionCatalogRepository>,
     pub printer_repo: Arc<dyn PrinterConsumerRepository>,
     pub user_fulltext_search_repo: Arc<dyn UserFulltextSearchRepository>,
     pub core_repo: Arc<dyn InitialCoreRepository>,
     pub user_producer_repo: Arc<dyn UserProducerRepository>,
     pub replicator_repo: Arc<dyn ReplicatorRepository>,
+    pub update_redirect_uris_repo: Arc<dyn UpdateRedirectUrisRepository>,
+    pub authorize_repo: Arc<dyn AuthorizeRepository>,
+    pub token_repo: Arc<dyn TokenRepository>,
+    pub discovery_repo: Arc<dyn DiscoveryRepository>,
+    pub userinfo_repo: Arc<dyn UserInfoRepository>,
+    pub introspect_repo: Arc<dyn IntrospectRepository>,
+    pub revoke_token_repo: Arc<dyn RevokeTokenRepository>,
+    pub rotate_signing_keys_repo: Arc<dyn RotateSigningKeysRepository>,
+    pub create_service_account_repo: Arc<dyn CreateServiceAccountRepository>,
+    pub get_service_accounts_repo: Arc<dyn GetServiceAccountsRepository>,
+    pub delete_service_account_repo: Arc<dyn DeleteServiceAccountRepository>,
+    pub client_credentials_repo: Arc<dyn ClientCredentialsRepository>,
+    pub device_authorization_repo: Arc<dyn DeviceAuthorizationRepository>,
+    pub federation_repo: Arc<dyn FederationRepository>,
+    pub update_identity_providers_repo: Arc<dyn UpdateIdentityProvidersRepository>,
+    pub application_config_repo: Arc<dyn ApplicationConfigRepository>,
+    pub scim_users_repo: Arc<dyn ScimUsersRepository>,
+    pub scim_groups_repo: Arc<dyn ScimGroupsRepository>,
+    pub import_users_repo: Arc<dyn ImportUsersRepository>,
 }
 
 pub async fn create_all(
@@ -106,16 +206,23 @@ pub async fn create_all(
     let secret = cfg.core.secret.clone();
     let cache_ttl = cfg.core.cache_ttl;
     let user_db = Arc::new(UserDatabaseRepositoryImpl::new(database.clone()).await);
-    let password_hasher = Arc::new(PasswordHasherImpl::new());
+    let password_hasher = Arc::new(PasswordHasherImpl::new(&cfg.core.argon2));
+    let breached_passwords = Arc::new(BreachedPasswordListImpl::new(
+        cfg.password_policy.breached_list_path.as_deref(),
+    ));
     let aes_repo = Arc::new(AesGcmCipherImpl::new(secret.as_bytes()));
     let base64_repo = Arc::new(Base64RepositoryImpl);
     let org_db_repo = Arc::new(OrganizationDatabaseRepositoryImpl::new(database.clone()));
     let app_db_repo = Arc::new(ApplicationDatabaseRepositoryImpl::new(database.clone()));
     let apporg_db_repo =
         Arc::new(ApplicationsOrganizationByClientIdDatabaseRepositoryImpl::new(database.clone()));
+    let user_fulltext_search_repo =
+        Arc::new(UserFulltextSearchRepositoryImpl::new(fulltext_search)); //repo
     let update_user_repo = Arc::new(UpdateUserRepositoryImpl::new(
         user_db.clone(),
         password_hasher.clone(),
+        user_fulltext_search_repo.clone(),
+        breached_passwords.clone(),
     ));
     let apporg_cache_repo = Arc::new(ApplicationsOrganizationByClientIdCacheImpl::new(
         cache.clone(),
@@ -127,7 +234,9 @@ pub async fn create_all(
     ));
     let create_user_repo = Arc::new(CreateUserRepositoryImpl::new(
         user_db.clone(),
-        password_hasher,
+        password_hasher.clone(),
+        user_fulltext_search_repo.clone(),
+        breached_passwords.clone(),
     ));
 
     let auth_repo = Arc::new(ValidateBearerAuthMiddlewareRepositoryImpl::new(
@@ -139,8 +248,6 @@ pub async fn create_all(
         user_db.clone(),
         base64_repo.clone(),
     ));
-    let user_fulltext_search_repo =
-        Arc::new(UserFulltextSearchRepositoryImpl::new(fulltext_search)); //repo
 
     let producer_repo = Box::new(ProducerRepositoryImpl::new(cfg.queue.clone()));
     let user_producer_repo = Arc::new(UserProducerRepositoryImpl::new(producer_repo));
@@ -149,35 +256,229 @@ pub async fn create_all(
     let refresh_token_database_repo =
         Arc::new(RefreshTokenDatabaseRepositoryImpl::new(database.clone()).await);
     let role_date_repo = Arc::new(RoleDatabaseRepositoryImpl::new(database.clone()).await);
+    let user_permissions_cache_repo =
+        Arc::new(UserPermissionsCacheImpl::new(cache.clone(), cache_ttl));
+    let group_db_repo = Arc::new(GroupDatabaseRepositoryImpl::new(database.clone()).await);
+    let permission_db_repo =
+        Arc::new(PermissionDatabaseRepositoryImpl::new(database.clone()).await);
 
-    let create_role_repo = Arc::new(CreateRoleRepositoryImpl::new(role_date_repo.clone()));
+    let create_role_repo = Arc::new(CreateRoleRepositoryImpl::new(
+        role_date_repo.clone(),
+        permission_db_repo.clone(),
+    ));
     let get_role_by_app_repo = Arc::new(GetRoleByAppRepositoryImpl::new(role_date_repo.clone()));
     let get_roles_by_app_repo = Arc::new(GetRolesByAppRepositoryImpl::new(role_date_repo.clone()));
     let get_users_by_role_repo =
         Arc::new(GetUsersByRoleRepositoryImpl::new(role_date_repo.clone()));
-    let update_role_repo = Arc::new(UpdateRoleRepositoryImpl::new(role_date_repo.clone()));
-    let delete_role_repo = Arc::new(DeleteRoleRepositoryImpl::new(role_date_repo.clone()));
-    let assign_repo = Arc::new(AssignRepositoryImpl::new(role_date_repo.clone()));
-    // let refresh_token_cache_repo = Arc::new(RefreshTokenCacheImpl::new(cache.clone(), 3600));
+    let get_roles_by_user_repo = Arc::new(GetRolesByUserRepositoryImpl::new(
+        role_date_repo.clone(),
+        group_db_repo.clone(),
+    ));
+    let get_user_permissions_repo = Arc::new(GetUserPermissionsRepositoryImpl::new(
+        role_date_repo.clone(),
+        group_db_repo.clone(),
+        user_permissions_cache_repo.clone(),
+    ));
+    let update_role_repo = Arc::new(UpdateRoleRepositoryImpl::new(
+        role_date_repo.clone(),
+        group_db_repo.clone(),
+        permission_db_repo.clone(),
+        user_permissions_cache_repo.clone(),
+    ));
+    let delete_role_repo = Arc::new(DeleteRoleRepositoryImpl::new(
+        role_date_repo.clone(),
+        group_db_repo.clone(),
+        user_permissions_cache_repo.clone(),
+    ));
+    let assign_repo = Arc::new(AssignRepositoryImpl::new(
+        role_date_repo.clone(),
+        user_permissions_cache_repo.clone(),
+    ));
+    let role_producer_repo = Arc::new(RoleProducerRepositoryImpl::new(Arc::new(Mutex::new(
+        ProducerRepositoryImpl::new(cfg.queue.clone()),
+    ))));
+    let expire_role_assignments_repo = Arc::new(ExpireRoleAssignmentsRepositoryImpl::new(
+        role_date_repo.clone(),
+        user_permissions_cache_repo.clone(),
+        role_producer_repo,
+    ));
+    let manage_groups_repo = Arc::new(ManageGroupsRepositoryImpl::new(
+        group_db_repo.clone(),
+        user_permissions_cache_repo.clone(),
+    ));
+    let group_members_repo = Arc::new(GroupMembersRepositoryImpl::new(
+        group_db_repo.clone(),
+        user_db.clone(),
+        user_permissions_cache_repo.clone(),
+        base64_repo.clone(),
+    ));
+    let group_roles_repo = Arc::new(GroupRolesRepositoryImpl::new(
+        group_db_repo.clone(),
+        role_date_repo.clone(),
+        user_permissions_cache_repo.clone(),
+    ));
+    let permission_catalog_repo = Arc::new(PermissionCatalogRepositoryImpl::new(
+        permission_db_repo,
+        role_date_repo.clone(),
+    ));
+    let user_identity_db_repo =
+        Arc::new(UserIdentityDatabaseRepositoryImpl::new(database.clone()).await);
+    let service_account_db_repo =
+        Arc::new(ServiceAccountDatabaseRepositoryImpl::new(database.clone()).await);
+    let create_service_account_repo = Arc::new(CreateServiceAccountRepositoryImpl::new(
+        service_account_db_repo.clone(),
+        aes_repo.clone(),
+        base64_repo.clone(),
+    ));
+    let get_service_accounts_repo = Arc::new(GetServiceAccountsRepositoryImpl::new(
+        service_account_db_repo.clone(),
+    ));
+    let delete_service_account_repo = Arc::new(DeleteServiceAccountRepositoryImpl::new(
+        service_account_db_repo.clone(),
+        role_date_repo.clone(),
+    ));
+    let refresh_token_cache_repo = Arc::new(RefreshTokenCacheImpl::new(cache.clone(), cache_ttl));
+    let refresh_token_cache_layer = Arc::new(RefreshTokenCacheLayerImpl::new(
+        refresh_token_cache_repo,
+        refresh_token_database_repo,
+    ));
     let refresh_token_paseto_repo = Arc::new(PasetoRepositoryImpl::new());
+    let signing_key_database_repo =
+        Arc::new(SigningKeyDatabaseRepositoryImpl::new(database.clone()).await);
+    let signing_key_store = Arc::new(SigningKeyStoreImpl::new(
+        signing_key_database_repo.clone(),
+        aes_repo.clone(),
+        base64_repo.clone(),
+    ));
     let create_refresh_token_repo = Arc::new(CreateRefreshTokenRepositoryImpl::new(
         refresh_token_paseto_repo.clone(),
-        refresh_token_database_repo.clone(),
+        refresh_token_cache_layer.clone(),
         base64_repo.clone(),
         aes_repo.clone(),
+        signing_key_store.clone(),
     ));
     let get_refresh_tokens_by_user = Arc::new(GetRefreshTokenRepositoryImpl::new(
-        refresh_token_database_repo.clone(),
+        refresh_token_cache_layer.clone(),
         base64_repo.clone(),
     ));
     let rotate_refresh_token_repo = Arc::new(RotateRefreshTokenRepositoryImpl::new(
         refresh_token_paseto_repo.clone(),
-        refresh_token_database_repo.clone(),
+        refresh_token_cache_layer.clone(),
         base64_repo.clone(),
         aes_repo.clone(),
+        signing_key_store.clone(),
     ));
     let revoke_refresh_token_repo = Arc::new(RevokeRefreshTokenRepositoryImpl::new(
-        refresh_token_database_repo.clone(),
+        refresh_token_cache_layer.clone(),
+    ));
+    let revoke_all_refresh_tokens_repo = Arc::new(RevokeAllRefreshTokensRepositoryImpl::new(
+        refresh_token_cache_layer.clone(),
+    ));
+
+    let update_redirect_uris_repo = Arc::new(UpdateRedirectUrisRepositoryImpl::new(
+        app_db_repo.clone(),
+        apporg_cache_layer.clone(),
+    ));
+    let update_identity_providers_repo = Arc::new(UpdateIdentityProvidersRepositoryImpl::new(
+        app_db_repo.clone(),
+        apporg_cache_layer.clone(),
+        aes_repo.clone(),
+    ));
+    let application_config_repo = Arc::new(ApplicationConfigRepositoryImpl::new(
+        app_db_repo.clone(),
+        apporg_cache_layer.clone(),
+    ));
+    let authorization_code_cache_repo = Arc::new(AuthorizationCodeCacheImpl::new(
+        cache.clone(),
+        cfg.oauth.authorization_code_ttl,
+    ));
+    let csrf_token_cache_repo = Arc::new(CsrfTokenCacheImpl::new(
+        cache.clone(),
+        cfg.oauth.csrf_token_ttl,
+    ));
+    let authorize_repo = Arc::new(AuthorizeRepositoryImpl::new(
+        apporg_cache_layer.clone(),
+        user_db.clone(),
+        password_hasher.clone(),
+        authorization_code_cache_repo.clone(),
+        csrf_token_cache_repo,
+    ));
+    let access_token_paseto_repo = Arc::new(AccessTokenPasetoRepositoryImpl::new(
+        cfg.oauth.access_token_ttl,
+    ));
+    let id_token_repo = Arc::new(IdTokenJwtRepositoryImpl::new(cfg.oauth.issuer.clone()));
+    let device_authorization_cache_repo = Arc::new(DeviceAuthorizationCacheImpl::new(
+        cache.clone(),
+        cfg.oauth.device_code_ttl,
+        cfg.oauth.device_poll_interval,
+    ));
+    let device_authorization_repo = Arc::new(DeviceAuthorizationRepositoryImpl::new(
+        apporg_cache_layer.clone(),
+        device_authorization_cache_repo.clone(),
+        id_token_repo.clone(),
+    ));
+    let federation_state_cache_repo = Arc::new(FederationStateCacheImpl::new(
+        cache.clone(),
+        cfg.oauth.federation_state_ttl,
+    ));
+    let federation_repo = Arc::new(FederationRepositoryImpl::new(
+        user_identity_db_repo.clone(),
+        user_db.clone(),
+        federation_state_cache_repo,
+        Arc::new(UpstreamOidcRepositoryImpl::new()),
+        aes_repo.clone(),
+        id_token_repo.clone(),
+    ));
+    let token_repo = Arc::new(TokenRepositoryImpl::new(
+        apporg_cache_layer.clone(),
+        user_db.clone(),
+        authorization_code_cache_repo,
+        access_token_paseto_repo.clone(),
+        id_token_repo.clone(),
+        signing_key_store.clone(),
+        device_authorization_cache_repo,
+    ));
+    let discovery_repo = Arc::new(DiscoveryRepositoryImpl::new(
+        apporg_cache_layer.clone(),
+        id_token_repo,
+        signing_key_store.clone(),
+    ));
+    let userinfo_repo = Arc::new(UserInfoRepositoryImpl::new(
+        apporg_cache_layer.clone(),
+        user_db.clone(),
+        access_token_paseto_repo.clone(),
+        signing_key_store.clone(),
+    ));
+    let client_credentials_repo = Arc::new(ClientCredentialsRepositoryImpl::new(
+        service_account_db_repo.clone(),
+        role_date_repo.clone(),
+        apporg_cache_layer.clone(),
+        aes_repo.clone(),
+        access_token_paseto_repo.clone(),
+        signing_key_store.clone(),
+    ));
+    let introspect_repo = Arc::new(IntrospectRepositoryImpl::new(
+        user_db.clone(),
+        service_account_db_repo,
+        refresh_token_cache_layer.clone(),
+        refresh_token_paseto_repo.clone(),
+        access_token_paseto_repo.clone(),
+        signing_key_store.clone(),
+    ));
+    let revoke_token_repo = Arc::new(RevokeTokenRepositoryImpl::new(
+        refresh_token_cache_layer.clone(),
+        refresh_token_paseto_repo,
+        access_token_paseto_repo,
+        signing_key_store.clone(),
+    ));
+    let rotate_signing_keys_repo = Arc::new(RotateSigningKeysRepositoryImpl::new(
+        signing_key_database_repo,
+        signing_key_store,
+    ));
+
+    let normalize_identifiers_repo = Arc::new(NormalizeIdentifiersRepositoryImpl::new(
+        user_db.clone(),
+        app_db_repo.clone(),
     ));
 
     let core_repo = Arc::new(InitialCoreRepositoryImpl::new(
@@ -187,12 +488,32 @@ pub async fn create_all(
         app_db_repo,
         apporg_cache_layer.clone(),
     ));
-    let del_user_repo = Arc::new(DeleteUserRepositoryImpl::new(user_db.clone()));
+    let del_user_repo = Arc::new(DeleteUserRepositoryImpl::new(
+        user_db.clone(),
+        role_date_repo.clone(),
+        refresh_token_cache_layer,
+        user_fulltext_search_repo.clone(),
+        user_identity_db_repo,
+        user_permissions_cache_repo.clone(),
+        group_db_repo,
+    ));
 
     let get_user_count_repo = Arc::new(GetUserCountRepositoryImpl::new(user_db.clone()));
     let ban_user_repo = Arc::new(BanUserRepositoryImpl::new(user_db.clone()));
     let unban_user_repo = Arc::new(UnbanUserRepositoryImpl::new(user_db.clone()));
     let disable_mfa_user_repo = Arc::new(DisableMFAUserRepositoryImpl::new(user_db.clone()));
+    let import_users_repo = Arc::new(ImportUsersRepositoryImpl::new(Arc::new(
+        UserImportJobCacheImpl::new(cache.clone(), cfg.user_import.job_ttl),
+    )));
+    let scim_users_repo = Arc::new(ScimUsersRepositoryImpl::new(
+        user_db.clone(),
+        user_fulltext_search_repo.clone(),
+    ));
+    let scim_groups_repo = Arc::new(ScimGroupsRepositoryImpl::new(
+        role_date_repo.clone(),
+        user_db.clone(),
+        user_permissions_cache_repo,
+    ));
     let printer_repo = Arc::new(PrinterConsumerRepositoryImpl);
 
     let replicator_repo = Arc::new(ReplicatorRepositoryImpl::new(
@@ -206,6 +527,7 @@ pub async fn create_all(
         get_user_repo,
         update_user_repo,
         del_user_repo,
+        normalize_identifiers_repo,
         get_user_count_repo,
         ban_user_repo,
         unban_user_repo,
@@ -214,17 +536,44 @@ pub async fn create_all(
         rotate_refresh_token_repo,
         revoke_refresh_token_repo,
         get_refresh_tokens_by_user,
+        revoke_all_refresh_tokens_repo,
         create_role_repo,
         get_role_by_app_repo,
         get_roles_by_app_repo,
         get_users_by_role_repo,
+        get_roles_by_user_repo,
+        get_user_permissions_repo,
         update_role_repo,
         delete_role_repo,
         assign_repo,
+        expire_role_assignments_repo,
+        manage_groups_repo,
+        group_members_repo,
+        group_roles_repo,
+        permission_catalog_repo,
         printer_repo,
         user_fulltext_search_repo,
         core_repo,
         user_producer_repo,
         replicator_repo,
+        update_redirect_uris_repo,
+        authorize_repo,
+        token_repo,
+        discovery_repo,
+        userinfo_repo,
+        introspect_repo,
+        revoke_token_repo,
+        rotate_signing_keys_repo,
+        create_service_account_repo,
+        get_service_accounts_repo,
+        delete_service_account_repo,
+        client_credentials_repo,
+        device_authorization_repo,
+        federation_repo,
+        update_identity_providers_repo,
+        application_config_repo,
+        scim_users_repo,
+        scim_groups_repo,
+        import_users_repo,
     }
 }
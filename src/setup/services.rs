@@ -1,27 +1,66 @@
 use std::sync::Arc;
 
 use crate::application::services::{
+    applications::{
+        config::{ApplicationConfigService, ApplicationConfigServiceImpl},
+        update_identity_providers::{
+            UpdateIdentityProvidersService, UpdateIdentityProvidersServiceImpl,
+        },
+        update_redirect_uris::{UpdateRedirectUrisService, UpdateRedirectUrisServiceImpl},
+    },
     cdc::{
         printer::{PrinterConsumerService, PrinterConsumerServiceImpl},
         replicator::{ReplicatorConsumerService, ReplicatorConsumerServiceImpl},
     },
+    groups::{
+        manage::{ManageGroupsService, ManageGroupsServiceImpl},
+        members::{GroupMembersService, GroupMembersServiceImpl},
+        roles::{GroupRolesService, GroupRolesServiceImpl},
+    },
     hello_service::{HelloService, HelloServiceImpl},
     initial_core_service::{InitialCoreService, InitialCoreServiceImpl},
+    permissions::catalog::{PermissionCatalogService, PermissionCatalogServiceImpl},
+    oauth::{
+        authorize::{AuthorizeService, AuthorizeServiceImpl},
+        client_credentials::{ClientCredentialsService, ClientCredentialsServiceImpl},
+        device::{DeviceAuthorizationService, DeviceAuthorizationServiceImpl},
+        discovery::{DiscoveryService, DiscoveryServiceImpl},
+        federation::{FederationService, FederationServiceImpl},
+        introspect::{IntrospectService, IntrospectServiceImpl},
+        revoke::{RevokeTokenService, RevokeTokenServiceImpl},
+        token::{TokenService, TokenServiceImpl},
+        userinfo::{UserInfoService, UserInfoServiceImpl},
+    },
     refresh_token::{
         create::{CreateRefreshTokenService, CreateRefreshTokenServiceImpl},
         get::{GetRefreshTokenService, GetRefreshTokenServiceImpl},
         revoke::{RevokeRefreshTokenService, RevokeRefreshTokenServiceImpl},
+        revoke_all::{RevokeAllSessionsService, RevokeAllSessionsServiceImpl},
+        sessions::{GetSessionsService, GetSessionsServiceImpl},
         rotate::{RotateRefreshTokenService, RotateRefreshTokenServiceImpl},
     },
     roles::{
         assign::{AssignService, AssignServiceImpl},
         create_roles::{CreateRoleService, CreateRoleServiceImpl},
         delete_role::{DeleteRoleService, DeleteRoleServiceImpl},
+        expire_assignments::{ExpireRoleAssignmentsService, ExpireRoleAssignmentsServiceImpl},
         get_role_by_app::{GetRoleByAppService, GetRoleByAppServiceImpl},
         get_roles_by_app::{GetRolesByAppService, GetRolesByAppServiceImpl},
+        get_roles_by_user::{GetRolesByUserService, GetRolesByUserServiceImpl},
+        get_user_permissions::{GetUserPermissionsService, GetUserPermissionsServiceImpl},
         get_users_by_role::{GetUsersByRoleService, GetUsersByRoleServiceImpl},
         update_role::{UpdateRoleService, UpdateRoleServiceImpl},
     },
+    service_accounts::{
+        create::{CreateServiceAccountService, CreateServiceAccountServiceImpl},
+        delete::{DeleteServiceAccountService, DeleteServiceAccountServiceImpl},
+        get::{GetServiceAccountsService, GetServiceAccountsServiceImpl},
+    },
+    scim::{
+        groups::{ScimGroupsService, ScimGroupsServiceImpl},
+        users::{ScimUsersService, ScimUsersServiceImpl},
+    },
+    signing_keys::rotate::{RotateSigningKeysService, RotateSigningKeysServiceImpl},
     users::{
         ban_user::{BanUserService, BanUserServiceImpl},
         create::{CreateUserService, CreateUserServiceImpl},
@@ -29,7 +68,11 @@ use crate::application::services::{
         disable_mfa_user::{DisableMFAUserService, DisableMFAUserServiceImpl},
         get_user::{GetUserService, GetUserServiceImpl},
         get_user_count::{GetUserCountService, GetUserCountServiceImpl},
+        export_users::{ExportUsersService, ExportUsersServiceImpl},
         get_users::{GetUsersService, GetUsersServiceImpl},
+        import_users::{ImportUsersService, ImportUsersServiceImpl},
+        normalize_identifiers::{NormalizeIdentifiersService, NormalizeIdentifiersServiceImpl},
+        purge_users::{PurgeUsersService, PurgeUsersServiceImpl},
         unban_user::{UnbanUserService, UnbanUserServiceImpl},
         update_user::{UpdateUserService, UpdateUserServiceImpl},
     },
@@ -59,6 +102,22 @@ pub fn create_get_user_service(repos: &Repositories) -> Arc<dyn GetUserService>
     })
 }
 
+pub fn create_import_users_service(
+    repos: &Repositories,
+    create_user_service: Arc<dyn CreateUserService>,
+) -> Arc<dyn ImportUsersService> {
+    Arc::new(ImportUsersServiceImpl {
+        repository: repos.import_users_repo.clone(),
+        create_user_service,
+    })
+}
+
+pub fn create_export_users_service(repos: &Repositories) -> Arc<dyn ExportUsersService> {
+    Arc::new(ExportUsersServiceImpl {
+        repository: repos.get_users_repo.clone(),
+    })
+}
+
 pub fn create_update_user_service(repos: &Repositories) -> Arc<dyn UpdateUserService> {
     Arc::new(UpdateUserServiceImpl {
         repository: repos.update_user_repo.clone(),
@@ -71,6 +130,20 @@ pub fn create_delete_user_service(repos: &Repositories) -> Arc<dyn DeleteUserSer
     })
 }
 
+pub fn create_purge_users_service(repos: &Repositories) -> Arc<dyn PurgeUsersService> {
+    Arc::new(PurgeUsersServiceImpl {
+        repository: repos.del_user_repo.clone(),
+    })
+}
+
+pub fn create_normalize_identifiers_service(
+    repos: &Repositories,
+) -> Arc<dyn NormalizeIdentifiersService> {
+    Arc::new(NormalizeIdentifiersServiceImpl {
+        repository: repos.normalize_identifiers_repo.clone(),
+    })
+}
+
 pub fn create_get_user_count_service(repos: &Repositories) -> Arc<dyn GetUserCountService> {
     Arc::new(GetUserCountServiceImpl {
         repository: repos.get_user_count_repo.clone(),
@@ -126,6 +199,19 @@ pub fn create_get_refresh_tokens_by_user_service(
         repository: repos.get_refresh_tokens_by_user.clone(),
     })
 }
+pub fn create_get_sessions_service(repos: &Repositories) -> Arc<dyn GetSessionsService> {
+    Arc::new(GetSessionsServiceImpl {
+        repository: repos.get_refresh_tokens_by_user.clone(),
+    })
+}
+
+pub fn create_revoke_all_sessions_service(
+    repos: &Repositories,
+) -> Arc<dyn RevokeAllSessionsService> {
+    Arc::new(RevokeAllSessionsServiceImpl {
+        repository: repos.revoke_all_refresh_tokens_repo.clone(),
+    })
+}
 pub fn create_create_role_service(repos: &Repositories) -> Arc<dyn CreateRoleService> {
     Arc::new(CreateRoleServiceImpl {
         repository: repos.create_role_repo.clone(),
@@ -150,6 +236,20 @@ pub fn create_get_users_by_role_service(repos: &Repositories) -> Arc<dyn GetUser
     })
 }
 
+pub fn create_get_roles_by_user_service(repos: &Repositories) -> Arc<dyn GetRolesByUserService> {
+    Arc::new(GetRolesByUserServiceImpl {
+        repository: repos.get_roles_by_user_repo.clone(),
+    })
+}
+
+pub fn create_get_user_permissions_service(
+    repos: &Repositories,
+) -> Arc<dyn GetUserPermissionsService> {
+    Arc::new(GetUserPermissionsServiceImpl {
+        repository: repos.get_user_permissions_repo.clone(),
+    })
+}
+
 pub fn create_update_role_service(repos: &Repositories) -> Arc<dyn UpdateRoleService> {
     Arc::new(UpdateRoleServiceImpl {
         repository: repos.update_role_repo.clone(),
@@ -168,6 +268,40 @@ pub fn create_assign_service(repos: &Repositories) -> Arc<dyn AssignService> {
     })
 }
 
+pub fn create_expire_role_assignments_service(
+    repos: &Repositories,
+) -> Arc<dyn ExpireRoleAssignmentsService> {
+    Arc::new(ExpireRoleAssignmentsServiceImpl {
+        repository: repos.expire_role_assignments_repo.clone(),
+    })
+}
+
+pub fn create_manage_groups_service(repos: &Repositories) -> Arc<dyn ManageGroupsService> {
+    Arc::new(ManageGroupsServiceImpl {
+        repository: repos.manage_groups_repo.clone(),
+    })
+}
+
+pub fn create_group_members_service(repos: &Repositories) -> Arc<dyn GroupMembersService> {
+    Arc::new(GroupMembersServiceImpl {
+        repository: repos.group_members_repo.clone(),
+    })
+}
+
+pub fn create_group_roles_service(repos: &Repositories) -> Arc<dyn GroupRolesService> {
+    Arc::new(GroupRolesServiceImpl {
+        repository: repos.group_roles_repo.clone(),
+    })
+}
+
+pub fn create_permission_catalog_service(
+    repos: &Repositories,
+) -> Arc<dyn PermissionCatalogService> {
+    Arc::new(PermissionCatalogServiceImpl {
+        repository: repos.permission_catalog_repo.clone(),
+    })
+}
+
 pub fn create_printer_service(repos: &Repositories) -> Arc<Mutex<dyn PrinterConsumerService>> {
     Arc::new(Mutex::new(PrinterConsumerServiceImpl::new(
         repos.printer_repo.clone(),
@@ -186,3 +320,156 @@ pub fn create_init_service(repos: &Repositories) -> Arc<dyn InitialCoreService>
         repository: repos.core_repo.clone(),
     })
 }
+
+pub fn create_update_redirect_uris_service(
+    repos: &Repositories,
+) -> Arc<dyn UpdateRedirectUrisService> {
+    Arc::new(UpdateRedirectUrisServiceImpl {
+        repository: repos.update_redirect_uris_repo.clone(),
+    })
+}
+
+pub fn create_update_identity_providers_service(
+    repos: &Repositories,
+) -> Arc<dyn UpdateIdentityProvidersService> {
+    Arc::new(UpdateIdentityProvidersServiceImpl {
+        repository: repos.update_identity_providers_repo.clone(),
+    })
+}
+
+pub fn create_application_config_service(
+    repos: &Repositories,
+) -> Arc<dyn ApplicationConfigService> {
+    Arc::new(ApplicationConfigServiceImpl {
+        repository: repos.application_config_repo.clone(),
+    })
+}
+
+pub fn create_authorize_service(repos: &Repositories) -> Arc<dyn AuthorizeService> {
+    Arc::new(AuthorizeServiceImpl {
+        repository: repos.authorize_repo.clone(),
+    })
+}
+
+pub fn create_token_service(
+    repos: &Repositories,
+    create_refresh_token_service: Arc<dyn CreateRefreshTokenService>,
+    rotate_refresh_token_service: Arc<dyn RotateRefreshTokenService>,
+) -> Arc<dyn TokenService> {
+    Arc::new(TokenServiceImpl {
+        repository: repos.token_repo.clone(),
+        create_refresh_token_service,
+        rotate_refresh_token_service,
+        client_credentials_service: create_client_credentials_service(repos),
+    })
+}
+
+pub fn create_client_credentials_service(
+    repos: &Repositories,
+) -> Arc<dyn ClientCredentialsService> {
+    Arc::new(ClientCredentialsServiceImpl {
+        repository: repos.client_credentials_repo.clone(),
+    })
+}
+
+pub fn create_device_authorization_service(
+    repos: &Repositories,
+    authorize_service: Arc<dyn AuthorizeService>,
+) -> Arc<dyn DeviceAuthorizationService> {
+    Arc::new(DeviceAuthorizationServiceImpl {
+        repository: repos.device_authorization_repo.clone(),
+        authorize_service,
+    })
+}
+
+pub fn create_federation_service(
+    repos: &Repositories,
+    authorize_service: Arc<dyn AuthorizeService>,
+    create_user_service: Arc<dyn CreateUserService>,
+) -> Arc<dyn FederationService> {
+    Arc::new(FederationServiceImpl {
+        repository: repos.federation_repo.clone(),
+        authorize_service,
+        create_user_service,
+    })
+}
+
+pub fn create_scim_users_service(
+    repos: &Repositories,
+    create_user_service: Arc<dyn CreateUserService>,
+    update_user_service: Arc<dyn UpdateUserService>,
+    delete_user_service: Arc<dyn DeleteUserService>,
+) -> Arc<dyn ScimUsersService> {
+    Arc::new(ScimUsersServiceImpl {
+        repository: repos.scim_users_repo.clone(),
+        create_user_service,
+        update_user_service,
+        delete_user_service,
+    })
+}
+
+pub fn create_scim_groups_service(
+    repos: &Repositories,
+    assign_service: Arc<dyn AssignService>,
+) -> Arc<dyn ScimGroupsService> {
+    Arc::new(ScimGroupsServiceImpl {
+        repository: repos.scim_groups_repo.clone(),
+        assign_service,
+    })
+}
+
+pub fn create_discovery_service(repos: &Repositories) -> Arc<dyn DiscoveryService> {
+    Arc::new(DiscoveryServiceImpl {
+        repository: repos.discovery_repo.clone(),
+    })
+}
+
+pub fn create_userinfo_service(repos: &Repositories) -> Arc<dyn UserInfoService> {
+    Arc::new(UserInfoServiceImpl {
+        repository: repos.userinfo_repo.clone(),
+    })
+}
+
+pub fn create_introspect_service(repos: &Repositories) -> Arc<dyn IntrospectService> {
+    Arc::new(IntrospectServiceImpl {
+        repository: repos.introspect_repo.clone(),
+    })
+}
+
+pub fn create_revoke_token_service(repos: &Repositories) -> Arc<dyn RevokeTokenService> {
+    Arc::new(RevokeTokenServiceImpl {
+        repository: repos.revoke_token_repo.clone(),
+    })
+}
+
+pub fn create_rotate_signing_keys_service(
+    repos: &Repositories,
+) -> Arc<dyn RotateSigningKeysService> {
+    Arc::new(RotateSigningKeysServiceImpl {
+        repository: repos.rotate_signing_keys_repo.clone(),
+    })
+}
+
+pub fn create_create_service_account_service(
+    repos: &Repositories,
+) -> Arc<dyn CreateServiceAccountService> {
+    Arc::new(CreateServiceAccountServiceImpl {
+        repository: repos.create_service_account_repo.clone(),
+    })
+}
+
+pub fn create_get_service_accounts_service(
+    repos: &Repositories,
+) -> Arc<dyn GetServiceAccountsService> {
+    Arc::new(GetServiceAccountsServiceImpl {
+        repository: repos.get_service_accounts_repo.clone(),
+    })
+}
+
+pub fn create_delete_service_account_service(
+    repos: &Repositories,
+) -> Arc<dyn DeleteServiceAccountService> {
+    Arc::new(DeleteServiceAccountServiceImpl {
+        repository: repos.delete_service_account_repo.clone(),
+    })
+}
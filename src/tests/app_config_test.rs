@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::applications::config::{
+        ApplicationConfigRepository, ApplicationConfigRepositoryImpl,
+        MockApplicationConfigRepository,
+    };
+    use crate::application::services::applications::config::{
+        ApplicationConfigService, ApplicationConfigServiceImpl,
+    };
+    use crate::domain::value_objects::app_config::{APP_CONFIG_VERSION, AppConfig};
+    use crate::domain::value_objects::identity_provider::IdentityProvider;
+    use crate::infrastructure::repositories::cache_layer::applications_organization_by_client_id_repository::MockApplicationsOrganizationByClientIdCacheLayerRepository;
+    use crate::infrastructure::repositories::database::application_repository::MockApplicationDatabaseRepository;
+    use crate::tests::fixtures::{build_apporg, build_apporg_with};
+    use scylla::value::CqlTimestamp;
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn errors(details: Option<serde_json::Value>) -> Vec<String> {
+        details.unwrap()["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_legacy_document_is_migrated() {
+        let legacy =
+            r#"{"is_must_name_unique":true,"user_metadata_schema":null,"redirect_uris":null}"#;
+
+        let config = AppConfig::from_document(legacy).unwrap();
+
+        assert_eq!(config.version, APP_CONFIG_VERSION);
+        assert!(config.is_must_name_unique);
+        assert!(!config.can_allow_email_nullable);
+        assert!(config.redirect_uris.is_empty());
+        assert!(config.password_policy.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_patch_merges_and_persists() {
+        let apporg = build_apporg_with(AppConfig::new(false, false));
+        let application_id = apporg.application_id;
+
+        let mut mock = MockApplicationConfigRepository::new();
+        mock.expect_update_application_config()
+            .withf(|_, config| {
+                config.can_allow_email_nullable
+                    && config
+                        .password_policy
+                        .as_ref()
+                        .is_some_and(|p| p.min_length == 12)
+                    && config.redirect_uris == vec!["https://app.example.com/cb".to_string()]
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let service = ApplicationConfigServiceImpl::new(Arc::new(mock));
+        let patch = json!({
+            "can_allow_email_nullable": true,
+            "password_policy": { "min_length": 12 },
+            "redirect_uris": ["https://app.example.com/cb", "https://app.example.com/cb"],
+        });
+        let res = service.update(apporg, application_id, patch).await.unwrap();
+
+        assert_eq!(res.config["password_policy"]["max_length"], 128);
+        assert_eq!(res.config["version"], APP_CONFIG_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_patch_rejects_unknown_and_read_only_keys() {
+        let apporg = build_apporg_with(AppConfig::default());
+        let application_id = apporg.application_id;
+        let mut mock = MockApplicationConfigRepository::new();
+        mock.expect_update_application_config().never();
+        let service = ApplicationConfigServiceImpl::new(Arc::new(mock));
+
+        for patch in [
+            json!({ "no_such_setting": true }),
+            json!({ "version": 7 }),
+            json!({ "identity_providers": [] }),
+            json!(["not", "an", "object"]),
+        ] {
+            let err = service
+                .update(apporg.clone(), application_id, patch)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code, 400);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_patch_reports_every_validation_error() {
+        let apporg = build_apporg_with(AppConfig::default());
+        let application_id = apporg.application_id;
+        let mut mock = MockApplicationConfigRepository::new();
+        mock.expect_update_application_config().never();
+
+        let service = ApplicationConfigServiceImpl::new(Arc::new(mock));
+        let patch = json!({
+            "redirect_uris": ["http://app.example.com/cb"],
+            "token_lifetimes": { "refresh_token_lifetime": 0 },
+            "password_policy": { "min_length": 4 },
+        });
+        let err = service
+            .update(apporg, application_id, patch)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 400);
+        assert_eq!(errors(err.details).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_config_of_other_application_is_not_found() {
+        let service =
+            ApplicationConfigServiceImpl::new(Arc::new(MockApplicationConfigRepository::new()));
+
+        let err = service
+            .get(build_apporg_with(AppConfig::default()), Uuid::new_v4())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 404);
+    }
+
+    #[tokio::test]
+    async fn test_get_hides_client_secrets() {
+        let mut config = AppConfig::default();
+        config.identity_providers.push(IdentityProvider {
+            id: "corp".into(),
+            name: "Corp".into(),
+            issuer: "https://idp.example.com".into(),
+            client_id: "axcelium".into(),
+            encrypted_client_secret: "nonce.ciphertext".into(),
+            scopes: vec!["openid".into()],
+            link_by_email: false,
+        });
+        let apporg = build_apporg_with(config);
+        let application_id = apporg.application_id;
+
+        let service =
+            ApplicationConfigServiceImpl::new(Arc::new(MockApplicationConfigRepository::new()));
+        let res = service.get(apporg, application_id).await.unwrap();
+
+        let provider = &res.config["identity_providers"][0];
+        assert_eq!(provider["client_id"], "axcelium");
+        assert!(provider.get("encrypted_client_secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_update_is_conditional_on_stored_updated_at() {
+        let mut apporg = build_apporg();
+        apporg.updated_at = CqlTimestamp(1_000);
+        let client_id = apporg.client_id;
+
+        let mut apporg_repo = MockApplicationsOrganizationByClientIdCacheLayerRepository::new();
+        apporg_repo
+            .expect_update_application_config()
+            .withf(move |id, _, updated_at, expected| {
+                *id == client_id && *expected == CqlTimestamp(1_000) && updated_at.0 > 1_000
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(true));
+        let mut application_repo = MockApplicationDatabaseRepository::new();
+        application_repo
+            .expect_update_application_config()
+            .withf(|_, _, _, updated_at| updated_at.0 > 1_000)
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let repo =
+            ApplicationConfigRepositoryImpl::new(Arc::new(application_repo), Arc::new(apporg_repo));
+        let res = repo
+            .update_application_config(&apporg, &AppConfig::default())
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_conflict_returns_409() {
+        let apporg = build_apporg();
+        let mut apporg_repo = MockApplicationsOrganizationByClientIdCacheLayerRepository::new();
+        apporg_repo
+            .expect_update_application_config()
+            .returning(|_, _, _, _| Ok(false));
+        let mut application_repo = MockApplicationDatabaseRepository::new();
+        application_repo.expect_update_application_config().never();
+
+        let repo =
+            ApplicationConfigRepositoryImpl::new(Arc::new(application_repo), Arc::new(apporg_repo));
+        let err = repo
+            .update_application_config(&apporg, &AppConfig::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 409);
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::payload::oauth::TokenPayload;
+    use crate::application::repositories::oauth::device::MockDeviceAuthorizationRepository;
+    use crate::application::repositories::oauth::token::MockTokenRepository;
+    use crate::application::services::oauth::authorize::MockAuthorizeService;
+    use crate::application::services::oauth::client_credentials::MockClientCredentialsService;
+    use crate::application::services::oauth::device::{
+        DeviceAuthorizationService, DeviceAuthorizationServiceImpl,
+    };
+    use crate::application::services::oauth::token::{TokenService, TokenServiceImpl};
+    use crate::application::services::refresh_token::create::MockCreateRefreshTokenService;
+    use crate::application::services::refresh_token::rotate::MockRotateRefreshTokenService;
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::errors::repositories_errors::RepositoryError;
+    use crate::domain::value_objects::client_metadata::ClientMetadata;
+    use crate::domain::value_objects::device_code::{
+        DEVICE_CODE_GRANT_TYPE, format_user_code, normalize_user_code,
+    };
+    use crate::infrastructure::models::device_authorization::{
+        DeviceAuthorizationModel, DeviceAuthorizationStatus,
+    };
+    use crate::tests::fixtures::build_apporg;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_authorization(client_id: Uuid) -> DeviceAuthorizationModel {
+        DeviceAuthorizationModel {
+            device_code: "device-code".into(),
+            user_code: "BDFHJKLM".into(),
+            client_id,
+            application_id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            scope: None,
+            status: DeviceAuthorizationStatus::Pending,
+            user_id: None,
+            interval: 5,
+            expires_at: Utc::now().timestamp() + 600,
+        }
+    }
+
+    fn build_token_service(repo: MockTokenRepository) -> TokenServiceImpl {
+        TokenServiceImpl::new(
+            Arc::new(repo),
+            Arc::new(MockCreateRefreshTokenService::new()),
+            Arc::new(MockRotateRefreshTokenService::new()),
+            Arc::new(MockClientCredentialsService::new()),
+        )
+    }
+
+    fn build_poll(client_id: Uuid) -> TokenPayload {
+        TokenPayload {
+            grant_type: DEVICE_CODE_GRANT_TYPE.into(),
+            client_id,
+            code: None,
+            redirect_uri: None,
+            code_verifier: None,
+            refresh_token: None,
+            client_secret: None,
+            device_code: Some("device-code".into()),
+        }
+    }
+
+    #[test]
+    fn test_normalize_user_code() {
+        assert_eq!(format_user_code("BDFHJKLM"), "BDFH-JKLM");
+        assert_eq!(
+            normalize_user_code(" bdfh-jklm "),
+            Some("BDFHJKLM".to_string())
+        );
+        // vowels are never issued, and the length is fixed
+        assert_eq!(normalize_user_code("BDFH-JKLA"), None);
+        assert_eq!(normalize_user_code("BDFH-JKL"), None);
+    }
+
+    #[tokio::test]
+    async fn test_device_code_polling_pending_then_slow_down() {
+        let client_id = Uuid::new_v4();
+        let mut repo = MockTokenRepository::new();
+        repo.expect_find_device_authorization()
+            .with(eq("device-code"))
+            .times(2)
+            .returning(move |_| Ok(Some(build_authorization(client_id))));
+        let mut polls = 0;
+        repo.expect_record_device_poll()
+            .with(eq("device-code"), eq(5))
+            .times(2)
+            .returning(move |_, _| {
+                polls += 1;
+                Ok(polls == 1)
+            });
+        // only the interval changes; the stored request is left alone
+        repo.expect_slow_down_device_polling()
+            .withf(|a, seconds| a.device_code == "device-code" && *seconds == 5)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let service = build_token_service(repo);
+        let first = service
+            .execute(build_poll(client_id), ClientMetadata::default())
+            .await
+            .unwrap_err();
+        assert_eq!(first.message, "authorization_pending");
+        let second = service
+            .execute(build_poll(client_id), ClientMetadata::default())
+            .await
+            .unwrap_err();
+        assert_eq!(second.message, "slow_down");
+    }
+
+    fn build_device_service(
+        authenticated: bool,
+        repo: MockDeviceAuthorizationRepository,
+    ) -> DeviceAuthorizationServiceImpl {
+        let mut authorize = MockAuthorizeService::new();
+        authorize
+            .expect_authenticate()
+            .returning(move |_, _, _| match authenticated {
+                true => Ok(Uuid::new_v4()),
+                false => Err(RepositoryError::new(
+                    "invalid username or password".to_string(),
+                    401,
+                )),
+            });
+        DeviceAuthorizationServiceImpl::new(Arc::new(repo), Arc::new(authorize))
+    }
+
+    fn build_device_repo(client_id: Uuid) -> MockDeviceAuthorizationRepository {
+        let mut repo = MockDeviceAuthorizationRepository::new();
+        repo.expect_find_device_authorization_by_user_code()
+            .with(eq("BDFHJKLM"))
+            .returning(move |_| Ok(Some(build_authorization(client_id))));
+        repo.expect_find_apporg_by_client_id()
+            .returning(|client_id| {
+                Ok(Some(CleanAppOrgByClientId {
+                    client_id,
+                    ..build_apporg()
+                }))
+            });
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_deny_rejects_unauthenticated_user() {
+        let mut repo = build_device_repo(Uuid::new_v4());
+        repo.expect_decide_device_authorization().never();
+
+        let service = build_device_service(false, repo);
+        let err = service
+            .deny("BDFH-JKLM", "mallory", "wrong")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 401);
+    }
+
+    #[tokio::test]
+    async fn test_deny_as_authenticated_user() {
+        let mut repo = build_device_repo(Uuid::new_v4());
+        repo.expect_decide_device_authorization()
+            .withf(|_, d| d.status == DeviceAuthorizationStatus::Denied && d.user_id.is_none())
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let service = build_device_service(true, repo);
+        let res = service.deny("BDFH-JKLM", "alice", "secret").await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_approve_loses_to_earlier_answer() {
+        let mut repo = build_device_repo(Uuid::new_v4());
+        // a deny landed between reading the request and answering it
+        repo.expect_decide_device_authorization()
+            .withf(|_, d| d.status == DeviceAuthorizationStatus::Approved && d.user_id.is_some())
+            .times(1)
+            .returning(|_, _| Ok(false));
+
+        let service = build_device_service(true, repo);
+        let err = service
+            .approve("BDFH-JKLM", "alice", "secret")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 404);
+    }
+}
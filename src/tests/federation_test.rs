@@ -0,0 +1,397 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::response::user::CreateUserResponse;
+    use crate::application::repositories::oauth::federation::MockFederationRepository;
+    use crate::application::repositories::users::create::MockCreateUserRepository;
+    use crate::application::services::oauth::authorize::MockAuthorizeService;
+    use crate::application::services::oauth::federation::{
+        FederationService, FederationServiceImpl,
+    };
+    use crate::application::services::users::create::{
+        CreateUserServiceImpl, MockCreateUserService,
+    };
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::entities::user::{UNUSABLE_PASSWORD, User};
+    use crate::domain::value_objects::app_config::AppConfig;
+    use crate::domain::value_objects::identity_provider::{IdentityProvider, default_scopes};
+    use crate::domain::value_objects::password_policy::PasswordPolicy;
+    use crate::infrastructure::models::federation_state::FederationStateModel;
+    use crate::infrastructure::models::upstream_oidc::UpstreamIdTokenClaims;
+    use crate::infrastructure::models::user::UserModel;
+    use crate::infrastructure::repositories::oidc::upstream::{
+        UpstreamOidcRepository, UpstreamOidcRepositoryImpl,
+    };
+    use crate::tests::fixtures::build_apporg_with;
+    use actix_web::{App, HttpResponse, HttpServer, web};
+    use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+    use chrono::Utc;
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    const ISSUER: &str = "https://login.example.com";
+    const UPSTREAM_CLIENT_ID: &str = "axcelium";
+    const REDIRECT_URI: &str = "https://app.example.com/callback";
+
+    fn build_id_token(claims: serde_json::Value) -> String {
+        format!(
+            "{}.{}.signature",
+            URL_SAFE_NO_PAD.encode(br#"{"alg":"RS256"}"#),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    fn build_claims(nonce: &str, email_verified: bool) -> UpstreamIdTokenClaims {
+        serde_json::from_value(json!({
+            "iss": ISSUER,
+            "sub": "upstream-subject",
+            "aud": [UPSTREAM_CLIENT_ID],
+            "exp": Utc::now().timestamp() + 300,
+            "nonce": nonce,
+            "email": "jane@example.com",
+            "email_verified": email_verified,
+        }))
+        .unwrap()
+    }
+
+    fn build_config() -> AppConfig {
+        AppConfig {
+            redirect_uris: vec![REDIRECT_URI.into()],
+            identity_providers: vec![IdentityProvider {
+                id: "corp".into(),
+                name: "Corp SSO".into(),
+                issuer: ISSUER.into(),
+                client_id: UPSTREAM_CLIENT_ID.into(),
+                encrypted_client_secret: "nonce.ciphertext".into(),
+                scopes: default_scopes(),
+                link_by_email: true,
+            }],
+            ..AppConfig::default()
+        }
+    }
+
+    fn build_apporg() -> CleanAppOrgByClientId {
+        build_apporg_with(build_config())
+    }
+
+    fn build_state(apporg: &CleanAppOrgByClientId) -> FederationStateModel {
+        FederationStateModel {
+            state: "state".into(),
+            provider_id: "corp".into(),
+            upstream_nonce: "upstream-nonce".into(),
+            code_verifier: "verifier".into(),
+            client_id: apporg.client_id,
+            redirect_uri: REDIRECT_URI.into(),
+            scope: Some("openid".into()),
+            client_state: Some("client-state".into()),
+            code_challenge: "challenge".into(),
+            code_challenge_method: None,
+            nonce: None,
+        }
+    }
+
+    #[test]
+    fn test_id_token_claims_validation() {
+        let claims = build_claims("nonce", true);
+        let now = Utc::now().timestamp();
+        assert!(
+            claims
+                .validate(ISSUER, UPSTREAM_CLIENT_ID, "nonce", now)
+                .is_ok()
+        );
+        assert!(
+            claims
+                .validate(ISSUER, UPSTREAM_CLIENT_ID, "other", now)
+                .is_err()
+        );
+        assert!(claims.validate(ISSUER, "other", "nonce", now).is_err());
+        assert!(
+            claims
+                .validate("https://evil.example.com", UPSTREAM_CLIENT_ID, "nonce", now)
+                .is_err()
+        );
+        assert!(
+            claims
+                .validate(ISSUER, UPSTREAM_CLIENT_ID, "nonce", now + 600)
+                .is_err()
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_upstream_oidc_against_mock_server() {
+        let server = HttpServer::new(|| {
+            App::new()
+                .route(
+                    "/.well-known/openid-configuration",
+                    web::get().to(|req: actix_web::HttpRequest| async move {
+                        let base = format!("http://{}", req.connection_info().host());
+                        HttpResponse::Ok().json(json!({
+                            "issuer": base,
+                            "authorization_endpoint": format!("{base}/authorize"),
+                            "token_endpoint": format!("{base}/token"),
+                        }))
+                    }),
+                )
+                .route(
+                    "/token",
+                    web::post().to(|form: web::Form<Vec<(String, String)>>| async move {
+                        let code = form.iter().find(|(k, _)| k == "code").map(|(_, v)| v);
+                        if code.map(String::as_str) != Some("good-code") {
+                            return HttpResponse::BadRequest().finish();
+                        }
+                        HttpResponse::Ok().json(json!({
+                            "access_token": "upstream-access-token",
+                            "token_type": "Bearer",
+                            "id_token": build_id_token(json!({
+                                "iss": "mock",
+                                "sub": "upstream-subject",
+                                "aud": UPSTREAM_CLIENT_ID,
+                                "exp": 0,
+                            })),
+                        }))
+                    }),
+                )
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let issuer = format!("http://{}", server.addrs()[0]);
+        let server = server.run();
+        let handle = server.handle();
+        actix_web::rt::spawn(server);
+
+        let upstream = UpstreamOidcRepositoryImpl::new();
+        let metadata = upstream.discover(&issuer).await.unwrap();
+        assert_eq!(metadata.token_endpoint, format!("{issuer}/token"));
+        let id_token = upstream
+            .exchange_code(
+                &metadata.token_endpoint,
+                UPSTREAM_CLIENT_ID,
+                "secret",
+                "good-code",
+                "http://127.0.0.1/callback",
+                "verifier",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            upstream.decode_id_token(&id_token).unwrap().sub,
+            "upstream-subject"
+        );
+        let rejected = upstream
+            .exchange_code(
+                &metadata.token_endpoint,
+                UPSTREAM_CLIENT_ID,
+                "secret",
+                "bad-code",
+                "http://127.0.0.1/callback",
+                "verifier",
+            )
+            .await;
+        assert_eq!(rejected.unwrap_err().code, 502);
+        handle.stop(false).await;
+    }
+
+    #[tokio::test]
+    async fn test_callback_links_existing_user_by_verified_email() {
+        let apporg = build_apporg();
+        let existing = UserModel::from_entity(User::new(
+            apporg.application_id,
+            apporg.organization_id,
+            "jane".into(),
+            "hash".into(),
+            Some("jane@example.com".into()),
+        ));
+        let user_id = existing.user_id;
+
+        let mut repo = MockFederationRepository::new();
+        let saved = build_state(&apporg);
+        repo.expect_take_state()
+            .returning(move |_| Ok(Some(saved.clone())));
+        repo.expect_discover().returning(|_| {
+            Ok(serde_json::from_value(json!({
+                "issuer": ISSUER,
+                "authorization_endpoint": format!("{ISSUER}/authorize"),
+                "token_endpoint": format!("{ISSUER}/token"),
+            }))
+            .unwrap())
+        });
+        repo.expect_decrypt_client_secret()
+            .returning(|_| Ok("secret".into()));
+        repo.expect_exchange_code()
+            .returning(|_, _, _, _, _| Ok("id-token".into()));
+        repo.expect_decode_id_token()
+            .returning(|_| Ok(build_claims("upstream-nonce", true)));
+        repo.expect_find_identity().returning(|_, _, _, _| Ok(None));
+        repo.expect_find_user_id_by_email()
+            .withf(|_, _, email| email == "jane@example.com")
+            .returning(move |_, _, _| Ok(Some(user_id)));
+        repo.expect_link_identity()
+            .withf(move |identity| identity.user_id == user_id && identity.provider_id == "corp")
+            .times(1)
+            .returning(|_| Ok(true));
+        repo.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(existing.clone())));
+
+        let mut authorize_service = MockAuthorizeService::new();
+        let validated = apporg.clone();
+        authorize_service
+            .expect_validate()
+            .returning(move |_| Ok(validated.clone()));
+        authorize_service
+            .expect_issue_authorization_code()
+            .withf(move |_, request, id| {
+                *id == user_id && request.state.as_deref() == Some("client-state")
+            })
+            .returning(|_, _, _| Ok(format!("{REDIRECT_URI}?code=abc&state=client-state")));
+        let mut create_user_service = MockCreateUserService::new();
+        create_user_service.expect_execute_passwordless().never();
+
+        let service = FederationServiceImpl::new(
+            Arc::new(repo),
+            Arc::new(authorize_service),
+            Arc::new(create_user_service),
+        );
+        let location = service
+            .callback("state".into(), Some("code".into()), None)
+            .await
+            .unwrap();
+        assert!(location.contains("code=abc"));
+    }
+
+    /// Mocks for a first sign-in whose upstream email is unverified, ending
+    /// in a code issued to the user created as `created_id`.
+    fn first_sign_in_mocks(
+        apporg: &CleanAppOrgByClientId,
+        created_id: Uuid,
+    ) -> (MockFederationRepository, MockAuthorizeService) {
+        let mut created = UserModel::from_entity(User::new(
+            apporg.application_id,
+            apporg.organization_id,
+            "jane".into(),
+            "hash".into(),
+            None,
+        ));
+        created.user_id = created_id;
+
+        let mut repo = MockFederationRepository::new();
+        let saved = build_state(apporg);
+        repo.expect_take_state()
+            .returning(move |_| Ok(Some(saved.clone())));
+        repo.expect_discover().returning(|_| {
+            Ok(serde_json::from_value(json!({
+                "issuer": ISSUER,
+                "authorization_endpoint": format!("{ISSUER}/authorize"),
+                "token_endpoint": format!("{ISSUER}/token"),
+            }))
+            .unwrap())
+        });
+        repo.expect_decrypt_client_secret()
+            .returning(|_| Ok("secret".into()));
+        repo.expect_exchange_code()
+            .returning(|_, _, _, _, _| Ok("id-token".into()));
+        repo.expect_decode_id_token()
+            .returning(|_| Ok(build_claims("upstream-nonce", false)));
+        repo.expect_find_identity().returning(|_, _, _, _| Ok(None));
+        repo.expect_find_user_id_by_email().never();
+        repo.expect_link_identity().returning(|_| Ok(true));
+        repo.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(created.clone())));
+
+        let mut authorize_service = MockAuthorizeService::new();
+        let validated = apporg.clone();
+        authorize_service
+            .expect_validate()
+            .returning(move |_| Ok(validated.clone()));
+        authorize_service
+            .expect_issue_authorization_code()
+            .withf(move |_, _, id| *id == created_id)
+            .returning(|_, _, _| Ok(REDIRECT_URI.to_string()));
+        (repo, authorize_service)
+    }
+
+    #[tokio::test]
+    async fn test_callback_creates_user_without_unverified_email() {
+        let apporg = build_apporg();
+        let created_id = Uuid::new_v4();
+        let (repo, authorize_service) = first_sign_in_mocks(&apporg, created_id);
+        let mut create_user_service = MockCreateUserService::new();
+        create_user_service
+            .expect_execute_passwordless()
+            .withf(|_, payload| payload.email.is_none() && payload.username == "jane@example.com")
+            .times(1)
+            .returning(move |_, payload| {
+                Ok(CreateUserResponse {
+                    user_id: created_id.to_string(),
+                    username: payload.username,
+                    email: payload.email,
+                })
+            });
+
+        let service = FederationServiceImpl::new(
+            Arc::new(repo),
+            Arc::new(authorize_service),
+            Arc::new(create_user_service),
+        );
+        service
+            .callback("state".into(), Some("code".into()), None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_first_sign_in_skips_password_policy() {
+        let apporg = build_apporg_with(AppConfig {
+            can_allow_email_nullable: true,
+            password_policy: Some(PasswordPolicy {
+                require_symbol: true,
+                history_size: 3,
+                ..PasswordPolicy::default()
+            }),
+            ..build_config()
+        });
+        let created_id = Uuid::new_v4();
+        let (repo, authorize_service) = first_sign_in_mocks(&apporg, created_id);
+
+        let mut create_repo = MockCreateUserRepository::new();
+        create_repo
+            .expect_find_user_by_username()
+            .returning(|_, _, _| Ok(None));
+        create_repo
+            .expect_reserve_username()
+            .returning(|_, _, _, _| Ok(true));
+        create_repo.expect_is_breached_password().never();
+        create_repo.expect_hash_password().never();
+        create_repo
+            .expect_new_user()
+            .withf(|_, _, hashed| hashed == UNUSABLE_PASSWORD)
+            .times(1)
+            .returning(move |apporg, payload, hashed| {
+                let mut user = User::new(
+                    apporg.application_id,
+                    apporg.organization_id,
+                    payload.username,
+                    hashed,
+                    payload.email,
+                );
+                user.user_id = created_id;
+                user
+            });
+        create_repo
+            .expect_create_user()
+            .times(1)
+            .returning(|_| Ok(()));
+        create_repo.expect_add_password_history().never();
+        create_repo.expect_index_user().returning(|_, _| Ok(()));
+
+        let service = FederationServiceImpl::new(
+            Arc::new(repo),
+            Arc::new(authorize_service),
+            Arc::new(CreateUserServiceImpl::new(Arc::new(create_repo))),
+        );
+        let res = service
+            .callback("state".into(), Some("code".into()), None)
+            .await;
+        assert!(res.is_ok());
+    }
+}
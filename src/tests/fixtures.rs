@@ -0,0 +1,45 @@
+use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+use crate::domain::value_objects::app_config::AppConfig;
+use crate::infrastructure::models::role::SelectedRoleByAppModel;
+use chrono::Utc;
+use scylla::value::CqlTimestamp;
+use uuid::Uuid;
+
+pub fn build_apporg() -> CleanAppOrgByClientId {
+    build_apporg_with(AppConfig::default())
+}
+
+pub fn build_apporg_with(config: AppConfig) -> CleanAppOrgByClientId {
+    let now = CqlTimestamp(Utc::now().timestamp_millis());
+    CleanAppOrgByClientId {
+        client_id: Uuid::new_v4(),
+        application_id: Uuid::new_v4(),
+        organization_id: Uuid::new_v4(),
+        organization_name: "Test Org".into(),
+        organization_slug: "test-org".into(),
+        application_name: "App".into(),
+        application_description: "desc".into(),
+        application_config: config.to_string(),
+        contact_email: "admin@test.org".into(),
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+pub fn build_role(
+    role_id: Uuid,
+    permissions: &[&str],
+    parent_role_ids: &[Uuid],
+) -> SelectedRoleByAppModel {
+    let now = CqlTimestamp(Utc::now().timestamp_millis());
+    SelectedRoleByAppModel {
+        role_id,
+        name: "role".into(),
+        description: None,
+        permissions: permissions.iter().map(|p| p.to_string()).collect(),
+        parent_role_ids: parent_role_ids.iter().copied().collect(),
+        created_at: now,
+        updated_at: now,
+    }
+}
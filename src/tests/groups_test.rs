@@ -0,0 +1,260 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::payload::group::CreateGroupPayload;
+    use crate::application::repositories::groups::manage::MockManageGroupsRepository;
+    use crate::application::repositories::groups::members::MockGroupMembersRepository;
+    use crate::application::repositories::groups::roles::MockGroupRolesRepository;
+    use crate::application::repositories::roles::get_roles_by_user::MockGetRolesByUserRepository;
+    use crate::application::repositories::roles::get_user_permissions::MockGetUserPermissionsRepository;
+    use crate::application::services::groups::manage::{
+        ManageGroupsService, ManageGroupsServiceImpl,
+    };
+    use crate::application::services::groups::members::{
+        GroupMembersService, GroupMembersServiceImpl,
+    };
+    use crate::application::services::groups::roles::{GroupRolesService, GroupRolesServiceImpl};
+    use crate::application::services::roles::get_roles_by_user::{
+        GetRolesByUserService, GetRolesByUserServiceImpl,
+    };
+    use crate::application::services::roles::get_user_permissions::{
+        GetUserPermissionsService, GetUserPermissionsServiceImpl,
+    };
+    use crate::infrastructure::models::group::{
+        GroupMemberModel, GroupModel, PaginatedGroupMembersModel, UserGroupModel,
+    };
+    use crate::infrastructure::models::role::UserRoleModel;
+    use crate::tests::fixtures::{build_apporg, build_role};
+    use chrono::Utc;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_group(group_id: Uuid, role_ids: &[Uuid]) -> GroupModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        GroupModel {
+            organization_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            group_id,
+            name: "engineering".into(),
+            description: None,
+            role_ids: role_ids.iter().copied().collect(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_group_rejects_blank_name() {
+        let mut mock = MockManageGroupsRepository::new();
+        mock.expect_new_group().never();
+        mock.expect_create_group().never();
+
+        let service = ManageGroupsServiceImpl::new(Arc::new(mock));
+        let err = service
+            .create(
+                build_apporg(),
+                CreateGroupPayload {
+                    name: "  ".into(),
+                    description: None,
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_create_group_returns_its_id() {
+        let group_id = Uuid::new_v4();
+        let mut mock = MockManageGroupsRepository::new();
+        mock.expect_new_group()
+            .returning(move |_, _, _, _| build_group(group_id, &[]));
+        mock.expect_create_group()
+            .withf(move |g| g.group_id == group_id && g.role_ids.is_empty())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ManageGroupsServiceImpl::new(Arc::new(mock));
+        let res = service
+            .create(
+                build_apporg(),
+                CreateGroupPayload {
+                    name: "engineering".into(),
+                    description: Some("all engineers".into()),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.group_id, group_id);
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_group_is_not_found() {
+        let mut mock = MockManageGroupsRepository::new();
+        mock.expect_get_group().returning(|_, _, _| Ok(None));
+        mock.expect_delete_group().never();
+
+        let service = ManageGroupsServiceImpl::new(Arc::new(mock));
+        let err = service
+            .delete(build_apporg(), Uuid::new_v4())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 404);
+    }
+
+    #[tokio::test]
+    async fn test_add_member_requires_existing_user() {
+        let mut mock = MockGroupMembersRepository::new();
+        mock.expect_get_group()
+            .returning(|_, _, group_id| Ok(Some(build_group(group_id, &[]))));
+        mock.expect_user_exists().returning(|_, _, _| Ok(false));
+        mock.expect_add_member().never();
+
+        let service = GroupMembersServiceImpl::new(Arc::new(mock));
+        let err = service
+            .add(build_apporg(), Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 404);
+        assert_eq!(err.message, "user not found");
+    }
+
+    #[tokio::test]
+    async fn test_list_members_round_trips_paging_state() {
+        let user_id = Uuid::new_v4();
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        let mut mock = MockGroupMembersRepository::new();
+        mock.expect_get_group()
+            .returning(|_, _, group_id| Ok(Some(build_group(group_id, &[]))));
+        mock.expect_base64_to_bytes()
+            .withf(|state| state == "cGFnZTE=")
+            .returning(|_| Ok(b"page1".to_vec()));
+        mock.expect_get_members()
+            .withf(|_, _, _, page_size, state| {
+                *page_size == 20 && state.as_deref() == Some(b"page1".as_slice())
+            })
+            .returning(move |_, _, _, _, _| {
+                Ok(PaginatedGroupMembersModel {
+                    members: vec![GroupMemberModel {
+                        user_id,
+                        added_at: now,
+                    }],
+                    paging_state: Some(b"page2".to_vec()),
+                })
+            });
+        mock.expect_bytes_to_base64()
+            .returning(|_| "cGFnZTI=".to_string());
+
+        let service = GroupMembersServiceImpl::new(Arc::new(mock));
+        let res = service
+            .list(
+                build_apporg(),
+                Uuid::new_v4(),
+                20,
+                Some("cGFnZTE=".to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.members.len(), 1);
+        assert_eq!(res.members[0].user_id, user_id);
+        assert_eq!(res.paging_state.as_deref(), Some("cGFnZTI="));
+    }
+
+    #[tokio::test]
+    async fn test_assign_role_to_group_requires_existing_role() {
+        let mut mock = MockGroupRolesRepository::new();
+        mock.expect_get_group()
+            .returning(|_, _, group_id| Ok(Some(build_group(group_id, &[]))));
+        mock.expect_get_role().returning(|_, _, _| Ok(None));
+        mock.expect_assign_role().never();
+
+        let service = GroupRolesServiceImpl::new(Arc::new(mock));
+        let err = service
+            .assign(build_apporg(), Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 404);
+        assert_eq!(err.message, "role not found");
+    }
+
+    #[tokio::test]
+    async fn test_roles_of_user_include_group_roles() {
+        let direct = Uuid::new_v4();
+        let via_group = Uuid::new_v4();
+        let member_of = Uuid::new_v4();
+        let other_group = Uuid::new_v4();
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+
+        let mut mock = MockGetRolesByUserRepository::new();
+        mock.expect_get_roles_by_user().returning(move |_, _, _| {
+            Ok(vec![UserRoleModel {
+                role_id: direct,
+                assigned_at: now,
+                expires_at: None,
+            }])
+        });
+        mock.expect_get_groups_of_user().returning(move |_, _, _| {
+            Ok(vec![UserGroupModel {
+                group_id: member_of,
+                added_at: now,
+            }])
+        });
+        mock.expect_get_groups().returning(move |_, _| {
+            Ok(vec![
+                build_group(member_of, &[via_group]),
+                build_group(other_group, &[Uuid::new_v4()]),
+            ])
+        });
+
+        let service = GetRolesByUserServiceImpl::new(Arc::new(mock));
+        let res = service
+            .execute(build_apporg(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert_eq!(res.roles.len(), 1);
+        assert_eq!(res.group_roles.len(), 1);
+        assert_eq!(res.group_roles[0].group_id, member_of);
+        assert_eq!(res.group_roles[0].role_id, via_group);
+    }
+
+    #[tokio::test]
+    async fn test_permissions_include_roles_granted_through_groups() {
+        let role_id = Uuid::new_v4();
+        let group_id = Uuid::new_v4();
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+
+        let mut mock = MockGetUserPermissionsRepository::new();
+        mock.expect_get_cached_permissions()
+            .returning(|_, _, _| Ok(None));
+        mock.expect_get_roles_by_user()
+            .returning(|_, _, _| Ok(vec![]));
+        mock.expect_get_groups_of_user().returning(move |_, _, _| {
+            Ok(vec![UserGroupModel {
+                group_id,
+                added_at: now,
+            }])
+        });
+        mock.expect_get_groups()
+            .returning(move |_, _| Ok(vec![build_group(group_id, &[role_id])]));
+        mock.expect_get_roles()
+            .returning(move |_, _| Ok(vec![build_role(role_id, &["incidents:write"], &[])]));
+        mock.expect_cache_permissions()
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+
+        let service = GetUserPermissionsServiceImpl::new(Arc::new(mock));
+        let res = service
+            .execute(build_apporg(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert_eq!(res.permissions, vec!["incidents:write"]);
+    }
+}
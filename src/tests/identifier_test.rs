@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::value_objects::identifier::{
+        EmailNormalization, normalize_email, normalize_username,
+    };
+
+    #[test]
+    fn test_username_case_and_width_folded() {
+        assert_eq!(normalize_username(" Alice "), "alice");
+        // fullwidth letters fold to their ASCII form under NFKC
+        assert_eq!(normalize_username("ＡＬＩＣＥ"), "alice");
+    }
+
+    #[test]
+    fn test_email_case_folded_by_default() {
+        let rules = EmailNormalization::default();
+        assert_eq!(
+            normalize_email("Alice@Example.com", &rules),
+            "alice@example.com"
+        );
+        assert_eq!(
+            normalize_email("a.lice+news@example.com", &rules),
+            "a.lice+news@example.com"
+        );
+    }
+
+    #[test]
+    fn test_email_dots_and_plus_tag_ignored_when_enabled() {
+        let rules = EmailNormalization {
+            ignore_dots: true,
+            ignore_plus_tag: true,
+        };
+        assert_eq!(
+            normalize_email("A.Lice+news@Gmail.com", &rules),
+            "alice@gmail.com"
+        );
+    }
+}
@@ -1 +1,24 @@
+#[cfg(test)]
+pub mod fixtures;
 pub mod users_service_test;
+pub mod identifier_test;
+pub mod oauth_service_test;
+pub mod signing_keys_service_test;
+pub mod sessions_service_test;
+pub mod token_lifetimes_test;
+pub mod session_limit_test;
+pub mod service_accounts_test;
+pub mod device_authorization_test;
+pub mod federation_test;
+pub mod scim_test;
+pub mod user_transfer_test;
+pub mod password_rehash_test;
+pub mod password_policy_test;
+pub mod app_config_test;
+pub mod user_permissions_test;
+pub mod role_hierarchy_test;
+pub mod groups_test;
+pub mod permission_catalog_test;
+pub mod role_expiry_test;
+pub mod user_deletion_test;
+pub mod refresh_token_cache_layer_test;
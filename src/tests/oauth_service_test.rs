@@ -0,0 +1,452 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::controllers::oauth_handle::authorize_handle;
+    use crate::application::dto::payload::oauth::{
+        AuthorizeQuery, TokenLookupPayload, TokenPayload,
+    };
+    use crate::application::dto::response::refresh_token::CreateTokenResponse;
+    use crate::application::repositories::oauth::authorize::MockAuthorizeRepository;
+    use crate::application::repositories::oauth::introspect::MockIntrospectRepository;
+    use crate::application::repositories::oauth::revoke::MockRevokeTokenRepository;
+    use crate::application::repositories::oauth::token::MockTokenRepository;
+    use crate::application::services::oauth::authorize::{
+        AuthorizeService, AuthorizeServiceImpl, MockAuthorizeService,
+    };
+    use crate::application::services::oauth::client_credentials::MockClientCredentialsService;
+    use crate::application::services::oauth::introspect::{
+        IntrospectService, IntrospectServiceImpl,
+    };
+    use crate::application::services::oauth::revoke::{RevokeTokenService, RevokeTokenServiceImpl};
+    use crate::application::services::oauth::token::{TokenService, TokenServiceImpl};
+    use crate::application::services::refresh_token::create::MockCreateRefreshTokenService;
+    use crate::application::services::refresh_token::rotate::MockRotateRefreshTokenService;
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::errors::repositories_errors::RepositoryError;
+    use crate::domain::value_objects::app_config::AppConfig;
+    use crate::domain::value_objects::client_metadata::ClientMetadata;
+    use crate::infrastructure::models::authorization_code::AuthorizationCodeModel;
+    use crate::infrastructure::models::refresh_token::FoundRefreshTokenModel;
+    use crate::infrastructure::models::token_claim::{AccessTokenClaims, IdTokenClaims};
+    use crate::infrastructure::models::user::CleannedUserModel;
+    use crate::infrastructure::repositories::jwt::id_token::{
+        IdTokenJwtRepository, IdTokenJwtRepositoryImpl,
+    };
+    use crate::infrastructure::repositories::paseto::signing_key::{
+        SigningKeyPair, VerificationKey,
+    };
+    use crate::tests::fixtures::build_apporg_with;
+    use actix_web::test::{TestRequest, call_service, init_service, read_body};
+    use actix_web::{App, http::header, web};
+    use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+    use chrono::Utc;
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    const REDIRECT_URI: &str = "https://app.example.com/callback";
+    const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    const CODE_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+    fn build_apporg() -> CleanAppOrgByClientId {
+        build_apporg_with(AppConfig {
+            redirect_uris: vec![REDIRECT_URI.into()],
+            ..AppConfig::default()
+        })
+    }
+
+    fn build_grant(apporg: &CleanAppOrgByClientId) -> AuthorizationCodeModel {
+        AuthorizationCodeModel {
+            code: "code".into(),
+            client_id: apporg.client_id,
+            application_id: apporg.application_id,
+            organization_id: apporg.organization_id,
+            user_id: Uuid::new_v4(),
+            redirect_uri: REDIRECT_URI.into(),
+            scope: None,
+            code_challenge: CODE_CHALLENGE.into(),
+            code_challenge_method: "S256".into(),
+            nonce: None,
+            issued_at: Utc::now().timestamp(),
+        }
+    }
+
+    fn build_token_payload(client_id: Uuid, code_verifier: &str) -> TokenPayload {
+        TokenPayload {
+            grant_type: "authorization_code".into(),
+            client_id,
+            code: Some("code".into()),
+            redirect_uri: Some(REDIRECT_URI.into()),
+            code_verifier: Some(code_verifier.into()),
+            refresh_token: None,
+            client_secret: None,
+            device_code: None,
+        }
+    }
+
+    fn build_token_service(mock: MockTokenRepository) -> TokenServiceImpl {
+        let mut create = MockCreateRefreshTokenService::new();
+        create.expect_execute().never();
+        TokenServiceImpl::new(
+            Arc::new(mock),
+            Arc::new(create),
+            Arc::new(MockRotateRefreshTokenService::new()),
+            Arc::new(MockClientCredentialsService::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_authorize_rejects_unregistered_redirect_uri() {
+        let apporg = build_apporg();
+        let client_id = apporg.client_id;
+        let mut mock = MockAuthorizeRepository::new();
+        mock.expect_find_apporg_by_client_id()
+            .returning(move |_| Ok(Some(apporg.clone())));
+        let service = AuthorizeServiceImpl::new(Arc::new(mock));
+
+        let request = AuthorizeQuery {
+            response_type: "code".into(),
+            client_id,
+            redirect_uri: "https://evil.example.com/callback".into(),
+            scope: None,
+            state: None,
+            code_challenge: CODE_CHALLENGE.into(),
+            code_challenge_method: Some("S256".into()),
+            nonce: None,
+        };
+        let res = service.validate(&request).await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().code, 400);
+    }
+
+    fn build_authorize_query(client_id: Uuid) -> AuthorizeQuery {
+        AuthorizeQuery {
+            response_type: "code".into(),
+            client_id,
+            redirect_uri: REDIRECT_URI.into(),
+            scope: None,
+            state: None,
+            code_challenge: CODE_CHALLENGE.into(),
+            code_challenge_method: Some("S256".into()),
+            nonce: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorize_rejects_form_without_matching_csrf_token() {
+        let apporg = build_apporg();
+        let client_id = apporg.client_id;
+        for issued_to in [None, Some(Uuid::new_v4())] {
+            let apporg = apporg.clone();
+            let mut mock = MockAuthorizeRepository::new();
+            mock.expect_find_apporg_by_client_id()
+                .returning(move |_| Ok(Some(apporg.clone())));
+            mock.expect_take_csrf_token()
+                .withf(|token| token == "csrf")
+                .times(1)
+                .returning(move |_| Ok(issued_to));
+            mock.expect_find_user_id_by_username().never();
+            let service = AuthorizeServiceImpl::new(Arc::new(mock));
+
+            let res = service
+                .execute(
+                    build_authorize_query(client_id),
+                    "csrf".into(),
+                    "alice".into(),
+                    "password".into(),
+                )
+                .await;
+            assert_eq!(res.unwrap_err().code, 403);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_login_page_embeds_csrf_token_and_denies_framing() {
+        let apporg = build_apporg();
+        let client_id = apporg.client_id;
+        let mut service = MockAuthorizeService::new();
+        service
+            .expect_validate()
+            .returning(move |_| Ok(apporg.clone()));
+        service
+            .expect_issue_csrf_token()
+            .times(1)
+            .returning(|_| Ok("csrf-token".into()));
+        let service: Arc<dyn AuthorizeService> = Arc::new(service);
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::from(service))
+                .route("/oauth/authorize", web::get().to(authorize_handle)),
+        )
+        .await;
+
+        let uri = format!(
+            "/oauth/authorize?response_type=code&client_id={client_id}&redirect_uri={REDIRECT_URI}&code_challenge={CODE_CHALLENGE}"
+        );
+        let res = call_service(&app, TestRequest::get().uri(&uri).to_request()).await;
+        assert_eq!(res.headers().get(header::X_FRAME_OPTIONS).unwrap(), "DENY");
+        assert_eq!(
+            res.headers().get(header::CONTENT_SECURITY_POLICY).unwrap(),
+            "frame-ancestors 'none'"
+        );
+        let body = String::from_utf8(read_body(res).await.to_vec()).unwrap();
+        assert!(body.contains(r#"<input type="hidden" name="csrf_token" value="csrf-token">"#));
+    }
+
+    #[tokio::test]
+    async fn test_token_rejects_wrong_code_verifier() {
+        let apporg = build_apporg();
+        let grant = build_grant(&apporg);
+        let mut mock = MockTokenRepository::new();
+        mock.expect_take_authorization_code()
+            .times(1)
+            .returning(move |_| Ok(Some(grant.clone())));
+        mock.expect_code_challenge()
+            .returning(|_| "not-the-challenge".into());
+        let service = build_token_service(mock);
+
+        let payload = build_token_payload(apporg.client_id, CODE_VERIFIER);
+        let res = service.execute(payload, ClientMetadata::default()).await;
+        assert!(res.is_err());
+        assert!(res.unwrap_err().message.starts_with("invalid_grant"));
+    }
+
+    #[tokio::test]
+    async fn test_token_rejects_used_code() {
+        let apporg = build_apporg();
+        let mut mock = MockTokenRepository::new();
+        // the first redemption already removed the code from the cache
+        mock.expect_take_authorization_code()
+            .times(1)
+            .returning(|_| Ok(None));
+        let service = build_token_service(mock);
+
+        let payload = build_token_payload(apporg.client_id, CODE_VERIFIER);
+        let res = service.execute(payload, ClientMetadata::default()).await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_token_rejects_code_from_other_client() {
+        let apporg = build_apporg();
+        let grant = build_grant(&apporg);
+        let mut mock = MockTokenRepository::new();
+        mock.expect_take_authorization_code()
+            .returning(move |_| Ok(Some(grant.clone())));
+        let service = build_token_service(mock);
+
+        let payload = build_token_payload(Uuid::new_v4(), CODE_VERIFIER);
+        let res = service.execute(payload, ClientMetadata::default()).await;
+        assert!(res.is_err());
+        assert!(res.unwrap_err().message.starts_with("invalid_grant"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_grant_keeps_granted_scope() {
+        let apporg = build_apporg();
+        let client_id = apporg.client_id;
+        let user_id = Uuid::new_v4();
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        let session = FoundRefreshTokenModel {
+            token_id: Uuid::new_v4(),
+            token_version: "v1".into(),
+            application_id: apporg.application_id,
+            organization_id: apporg.organization_id,
+            user_id,
+            encrypted_token_secret: String::new(),
+            parent_version: None,
+            issued_at: now,
+            expires_at: now,
+            revoked: false,
+            last_used_at: Some(now),
+            session_started_at: Some(now),
+            device_name: None,
+            ip_address: None,
+            user_agent: None,
+            scope: Some("openid profile".into()),
+        };
+        let user = CleannedUserModel {
+            user_id,
+            organization_id: apporg.organization_id,
+            application_id: apporg.application_id,
+            username: "alice".into(),
+            email: None,
+            first_name: None,
+            last_name: None,
+            locale: None,
+            phone: None,
+            avatar_url: None,
+            metadata: None,
+            created_at: now,
+            updated_at: now,
+            is_active: true,
+            is_verified: true,
+            is_locked: false,
+            last_login: None,
+            mfa_enabled: false,
+            deactivated_at: None,
+            locked_at: None,
+        };
+
+        let mut mock = MockTokenRepository::new();
+        mock.expect_find_apporg_by_client_id()
+            .returning(move |_| Ok(Some(apporg.clone())));
+        mock.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(user.clone())));
+        mock.expect_signing_key()
+            .returning(|_, _| Ok(SigningKeyPair::generate().unwrap()));
+        mock.expect_access_token_ttl().returning(|| 900);
+        mock.expect_create_access_token()
+            .withf(|_, _, _, scope, _, _| scope.as_deref() == Some("openid profile"))
+            .times(1)
+            .returning(|_, _, _, _, _, _| Ok("access".into()));
+        mock.expect_create_id_token()
+            .times(1)
+            .returning(|_, _, _, _, _, _| Ok("id".into()));
+        let mut rotate = MockRotateRefreshTokenService::new();
+        rotate
+            .expect_find_session()
+            .returning(move |_, _| Ok(Some(session.clone())));
+        rotate.expect_execute().times(1).returning(|_, _, _| {
+            Ok(CreateTokenResponse {
+                refresh_token: "rotated".into(),
+            })
+        });
+        let service = TokenServiceImpl::new(
+            Arc::new(mock),
+            Arc::new(MockCreateRefreshTokenService::new()),
+            Arc::new(rotate),
+            Arc::new(MockClientCredentialsService::new()),
+        );
+
+        let payload = TokenPayload {
+            grant_type: "refresh_token".into(),
+            client_id,
+            code: None,
+            redirect_uri: None,
+            code_verifier: None,
+            refresh_token: Some("refresh".into()),
+            client_secret: None,
+            device_code: None,
+        };
+        let res = service
+            .execute(payload, ClientMetadata::default())
+            .await
+            .unwrap();
+        assert_eq!(res.scope.as_deref(), Some("openid profile"));
+        assert_eq!(res.id_token.as_deref(), Some("id"));
+        assert_eq!(res.refresh_token.as_deref(), Some("rotated"));
+    }
+
+    #[test]
+    fn test_id_token_verifies_with_published_jwk() {
+        let repo = IdTokenJwtRepositoryImpl::new("https://sso.example.com".into());
+        let signing_key = SigningKeyPair::generate().unwrap();
+        let token = repo
+            .sign(
+                &signing_key,
+                &IdTokenClaims {
+                    iss: repo.issuer(),
+                    sub: Uuid::new_v4().to_string(),
+                    aud: Uuid::new_v4().to_string(),
+                    iat: 0,
+                    exp: 60,
+                    nonce: Some("n-0S6_WzA2Mj".into()),
+                    email: None,
+                    email_verified: false,
+                },
+            )
+            .unwrap();
+
+        let (signing_input, signature) = token.rsplit_once('.').unwrap();
+        let jwk = repo.jwk(&VerificationKey {
+            organization_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            kid: signing_key.kid.clone(),
+            public_key: signing_key.public_key.clone(),
+        });
+        let x: [u8; 32] = URL_SAFE_NO_PAD.decode(jwk.x).unwrap().try_into().unwrap();
+        let signature: [u8; 64] = URL_SAFE_NO_PAD
+            .decode(signature)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let verifying_key = VerifyingKey::from_bytes(&x).unwrap();
+        assert!(
+            verifying_key
+                .verify(signing_input.as_bytes(), &Signature::from_bytes(&signature))
+                .is_ok()
+        );
+    }
+
+    fn build_verification_key(organization_id: Uuid, application_id: Uuid) -> VerificationKey {
+        let signing_key = SigningKeyPair::generate().unwrap();
+        VerificationKey {
+            organization_id,
+            application_id,
+            kid: signing_key.kid,
+            public_key: signing_key.public_key,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_introspect_reports_other_clients_token_inactive() {
+        let apporg = build_apporg();
+        let mut mock = MockIntrospectRepository::new();
+        mock.expect_find_verification_key()
+            .returning(|_| Ok(Some(build_verification_key(Uuid::new_v4(), Uuid::new_v4()))));
+        mock.expect_decrypt_refresh_token().never();
+        mock.expect_decrypt_access_token().never();
+        let service = IntrospectServiceImpl::new(Arc::new(mock));
+
+        let res = service
+            .execute(
+                apporg,
+                TokenLookupPayload {
+                    token: "v4.public.token".into(),
+                    token_type_hint: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(!res.active);
+        assert!(res.sub.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_rejects_access_token() {
+        let apporg = build_apporg();
+        let key = build_verification_key(apporg.organization_id, apporg.application_id);
+        let client_id = apporg.client_id.to_string();
+        let mut mock = MockRevokeTokenRepository::new();
+        mock.expect_find_verification_key()
+            .returning(move |_| Ok(Some(key.clone())));
+        mock.expect_decrypt_refresh_token()
+            .returning(|_, _| Err(RepositoryError::new("invalid token".into(), 401)));
+        mock.expect_decrypt_access_token().returning(move |_, _| {
+            Ok(AccessTokenClaims {
+                jti: Uuid::new_v4().to_string(),
+                sub: Uuid::new_v4().to_string(),
+                client_id: client_id.clone(),
+                scope: None,
+                permissions: None,
+                iat: 0,
+                exp: Utc::now().timestamp() + 60,
+            })
+        });
+        mock.expect_revoke_refresh_token().never();
+        let service = RevokeTokenServiceImpl::new(Arc::new(mock));
+
+        let res = service
+            .execute(
+                apporg,
+                TokenLookupPayload {
+                    token: "v4.public.token".into(),
+                    token_type_hint: Some("access_token".into()),
+                },
+            )
+            .await;
+        assert_eq!(res.unwrap_err().message, "unsupported_token_type");
+    }
+}
@@ -0,0 +1,277 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::payload::user::{CreateUserPayload, UpdateUserPayload};
+    use crate::application::repositories::users::create::MockCreateUserRepository;
+    use crate::application::repositories::users::update_user::MockUpdateUserRepository;
+    use crate::application::services::users::create::{CreateUserService, CreateUserServiceImpl};
+    use crate::application::services::users::update_user::{
+        UpdateUserService, UpdateUserServiceImpl,
+    };
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::entities::user::User;
+    use crate::domain::value_objects::app_config::AppConfig;
+    use crate::domain::value_objects::password_policy::{PasswordPolicy, PasswordRule};
+    use crate::infrastructure::models::user::UserModel;
+    use crate::infrastructure::repositories::security::breached_password_repository::{
+        BreachedPasswordListImpl, BreachedPasswordRepository,
+    };
+    use crate::tests::fixtures::build_apporg_with;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_apporg(policy: PasswordPolicy) -> CleanAppOrgByClientId {
+        build_apporg_with(AppConfig {
+            password_policy: Some(policy),
+            can_allow_email_nullable: true,
+            ..Default::default()
+        })
+    }
+
+    fn build_user(apporg: &CleanAppOrgByClientId) -> UserModel {
+        UserModel::from_entity(User::new(
+            apporg.application_id,
+            apporg.organization_id,
+            "jane".into(),
+            "current-hash".into(),
+            Some("jane.doe@example.com".into()),
+        ))
+    }
+
+    fn password_update(password: &str) -> UpdateUserPayload {
+        UpdateUserPayload {
+            username: None,
+            email: None,
+            password: Some(password.into()),
+            profile: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+
+    fn rules(details: Option<serde_json::Value>) -> Vec<String> {
+        details.unwrap()["violations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["rule"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_policy_reports_every_failed_rule() {
+        let policy = PasswordPolicy {
+            min_length: 12,
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: true,
+            ..PasswordPolicy::default()
+        };
+
+        let violations = policy.check("short", "jane", None);
+        let failed: Vec<PasswordRule> = violations.iter().map(|v| v.rule).collect();
+
+        assert_eq!(
+            failed,
+            vec![
+                PasswordRule::MinLength,
+                PasswordRule::Uppercase,
+                PasswordRule::Digit,
+                PasswordRule::Symbol,
+            ]
+        );
+        assert!(policy.check("Long-enough-42", "jane", None).is_empty());
+    }
+
+    #[test]
+    fn test_policy_rejects_username_and_email_local_part() {
+        let policy = PasswordPolicy::default();
+
+        let by_username = policy.check("my-JANE-password", "jane", None);
+        let by_email = policy.check("jane.doe.rocks!", "someone", Some("Jane.Doe@example.com"));
+
+        assert_eq!(by_username[0].rule, PasswordRule::ContainsIdentifier);
+        assert_eq!(by_email[0].rule, PasswordRule::ContainsIdentifier);
+        // two characters would match far too many passwords
+        assert!(policy.check("jo-horse-battery", "jo", None).is_empty());
+    }
+
+    #[test]
+    fn test_breached_list_matches_case_insensitively() {
+        let path = std::env::temp_dir().join(format!("breached-{}.txt", Uuid::new_v4()));
+        std::fs::write(&path, "password1\n\n  qwerty123  \n").unwrap();
+
+        let list = BreachedPasswordListImpl::new(path.to_str());
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(list.contains("Password1"));
+        assert!(list.contains("qwerty123"));
+        assert!(!list.contains("correct-horse"));
+        assert!(!BreachedPasswordListImpl::new(None).contains("password1"));
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_breached_password_with_details() {
+        let mut mock = MockCreateUserRepository::new();
+        mock.expect_is_breached_password().returning(|_| true);
+        mock.expect_hash_password().never();
+        mock.expect_create_user().never();
+
+        let service = CreateUserServiceImpl::new(Arc::new(mock));
+        let payload = CreateUserPayload {
+            username: "alice".into(),
+            email: None,
+            password: "password1".into(),
+            profile: Default::default(),
+            metadata: Default::default(),
+        };
+        let err = service
+            .execute(build_apporg(PasswordPolicy::default()), payload)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 400);
+        assert_eq!(rules(err.details), vec!["breached"]);
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_recent_password() {
+        let apporg = build_apporg(PasswordPolicy {
+            history_size: 3,
+            ..PasswordPolicy::default()
+        });
+        let user = build_user(&apporg);
+
+        let mut mock = MockUpdateUserRepository::new();
+        mock.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(user.clone())));
+        mock.expect_is_breached_password().returning(|_| false);
+        mock.expect_find_password_history()
+            .withf(|_, _, _, limit| *limit == 3)
+            .returning(|_, _, _, _| Ok(vec!["current-hash".into(), "older-hash".into()]));
+        mock.expect_verify_password()
+            .returning(|hashed, _| Ok(hashed == "older-hash"));
+        mock.expect_hash_password().never();
+        mock.expect_update_user().never();
+
+        let service = UpdateUserServiceImpl::new(Arc::new(mock));
+        let err = service
+            .execute(apporg, Uuid::new_v4(), password_update("battery-staple"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(rules(err.details), vec!["reused"]);
+    }
+
+    /// Hashes are `hash:<password>`, so a password matches its own entry.
+    fn history_mock(
+        current: &str,
+        history: &'static [&'static str],
+        apporg: &CleanAppOrgByClientId,
+    ) -> MockUpdateUserRepository {
+        let mut user = build_user(apporg);
+        user.hashed_password = current.into();
+        let mut mock = MockUpdateUserRepository::new();
+        mock.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(user.clone())));
+        mock.expect_is_breached_password().returning(|_| false);
+        mock.expect_find_password_history()
+            .withf(|_, _, _, limit| *limit == 2)
+            .returning(move |_, _, _, _| Ok(history.iter().map(|h| h.to_string()).collect()));
+        mock.expect_verify_password()
+            .returning(|hashed, password| Ok(hashed == format!("hash:{password}")));
+        mock.expect_hash_password()
+            .returning(|password| Ok(format!("hash:{password}")));
+        mock.expect_update_user().returning(|_| Ok(()));
+        mock.expect_add_password_history()
+            .returning(|_, _, _, _| Ok(()));
+        mock.expect_index_user().returning(|_, _| Ok(()));
+        mock
+    }
+
+    #[tokio::test]
+    async fn test_history_blocks_exactly_history_size_passwords() {
+        let apporg = build_apporg(PasswordPolicy {
+            history_size: 2,
+            ..PasswordPolicy::default()
+        });
+        // the current password is the newest history entry
+        let history = &["hash:third-password", "hash:second-password"];
+
+        let service = UpdateUserServiceImpl::new(Arc::new(history_mock(
+            "hash:third-password",
+            history,
+            &apporg,
+        )));
+        let err = service
+            .execute(
+                apporg.clone(),
+                Uuid::new_v4(),
+                password_update("second-password"),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(rules(err.details), vec!["reused"]);
+
+        let service = UpdateUserServiceImpl::new(Arc::new(history_mock(
+            "hash:third-password",
+            history,
+            &apporg,
+        )));
+        let res = service
+            .execute(apporg, Uuid::new_v4(), password_update("first-password"))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_history_counts_current_password_predating_history() {
+        let apporg = build_apporg(PasswordPolicy {
+            history_size: 2,
+            ..PasswordPolicy::default()
+        });
+
+        let service = UpdateUserServiceImpl::new(Arc::new(history_mock(
+            "hash:legacy-password",
+            &["hash:second-password", "hash:first-password"],
+            &apporg,
+        )));
+        let res = service
+            .execute(apporg, Uuid::new_v4(), password_update("first-password"))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_records_new_password_in_history() {
+        let apporg = build_apporg(PasswordPolicy {
+            history_size: 3,
+            ..PasswordPolicy::default()
+        });
+        let user = build_user(&apporg);
+        let user_id = user.user_id;
+
+        let mut mock = MockUpdateUserRepository::new();
+        mock.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(user.clone())));
+        mock.expect_is_breached_password().returning(|_| false);
+        mock.expect_find_password_history()
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock.expect_verify_password().returning(|_, _| Ok(false));
+        mock.expect_hash_password()
+            .returning(|_| Ok("new-hash".into()));
+        mock.expect_update_user()
+            .withf(|user| user.hashed_password == "new-hash")
+            .times(1)
+            .returning(|_| Ok(()));
+        mock.expect_add_password_history()
+            .withf(move |_, _, id, hashed| *id == user_id && hashed == "new-hash")
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        mock.expect_index_user().returning(|_, _| Ok(()));
+
+        let service = UpdateUserServiceImpl::new(Arc::new(mock));
+        service
+            .execute(apporg, user_id, password_update("battery-staple"))
+            .await
+            .unwrap();
+    }
+}
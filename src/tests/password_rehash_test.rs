@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::oauth::authorize::MockAuthorizeRepository;
+    use crate::application::services::oauth::authorize::{AuthorizeService, AuthorizeServiceImpl};
+    use crate::config::Argon2Config;
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::entities::user::User;
+    use crate::domain::errors::repositories_errors::RepositoryError;
+    use crate::infrastructure::models::user::UserModel;
+    use crate::infrastructure::repositories::security::argon2_repository::{
+        PasswordHasherImpl, PasswordHasherRepository,
+    };
+    use crate::tests::fixtures::build_apporg;
+    use argon2::password_hash::{PasswordHasher, SaltString, rand_core::OsRng};
+    use std::sync::Arc;
+
+    const PASSWORD: &str = "correct-horse";
+
+    fn build_hasher(memory_cost: u32) -> PasswordHasherImpl {
+        PasswordHasherImpl::new(&Argon2Config {
+            memory_cost,
+            iterations: 1,
+            parallelism: 1,
+        })
+    }
+
+    fn build_user(apporg: &CleanAppOrgByClientId, hashed_password: &str) -> UserModel {
+        UserModel::from_entity(User::new(
+            apporg.application_id,
+            apporg.organization_id,
+            "jane".into(),
+            hashed_password.into(),
+            Some("jane@example.com".into()),
+        ))
+    }
+
+    fn mock_sign_in(
+        apporg: &CleanAppOrgByClientId,
+        hashed_password: &str,
+    ) -> MockAuthorizeRepository {
+        let user = build_user(apporg, hashed_password);
+        let user_id = user.user_id;
+        let mut mock = MockAuthorizeRepository::new();
+        mock.expect_find_user_id_by_username()
+            .returning(move |_, _, _| Ok(Some(user_id)));
+        mock.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(user.clone())));
+        mock.expect_verify_password().returning(|_, _| Ok(true));
+        mock
+    }
+
+    #[test]
+    fn test_verify_bcrypt_hash() {
+        let hasher = build_hasher(256);
+        let hashed = bcrypt::hash(PASSWORD, 4).unwrap();
+
+        assert!(hasher.is_supported(&hashed));
+        assert!(hasher.verify(&hashed, PASSWORD).unwrap());
+        assert!(!hasher.verify(&hashed, "wrong-horse").unwrap());
+        assert!(hasher.needs_rehash(&hashed));
+    }
+
+    #[test]
+    fn test_verify_pbkdf2_hash() {
+        let hasher = build_hasher(256);
+        let salt = SaltString::generate(&mut OsRng);
+        let params = pbkdf2::Params {
+            rounds: 1000,
+            output_length: 32,
+        };
+        let hashed = pbkdf2::Pbkdf2
+            .hash_password_customized(PASSWORD.as_bytes(), None, None, params, &salt)
+            .unwrap()
+            .to_string();
+
+        assert!(hashed.starts_with("$pbkdf2-sha256$"));
+        assert!(hasher.verify(&hashed, PASSWORD).unwrap());
+        assert!(!hasher.verify(&hashed, "wrong-horse").unwrap());
+        assert!(hasher.needs_rehash(&hashed));
+    }
+
+    #[test]
+    fn test_needs_rehash_follows_argon2_config() {
+        let hasher = build_hasher(256);
+        let hashed = hasher.hash(PASSWORD).unwrap();
+
+        assert!(!hasher.needs_rehash(&hashed));
+        assert!(build_hasher(512).needs_rehash(&hashed));
+        // older parameters still verify until the hash is upgraded
+        assert!(build_hasher(512).verify(&hashed, PASSWORD).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_hash_is_not_supported() {
+        let hasher = build_hasher(256);
+
+        assert!(!hasher.is_supported("$md5$c2FsdA$aGFzaA"));
+        assert!(!hasher.is_supported("plain-text"));
+        assert!(hasher.verify("$md5$c2FsdA$aGFzaA", PASSWORD).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sign_in_rehashes_legacy_password() {
+        let apporg = build_apporg();
+        let legacy = "$2b$04$legacylegacylegacylegacylegacylegacylegacylegacylegac";
+        let mut mock = mock_sign_in(&apporg, legacy);
+        mock.expect_needs_rehash().returning(|_| true);
+        mock.expect_hash_password()
+            .returning(|_| Ok("$argon2id$fresh".into()));
+        mock.expect_update_hashed_password()
+            .withf(move |_, _, _, previous, hashed| {
+                previous == legacy && hashed == "$argon2id$fresh"
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(true));
+
+        let service = AuthorizeServiceImpl::new(Arc::new(mock));
+        service
+            .authenticate(&apporg, "jane", PASSWORD)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sign_in_ignores_failed_rehash() {
+        let apporg = build_apporg();
+        let mut mock = mock_sign_in(&apporg, "$2b$04$legacy");
+        mock.expect_needs_rehash().returning(|_| true);
+        mock.expect_hash_password()
+            .returning(|_| Ok("$argon2id$fresh".into()));
+        mock.expect_update_hashed_password()
+            .returning(|_, _, _, _, _| Err(RepositoryError::new("timeout".into(), 500)));
+
+        let service = AuthorizeServiceImpl::new(Arc::new(mock));
+        assert!(
+            service
+                .authenticate(&apporg, "jane", PASSWORD)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_in_keeps_current_hash() {
+        let apporg = build_apporg();
+        let mut mock = mock_sign_in(&apporg, "$argon2id$current");
+        mock.expect_needs_rehash().returning(|_| false);
+        mock.expect_hash_password().never();
+        mock.expect_update_hashed_password().never();
+
+        let service = AuthorizeServiceImpl::new(Arc::new(mock));
+        service
+            .authenticate(&apporg, "jane", PASSWORD)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,274 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::payload::permission::CreatePermissionPayload;
+    use crate::application::dto::payload::role::CreateRolePayload;
+    use crate::application::repositories::permissions::catalog::MockPermissionCatalogRepository;
+    use crate::application::repositories::roles::create_roles::MockCreateRoleRepository;
+    use crate::application::services::permissions::catalog::{
+        PermissionCatalogService, PermissionCatalogServiceImpl,
+    };
+    use crate::application::services::roles::create_roles::{
+        CreateRoleService, CreateRoleServiceImpl,
+    };
+    use crate::domain::entities::role_by_app::RoleByApp;
+    use crate::domain::value_objects::permission_catalog::{
+        PermissionCatalog, validate_permission_name,
+    };
+    use crate::infrastructure::models::permission::PermissionModel;
+    use crate::infrastructure::models::role::SelectedRoleByAppModel;
+    use crate::tests::fixtures::{build_apporg, build_role};
+    use chrono::Utc;
+    use scylla::value::CqlTimestamp;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_permission(name: &str) -> PermissionModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        PermissionModel {
+            organization_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            name: name.into(),
+            description: None,
+            category: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn build_delete_mock(
+        catalog: &'static [&'static str],
+        roles: Vec<SelectedRoleByAppModel>,
+    ) -> MockPermissionCatalogRepository {
+        let mut mock = MockPermissionCatalogRepository::new();
+        mock.expect_get_permission()
+            .returning(|_, _, name| Ok(Some(build_permission(name))));
+        mock.expect_get_permissions()
+            .returning(|_, _| Ok(catalog.iter().map(|name| build_permission(name)).collect()));
+        mock.expect_get_roles()
+            .returning(move |_, _| Ok(roles.clone()));
+        mock
+    }
+
+    fn build_catalog(names: &[&str]) -> PermissionCatalog {
+        let mut catalog = PermissionCatalog::new();
+        for name in names {
+            catalog.insert(name.to_string());
+        }
+        catalog
+    }
+
+    #[test]
+    fn test_wildcards_resolve_against_declared_prefixes() {
+        let catalog = build_catalog(&["users:read", "users:write", "billing:read"]);
+
+        assert!(catalog.resolves("users:read"));
+        assert!(catalog.resolves("users:*"));
+        assert!(catalog.resolves("*"));
+        assert!(!catalog.resolves("groups:*"));
+        assert!(!catalog.resolves("users*"));
+        assert!(!catalog.resolves("users:delete"));
+    }
+
+    #[test]
+    fn test_empty_catalog_accepts_everything() {
+        let catalog = PermissionCatalog::new();
+        let permissions = HashSet::from(["anything:goes".to_string()]);
+
+        assert!(catalog.unknown(&permissions).is_empty());
+    }
+
+    #[test]
+    fn test_validate_permission_name() {
+        assert!(validate_permission_name("users:read").is_ok());
+        assert!(validate_permission_name("reports.v2:export-csv").is_ok());
+        assert!(validate_permission_name("").is_err());
+        assert!(validate_permission_name("users:*").is_err());
+        assert!(validate_permission_name("users::read").is_err());
+        assert!(validate_permission_name(&"a".repeat(129)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_role_rejects_unknown_permission() {
+        let mut mock = MockCreateRoleRepository::new();
+        mock.expect_new_role()
+            .returning(|org, app, name, description, permissions, parents| {
+                let now = CqlTimestamp(Utc::now().timestamp_millis());
+                RoleByApp {
+                    organization_id: org,
+                    application_id: app,
+                    role_id: Uuid::new_v4(),
+                    name,
+                    description,
+                    permissions,
+                    parent_role_ids: parents,
+                    created_at: now,
+                    updated_at: now,
+                }
+            });
+        mock.expect_get_permissions()
+            .returning(|_, _| Ok(vec![build_permission("users:read")]));
+        mock.expect_create_role().never();
+
+        let service = CreateRoleServiceImpl::new(Arc::new(mock));
+        let payload = CreateRolePayload {
+            name: "editor".into(),
+            description: None,
+            permissions: HashSet::from(["users:*".to_string(), "users:write".to_string()]),
+            parent_role_ids: HashSet::new(),
+        };
+        let err = service.execute(build_apporg(), payload).await.unwrap_err();
+
+        assert_eq!(err.code, 400);
+        assert_eq!(
+            err.details.unwrap()["permissions"],
+            serde_json::json!(["users:write"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unresolved_roles_lists_removed_permissions() {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        let (stale, clean) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut mock = MockPermissionCatalogRepository::new();
+        mock.expect_get_permissions()
+            .returning(|_, _| Ok(vec![build_permission("users:read")]));
+        mock.expect_get_roles().returning(move |_, _| {
+            Ok(vec![
+                SelectedRoleByAppModel {
+                    role_id: stale,
+                    name: "stale".into(),
+                    description: None,
+                    permissions: HashSet::from([
+                        "users:read".to_string(),
+                        "billing:read".to_string(),
+                    ]),
+                    parent_role_ids: HashSet::new(),
+                    created_at: now,
+                    updated_at: now,
+                },
+                SelectedRoleByAppModel {
+                    role_id: clean,
+                    name: "clean".into(),
+                    description: None,
+                    permissions: HashSet::from(["users:*".to_string()]),
+                    parent_role_ids: HashSet::new(),
+                    created_at: now,
+                    updated_at: now,
+                },
+            ])
+        });
+
+        let service = PermissionCatalogServiceImpl::new(Arc::new(mock));
+        let res = service.unresolved_roles(build_apporg()).await.unwrap();
+
+        assert_eq!(res.roles.len(), 1);
+        assert_eq!(res.roles[0].role_id, stale);
+        assert_eq!(res.roles[0].permissions, vec!["billing:read".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_create_duplicate_permission_conflicts() {
+        let mut mock = MockPermissionCatalogRepository::new();
+        mock.expect_new_permission()
+            .returning(|_, _, name, _, _| build_permission(&name));
+        // the conditional insert reports the name as already declared
+        mock.expect_create_permission()
+            .times(1)
+            .returning(|_| Ok(false));
+
+        let service = PermissionCatalogServiceImpl::new(Arc::new(mock));
+        let payload = CreatePermissionPayload {
+            name: "users:read".into(),
+            description: None,
+            category: None,
+        };
+        let err = service.create(build_apporg(), payload).await.unwrap_err();
+
+        assert_eq!(err.code, 409);
+    }
+
+    #[tokio::test]
+    async fn test_strict_delete_of_permission_still_granted_conflicts() {
+        // `users:*` only resolves through `users:read`
+        let role = build_role(Uuid::new_v4(), &["users:*"], &[]);
+        let role_id = role.role_id;
+        let mut mock = build_delete_mock(&["users:read", "billing:read"], vec![role]);
+        mock.expect_delete_permission().never();
+
+        let service = PermissionCatalogServiceImpl::new(Arc::new(mock));
+        let err = service
+            .delete(build_apporg(), "users:read".into(), true)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 409);
+        assert_eq!(err.details.unwrap()["roles"], serde_json::json!([role_id]));
+    }
+
+    #[tokio::test]
+    async fn test_delete_last_permission_conflicts_while_roles_grant_any() {
+        // the role is already stale, but an empty catalog would hide it
+        let mut mock = build_delete_mock(
+            &["users:read"],
+            vec![build_role(Uuid::new_v4(), &["billing:read"], &[])],
+        );
+        mock.expect_delete_permission().never();
+
+        let service = PermissionCatalogServiceImpl::new(Arc::new(mock));
+        let err = service
+            .delete(build_apporg(), "users:read".into(), false)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 409);
+    }
+
+    #[tokio::test]
+    async fn test_delete_unused_permission() {
+        let mut mock = build_delete_mock(
+            &["users:read", "users:write"],
+            vec![
+                build_role(Uuid::new_v4(), &["users:*"], &[]),
+                build_role(Uuid::new_v4(), &[], &[]),
+            ],
+        );
+        mock.expect_delete_permission()
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let service = PermissionCatalogServiceImpl::new(Arc::new(mock));
+        let res = service
+            .delete(build_apporg(), "users:read".into(), false)
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_of_permission_still_granted_reports_role() {
+        let role = build_role(Uuid::new_v4(), &["users:*"], &[]);
+        let role_id = role.role_id;
+        let mut mock = build_delete_mock(&["users:read", "billing:read"], vec![role.clone()]);
+        mock.expect_delete_permission()
+            .withf(|_, _, name| name == "users:read")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let service = PermissionCatalogServiceImpl::new(Arc::new(mock));
+        let res = service
+            .delete(build_apporg(), "users:read".into(), false)
+            .await;
+        assert!(res.is_ok());
+
+        let service = PermissionCatalogServiceImpl::new(Arc::new(build_delete_mock(
+            &["billing:read"],
+            vec![role],
+        )));
+        let unresolved = service.unresolved_roles(build_apporg()).await.unwrap();
+
+        assert_eq!(unresolved.roles.len(), 1);
+        assert_eq!(unresolved.roles[0].role_id, role_id);
+        assert_eq!(unresolved.roles[0].permissions, vec!["users:*"]);
+    }
+}
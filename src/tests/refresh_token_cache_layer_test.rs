@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::models::refresh_token::{FoundRefreshTokenModel, RefreshTokenModel};
+    use crate::infrastructure::repositories::cache::refresh_token_repository::MockRefreshTokenCacheRepository;
+    use crate::infrastructure::repositories::cache_layer::refresh_token_repository::{
+        RefreshTokenCacheLayerImpl, RefreshTokenCacheLayerRepository,
+    };
+    use crate::infrastructure::repositories::database::refresh_token::MockRefreshTokenDatabaseRepository;
+    use chrono::Utc;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_model(token_version: &str, parent_version: Option<&str>) -> RefreshTokenModel {
+        let now = Utc::now().timestamp_millis();
+        RefreshTokenModel {
+            token_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            encrypted_token_secret: "secret".into(),
+            token_version: token_version.into(),
+            parent_version: parent_version.map(Into::into),
+            issued_at: CqlTimestamp(now),
+            expires_at: CqlTimestamp(now + 3_600_000),
+            revoked: false,
+            last_used_at: CqlTimestamp(now),
+            session_started_at: CqlTimestamp(now),
+            device_name: None,
+            ip_address: None,
+            user_agent: None,
+            scope: None,
+        }
+    }
+
+    fn build_found(token_version: &str) -> FoundRefreshTokenModel {
+        FoundRefreshTokenModel::from(&build_model(token_version, None))
+    }
+
+    fn build_layer(
+        cache: MockRefreshTokenCacheRepository,
+        database: MockRefreshTokenDatabaseRepository,
+    ) -> RefreshTokenCacheLayerImpl {
+        RefreshTokenCacheLayerImpl::new(Arc::new(cache), Arc::new(database))
+    }
+
+    #[tokio::test]
+    async fn test_find_cache_hit_skips_database() {
+        let token = build_found("v1");
+        let (org_id, app_id, token_id) =
+            (token.organization_id, token.application_id, token.token_id);
+
+        let mut cache = MockRefreshTokenCacheRepository::new();
+        let cached = token.clone();
+        cache
+            .expect_get_cached_refresh_token()
+            .returning(move |_| Ok(Some(cached.clone())));
+        cache
+            .expect_is_refresh_token_denied()
+            .returning(|_, _| Ok(false));
+        cache.expect_cache_refresh_token().never();
+        let mut database = MockRefreshTokenDatabaseRepository::new();
+        database.expect_find_refresh_token().never();
+
+        let layer = build_layer(cache, database);
+        let found = layer
+            .find_refresh_token(org_id, app_id, token_id, "v1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.token_id, token_id);
+        assert!(!found.revoked);
+    }
+
+    #[tokio::test]
+    async fn test_find_cache_miss_fills_cache() {
+        let token = build_found("v1");
+        let (org_id, app_id, token_id) =
+            (token.organization_id, token.application_id, token.token_id);
+
+        let mut cache = MockRefreshTokenCacheRepository::new();
+        cache
+            .expect_get_cached_refresh_token()
+            .returning(|_| Ok(None));
+        cache
+            .expect_is_refresh_token_denied()
+            .returning(|_, _| Ok(false));
+        cache
+            .expect_cache_refresh_token()
+            .withf(move |t| t.token_id == token_id && t.token_version == "v1")
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut database = MockRefreshTokenDatabaseRepository::new();
+        database
+            .expect_find_refresh_token()
+            .withf(move |_, _, id, version| *id == token_id && *version == "v1")
+            .times(1)
+            .returning(move |_, _, _, _| Ok(Some(token.clone())));
+
+        let layer = build_layer(cache, database);
+        let found = layer
+            .find_refresh_token(org_id, app_id, token_id, "v1")
+            .await
+            .unwrap();
+        assert!(found.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_find_rejects_denylisted_token_still_in_database() {
+        let token = build_found("v1");
+        let (org_id, app_id, token_id) =
+            (token.organization_id, token.application_id, token.token_id);
+
+        let mut cache = MockRefreshTokenCacheRepository::new();
+        cache
+            .expect_get_cached_refresh_token()
+            .returning(|_| Ok(None));
+        cache.expect_cache_refresh_token().returning(|_| Ok(()));
+        cache
+            .expect_is_refresh_token_denied()
+            .withf(move |id, version| *id == token_id && version == "v1")
+            .returning(|_, _| Ok(true));
+        let mut database = MockRefreshTokenDatabaseRepository::new();
+        database
+            .expect_find_refresh_token()
+            .returning(move |_, _, _, _| Ok(Some(token.clone())));
+
+        let layer = build_layer(cache, database);
+        let found = layer
+            .find_refresh_token(org_id, app_id, token_id, "v1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(found.revoked);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_denies_and_invalidates() {
+        let (org_id, app_id, token_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let mut cache = MockRefreshTokenCacheRepository::new();
+        cache
+            .expect_deny_refresh_token()
+            .withf(move |id| *id == token_id)
+            .times(1)
+            .returning(|_| Ok(()));
+        cache
+            .expect_invalidate_refresh_token()
+            .withf(move |id| *id == token_id)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut database = MockRefreshTokenDatabaseRepository::new();
+        database
+            .expect_revoke_refresh_token()
+            .withf(move |o, a, id| (*o, *a, *id) == (org_id, app_id, token_id))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let layer = build_layer(cache, database);
+        let res = layer.revoke_refresh_token(org_id, app_id, token_id).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rotate_denies_parent_version_and_replaces_cached_entry() {
+        let rotated = build_model("v2", Some("v1"));
+        let token_id = rotated.token_id;
+
+        let mut cache = MockRefreshTokenCacheRepository::new();
+        cache
+            .expect_deny_refresh_token_version()
+            .withf(move |id, version| *id == token_id && version == "v1")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        cache.expect_deny_refresh_token().never();
+        cache
+            .expect_cache_refresh_token()
+            .withf(move |t| t.token_id == token_id && t.token_version == "v2")
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut database = MockRefreshTokenDatabaseRepository::new();
+        database
+            .expect_update_refresh_token()
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let layer = build_layer(cache, database);
+        let applied = layer.update_refresh_token(&rotated, 3600).await.unwrap();
+        assert!(applied);
+    }
+
+    #[tokio::test]
+    async fn test_rotate_conflict_invalidates_cached_entry() {
+        let rotated = build_model("v2", Some("v1"));
+        let token_id = rotated.token_id;
+
+        let mut cache = MockRefreshTokenCacheRepository::new();
+        cache
+            .expect_invalidate_refresh_token()
+            .withf(move |id| *id == token_id)
+            .times(1)
+            .returning(|_| Ok(()));
+        cache.expect_cache_refresh_token().never();
+        cache.expect_deny_refresh_token_version().never();
+        let mut database = MockRefreshTokenDatabaseRepository::new();
+        database
+            .expect_update_refresh_token()
+            .returning(|_, _| Ok(false));
+
+        let layer = build_layer(cache, database);
+        let applied = layer.update_refresh_token(&rotated, 3600).await.unwrap();
+        assert!(!applied);
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::roles::assign::MockAssignRepository;
+    use crate::application::repositories::roles::expire_assignments::MockExpireRoleAssignmentsRepository;
+    use crate::application::repositories::roles::expire_assignments::{
+        ExpireRoleAssignmentsRepository, ExpireRoleAssignmentsRepositoryImpl,
+    };
+    use crate::application::services::roles::assign::{AssignService, AssignServiceImpl};
+    use crate::application::services::roles::expire_assignments::{
+        ExpireRoleAssignmentsService, ExpireRoleAssignmentsServiceImpl,
+    };
+    use crate::infrastructure::models::role::PendingRoleExpiryModel;
+    use crate::infrastructure::repositories::cache::user_permissions_repository::MockUserPermissionsCacheRepository;
+    use crate::infrastructure::repositories::database::roles::MockRoleDatabaseRepository;
+    use crate::infrastructure::repositories::queue::producer_roles_repository::MockRoleProducerRepository;
+    use crate::tests::fixtures::build_apporg;
+    use chrono::Utc;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_expiry(user_id: Uuid, expires_at: i64) -> PendingRoleExpiryModel {
+        PendingRoleExpiryModel {
+            organization_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            user_id,
+            role_id: Uuid::new_v4(),
+            expires_at: CqlTimestamp(expires_at),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assign_rejects_past_expiry() {
+        let mut mock = MockAssignRepository::new();
+        mock.expect_assign().never();
+
+        let service = AssignServiceImpl::new(Arc::new(mock));
+        let past = Utc::now().timestamp_millis() - 1000;
+        let err = service
+            .execute(build_apporg(), Uuid::new_v4(), Uuid::new_v4(), Some(past))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_assign_passes_expiry_to_repository() {
+        let expires_at = Utc::now().timestamp_millis() + 3_600_000;
+        let mut mock = MockAssignRepository::new();
+        mock.expect_assign()
+            .withf(move |_, _, _, _, e| *e == Some(expires_at))
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+
+        let service = AssignServiceImpl::new(Arc::new(mock));
+        service
+            .execute(
+                build_apporg(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Some(expires_at),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_expiry_reports_only_lapsed_assignments() {
+        let now = Utc::now().timestamp_millis();
+        let today = PendingRoleExpiryModel::day(now);
+        let (lapsed, renewed, removed, upcoming) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+
+        let mut mock = MockExpireRoleAssignmentsRepository::new();
+        mock.expect_expiry_day()
+            .returning(PendingRoleExpiryModel::day);
+        let pending_day = today.clone();
+        mock.expect_get_pending_expiries().returning(move |day| {
+            if day != pending_day {
+                return Ok(vec![]);
+            }
+            Ok(vec![
+                build_expiry(lapsed, now - 1000),
+                build_expiry(renewed, now - 1000),
+                build_expiry(removed, now - 1000),
+                build_expiry(upcoming, now + 3_600_000),
+            ])
+        });
+        mock.expect_remove_assignment()
+            .withf(move |p| p.user_id != upcoming)
+            .times(3)
+            .returning(move |p| Ok(p.user_id == lapsed));
+        mock.expect_lapse()
+            .withf(move |p| p.user_id == lapsed)
+            .times(1)
+            .returning(|_| Ok(()));
+        mock.expect_clear_pending_expiry()
+            .withf(move |day, p| *day == today && p.user_id != upcoming)
+            .times(3)
+            .returning(|_, _| Ok(()));
+
+        let service = ExpireRoleAssignmentsServiceImpl::new(Arc::new(mock));
+
+        assert_eq!(service.execute().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_remove_assignment_is_conditional_on_expiry() {
+        let expiry = build_expiry(Uuid::new_v4(), Utc::now().timestamp_millis() - 1000);
+        let expected = expiry.clone();
+
+        let mut database = MockRoleDatabaseRepository::new();
+        database
+            .expect_lapse_assignment()
+            .withf(move |o, a, u, r, expires_at| {
+                (*o, *a, *u, *r)
+                    == (
+                        expected.organization_id,
+                        expected.application_id,
+                        expected.user_id,
+                        expected.role_id,
+                    )
+                    && *expires_at == expected.expires_at
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(false));
+
+        let repo = ExpireRoleAssignmentsRepositoryImpl::new(
+            Arc::new(database),
+            Arc::new(MockUserPermissionsCacheRepository::new()),
+            Arc::new(MockRoleProducerRepository::new()),
+        );
+
+        assert!(!repo.remove_assignment(&expiry).await.unwrap());
+    }
+}
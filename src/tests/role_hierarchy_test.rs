@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::payload::role::{CreateRolePayload, UpdateRolePayload};
+    use crate::application::repositories::roles::create_roles::MockCreateRoleRepository;
+    use crate::application::repositories::roles::update_role::MockUpdateRoleRepository;
+    use crate::application::services::roles::create_roles::{
+        CreateRoleService, CreateRoleServiceImpl,
+    };
+    use crate::application::services::roles::update_role::{
+        UpdateRoleService, UpdateRoleServiceImpl,
+    };
+    use crate::domain::entities::role_by_app::RoleByApp;
+    use crate::domain::value_objects::role_hierarchy::RoleHierarchy;
+    use crate::infrastructure::models::role::{SelectedRoleByIdModel, UpdateRoleModel};
+    use crate::tests::fixtures::{build_apporg, build_role};
+    use chrono::Utc;
+    use scylla::value::CqlTimestamp;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    /// viewer <- editor <- admin
+    fn chain() -> (Uuid, Uuid, Uuid, RoleHierarchy) {
+        let (viewer, editor, admin) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut hierarchy = RoleHierarchy::new();
+        for role in [
+            build_role(viewer, &["posts:read"], &[]),
+            build_role(editor, &["posts:write"], &[viewer]),
+            build_role(admin, &["posts:delete"], &[editor]),
+        ] {
+            hierarchy.insert(role.role_id, role.parent_role_ids, role.permissions);
+        }
+        (viewer, editor, admin, hierarchy)
+    }
+
+    #[test]
+    fn test_permissions_are_inherited_transitively() {
+        let (viewer, editor, admin, hierarchy) = chain();
+
+        assert_eq!(hierarchy.effective_permissions([admin]).len(), 3);
+        assert_eq!(hierarchy.effective_permissions([viewer]).len(), 1);
+        assert_eq!(
+            hierarchy.descendants(viewer),
+            HashSet::from([viewer, editor, admin])
+        );
+    }
+
+    #[test]
+    fn test_validate_parents_detects_cycles() {
+        let (viewer, editor, admin, hierarchy) = chain();
+
+        assert!(
+            hierarchy
+                .validate_parents(viewer, &HashSet::from([admin]))
+                .is_err()
+        );
+        assert!(
+            hierarchy
+                .validate_parents(editor, &HashSet::from([editor]))
+                .is_err()
+        );
+        assert!(
+            hierarchy
+                .validate_parents(admin, &HashSet::from([viewer]))
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_unknown_parent() {
+        let apporg = build_apporg();
+        let mut mock = MockCreateRoleRepository::new();
+        mock.expect_new_role()
+            .returning(|org, app, name, description, permissions, parents| {
+                let now = CqlTimestamp(Utc::now().timestamp_millis());
+                RoleByApp {
+                    organization_id: org,
+                    application_id: app,
+                    role_id: Uuid::new_v4(),
+                    name,
+                    description,
+                    permissions,
+                    parent_role_ids: parents,
+                    created_at: now,
+                    updated_at: now,
+                }
+            });
+        mock.expect_get_roles().returning(|_, _| Ok(vec![]));
+        mock.expect_create_role().never();
+
+        let service = CreateRoleServiceImpl::new(Arc::new(mock));
+        let payload = CreateRolePayload {
+            name: "editor".into(),
+            description: None,
+            permissions: HashSet::new(),
+            parent_role_ids: HashSet::from([Uuid::new_v4()]),
+        };
+        let err = service.execute(apporg, payload).await.unwrap_err();
+
+        assert_eq!(err.code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_cycle() {
+        let (viewer, editor, admin, _) = chain();
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+
+        let mut mock = MockUpdateRoleRepository::new();
+        mock.expect_get_role().returning(move |_, _, _| {
+            Ok(Some(SelectedRoleByIdModel {
+                name: "viewer".into(),
+                description: None,
+                permissions: HashSet::new(),
+                parent_role_ids: HashSet::new(),
+                created_at: now,
+                updated_at: now,
+            }))
+        });
+        mock.expect_new_model()
+            .returning(
+                |org, app, role_id, name, description, permissions| UpdateRoleModel {
+                    organization_id: org,
+                    application_id: app,
+                    role_id,
+                    name,
+                    description,
+                    permissions,
+                    parent_role_ids: HashSet::new(),
+                },
+            );
+        mock.expect_get_roles().returning(move |_, _| {
+            Ok(vec![
+                build_role(viewer, &["posts:read"], &[]),
+                build_role(editor, &["posts:write"], &[viewer]),
+                build_role(admin, &["posts:delete"], &[editor]),
+            ])
+        });
+        mock.expect_update_role().never();
+
+        let service = UpdateRoleServiceImpl::new(Arc::new(mock));
+        let payload = UpdateRolePayload {
+            name: None,
+            description: None,
+            permissions: None,
+            parent_role_ids: Some(HashSet::from([admin])),
+        };
+        let err = service
+            .execute(build_apporg(), viewer, payload)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 400);
+        assert!(err.message.contains("cycle"));
+    }
+}
@@ -0,0 +1,326 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::payload::scim::{
+        ScimGroupPayload, ScimListQuery, ScimMemberPayload, ScimNamePayload, ScimPatchPayload,
+        ScimUserPayload,
+    };
+    use crate::application::dto::response::refresh_token::SimpleResponse;
+    use crate::application::dto::response::user::UpdateUsersResponse;
+    use crate::application::repositories::scim::groups::MockScimGroupsRepository;
+    use crate::application::repositories::scim::users::MockScimUsersRepository;
+    use crate::application::services::roles::assign::MockAssignService;
+    use crate::application::services::scim::groups::{ScimGroupsService, ScimGroupsServiceImpl};
+    use crate::application::services::scim::users::{ScimUsersService, ScimUsersServiceImpl};
+    use crate::application::services::users::create::MockCreateUserService;
+    use crate::application::services::users::delete::MockDeleteUserService;
+    use crate::application::services::users::update_user::MockUpdateUserService;
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::entities::user::User;
+    use crate::domain::errors::scim_errors::ScimError;
+    use crate::domain::value_objects::scim::{EqFilter, PatchPath};
+    use crate::infrastructure::models::role::{RoleUserModel, SelectedRoleByAppModel};
+    use crate::infrastructure::models::user::{CleannedUserModel, UserModel};
+    use crate::tests::fixtures::{build_apporg, build_role};
+    use actix_web::ResponseError;
+    use chrono::Utc;
+    use scylla::value::CqlTimestamp;
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_user(apporg: &CleanAppOrgByClientId, user_id: Uuid) -> CleannedUserModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        CleannedUserModel {
+            user_id,
+            organization_id: apporg.organization_id,
+            application_id: apporg.application_id,
+            username: "jane".into(),
+            email: Some("jane@example.com".into()),
+            first_name: Some("Jane".into()),
+            last_name: None,
+            locale: None,
+            phone: None,
+            avatar_url: None,
+            metadata: None,
+            created_at: now,
+            updated_at: now,
+            is_active: true,
+            is_verified: true,
+            is_locked: false,
+            last_login: None,
+            mfa_enabled: false,
+            deactivated_at: None,
+            locked_at: None,
+        }
+    }
+
+    fn build_raw_user(apporg: &CleanAppOrgByClientId, user_id: Uuid) -> UserModel {
+        let mut user = UserModel::from_entity(User::new(
+            apporg.application_id,
+            apporg.organization_id,
+            "jane".into(),
+            "hash".into(),
+            Some("jane@example.com".into()),
+        ));
+        user.user_id = user_id;
+        user
+    }
+
+    fn users_service(
+        repo: MockScimUsersRepository,
+        update_user_service: MockUpdateUserService,
+    ) -> ScimUsersServiceImpl {
+        ScimUsersServiceImpl::new(
+            Arc::new(repo),
+            Arc::new(MockCreateUserService::new()),
+            Arc::new(update_user_service),
+            Arc::new(MockDeleteUserService::new()),
+        )
+    }
+
+    fn patch(operations: serde_json::Value) -> ScimPatchPayload {
+        serde_json::from_value(json!({
+            "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+            "Operations": operations,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_eq_filter_parses_json_string_value() {
+        let filter = EqFilter::parse(r#"userName eq "jane@example.com""#).unwrap();
+        assert!(filter.is_on("username"));
+        assert_eq!(filter.value, "jane@example.com");
+
+        assert!(EqFilter::parse(r#"userName co "jane""#).is_none());
+        assert!(EqFilter::parse("userName eq jane").is_none());
+    }
+
+    #[test]
+    fn test_patch_path_parses_value_filter_and_sub_attribute() {
+        let path = PatchPath::parse(r#"emails[type eq "work"].value"#).unwrap();
+        assert!(path.is("emails"));
+        assert_eq!(path.filter.as_ref().unwrap().value, "work");
+        assert!(path.sub_is("value"));
+
+        let path = PatchPath::parse("name.givenName").unwrap();
+        assert!(path.is("name") && path.sub_is("givenName"));
+
+        assert!(PatchPath::parse("emails[type eq \"work\"").is_none());
+        assert!(PatchPath::parse("name.").is_none());
+    }
+
+    #[test]
+    fn test_uniqueness_error_maps_to_conflict() {
+        let err = ScimError::from(crate::domain::errors::scim_errors::scim_type_error(
+            "uniqueness",
+            "displayName already used",
+        ));
+        assert_eq!(err.status_code().as_u16(), 409);
+    }
+
+    #[tokio::test]
+    async fn test_list_users_filters_by_user_name() {
+        let apporg = build_apporg();
+        let user_id = Uuid::new_v4();
+        let user = build_user(&apporg, user_id);
+
+        let mut repo = MockScimUsersRepository::new();
+        repo.expect_find_user_id_by_username()
+            .withf(|_, _, username| username == "jane")
+            .returning(move |_, _, _| Ok(Some(user_id)));
+        repo.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(user.clone())));
+        repo.expect_find_users_page().never();
+
+        let service = users_service(repo, MockUpdateUserService::new());
+        let query = ScimListQuery {
+            filter: Some(r#"userName eq "jane""#.into()),
+            start_index: None,
+            count: None,
+            excluded_attributes: None,
+        };
+        let res = service.list(apporg, query).await.unwrap();
+
+        assert_eq!(res.total_results, 1);
+        assert_eq!(res.resources[0].id, user_id);
+        assert!(res.resources[0].active);
+    }
+
+    #[tokio::test]
+    async fn test_list_users_rejects_unsupported_filter() {
+        let service = users_service(MockScimUsersRepository::new(), MockUpdateUserService::new());
+        let query = ScimListQuery {
+            filter: Some(r#"title eq "boss""#.into()),
+            start_index: None,
+            count: None,
+            excluded_attributes: None,
+        };
+        let err = service.list(build_apporg(), query).await.unwrap_err();
+        assert!(err.message.starts_with("invalidFilter"));
+    }
+
+    #[tokio::test]
+    async fn test_patch_active_false_locks_user() {
+        let apporg = build_apporg();
+        let user_id = Uuid::new_v4();
+        let user = build_user(&apporg, user_id);
+        let raw = build_raw_user(&apporg, user_id);
+        let mut locked = user.clone();
+        locked.is_locked = true;
+
+        let mut repo = MockScimUsersRepository::new();
+        let mut calls = 0;
+        repo.expect_find_user().returning(move |_, _, _| {
+            calls += 1;
+            Ok(Some(if calls == 1 {
+                user.clone()
+            } else {
+                locked.clone()
+            }))
+        });
+        repo.expect_find_raw_user()
+            .returning(move |_, _, _| Ok(Some(raw.clone())));
+        repo.expect_update_user()
+            .withf(|user| user.is_locked && user.locked_at.is_some())
+            .times(1)
+            .returning(|_| Ok(()));
+        repo.expect_index_user().returning(|_, _| Ok(()));
+
+        let mut update_user_service = MockUpdateUserService::new();
+        update_user_service.expect_execute().never();
+
+        let service = users_service(repo, update_user_service);
+        let res = service
+            .patch(
+                apporg,
+                user_id,
+                patch(json!([{ "op": "Replace", "value": { "active": "False" } }])),
+            )
+            .await
+            .unwrap();
+
+        assert!(!res.active);
+    }
+
+    #[tokio::test]
+    async fn test_replace_user_only_sends_changed_identifiers() {
+        let apporg = build_apporg();
+        let user_id = Uuid::new_v4();
+        let user = build_user(&apporg, user_id);
+
+        let mut repo = MockScimUsersRepository::new();
+        repo.expect_find_user()
+            .returning(move |_, _, _| Ok(Some(user.clone())));
+        repo.expect_update_user().never();
+
+        let mut update_user_service = MockUpdateUserService::new();
+        update_user_service
+            .expect_execute()
+            .withf(|_, _, update| {
+                update.username.is_none()
+                    && update.email.as_deref() == Some("jane@corp.example.com")
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(UpdateUsersResponse {
+                    massage: "ok".into(),
+                })
+            });
+
+        let service = users_service(repo, update_user_service);
+        let payload = ScimUserPayload {
+            user_name: "jane".into(),
+            password: None,
+            name: ScimNamePayload {
+                given_name: Some("Jane".into()),
+                family_name: None,
+            },
+            emails: serde_json::from_value(
+                json!([{ "value": "jane@corp.example.com", "primary": true }]),
+            )
+            .unwrap(),
+            phone_numbers: vec![],
+            locale: None,
+            active: Some(true),
+        };
+        service.replace(apporg, user_id, payload).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_patch_group_adds_and_removes_members() {
+        let apporg = build_apporg();
+        let group_id = Uuid::new_v4();
+        let (added, removed) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut repo = MockScimGroupsRepository::new();
+        repo.expect_get_role().returning(move |_, _, _| {
+            Ok(Some(SelectedRoleByAppModel {
+                name: "engineering".into(),
+                ..build_role(group_id, &[], &[])
+            }))
+        });
+        repo.expect_user_exists().returning(|_, _, _| Ok(true));
+        repo.expect_remove_member()
+            .withf(move |_, _, role_id, user_id| *role_id == group_id && *user_id == removed)
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        repo.expect_get_members().returning(move |_, _, _| {
+            Ok(vec![RoleUserModel {
+                user_id: added,
+                assigned_at: CqlTimestamp(Utc::now().timestamp_millis()),
+                expires_at: None,
+            }])
+        });
+
+        let mut assign_service = MockAssignService::new();
+        assign_service
+            .expect_execute()
+            .withf(move |_, role_id, user_id, expires_at| {
+                *role_id == group_id && *user_id == added && expires_at.is_none()
+            })
+            .times(1)
+            .returning(|_, _, _, _| {
+                Ok(SimpleResponse {
+                    message: "ok".into(),
+                })
+            });
+
+        let service = ScimGroupsServiceImpl::new(Arc::new(repo), Arc::new(assign_service));
+        let res = service
+            .patch(
+                apporg,
+                group_id,
+                patch(json!([
+                    { "op": "add", "path": "members", "value": [{ "value": added }] },
+                    { "op": "remove", "path": format!("members[value eq \"{removed}\"]") },
+                ])),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.members.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_group_rejects_taken_display_name() {
+        let mut repo = MockScimGroupsRepository::new();
+        repo.expect_get_roles().returning(|_, _| {
+            Ok(vec![SelectedRoleByAppModel {
+                name: "engineering".into(),
+                ..build_role(Uuid::new_v4(), &[], &[])
+            }])
+        });
+        repo.expect_create_role().never();
+
+        let service =
+            ScimGroupsServiceImpl::new(Arc::new(repo), Arc::new(MockAssignService::new()));
+        let payload = ScimGroupPayload {
+            display_name: "engineering".into(),
+            members: Vec::<ScimMemberPayload>::new(),
+        };
+        let err = service.create(build_apporg(), payload).await.unwrap_err();
+
+        assert!(err.message.starts_with("uniqueness"));
+    }
+}
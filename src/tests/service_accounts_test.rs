@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::oauth::client_credentials::MockClientCredentialsRepository;
+    use crate::application::services::oauth::client_credentials::{
+        ClientCredentialsService, ClientCredentialsServiceImpl,
+    };
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::entities::service_account::ServiceAccount;
+    use crate::infrastructure::models::service_account::ServiceAccountModel;
+    use crate::infrastructure::repositories::paseto::signing_key::SigningKeyPair;
+    use crate::tests::fixtures::build_apporg;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    const CLIENT_KEY: &str = "bm9uY2U=";
+    const SECRET: &str = "c2VjcmV0";
+
+    fn build_account(apporg: &CleanAppOrgByClientId) -> ServiceAccountModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        ServiceAccountModel {
+            organization_id: apporg.organization_id,
+            application_id: apporg.application_id,
+            service_account_id: Uuid::new_v4(),
+            application_client_id: apporg.client_id,
+            client_id: Uuid::new_v4(),
+            name: "nightly-export".into(),
+            description: None,
+            encrypted_client_secret: "encrypted".into(),
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials_rejects_wrong_secret() {
+        let apporg = build_apporg();
+        let account = build_account(&apporg);
+        let client_id = account.client_id;
+        let mut mock = MockClientCredentialsRepository::new();
+        mock.expect_find_service_account_by_client_id()
+            .returning(move |_| Ok(Some(account.clone())));
+        mock.expect_decrypt_client_secret()
+            .returning(|_, _| Ok(SECRET.to_string()));
+        mock.expect_signing_key().never();
+        mock.expect_create_access_token().never();
+        let service = ClientCredentialsServiceImpl::new(Arc::new(mock));
+
+        let res = service
+            .execute(
+                client_id,
+                ServiceAccount::client_secret(CLIENT_KEY, "bm9wZQ=="),
+            )
+            .await;
+        assert_eq!(res.unwrap_err().code, 401);
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials_issues_token_with_role_permissions() {
+        let apporg = build_apporg();
+        let account = build_account(&apporg);
+        let client_id = account.client_id;
+        let service_account_id = account.service_account_id;
+        let app_client_id = apporg.client_id;
+        let mut mock = MockClientCredentialsRepository::new();
+        mock.expect_find_service_account_by_client_id()
+            .with(eq(client_id))
+            .returning(move |_| Ok(Some(account.clone())));
+        mock.expect_decrypt_client_secret()
+            .with(eq(CLIENT_KEY), eq("encrypted"))
+            .returning(|_, _| Ok(SECRET.to_string()));
+        mock.expect_find_apporg_by_client_id()
+            .with(eq(app_client_id))
+            .returning(move |_| Ok(Some(apporg.clone())));
+        mock.expect_find_permissions()
+            .returning(|_, _, _| Ok(vec!["reports:read".to_string()]));
+        mock.expect_signing_key()
+            .returning(|_, _| Ok(SigningKeyPair::generate().unwrap()));
+        mock.expect_create_access_token()
+            .withf(move |_, sub, client, permissions, _, _| {
+                *sub == service_account_id
+                    && *client == app_client_id
+                    && permissions == &vec!["reports:read".to_string()]
+            })
+            .returning(|_, _, _, _, _, _| Ok("access".to_string()));
+        let service = ClientCredentialsServiceImpl::new(Arc::new(mock));
+
+        let res = service
+            .execute(client_id, ServiceAccount::client_secret(CLIENT_KEY, SECRET))
+            .await
+            .unwrap();
+        assert_eq!(res.access_token, "access");
+        assert_eq!(res.expires_in, 600);
+        assert!(res.refresh_token.is_none());
+    }
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::refresh_tokens::create::MockCreateRefreshTokenRepository;
+    use crate::application::services::refresh_token::create::{
+        CreateRefreshTokenService, CreateRefreshTokenServiceImpl,
+    };
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::errors::repositories_errors::RepositoryError;
+    use crate::domain::value_objects::app_config::AppConfig;
+    use crate::domain::value_objects::client_metadata::ClientMetadata;
+    use crate::domain::value_objects::session_limit::{SessionLimit, SessionLimitAction};
+    use crate::infrastructure::models::refresh_token::{
+        FoundRefreshTokenModelByUser, PaginatedRefreshTokensByUserModel,
+    };
+    use crate::tests::fixtures::build_apporg_with;
+    use mockall::Sequence;
+    use mockall::predicate::eq;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_limited_apporg(
+        max_sessions: u32,
+        on_limit: SessionLimitAction,
+    ) -> CleanAppOrgByClientId {
+        build_apporg_with(AppConfig {
+            session_limit: SessionLimit {
+                max_sessions,
+                on_limit,
+            },
+            ..Default::default()
+        })
+    }
+
+    fn build_token(
+        apporg: &CleanAppOrgByClientId,
+        last_used_at: Option<i64>,
+        revoked: bool,
+    ) -> FoundRefreshTokenModelByUser {
+        let far_future = time::OffsetDateTime::now_utc().unix_timestamp() * 1000 + 3_600_000;
+        FoundRefreshTokenModelByUser {
+            application_id: apporg.application_id,
+            organization_id: apporg.organization_id,
+            token_id: Uuid::new_v4(),
+            encrypted_token_secret: String::new(),
+            parent_version: None,
+            issued_at: CqlTimestamp(10),
+            expires_at: CqlTimestamp(far_future),
+            revoked,
+            last_used_at: last_used_at.map(CqlTimestamp),
+            device_name: None,
+            ip_address: None,
+            user_agent: None,
+        }
+    }
+
+    /// Serves `first` on the opening page and `second` on the follow-up one.
+    fn expect_two_pages(
+        mock: &mut MockCreateRefreshTokenRepository,
+        first: Vec<FoundRefreshTokenModelByUser>,
+        second: Vec<FoundRefreshTokenModelByUser>,
+    ) {
+        mock.expect_find_refresh_tokens_by_user()
+            .withf(|_, _, _, _, paging| paging.is_none())
+            .times(1)
+            .returning(move |_, _, _, _, _| {
+                Ok(PaginatedRefreshTokensByUserModel {
+                    refresh_tokens: first.clone(),
+                    paging_state: Some(vec![1]),
+                })
+            });
+        mock.expect_find_refresh_tokens_by_user()
+            .withf(|_, _, _, _, paging| paging.is_some())
+            .times(1)
+            .returning(move |_, _, _, _, _| {
+                Ok(PaginatedRefreshTokensByUserModel {
+                    refresh_tokens: second.clone(),
+                    paging_state: None,
+                })
+            });
+    }
+
+    #[test]
+    fn test_session_limit_is_off_unless_configured() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{"is_must_name_unique":false,"can_allow_email_nullable":false}"#,
+        )
+        .unwrap();
+        assert!(!config.session_limit.is_enabled());
+        assert_eq!(config.session_limit.excess(1_000), 0);
+    }
+
+    #[test]
+    fn test_excess_leaves_room_for_one_new_session() {
+        let limit: SessionLimit =
+            serde_json::from_str(r#"{"max_sessions":3,"on_limit":"revoke_least_recently_used"}"#)
+                .unwrap();
+        assert_eq!(limit.on_limit, SessionLimitAction::RevokeLeastRecentlyUsed);
+        assert_eq!(limit.excess(0), 0);
+        assert_eq!(limit.excess(2), 0);
+        assert_eq!(limit.excess(3), 1);
+        assert_eq!(limit.excess(5), 3);
+    }
+
+    #[tokio::test]
+    async fn test_reject_refuses_new_session_without_issuing_a_token() {
+        let apporg = build_limited_apporg(2, SessionLimitAction::Reject);
+        let mut mock = MockCreateRefreshTokenRepository::new();
+        expect_two_pages(
+            &mut mock,
+            vec![build_token(&apporg, Some(100), false)],
+            vec![build_token(&apporg, Some(200), false)],
+        );
+        mock.expect_revoke_refresh_token().never();
+        mock.expect_generate_token_secret().never();
+        mock.expect_store_refresh_token().never();
+        mock.expect_create_pesato_token().never();
+        let service = CreateRefreshTokenServiceImpl::new(Arc::new(mock));
+
+        let err = service
+            .execute(apporg, Uuid::new_v4(), ClientMetadata::default(), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 403);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_lru_revokes_oldest_sessions_across_pages() {
+        let apporg = build_limited_apporg(3, SessionLimitAction::RevokeLeastRecentlyUsed);
+        let recent = build_token(&apporg, Some(500), false);
+        let second_oldest = build_token(&apporg, Some(100), false);
+        let already_revoked = build_token(&apporg, Some(1), true);
+        // never used, so it counts from issued_at (10)
+        let oldest = build_token(&apporg, None, false);
+        let middle = build_token(&apporg, Some(300), false);
+        let (oldest_id, second_oldest_id) = (oldest.token_id, second_oldest.token_id);
+
+        let mut mock = MockCreateRefreshTokenRepository::new();
+        expect_two_pages(
+            &mut mock,
+            vec![recent, second_oldest, already_revoked],
+            vec![oldest, middle],
+        );
+        let mut seq = Sequence::new();
+        for token_id in [oldest_id, second_oldest_id] {
+            mock.expect_revoke_refresh_token()
+                .with(
+                    eq(apporg.organization_id),
+                    eq(apporg.application_id),
+                    eq(token_id),
+                )
+                .times(1)
+                .in_sequence(&mut seq)
+                .returning(|_, _, _| Ok(()));
+        }
+        // stop right after the limit is enforced; issuing is covered elsewhere
+        mock.expect_generate_token_secret()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|| Err(RepositoryError::new("stop".to_string(), 599)));
+        let service = CreateRefreshTokenServiceImpl::new(Arc::new(mock));
+
+        let err = service
+            .execute(apporg, Uuid::new_v4(), ClientMetadata::default(), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 599);
+    }
+}
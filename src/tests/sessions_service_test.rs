@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::refresh_tokens::revoke_all::MockRevokeAllRefreshTokensRepository;
+    use crate::application::services::refresh_token::revoke_all::{
+        RevokeAllSessionsService, RevokeAllSessionsServiceImpl,
+    };
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::value_objects::client_metadata::ClientMetadata;
+    use crate::infrastructure::models::refresh_token::{
+        FoundRefreshTokenModelByUser, PaginatedRefreshTokensByUserModel,
+    };
+    use crate::tests::fixtures::build_apporg;
+    use mockall::predicate::eq;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_token(apporg: &CleanAppOrgByClientId, revoked: bool) -> FoundRefreshTokenModelByUser {
+        FoundRefreshTokenModelByUser {
+            application_id: apporg.application_id,
+            organization_id: apporg.organization_id,
+            token_id: Uuid::new_v4(),
+            encrypted_token_secret: String::new(),
+            parent_version: None,
+            issued_at: CqlTimestamp(0),
+            expires_at: CqlTimestamp(0),
+            revoked,
+            last_used_at: None,
+            device_name: None,
+            ip_address: None,
+            user_agent: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_walks_every_page_and_skips_revoked() {
+        let apporg = build_apporg();
+        let first = build_token(&apporg, false);
+        let second = build_token(&apporg, true);
+        let third = build_token(&apporg, false);
+        let (first_id, third_id) = (first.token_id, third.token_id);
+
+        let mut mock = MockRevokeAllRefreshTokensRepository::new();
+        mock.expect_find_refresh_tokens_by_user()
+            .withf(|_, _, _, _, paging| paging.is_none())
+            .returning(move |_, _, _, _, _| {
+                Ok(PaginatedRefreshTokensByUserModel {
+                    refresh_tokens: vec![first.clone(), second.clone()],
+                    paging_state: Some(vec![1]),
+                })
+            });
+        mock.expect_find_refresh_tokens_by_user()
+            .withf(|_, _, _, _, paging| paging.is_some())
+            .returning(move |_, _, _, _, _| {
+                Ok(PaginatedRefreshTokensByUserModel {
+                    refresh_tokens: vec![third.clone()],
+                    paging_state: None,
+                })
+            });
+        mock.expect_revoke_refresh_token()
+            .with(
+                eq(apporg.organization_id),
+                eq(apporg.application_id),
+                eq(first_id),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock.expect_revoke_refresh_token()
+            .with(
+                eq(apporg.organization_id),
+                eq(apporg.application_id),
+                eq(third_id),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let service = RevokeAllSessionsServiceImpl::new(Arc::new(mock));
+
+        let res = service.execute(apporg, Uuid::new_v4()).await.unwrap();
+        assert_eq!(res.revoked, 2);
+    }
+
+    #[test]
+    fn test_client_metadata_keeps_explicit_values() {
+        let explicit = ClientMetadata {
+            device_name: Some("  Work laptop ".into()),
+            ip_address: Some(" ".into()),
+            user_agent: None,
+        };
+        let request = ClientMetadata {
+            device_name: None,
+            ip_address: Some("203.0.113.7".into()),
+            user_agent: Some("curl/8.0".into()),
+        };
+        let merged = explicit.sanitized().or(request);
+        assert_eq!(merged.device_name.as_deref(), Some("Work laptop"));
+        assert_eq!(merged.ip_address.as_deref(), Some("203.0.113.7"));
+        assert_eq!(merged.user_agent.as_deref(), Some("curl/8.0"));
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::signing_keys::rotate::MockRotateSigningKeysRepository;
+    use crate::application::services::signing_keys::rotate::{
+        RotateSigningKeysService, RotateSigningKeysServiceImpl,
+    };
+    use crate::domain::entities::refresh_token::RefreshToken;
+    use crate::domain::value_objects::client_metadata::ClientMetadata;
+    use crate::infrastructure::models::signing_key::{PaginatedSigningKeysModel, SigningKeyModel};
+    use crate::infrastructure::repositories::paseto::footer_kid;
+    use crate::infrastructure::repositories::paseto::refresh_token::{
+        PasetoRepository, PasetoRepositoryImpl,
+    };
+    use crate::infrastructure::repositories::paseto::signing_key::{
+        SigningKeyPair, VerificationKey,
+    };
+    use chrono::{Duration, Utc};
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    const ROTATION_INTERVAL: i64 = 30 * 24 * 3600;
+    const OVERLAP: i64 = 31 * 24 * 3600;
+
+    fn build_key(
+        organization_id: Uuid,
+        application_id: Uuid,
+        kid: &str,
+        age: Duration,
+        expires_at: Option<i64>,
+    ) -> SigningKeyModel {
+        SigningKeyModel {
+            organization_id,
+            application_id,
+            kid: kid.into(),
+            public_key: String::new(),
+            encrypted_private_key: String::new(),
+            private_key_nonce: String::new(),
+            created_at: CqlTimestamp((Utc::now() - age).timestamp_millis()),
+            retired_at: expires_at.map(|_| CqlTimestamp(0)),
+            expires_at: expires_at.map(CqlTimestamp),
+        }
+    }
+
+    fn single_page(keys: Vec<SigningKeyModel>) -> MockRotateSigningKeysRepository {
+        let mut mock = MockRotateSigningKeysRepository::new();
+        mock.expect_scan_signing_keys()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(PaginatedSigningKeysModel {
+                    keys: keys.clone(),
+                    paging_state: None,
+                })
+            });
+        mock
+    }
+
+    #[tokio::test]
+    async fn test_rotation_replaces_stale_key_and_drops_expired() {
+        let (org, app) = (Uuid::new_v4(), Uuid::new_v4());
+        let past = (Utc::now() - Duration::days(1)).timestamp_millis();
+        let mut mock = single_page(vec![
+            build_key(org, app, "stale", Duration::days(40), None),
+            build_key(org, app, "expired", Duration::days(90), Some(past)),
+        ]);
+        mock.expect_create_signing_key()
+            .times(1)
+            .returning(move |o, a| Ok(build_key(o, a, "fresh", Duration::zero(), None)));
+        mock.expect_retire_signing_key()
+            .withf(move |o, a, kid, retired_at, expires_at| {
+                (*o, *a) == (org, app)
+                    && kid == "stale"
+                    && *expires_at == *retired_at + OVERLAP * 1000
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        mock.expect_delete_signing_key()
+            .withf(|_, _, kid| kid == "expired")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let service = RotateSigningKeysServiceImpl::new(Arc::new(mock));
+        let rotated = service.execute(ROTATION_INTERVAL, OVERLAP).await.unwrap();
+        assert_eq!(rotated, 1);
+    }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_fresh_key() {
+        let (org, app) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut mock = single_page(vec![build_key(org, app, "fresh", Duration::days(1), None)]);
+        mock.expect_create_signing_key().never();
+        mock.expect_retire_signing_key().never();
+        mock.expect_delete_signing_key().never();
+
+        let service = RotateSigningKeysServiceImpl::new(Arc::new(mock));
+        let rotated = service.execute(ROTATION_INTERVAL, OVERLAP).await.unwrap();
+        assert_eq!(rotated, 0);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_footer_names_signing_key() {
+        let signing_key = SigningKeyPair::generate().unwrap();
+        let now = time::OffsetDateTime::now_utc();
+        let format = time::format_description::well_known::Rfc3339;
+        let token = PasetoRepositoryImpl::new()
+            .encrypt(
+                &signing_key,
+                RefreshToken {
+                    token_id: Uuid::new_v4(),
+                    application_id: Uuid::new_v4(),
+                    organization_id: Uuid::new_v4(),
+                    user_id: Uuid::new_v4(),
+                    encrypted_token_secret: String::new(),
+                    token_version: "v1".into(),
+                    parent_version: None,
+                    issued_at: CqlTimestamp(0),
+                    expires_at: CqlTimestamp(0),
+                    revoked: false,
+                    last_used_at: CqlTimestamp(0),
+                    session_started_at: CqlTimestamp(0),
+                    client: ClientMetadata::default(),
+                    scope: None,
+                },
+                "secret",
+                "secret_key",
+                now.format(&format).unwrap(),
+                (now + time::Duration::days(1)).format(&format).unwrap(),
+                now.format(&format).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(footer_kid(&token), Some(signing_key.kid.clone()));
+
+        let verification_key = |public_key: Vec<u8>| VerificationKey {
+            organization_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            kid: signing_key.kid.clone(),
+            public_key,
+        };
+        let repo = PasetoRepositoryImpl::new();
+        let claims = repo
+            .decrypt(&token, &verification_key(signing_key.public_key.clone()))
+            .await
+            .unwrap();
+        assert_eq!(claims.version, "v1");
+        let other = SigningKeyPair::generate().unwrap();
+        assert!(
+            repo.decrypt(&token, &verification_key(other.public_key))
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::value_objects::app_config::AppConfig;
+    use crate::domain::value_objects::token_lifetimes::TokenLifetimes;
+
+    const DAY: i64 = 24 * 3600;
+
+    #[test]
+    fn test_missing_lifetimes_keep_previous_defaults() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{"is_must_name_unique":false,"can_allow_email_nullable":false}"#,
+        )
+        .unwrap();
+        assert_eq!(config.token_lifetimes.refresh_token_lifetime, 30 * DAY);
+        assert_eq!(config.token_lifetimes.not_before_offset, 40 * 60);
+    }
+
+    #[test]
+    fn test_expiry_is_capped_by_absolute_lifetime() {
+        let lifetimes = TokenLifetimes {
+            refresh_token_lifetime: 30 * DAY,
+            absolute_lifetime: 45 * DAY,
+            ..TokenLifetimes::default()
+        };
+        let started = 1_000_000;
+        assert_eq!(lifetimes.expires_in(started, started), 30 * DAY);
+        assert_eq!(lifetimes.expires_in(started + 40 * DAY, started), 5 * DAY);
+        assert_eq!(lifetimes.row_ttl(started + 46 * DAY, started), 1);
+    }
+
+    #[test]
+    fn test_leeway_applies_to_time_checks() {
+        let lifetimes = TokenLifetimes {
+            idle_timeout: 3600,
+            leeway: 30,
+            ..TokenLifetimes::default()
+        };
+        assert!(lifetimes.is_current(1_000, 1_020, 2_000));
+        assert!(!lifetimes.is_current(1_000, 1_031, 2_000));
+        assert!(lifetimes.is_current(2_020, 1_000, 2_000));
+        assert!(!lifetimes.is_idle(3_600 + 30, 0));
+        assert!(lifetimes.is_idle(3_600 + 31, 0));
+        assert!(!TokenLifetimes::default().is_idle(100 * DAY, 0));
+    }
+}
@@ -0,0 +1,312 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::users::delete::{
+        DeleteUserRepository, DeleteUserRepositoryImpl, MockDeleteUserRepository,
+    };
+    use crate::application::services::users::delete::{DeleteUserService, DeleteUserServiceImpl};
+    use crate::application::services::users::purge_users::{
+        PurgeUsersService, PurgeUsersServiceImpl,
+    };
+    use crate::infrastructure::models::refresh_token::{
+        FoundRefreshTokenModelByUser, PaginatedRefreshTokensByUserModel,
+    };
+    use crate::infrastructure::models::role::UserRoleModel;
+    use crate::infrastructure::models::user::{CleannedUserModel, PendingPurgeUserModel};
+    use crate::infrastructure::repositories::cache::user_permissions_repository::MockUserPermissionsCacheRepository;
+    use crate::infrastructure::repositories::cache_layer::refresh_token_repository::MockRefreshTokenCacheLayerRepository;
+    use crate::infrastructure::repositories::database::groups::MockGroupDatabaseRepository;
+    use crate::infrastructure::repositories::database::roles::MockRoleDatabaseRepository;
+    use crate::infrastructure::repositories::database::user_identity::MockUserIdentityDatabaseRepository;
+    use crate::infrastructure::repositories::database::user_repository::MockUserDatabaseRepository;
+    use crate::infrastructure::repositories::fulltext_search::user_fulltext_search::MockUserFulltextSearchRepository;
+    use chrono::{DateTime, Duration, Utc};
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn build_user(user_id: Uuid, deactivated_at: Option<CqlTimestamp>) -> CleannedUserModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        CleannedUserModel {
+            user_id,
+            organization_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            username: "alice".into(),
+            email: Some("alice@example.com".into()),
+            first_name: None,
+            last_name: None,
+            locale: None,
+            phone: None,
+            avatar_url: None,
+            metadata: None,
+            created_at: now,
+            updated_at: now,
+            is_active: deactivated_at.is_none(),
+            is_verified: true,
+            is_locked: false,
+            last_login: None,
+            mfa_enabled: false,
+            deactivated_at,
+            locked_at: None,
+        }
+    }
+
+    fn build_token(org_id: Uuid, app_id: Uuid) -> FoundRefreshTokenModelByUser {
+        let now = Utc::now().timestamp_millis();
+        FoundRefreshTokenModelByUser {
+            application_id: app_id,
+            organization_id: org_id,
+            token_id: Uuid::new_v4(),
+            encrypted_token_secret: "secret".into(),
+            parent_version: None,
+            issued_at: CqlTimestamp(now),
+            expires_at: CqlTimestamp(now + 3_600_000),
+            revoked: false,
+            last_used_at: None,
+            device_name: None,
+            ip_address: None,
+            user_agent: None,
+        }
+    }
+
+    fn day_of(timestamp_millis: i64) -> String {
+        DateTime::from_timestamp_millis(timestamp_millis)
+            .unwrap_or_default()
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_marks_user_and_revokes_tokens() {
+        let (org_id, app_id, user_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut repo = MockDeleteUserRepository::new();
+        repo.expect_find_user()
+            .returning(|_, _, user_id| Ok(Some(build_user(user_id, None))));
+        let before = Utc::now().timestamp_millis();
+        repo.expect_soft_delete_user()
+            .withf(move |o, a, u, deactivated_at| {
+                (*o, *a, *u) == (org_id, app_id, user_id) && *deactivated_at >= before
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        repo.expect_revoke_refresh_tokens()
+            .withf(move |o, a, u| (*o, *a, *u) == (org_id, app_id, user_id))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        repo.expect_purge_user().never();
+
+        let service = DeleteUserServiceImpl::new(Arc::new(repo));
+        let res = service.execute(org_id, app_id, user_id, true).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_rejects_already_deactivated_user() {
+        let mut repo = MockDeleteUserRepository::new();
+        repo.expect_find_user().returning(|_, _, user_id| {
+            Ok(Some(build_user(
+                user_id,
+                Some(CqlTimestamp(Utc::now().timestamp_millis())),
+            )))
+        });
+        repo.expect_soft_delete_user().never();
+        repo.expect_revoke_refresh_tokens().never();
+
+        let service = DeleteUserServiceImpl::new(Arc::new(repo));
+        let err = service
+            .execute(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), true)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_refresh_tokens_revokes_every_page() {
+        let (org_id, app_id, user_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let first_page = vec![build_token(org_id, app_id), build_token(org_id, app_id)];
+        let second_page = vec![build_token(org_id, app_id)];
+        let expected: Vec<Uuid> = first_page
+            .iter()
+            .chain(second_page.iter())
+            .map(|t| t.token_id)
+            .collect();
+
+        let mut refresh_tokens = MockRefreshTokenCacheLayerRepository::new();
+        refresh_tokens
+            .expect_find_refresh_token_by_user()
+            .returning(move |_, _, _, _, paging_state| {
+                Ok(match paging_state {
+                    None => PaginatedRefreshTokensByUserModel {
+                        refresh_tokens: first_page.clone(),
+                        paging_state: Some(vec![1]),
+                    },
+                    Some(_) => PaginatedRefreshTokensByUserModel {
+                        refresh_tokens: second_page.clone(),
+                        paging_state: None,
+                    },
+                })
+            });
+        refresh_tokens
+            .expect_revoke_refresh_token()
+            .withf(move |o, a, token_id| {
+                (*o, *a) == (org_id, app_id) && expected.contains(token_id)
+            })
+            .times(3)
+            .returning(|_, _, _| Ok(()));
+        refresh_tokens.expect_delete_refresh_token().never();
+
+        let repo = DeleteUserRepositoryImpl::new(
+            Arc::new(MockUserDatabaseRepository::new()),
+            Arc::new(MockRoleDatabaseRepository::new()),
+            Arc::new(refresh_tokens),
+            Arc::new(MockUserFulltextSearchRepository::new()),
+            Arc::new(MockUserIdentityDatabaseRepository::new()),
+            Arc::new(MockUserPermissionsCacheRepository::new()),
+            Arc::new(MockGroupDatabaseRepository::new()),
+        );
+        let res = repo.revoke_refresh_tokens(org_id, app_id, user_id).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_purge_only_removes_users_past_retention() {
+        let retention_days = 30;
+        let cutoff_day = day_of((Utc::now() - Duration::days(retention_days)).timestamp_millis());
+        let today = day_of(Utc::now().timestamp_millis());
+        let (org_id, app_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let expired = Uuid::new_v4();
+        let restored = Uuid::new_v4();
+        let recent = Uuid::new_v4();
+
+        let mut repo = MockDeleteUserRepository::new();
+        repo.expect_purge_day().returning(day_of);
+        let pending_day = cutoff_day.clone();
+        repo.expect_find_users_pending_purge()
+            .returning(move |day| {
+                let pending = |user_id| PendingPurgeUserModel {
+                    organization_id: org_id,
+                    application_id: app_id,
+                    user_id,
+                    deactivated_at: CqlTimestamp(0),
+                };
+                Ok(if day == pending_day {
+                    vec![pending(expired), pending(restored)]
+                } else if day == today {
+                    vec![pending(recent)]
+                } else {
+                    Vec::new()
+                })
+            });
+        repo.expect_find_user().returning(move |_, _, user_id| {
+            let deactivated_at =
+                (user_id != restored).then(|| CqlTimestamp(Utc::now().timestamp_millis()));
+            Ok(Some(build_user(user_id, deactivated_at)))
+        });
+        repo.expect_purge_user()
+            .withf(move |_, _, user_id| *user_id == expired)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        repo.expect_clear_pending_purge()
+            .withf(move |day, _, _, user_id| {
+                *day == cutoff_day && (*user_id == expired || *user_id == restored)
+            })
+            .times(2)
+            .returning(|_, _, _, _| Ok(()));
+
+        let service = PurgeUsersServiceImpl::new(Arc::new(repo));
+        let purged = service.execute(retention_days).await.unwrap();
+        assert_eq!(purged, 1);
+    }
+
+    #[tokio::test]
+    async fn test_purge_user_cascades_to_roles_and_sessions() {
+        let (org_id, app_id, user_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let role_ids = [Uuid::new_v4(), Uuid::new_v4()];
+        let tokens = vec![build_token(org_id, app_id), build_token(org_id, app_id)];
+        let token_ids: Vec<Uuid> = tokens.iter().map(|t| t.token_id).collect();
+
+        let mut roles = MockRoleDatabaseRepository::new();
+        roles.expect_get_roles_by_user().returning(move |_, _, _| {
+            Ok(role_ids
+                .iter()
+                .map(|role_id| UserRoleModel {
+                    role_id: *role_id,
+                    assigned_at: CqlTimestamp(0),
+                    expires_at: None,
+                })
+                .collect())
+        });
+        roles
+            .expect_remove_user_from_role()
+            .withf(move |_, _, role_id, u| role_ids.contains(role_id) && *u == user_id)
+            .times(2)
+            .returning(|_, _, _, _| Ok(()));
+        roles
+            .expect_remove_roles_of_user()
+            .withf(move |_, _, u| *u == user_id)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut groups = MockGroupDatabaseRepository::new();
+        groups
+            .expect_get_groups_of_user()
+            .returning(|_, _, _| Ok(Vec::new()));
+        groups
+            .expect_remove_groups_of_user()
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut permissions = MockUserPermissionsCacheRepository::new();
+        permissions
+            .expect_invalidate_cache()
+            .withf(move |_, _, users| *users == [user_id])
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut refresh_tokens = MockRefreshTokenCacheLayerRepository::new();
+        refresh_tokens
+            .expect_find_refresh_token_by_user()
+            .returning(move |_, _, _, _, _| {
+                Ok(PaginatedRefreshTokensByUserModel {
+                    refresh_tokens: tokens.clone(),
+                    paging_state: None,
+                })
+            });
+        refresh_tokens
+            .expect_delete_refresh_token()
+            .withf(move |_, _, token_id| token_ids.contains(token_id))
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+
+        let mut identities = MockUserIdentityDatabaseRepository::new();
+        identities
+            .expect_find_user_identities_by_user()
+            .returning(|_, _, _| Ok(Vec::new()));
+
+        let mut users = MockUserDatabaseRepository::new();
+        users.expect_find_raw_user().returning(|_, _, _| Ok(None));
+        users
+            .expect_delete_user()
+            .withf(move |_, _, u| *u == user_id)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut fulltext = MockUserFulltextSearchRepository::new();
+        fulltext
+            .expect_delete()
+            .withf(move |u| *u == user_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let repo = DeleteUserRepositoryImpl::new(
+            Arc::new(users),
+            Arc::new(roles),
+            Arc::new(refresh_tokens),
+            Arc::new(fulltext),
+            Arc::new(identities),
+            Arc::new(permissions),
+            Arc::new(groups),
+        );
+        let res = repo.purge_user(org_id, app_id, user_id).await;
+        assert!(res.is_ok());
+    }
+}
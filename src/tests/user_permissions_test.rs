@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::repositories::roles::get_user_permissions::MockGetUserPermissionsRepository;
+    use crate::application::services::roles::get_user_permissions::{
+        GetUserPermissionsService, GetUserPermissionsServiceImpl,
+    };
+    use crate::infrastructure::models::role::UserRoleModel;
+    use crate::tests::fixtures::{build_apporg, build_role};
+    use chrono::Utc;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_permissions_are_served_from_cache() {
+        let mut mock = MockGetUserPermissionsRepository::new();
+        mock.expect_get_cached_permissions()
+            .returning(|_, _, _| Ok(Some(vec!["users:read".into()])));
+        mock.expect_get_roles_by_user().never();
+        mock.expect_cache_permissions().never();
+
+        let service = GetUserPermissionsServiceImpl::new(Arc::new(mock));
+        let res = service
+            .execute(build_apporg(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert_eq!(res.permissions, vec!["users:read"]);
+    }
+
+    #[tokio::test]
+    async fn test_permissions_merge_all_roles_and_are_cached() {
+        let editor = Uuid::new_v4();
+        let viewer = Uuid::new_v4();
+        let deleted = Uuid::new_v4();
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+
+        let mut mock = MockGetUserPermissionsRepository::new();
+        mock.expect_get_cached_permissions()
+            .returning(|_, _, _| Ok(None));
+        mock.expect_get_roles_by_user().returning(move |_, _, _| {
+            Ok([editor, viewer, deleted]
+                .into_iter()
+                .map(|role_id| UserRoleModel {
+                    role_id,
+                    assigned_at: now,
+                    expires_at: None,
+                })
+                .collect())
+        });
+        mock.expect_get_groups_of_user()
+            .returning(|_, _, _| Ok(vec![]));
+        mock.expect_get_roles().returning(move |_, _| {
+            Ok(vec![
+                build_role(editor, &["posts:write", "posts:read"], &[]),
+                build_role(viewer, &["posts:read", "users:read"], &[]),
+            ])
+        });
+        mock.expect_cache_permissions()
+            .withf(|_, _, _, permissions, expires_at| {
+                permissions.len() == 3 && expires_at.is_none()
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+
+        let service = GetUserPermissionsServiceImpl::new(Arc::new(mock));
+        let res = service
+            .execute(build_apporg(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.permissions,
+            vec!["posts:read", "posts:write", "users:read"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_user_without_roles_has_no_permissions() {
+        let mut mock = MockGetUserPermissionsRepository::new();
+        mock.expect_get_cached_permissions()
+            .returning(|_, _, _| Ok(None));
+        mock.expect_get_roles_by_user()
+            .returning(|_, _, _| Ok(vec![]));
+        mock.expect_get_groups_of_user()
+            .returning(|_, _, _| Ok(vec![]));
+        mock.expect_get_roles().never();
+        mock.expect_cache_permissions()
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+
+        let service = GetUserPermissionsServiceImpl::new(Arc::new(mock));
+        let res = service
+            .execute(build_apporg(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert!(res.permissions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cached_permissions_expire_with_first_assignment() {
+        let (soon, later, permanent) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let now = Utc::now().timestamp_millis();
+        let first_expiry = now + 60_000;
+
+        let mut mock = MockGetUserPermissionsRepository::new();
+        mock.expect_get_cached_permissions()
+            .returning(|_, _, _| Ok(None));
+        mock.expect_get_roles_by_user().returning(move |_, _, _| {
+            Ok([
+                (later, Some(now + 3_600_000)),
+                (soon, Some(first_expiry)),
+                (permanent, None),
+            ]
+            .into_iter()
+            .map(|(role_id, expires_at)| UserRoleModel {
+                role_id,
+                assigned_at: CqlTimestamp(now),
+                expires_at: expires_at.map(CqlTimestamp),
+            })
+            .collect())
+        });
+        mock.expect_get_groups_of_user()
+            .returning(|_, _, _| Ok(vec![]));
+        mock.expect_get_roles()
+            .returning(move |_, _| Ok(vec![build_role(soon, &["posts:read"], &[])]));
+        mock.expect_cache_permissions()
+            .withf(move |_, _, _, _, expires_at| *expires_at == Some(first_expiry))
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+
+        let service = GetUserPermissionsServiceImpl::new(Arc::new(mock));
+        let res = service
+            .execute(build_apporg(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert_eq!(res.permissions, vec!["posts:read"]);
+    }
+}
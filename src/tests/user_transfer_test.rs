@@ -0,0 +1,267 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::payload::user::CreateUserPayload;
+    use crate::application::dto::response::user::CreateUserResponse;
+    use crate::application::repositories::users::create::MockCreateUserRepository;
+    use crate::application::repositories::users::get_users::MockGetUsersRepository;
+    use crate::application::repositories::users::import_users::MockImportUsersRepository;
+    use crate::application::services::users::create::{
+        CreateUserService, CreateUserServiceImpl, MockCreateUserService,
+    };
+    use crate::application::services::users::export_users::{
+        ExportUsersService, ExportUsersServiceImpl,
+    };
+    use crate::application::services::users::import_users::{
+        ImportUsersService, ImportUsersServiceImpl,
+    };
+    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::value_objects::app_config::AppConfig;
+    use crate::domain::value_objects::user_transfer::TransferFormat;
+    use crate::infrastructure::models::user::{CleannedUserModel, PaginatedUsersModel};
+    use crate::infrastructure::models::user_import::{UserImportJobModel, UserImportStatus};
+    use crate::tests::fixtures::build_apporg_with;
+    use actix_web::web::Bytes;
+    use chrono::Utc;
+    use futures::TryStreamExt;
+    use scylla::value::CqlTimestamp;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    const ARGON2_HASH: &str =
+        "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$oG0LRhNXdpUsCI0UvMjLAjs2H4pXtD5Sn8vjO/ksiOU";
+
+    fn build_apporg() -> CleanAppOrgByClientId {
+        build_apporg_with(AppConfig {
+            can_allow_email_nullable: true,
+            ..AppConfig::default()
+        })
+    }
+
+    fn build_job(apporg: &CleanAppOrgByClientId, format: TransferFormat) -> UserImportJobModel {
+        UserImportJobModel {
+            job_id: Uuid::new_v4(),
+            organization_id: apporg.organization_id,
+            application_id: apporg.application_id,
+            format,
+            status: UserImportStatus::Running,
+            processed: 0,
+            created: 0,
+            failed: 0,
+            errors: Vec::new(),
+            started_at: Utc::now().timestamp_millis(),
+            finished_at: None,
+        }
+    }
+
+    fn build_user(username: &str) -> CleannedUserModel {
+        let now = CqlTimestamp(Utc::now().timestamp_millis());
+        CleannedUserModel {
+            user_id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            application_id: Uuid::new_v4(),
+            username: username.into(),
+            email: Some(format!("{username}@example.com")),
+            first_name: None,
+            last_name: None,
+            locale: None,
+            phone: None,
+            avatar_url: None,
+            metadata: Some(r#"{"plan":"pro"}"#.into()),
+            created_at: now,
+            updated_at: now,
+            is_active: true,
+            is_verified: false,
+            is_locked: false,
+            last_login: None,
+            mfa_enabled: false,
+            deactivated_at: None,
+            locked_at: None,
+        }
+    }
+
+    fn created(user: &CreateUserPayload) -> CreateUserResponse {
+        CreateUserResponse {
+            user_id: Uuid::new_v4().to_string(),
+            username: user.username.clone(),
+            email: user.email.clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_ndjson_reports_row_errors() {
+        let apporg = build_apporg();
+        let job = build_job(&apporg, TransferFormat::Ndjson);
+        let bob = format!(r#"{{"username":"bob","password_hash":"{ARGON2_HASH}"}}"#);
+        let body = Bytes::from(format!(
+            "{}\n\n{}\n{}\nnot json\n",
+            r#"{"username":"alice","password":"correct-horse"}"#,
+            bob,
+            r#"{"username":"carol","password":"x","password_hash":"y"}"#,
+        ));
+
+        let mut create_user_service = MockCreateUserService::new();
+        create_user_service
+            .expect_execute()
+            .withf(|_, user| user.username == "alice")
+            .times(1)
+            .returning(|_, user| Ok(created(&user)));
+        create_user_service
+            .expect_execute_hashed()
+            .withf(|_, user| user.username == "bob" && user.password == ARGON2_HASH)
+            .times(1)
+            .returning(|_, user| Ok(created(&user)));
+
+        let mut repo = MockImportUsersRepository::new();
+        repo.expect_store_job()
+            .withf(|job| job.status == UserImportStatus::Completed)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ImportUsersServiceImpl::new(Arc::new(repo), Arc::new(create_user_service));
+        let report = service.run(apporg, job, body).await.unwrap();
+
+        assert_eq!(report.processed, 4);
+        assert_eq!(report.created, 2);
+        assert_eq!(report.failed, 2);
+        assert_eq!(report.errors[0].row, 3);
+        assert!(report.errors[0].message.contains("not both"));
+        assert_eq!(report.errors[1].row, 4);
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_decodes_metadata_and_keeps_service_errors() {
+        let apporg = build_apporg();
+        let job = build_job(&apporg, TransferFormat::Csv);
+        let body = Bytes::from(
+            "username,email,password,metadata\n\
+             alice,alice@example.com,correct-horse,\"{\"\"plan\"\":\"\"pro\"\"}\"\n\
+             bob,bob@example.com,correct-horse,\n",
+        );
+
+        let mut create_user_service = MockCreateUserService::new();
+        create_user_service
+            .expect_execute()
+            .withf(|_, user| user.username == "alice" && user.metadata["plan"] == "pro")
+            .returning(|_, user| Ok(created(&user)));
+        create_user_service
+            .expect_execute()
+            .withf(|_, user| user.username == "bob" && user.metadata.is_empty())
+            .returning(|_, _| {
+                Err(
+                    crate::domain::errors::repositories_errors::RepositoryError::new(
+                        "email already used".into(),
+                        400,
+                    ),
+                )
+            });
+
+        let mut repo = MockImportUsersRepository::new();
+        repo.expect_store_job().returning(|_| Ok(()));
+
+        let service = ImportUsersServiceImpl::new(Arc::new(repo), Arc::new(create_user_service));
+        let report = service.run(apporg, job, body).await.unwrap();
+
+        assert_eq!(report.created, 1);
+        assert_eq!(report.errors[0].row, 2);
+        assert_eq!(report.errors[0].message, "email already used");
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_requires_username_column() {
+        let mut repo = MockImportUsersRepository::new();
+        repo.expect_store_job().never();
+
+        let service =
+            ImportUsersServiceImpl::new(Arc::new(repo), Arc::new(MockCreateUserService::new()));
+        let err = service
+            .start(
+                build_apporg(),
+                TransferFormat::Csv,
+                Bytes::from("email,password\nalice@example.com,correct-horse\n"),
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_import_job_is_scoped_to_application() {
+        let apporg = build_apporg();
+        let job = build_job(&build_apporg(), TransferFormat::Ndjson);
+        let job_id = job.job_id;
+
+        let mut repo = MockImportUsersRepository::new();
+        repo.expect_find_job()
+            .returning(move |_| Ok(Some(job.clone())));
+
+        let service =
+            ImportUsersServiceImpl::new(Arc::new(repo), Arc::new(MockCreateUserService::new()));
+        let err = service.get_job(apporg, job_id).await.unwrap_err();
+
+        assert_eq!(err.code, 404);
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_streams_every_page() {
+        let mut repo = MockGetUsersRepository::new();
+        repo.expect_find_all_users_paginated()
+            .withf(|_, _, _, paging_state| paging_state.is_none())
+            .times(1)
+            .returning(|_, _, _, _| {
+                Ok(PaginatedUsersModel {
+                    users: vec![build_user("alice"), build_user("bob")],
+                    paging_state: Some(vec![1]),
+                })
+            });
+        repo.expect_find_all_users_paginated()
+            .withf(|_, _, _, paging_state| paging_state.as_deref() == Some(&[1][..]))
+            .times(1)
+            .returning(|_, _, _, _| {
+                Ok(PaginatedUsersModel {
+                    users: vec![build_user("carol")],
+                    paging_state: None,
+                })
+            });
+
+        let service = ExportUsersServiceImpl::new(Arc::new(repo));
+        let chunks: Vec<Bytes> = service
+            .execute(Uuid::new_v4(), Uuid::new_v4(), TransferFormat::Csv)
+            .try_collect()
+            .await
+            .unwrap();
+        let csv = String::from_utf8(chunks.concat()).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("user_id,username,email"));
+        assert!(lines[3].contains(",carol,"));
+        assert!(lines[1].contains(r#""{""plan"":""pro""}""#));
+    }
+
+    #[tokio::test]
+    async fn test_create_hashed_rejects_unknown_hash() {
+        let mut mock = MockCreateUserRepository::new();
+        mock.expect_find_user_by_email()
+            .returning(|_, _, _| Ok(None));
+        mock.expect_is_supported_hash().returning(|_| false);
+        mock.expect_hash_password().never();
+        mock.expect_create_user().never();
+
+        let service = CreateUserServiceImpl::new(Arc::new(mock));
+        let payload = CreateUserPayload {
+            username: "alice".into(),
+            email: Some("alice@example.com".into()),
+            password: "$2b$12$notanargon2hash".into(),
+            profile: Default::default(),
+            metadata: Default::default(),
+        };
+        let err = service
+            .execute_hashed(build_apporg(), payload)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.message, "password hash is not supported");
+    }
+}
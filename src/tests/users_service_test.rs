@@ -4,7 +4,7 @@ mod tests {
     use crate::application::dto::response::user::CreateUserResponse;
     use crate::application::repositories::users::create::MockCreateUserRepository;
     use crate::application::services::users::create::{CreateUserService, CreateUserServiceImpl};
-    use crate::domain::entities::apporg_client_id::CleanAppOrgByClientId;
+    use crate::domain::entities::apporg_client_id::{CleanAppOrgByClientId, HasAppConfig};
     use crate::domain::entities::user::User;
     use crate::domain::entities::user_organization::UserOrganization;
     use crate::domain::value_objects::app_config::AppConfig;
@@ -49,6 +49,7 @@ mod tests {
             application_config: AppConfig {
                 can_allow_email_nullable: can_email_null,
                 is_must_name_unique: must_name_unique,
+                ..AppConfig::default()
             }
             .to_string(),
             contact_email: "admin@test.org".into(),
@@ -63,6 +64,8 @@ mod tests {
             email,
             username: username.into(),
             password: "secret".into(),
+            profile: Default::default(),
+            metadata: Default::default(),
         }
     }
 
@@ -119,7 +122,7 @@ mod tests {
         let service = CreateUserServiceImpl::new(Arc::new(mock));
 
         let payload = build_payload(Some("mika@example.com".into()), "duplicate");
-        let apporg = build_apporg(true, false); // username must be unique
+        let apporg = build_apporg(true, true); // username must be unique
 
         let res = service.execute(apporg, payload).await;
         assert!(res.is_err());
@@ -155,7 +158,10 @@ mod tests {
         mock.expect_new_user_organization()
             .returning(move |_, _| uorg.clone());
 
+        mock.expect_reserve_email()
+            .returning(|_, _, _, _| Ok(true));
         mock.expect_create_user().returning(|_| Ok(()));
+        mock.expect_index_user().returning(|_, _| Ok(()));
 
         let service = CreateUserServiceImpl::new(Arc::new(mock));
 
@@ -169,4 +175,55 @@ mod tests {
         assert_eq!(user_response.username, username);
         assert_eq!(user_response.email, email);
     }
+
+    #[tokio::test]
+    async fn test_username_reservation_lost() {
+        let mut mock = MockCreateUserRepository::new();
+        mock.expect_find_user_by_email()
+            .returning(|_, _, _| Ok(None));
+        mock.expect_find_user_by_username()
+            .returning(|_, _, _| Ok(None));
+        mock.expect_hash_password()
+            .returning(|_| Ok("hashed".into()));
+        mock.expect_new_user()
+            .returning(|_, _, _| User::fake());
+        // a concurrent signup claimed the username between the read and the insert
+        mock.expect_reserve_username()
+            .returning(|_, _, _, _| Ok(false));
+        mock.expect_create_user().never();
+
+        let service = CreateUserServiceImpl::new(Arc::new(mock));
+
+        let payload = build_payload(Some("mika@example.com".into()), "racer");
+        let apporg = build_apporg(true, true);
+
+        let res = service.execute(apporg, payload).await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_metadata_rejected_by_schema() {
+        let mock = MockCreateUserRepository::new();
+        let service = CreateUserServiceImpl::new(Arc::new(mock));
+
+        let mut apporg = build_apporg(true, false);
+        let mut config = apporg.get_config().unwrap();
+        config.user_metadata_schema = Some(
+            serde_json::from_value(serde_json::json!({
+                "properties": { "tier": { "type": "string", "required": true } }
+            }))
+            .unwrap(),
+        );
+        apporg.set_config(&config).unwrap();
+
+        let mut payload = build_payload(Some("mika@example.com".into()), "mikachan");
+        payload
+            .metadata
+            .insert("tier".into(), serde_json::json!(3));
+
+        let res = service.execute(apporg, payload).await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().code, 400);
+    }
 }